@@ -1,6 +1,8 @@
+use crate::format::*;
 use crate::*;
 use std::mem::*;
 use std::ops::*;
+use std::path::*;
 
 /// Provides the ability to process data directly from memory.
 pub struct ByteBuffer;
@@ -8,6 +10,10 @@ pub struct ByteBuffer;
 impl AdapterDescriptor<Input> for ByteBuffer {
     type Configuration<'a> = &'a [u8];
     type Handler = ExternalHandler;
+
+    fn input_size(config: &&[u8]) -> Option<usize> {
+        Some(config.len())
+    }
 }
 
 impl AdapterDescriptor<Output> for ByteBuffer {
@@ -34,7 +40,20 @@ pub struct ByteBufferOutputAdapterConfig<'a> {
     old: Box<[u8]>,
 }
 
+// The native configuration only points into the referenced output buffer.
+unsafe impl<'a> Send for ByteBufferOutputAdapterConfig<'a> {}
+
 impl<'a> ByteBufferOutputAdapterConfig<'a> {
+    /// Replaces the referenced output buffer with the provided bytes, as though the adapter had written them.
+    pub fn replace_output(&mut self, bytes: Box<[u8]>) {
+        *self.output = Some(bytes);
+    }
+
+    /// The bytes which the adapter wrote to the referenced output buffer, if it has written any.
+    pub fn written(&self) -> Option<&[u8]> {
+        self.output.as_deref()
+    }
+
     /// Allocates a zeroed segment of memory with the default Rust allocator.
     extern "C" fn allocate(len: usize) -> *mut c_void {
         Box::into_raw(vec![0; len].into_boxed_slice()) as *mut c_void
@@ -75,326 +94,6597 @@ impl<'a> Drop for ByteBufferOutputAdapterConfig<'a> {
     }
 }
 
-/// Provides the ability to process data from a storage device.
-pub struct File;
+/// Provides the ability to read data from memory which is owned by the adapter context, so that the
+/// adapter context does not borrow it. Adapter contexts created with clones of the same shared buffer
+/// read from it without copying.
+pub struct ByteBufferOwned;
 
-impl AdapterDescriptor<Input> for File {
-    type Configuration<'a> = FileInputAdapterConfig;
-    type Handler = ExternalHandler;
-}
+impl AdapterDescriptor<Input> for ByteBufferOwned {
+    type Configuration<'a> = Arc<[u8]>;
+    type Handler = ByteBufferOwnedHandler;
 
-impl AdapterDescriptor<Output> for File {
-    type Configuration<'a> = FileOutputAdapterConfig;
-    type Handler = ExternalHandler;
+    fn input_size(config: &Arc<[u8]>) -> Option<usize> {
+        Some(config.len())
+    }
 }
 
-impl NamedAdapter for File {
+impl NamedAdapter for ByteBufferOwned {
     fn name() -> &'static str {
-        "file"
+        "byte_buffer_owned"
     }
 }
 
-/// Specifies the way that a file adapter will read from storage.
-#[derive(Clone, Debug)]
-#[repr(C)]
-pub struct FileInputAdapterConfig {
-    /// A configuration describing the file that the adapter should use. This member must come first
-    /// in order for the native adapter to use it.
-    config: gvox_sys::GvoxFileInputAdapterConfig,
-    /// The name of this file. This must outlive `config`, which references the underlying buffer.
-    file_name: CString,
+/// Handles the adapter context operations of owned byte buffer inputs.
+pub struct ByteBufferOwnedHandler {
+    /// The bytes from which input is read.
+    bytes: Arc<[u8]>,
 }
 
-impl FileInputAdapterConfig {
-    /// Create a new file input for the given file name and byte offset.
-    pub fn new(file_name: impl Into<String>, byte_offset: usize) -> Self {
-        let name = Into::<String>::into(file_name);
-        let file_name = CString::new(name).expect("Could not convert file name to C string.");
-        let config = gvox_sys::GvoxFileInputAdapterConfig {
-            filepath: file_name.as_ptr(),
-            byte_offset,
-        };
-
-        Self { file_name, config }
+impl BaseAdapterHandler<Input, ByteBufferOwned> for ByteBufferOwnedHandler {
+    fn create(config: &Arc<[u8]>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            bytes: config.clone(),
+        })
     }
-}
 
-/// Specifies the way that a file adapter will write to storage.
-#[derive(Clone, Debug)]
-#[repr(C)]
-pub struct FileOutputAdapterConfig {
-    /// A configuration describing the file that the adapter should use. This member must come first
-    /// in order for the native adapter to use it.
-    config: gvox_sys::GvoxFileOutputAdapterConfig,
-    /// The name of this file. This must outlive `config`, which references the underlying buffer.
-    file_name: CString,
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
 }
 
-impl FileOutputAdapterConfig {
-    /// Create a new file output for the given file name.
-    pub fn new(file_name: impl Into<String>) -> Self {
-        let name = Into::<String>::into(file_name);
-        let file_name = CString::new(name).expect("Could not convert file name to C string.");
-        let config = gvox_sys::GvoxFileOutputAdapterConfig {
-            filepath: file_name.as_ptr(),
-        };
-
-        Self { file_name, config }
+impl InputAdapterHandler<ByteBufferOwned> for ByteBufferOwnedHandler {
+    fn read(
+        &mut self,
+        _: &InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), GvoxError> {
+        let source = position
+            .checked_add(data.len())
+            .and_then(|end| self.bytes.get(position..end))
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::InputAdapter,
+                    format!(
+                        "Could not read {} bytes at position {position} of a {} byte buffer.",
+                        data.len(),
+                        self.bytes.len()
+                    ),
+                )
+            })?;
+        data.copy_from_slice(source);
+        Ok(())
     }
 }
 
-/// Converts voxels to a text visualization which may be displayed in a console.
-pub struct ColoredText;
+/// Decompresses gzip, zlib, or raw deflate compressed input from an inner source. Since parse adapters read
+/// at arbitrary positions, the input is decompressed lazily, and the decompressed stream is cached up to the
+/// furthest position which has been read. Reads which would decompress more than
+/// [`GzipInputAdapterConfig::with_max_decompressed_bytes`] bytes fail instead.
+#[cfg(feature = "flate2")]
+pub struct GzipInput;
 
-impl AdapterDescriptor<Serialize> for ColoredText {
-    type Configuration<'a> = ColoredTextSerializeAdapterConfig;
-    type Handler = ExternalHandler;
+#[cfg(feature = "flate2")]
+impl AdapterDescriptor<Input> for GzipInput {
+    type Configuration<'a> = GzipInputAdapterConfig<'a>;
+    type Handler = GzipInputHandler;
 }
 
-impl NamedAdapter for ColoredText {
+#[cfg(feature = "flate2")]
+impl NamedAdapter for GzipInput {
     fn name() -> &'static str {
-        "colored_text"
+        "gzip"
     }
 }
 
-/// Describes how voxels should be downscaled when creating a visualization.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-#[repr(i32)]
-pub enum ColoredTextSerializeAdapterDownscaleMode {
-    /// The nearest voxel's value should be taken during filtering.
-    Nearest = 0,
-    /// Linear blending should be utilized to filter the voxels.
-    Linear = 1,
+/// Describes the way in which the input of a [`GzipInput`] adapter is compressed.
+#[cfg(feature = "flate2")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CompressedInputFormat {
+    /// The input is one or more gzip members, which are decompressed as a single stream.
+    #[default]
+    Gzip,
+    /// The input is a zlib stream.
+    Zlib,
+    /// The input is a raw deflate stream, without any header.
+    Deflate,
 }
 
-/// Provides settings for controlling how voxels are visualized as colored text.
-#[derive(Debug, Copy, Clone)]
-#[repr(C)]
-pub struct ColoredTextSerializeAdapterConfig {
-    /// The factor by which voxels should be downscaled.
-    pub downscale_factor: u32,
-    /// The filtering mode that should be employed during downscaling.
-    pub downscale_mode: ColoredTextSerializeAdapterDownscaleMode,
-    /// The value that should be considered greatest when handling non-color data.
-    pub non_color_max_value: u32,
-    /// Whether each layer should be printed below the last, as opposed to the right of the last
-    pub vertical: bool,
+/// The number of bytes that a [`GzipInput`] adapter decompresses at most, unless another limit is configured.
+#[cfg(feature = "flate2")]
+pub const DEFAULT_MAX_DECOMPRESSED_BYTES: usize = 1 << 30;
+
+/// A compressed source from which a [`GzipInput`] adapter reads.
+#[cfg(feature = "flate2")]
+pub type GzipInputSource<'a> = Box<dyn std::io::Read + Send + 'a>;
+
+/// Describes the compressed source from which a [`GzipInput`] adapter reads.
+#[cfg(feature = "flate2")]
+pub struct GzipInputAdapterConfig<'a> {
+    /// The source, until it is moved into the adapter context that is created with this configuration.
+    source: MoveOnce<GzipInputSource<'a>>,
+    /// The way in which the source is compressed.
+    format: CompressedInputFormat,
+    /// The number of bytes that may be decompressed from the source at most.
+    max_decompressed_bytes: usize,
 }
 
-impl Default for ColoredTextSerializeAdapterConfig {
-    fn default() -> Self {
+#[cfg(feature = "flate2")]
+impl<'a> GzipInputAdapterConfig<'a> {
+    /// Creates a configuration which decompresses gzip data from the provided reader. The reader is read
+    /// sequentially, so any other input, such as a closure, may be used by wrapping it in a reader.
+    pub fn new(source: impl std::io::Read + Send + 'a) -> Self {
         Self {
-            downscale_factor: 1,
-            downscale_mode: ColoredTextSerializeAdapterDownscaleMode::Nearest,
-            non_color_max_value: 0,
-            vertical: false,
+            source: MoveOnce::new(Box::new(source)),
+            format: CompressedInputFormat::default(),
+            max_decompressed_bytes: DEFAULT_MAX_DECOMPRESSED_BYTES,
         }
     }
-}
 
-/// Prints voxel data directly to the standard console output.
-pub struct StdOut;
+    /// Sets the way in which the source is compressed.
+    pub fn with_format(mut self, format: CompressedInputFormat) -> Self {
+        self.format = format;
+        self
+    }
 
-impl AdapterDescriptor<Output> for StdOut {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+    /// Sets the number of bytes that may be decompressed from the source at most, which is
+    /// [`DEFAULT_MAX_DECOMPRESSED_BYTES`] by default. Reads which would decompress the input past
+    /// the limit fail with [`ErrorType::InputAdapter`], so that small inputs which decompress to
+    /// enormous sizes cannot exhaust memory.
+    pub fn with_max_decompressed_bytes(mut self, max_decompressed_bytes: usize) -> Self {
+        self.max_decompressed_bytes = max_decompressed_bytes;
+        self
+    }
 }
 
-impl NamedAdapter for StdOut {
-    fn name() -> &'static str {
-        "stdout"
+#[cfg(feature = "flate2")]
+impl<'a> From<&'a [u8]> for GzipInputAdapterConfig<'a> {
+    fn from(value: &'a [u8]) -> Self {
+        Self::new(value)
     }
 }
 
-/// Handles conversions from the tagged gvox format.
-pub struct GvoxPalette;
-
-impl AdapterDescriptor<Parse> for GvoxPalette {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+/// Handles the adapter context operations of compressed inputs.
+#[cfg(feature = "flate2")]
+pub struct GzipInputHandler {
+    /// The decompressor of the source. Its borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    decoder: GzipInputSource<'static>,
+    /// The input which has been decompressed so far.
+    inflated: Vec<u8>,
+    /// Whether the end of the decompressed input has been reached.
+    finished: bool,
+    /// The number of bytes that may be decompressed at most.
+    max_decompressed_bytes: usize,
 }
 
-impl AdapterDescriptor<Serialize> for GvoxPalette {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
-}
+#[cfg(feature = "flate2")]
+impl GzipInputHandler {
+    /// The number of bytes which are decompressed at a time.
+    const CHUNK_SIZE: usize = 64 * 1024;
 
-impl NamedAdapter for GvoxPalette {
-    fn name() -> &'static str {
-        "gvox_palette"
+    /// Decompresses the input until at least `end` bytes are available, or the input ends. Returns an
+    /// error if the input continues past the decompression limit.
+    fn inflate_to(&mut self, end: usize) -> Result<(), GvoxError> {
+        while self.inflated.len() < end && !self.finished {
+            let start = self.inflated.len();
+            // At most one byte past the limit is decompressed, which shows whether the input exceeds it.
+            let remaining = (self.max_decompressed_bytes - start).saturating_add(1);
+            let size = Self::CHUNK_SIZE.min(remaining);
+            self.inflated.resize(start + size, 0);
+            let count = match self.decoder.read(&mut self.inflated[start..]) {
+                Ok(count) => {
+                    self.finished = count == 0;
+                    count
+                }
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => 0,
+                Err(error) => {
+                    self.inflated.truncate(start);
+                    return Err(GvoxError::new(
+                        ErrorType::InputAdapter,
+                        format!("Failed to decompress input: {error}"),
+                    ));
+                }
+            };
+            self.inflated.truncate(start + count);
+
+            if self.inflated.len() > self.max_decompressed_bytes {
+                self.inflated.truncate(self.max_decompressed_bytes);
+                return Err(GvoxError::new(
+                    ErrorType::InputAdapter,
+                    format!(
+                        "Decompressed input exceeded the limit of {} bytes.",
+                        self.max_decompressed_bytes
+                    ),
+                ));
+            }
+        }
+        Ok(())
     }
 }
 
-/// Handles conversions from the raw gvox format.
-pub struct GvoxRaw;
+#[cfg(feature = "flate2")]
+impl BaseAdapterHandler<Input, GzipInput> for GzipInputHandler {
+    fn create(config: &GzipInputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        let source = config
+            .source
+            .take("source of a compressed input configuration")?;
+        let decoder: GzipInputSource<'_> = match config.format {
+            CompressedInputFormat::Gzip => Box::new(flate2::read::MultiGzDecoder::new(source)),
+            CompressedInputFormat::Zlib => Box::new(flate2::read::ZlibDecoder::new(source)),
+            CompressedInputFormat::Deflate => Box::new(flate2::read::DeflateDecoder::new(source)),
+        };
 
-impl AdapterDescriptor<Parse> for GvoxRaw {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
-}
+        Ok(Self {
+            decoder: unsafe { extend_lifetimes(decoder) },
+            inflated: Vec::new(),
+            finished: false,
+            max_decompressed_bytes: config.max_decompressed_bytes,
+        })
+    }
 
-impl AdapterDescriptor<Serialize> for GvoxRaw {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
 }
 
-impl NamedAdapter for GvoxRaw {
-    fn name() -> &'static str {
-        "gvox_raw"
+#[cfg(feature = "flate2")]
+impl InputAdapterHandler<GzipInput> for GzipInputHandler {
+    fn read(
+        &mut self,
+        _: &InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), GvoxError> {
+        let end = position.checked_add(data.len()).ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::InputAdapter,
+                "Read range of compressed input overflowed.",
+            )
+        })?;
+        self.inflate_to(end)?;
+
+        let source = self.inflated.get(position..end).ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::InputAdapter,
+                format!(
+                    "Could not read {} bytes at position {position} of {} bytes of decompressed input.",
+                    data.len(),
+                    self.inflated.len()
+                ),
+            )
+        })?;
+        data.copy_from_slice(source);
+        Ok(())
     }
 }
 
-/// Handles conversions for Gvox Brickmap files.
-pub struct GvoxBrickmap;
+/// Reads input data from any Rust reader which can seek, such as a [`std::fs::File`] or a [`std::io::Cursor`].
+/// Readers may be owned by the adapter context, or mutably borrowed for as long as the adapter context is alive.
+pub struct IoReader;
 
-impl AdapterDescriptor<Parse> for GvoxBrickmap {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
-}
+impl AdapterDescriptor<Input> for IoReader {
+    type Configuration<'a> = IoReaderAdapterConfig<'a>;
+    type Handler = IoReaderHandler;
 
-impl AdapterDescriptor<Serialize> for GvoxBrickmap {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+    fn input_size(config: &IoReaderAdapterConfig<'_>) -> Option<usize> {
+        config.size
+    }
 }
 
-impl NamedAdapter for GvoxBrickmap {
+impl NamedAdapter for IoReader {
     fn name() -> &'static str {
-        "gvox_brickmap"
+        "io_reader"
     }
 }
 
-/// Handles conversions for Gvox Global Palette files.
-pub struct GvoxGlobalPalette;
+/// A reader which can also seek, from which an [`IoReader`] adapter reads.
+pub trait ReadSeek: std::io::Read + std::io::Seek {}
 
-impl AdapterDescriptor<Parse> for GvoxGlobalPalette {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
-}
+impl<T: std::io::Read + std::io::Seek + ?Sized> ReadSeek for T {}
 
-impl AdapterDescriptor<Serialize> for GvoxGlobalPalette {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+/// A seekable reader from which an [`IoReader`] adapter reads.
+pub type IoReaderSource<'a> = Box<dyn ReadSeek + Send + 'a>;
+
+/// Describes the reader from which an [`IoReader`] adapter reads.
+pub struct IoReaderAdapterConfig<'a> {
+    /// The reader, until it is moved into the adapter context that is created with this configuration.
+    reader: MoveOnce<IoReaderSource<'a>>,
+    /// The length of the reader's stream, if it could be determined.
+    size: Option<usize>,
 }
 
-impl NamedAdapter for GvoxGlobalPalette {
-    fn name() -> &'static str {
-        "gvox_global_palette"
+impl<'a> IoReaderAdapterConfig<'a> {
+    /// Creates a configuration which reads from the provided reader. The length of the input is
+    /// determined by seeking to the end of the reader.
+    pub fn new(reader: impl ReadSeek + Send + 'a) -> Self {
+        Self::from(Box::new(reader) as IoReaderSource<'a>)
     }
 }
 
-/// Handles conversions for Gvox Octree files.
-pub struct GvoxOctree;
-
-impl AdapterDescriptor<Parse> for GvoxOctree {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+impl<'a> From<IoReaderSource<'a>> for IoReaderAdapterConfig<'a> {
+    fn from(mut value: IoReaderSource<'a>) -> Self {
+        let size = value
+            .seek(std::io::SeekFrom::End(0))
+            .ok()
+            .and_then(|x| usize::try_from(x).ok());
+        Self {
+            reader: MoveOnce::new(value),
+            size,
+        }
+    }
 }
 
-impl AdapterDescriptor<Serialize> for GvoxOctree {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+/// Handles the adapter context operations of seekable reader inputs.
+pub struct IoReaderHandler {
+    /// The reader from which input is read. Its borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    reader: IoReaderSource<'static>,
+    /// The current position of the reader, if it is known. Reads which begin here need not seek.
+    position: Option<u64>,
 }
 
-impl NamedAdapter for GvoxOctree {
-    fn name() -> &'static str {
-        "gvox_octree"
+impl BaseAdapterHandler<Input, IoReader> for IoReaderHandler {
+    fn create(config: &IoReaderAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            reader: unsafe {
+                config
+                    .reader
+                    .take_extended("reader of a reader input configuration")?
+            },
+            position: None,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
     }
 }
 
-/// Handles conversions for Gvox RLE files.
-pub struct GvoxRunLengthEncoding;
+impl InputAdapterHandler<IoReader> for IoReaderHandler {
+    fn read(
+        &mut self,
+        _: &InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), GvoxError> {
+        let start = position as u64;
+        let result = if self.position == Some(start) {
+            self.reader.read_exact(data)
+        } else {
+            self.reader
+                .seek(std::io::SeekFrom::Start(start))
+                .and_then(|_| self.reader.read_exact(data))
+        };
 
-impl AdapterDescriptor<Parse> for GvoxRunLengthEncoding {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+        match result {
+            Ok(()) => {
+                self.position = Some(start + data.len() as u64);
+                Ok(())
+            }
+            Err(error) => {
+                self.position = None;
+                let message = if error.kind() == std::io::ErrorKind::UnexpectedEof {
+                    format!(
+                        "Could not read {} bytes at position {position}, since the input ended first.",
+                        data.len()
+                    )
+                } else {
+                    format!("Failed to read from the input: {error}")
+                };
+                Err(GvoxError::new(ErrorType::InputAdapter, message))
+            }
+        }
+    }
 }
 
-impl AdapterDescriptor<Serialize> for GvoxRunLengthEncoding {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+/// Reads input data from an ordered sequence of byte segments, as though they were one contiguous buffer.
+/// Segments may be borrowed for as long as the adapter context is alive, or owned by it.
+pub struct ChainedInput;
+
+impl AdapterDescriptor<Input> for ChainedInput {
+    type Configuration<'a> = ChainedInputAdapterConfig<'a>;
+    type Handler = ChainedInputHandler;
+
+    fn input_size(config: &ChainedInputAdapterConfig<'_>) -> Option<usize> {
+        Some(config.size)
+    }
 }
 
-impl NamedAdapter for GvoxRunLengthEncoding {
+impl NamedAdapter for ChainedInput {
     fn name() -> &'static str {
-        "gvox_run_length_encoding"
+        "chained"
     }
 }
 
-/// Handles conversions for MagicaVoxel files.
-pub struct MagicaVoxel;
+/// Describes the segments from which a chained input adapter reads.
+pub struct ChainedInputAdapterConfig<'a> {
+    /// The segments, until they are moved into the adapter context that is created with this configuration.
+    segments: MoveOnce<Vec<std::borrow::Cow<'a, [u8]>>>,
+    /// The combined length of the segments.
+    size: usize,
+}
 
-impl AdapterDescriptor<Parse> for MagicaVoxel {
-    type Configuration<'a> = ();
-    type Handler = ExternalHandler;
+impl<'a> ChainedInputAdapterConfig<'a> {
+    /// Creates a configuration which reads from the provided segments, in order.
+    pub fn new(segments: impl IntoIterator<Item = impl Into<std::borrow::Cow<'a, [u8]>>>) -> Self {
+        let segments = segments.into_iter().map(Into::into).collect::<Vec<_>>();
+        Self {
+            size: segments.iter().map(|x| x.len()).sum(),
+            segments: MoveOnce::new(segments),
+        }
+    }
 }
 
-impl NamedAdapter for MagicaVoxel {
-    fn name() -> &'static str {
-        "magicavoxel"
+impl<'a> From<Vec<&'a [u8]>> for ChainedInputAdapterConfig<'a> {
+    fn from(value: Vec<&'a [u8]>) -> Self {
+        Self::new(value)
     }
 }
 
-/// Handles conversions for Voxlap and Ace of Spades files.
-pub struct Voxlap;
+impl From<Vec<Vec<u8>>> for ChainedInputAdapterConfig<'_> {
+    fn from(value: Vec<Vec<u8>>) -> Self {
+        Self::new(value)
+    }
+}
 
-impl AdapterDescriptor<Parse> for Voxlap {
-    type Configuration<'a> = VoxlapParseAdapterConfig;
-    type Handler = ExternalHandler;
+/// Handles the adapter context operations of chained inputs.
+pub struct ChainedInputHandler {
+    /// The segments from which input is read. Their borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    segments: Vec<std::borrow::Cow<'static, [u8]>>,
+    /// The position at which each segment ends, within the combined input.
+    ends: Vec<usize>,
 }
 
-impl NamedAdapter for Voxlap {
-    fn name() -> &'static str {
-        "voxlap"
+impl BaseAdapterHandler<Input, ChainedInput> for ChainedInputHandler {
+    fn create(config: &ChainedInputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        let segments: Vec<std::borrow::Cow<'static, [u8]>> = unsafe {
+            config
+                .segments
+                .take_extended("segments of a chained input configuration")?
+        };
+        let ends = segments
+            .iter()
+            .scan(0, |end, x| {
+                *end += x.len();
+                Some(*end)
+            })
+            .collect();
+
+        Ok(Self { segments, ends })
     }
-}
 
-/// Describes how Voxlap data should be parsed.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct VoxlapParseAdapterConfig {
-    /// The dimensions of the input data.
-    pub size: Extent3D,
-    /// Whether to fill in the inside of objects, or leave them hollow.
-    pub make_solid: bool,
-    /// Whether this an Ace of Spades file. Ace of Spades files do not have a header.
-    pub is_ace_of_spades: bool,
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
 }
 
-impl Default for VoxlapParseAdapterConfig {
-    fn default() -> Self {
-        Self {
-            size: Extent3D {
-                x: 1024,
-                y: 1024,
-                z: 256,
-            },
-            make_solid: true,
-            is_ace_of_spades: Default::default(),
+impl InputAdapterHandler<ChainedInput> for ChainedInputHandler {
+    fn read(
+        &mut self,
+        _: &InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), GvoxError> {
+        let size = self.ends.last().copied().unwrap_or(0);
+        if position
+            .checked_add(data.len())
+            .filter(|&end| end <= size)
+            .is_none()
+        {
+            return Err(GvoxError::new(
+                ErrorType::InputAdapter,
+                format!(
+                    "Could not read {} bytes at position {position} of a {size} byte chained input.",
+                    data.len()
+                ),
+            ));
         }
+
+        // Segments which end at or before the position cannot contain any of the requested bytes.
+        let mut index = self.ends.partition_point(|&end| end <= position);
+        let mut written = 0;
+        while written < data.len() {
+            let start = self.ends[index] - self.segments[index].len();
+            let source = &self.segments[index][position + written - start..];
+            let count = source.len().min(data.len() - written);
+            data[written..written + count].copy_from_slice(&source[..count]);
+            written += count;
+            index += 1;
+        }
+        Ok(())
     }
 }
 
-/// Handles conversions for Voxlap and Ace of Spades files.
-pub struct Kvx;
+/// Restricts reads to a window of an inner source, so that a payload embedded within a larger container may be
+/// parsed without copying it, and without allowing the parser to read the surrounding container. Reads are
+/// relative to the beginning of the window, and any read which extends past its end fails.
+/// The native [`ByteBuffer`] and [`File`] adapters cannot be forwarded to, so windows are instead created over
+/// the same bytes or file with [`WindowedInputAdapterConfig::from_bytes`] or [`WindowedInputAdapterConfig::from_file`].
+pub struct WindowedInput;
 
-impl AdapterDescriptor<Parse> for Kvx {
-    type Configuration<'a> = KvxParseAdapterConfig;
-    type Handler = ExternalHandler;
+impl AdapterDescriptor<Input> for WindowedInput {
+    type Configuration<'a> = WindowedInputAdapterConfig<'a>;
+    type Handler = WindowedInputHandler;
+
+    fn input_size(config: &WindowedInputAdapterConfig<'_>) -> Option<usize> {
+        Some(config.len)
+    }
 }
 
-impl NamedAdapter for Kvx {
+impl NamedAdapter for WindowedInput {
     fn name() -> &'static str {
-        "kvx"
+        "windowed"
     }
 }
 
-/// Describes how Kvx data should be parsed.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub struct KvxParseAdapterConfig {
-    pub mipmaplevels: u8,
+/// Describes the source and window from which a windowed input adapter reads.
+pub struct WindowedInputAdapterConfig<'a> {
+    /// The source, until it is moved into the adapter context that is created with this configuration.
+    source: MoveOnce<IoReaderSource<'a>>,
+    /// The position within the source at which the window begins.
+    offset: usize,
+    /// The length of the window.
+    len: usize,
 }
 
-impl Default for KvxParseAdapterConfig {
-    fn default() -> Self {
-        Self { mipmaplevels: 5 }
+impl<'a> WindowedInputAdapterConfig<'a> {
+    /// Creates a configuration which reads the `len` bytes of the provided reader that begin at `offset`.
+    pub fn new(source: impl ReadSeek + Send + 'a, offset: usize, len: usize) -> Self {
+        Self {
+            source: MoveOnce::new(Box::new(source)),
+            offset,
+            len,
+        }
+    }
+
+    /// Creates a configuration which reads the `len` bytes of the buffer that begin at `offset`.
+    pub fn from_bytes(bytes: &'a [u8], offset: usize, len: usize) -> Self {
+        Self::new(std::io::Cursor::new(bytes), offset, len)
+    }
+
+    /// Creates a configuration which reads `len` bytes of the file described by the file input
+    /// configuration, beginning at its byte offset.
+    pub fn from_file(config: &FileInputAdapterConfig, len: usize) -> Result<Self, GvoxError> {
+        let file = std::fs::File::open(&config.path).map_err(|error| {
+            GvoxError::new(
+                ErrorType::InputAdapter,
+                format!("Failed to open file {:?}: {error}", config.path),
+            )
+        })?;
+        Ok(Self::new(file, config.config.byte_offset, len))
+    }
+}
+
+/// Handles the adapter context operations of windowed inputs.
+pub struct WindowedInputHandler {
+    /// The handler which reads from the source.
+    reader: IoReaderHandler,
+    /// The position within the source at which the window begins.
+    offset: usize,
+    /// The length of the window.
+    len: usize,
+}
+
+impl BaseAdapterHandler<Input, WindowedInput> for WindowedInputHandler {
+    fn create(config: &WindowedInputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            reader: IoReaderHandler {
+                reader: unsafe {
+                    config
+                        .source
+                        .take_extended("source of a windowed input configuration")?
+                },
+                position: None,
+            },
+            offset: config.offset,
+            len: config.len,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl InputAdapterHandler<WindowedInput> for WindowedInputHandler {
+    fn read(
+        &mut self,
+        blit_ctx: &InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), GvoxError> {
+        let source_position = position
+            .checked_add(data.len())
+            .filter(|&end| end <= self.len)
+            .and_then(|_| self.offset.checked_add(position))
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::InputAdapter,
+                    format!(
+                        "Could not read {} bytes at position {position} of a {} byte input window.",
+                        data.len(),
+                        self.len
+                    ),
+                )
+            })?;
+        self.reader.read(blit_ctx, source_position, data)
+    }
+}
+
+/// Provides the ability to process data from a storage device.
+pub struct File;
+
+impl AdapterDescriptor<Input> for File {
+    type Configuration<'a> = FileInputAdapterConfig;
+    type Handler = ExternalHandler;
+
+    fn input_size(config: &FileInputAdapterConfig) -> Option<usize> {
+        let metadata = std::fs::metadata(&config.path).ok()?;
+        let length = usize::try_from(metadata.len()).ok()?;
+        Some(length.saturating_sub(config.config.byte_offset))
+    }
+}
+
+impl AdapterDescriptor<Output> for File {
+    type Configuration<'a> = FileOutputAdapterConfig;
+    type Handler = ExternalHandler;
+
+    fn blit_substitute<'a>(
+        ctx: &Context,
+        config: &FileOutputAdapterConfig,
+    ) -> Result<Option<AdapterContext<'a, Output>>, GvoxError> {
+        if config.mode == FileOutputMode::Truncate {
+            return Ok(None);
+        }
+
+        ctx.get_adapter::<Output, PositionedFile>()?
+            .create_adapter_context(config.clone())
+            .map(Some)
+    }
+}
+
+impl NamedAdapter for File {
+    fn name() -> &'static str {
+        "file"
+    }
+}
+
+/// Specifies the way that a file adapter will read from storage.
+#[derive(Debug)]
+#[repr(C)]
+pub struct FileInputAdapterConfig {
+    /// A configuration describing the file that the adapter should use. This member must come first
+    /// in order for the native adapter to use it.
+    config: gvox_sys::GvoxFileInputAdapterConfig,
+    /// The name of this file. This must outlive `config`, which references the underlying buffer.
+    file_name: CString,
+    /// The path of this file, used to query its size.
+    path: PathBuf,
+}
+
+// The native configuration only points into the owned file name.
+unsafe impl Send for FileInputAdapterConfig {}
+
+impl Clone for FileInputAdapterConfig {
+    fn clone(&self) -> Self {
+        // The native configuration must point into the cloned file name, rather than the original.
+        Self::with_file_name(
+            self.file_name.clone(),
+            self.path.clone(),
+            self.config.byte_offset,
+        )
+    }
+}
+
+impl FileInputAdapterConfig {
+    /// Create a new file input for the given file name and byte offset.
+    pub fn new(file_name: impl Into<String>, byte_offset: usize) -> Self {
+        let name = Into::<String>::into(file_name);
+        let file_name =
+            CString::new(name.clone()).expect("Could not convert file name to C string.");
+        Self::with_file_name(file_name, name.into(), byte_offset)
+    }
+
+    /// Create a new file input for the given path and byte offset. Unlike [`FileInputAdapterConfig::new`],
+    /// this accepts paths which are not valid UTF-8 on Unix platforms, and returns an error rather than
+    /// panicking if the path contains a null byte.
+    ///
+    /// On Windows, the native adapter opens the path in the ANSI code page, so paths which are not ASCII
+    /// are rejected with [`ErrorType::InvalidParameter`]. Such files may be opened with [`std::fs::File`]
+    /// and read through [`IoReader`] instead.
+    pub fn from_path(path: &Path, byte_offset: usize) -> Result<Self, GvoxError> {
+        Ok(Self::with_file_name(
+            file_path_c_name(path)?,
+            path.to_path_buf(),
+            byte_offset,
+        ))
+    }
+
+    /// Reads the bytes of the file which begin at the provided position, relative to the byte offset,
+    /// without going through the native adapter.
+    pub fn read_at(&self, position: usize, data: &mut [u8]) -> Result<(), GvoxError> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let error = |error: std::io::Error| {
+            GvoxError::new(
+                ErrorType::InputAdapter,
+                format!("Failed to read file {:?}: {error}", self.path),
+            )
+        };
+        let start = (self.config.byte_offset as u64).saturating_add(position as u64);
+        let mut file = std::fs::File::open(&self.path).map_err(error)?;
+        file.seek(SeekFrom::Start(start)).map_err(error)?;
+        file.read_exact(data).map_err(error)
+    }
+
+    /// Creates a configuration which refers to the provided native file name.
+    fn with_file_name(file_name: CString, path: PathBuf, byte_offset: usize) -> Self {
+        let config = gvox_sys::GvoxFileInputAdapterConfig {
+            filepath: file_name.as_ptr(),
+            byte_offset,
+        };
+
+        Self {
+            file_name,
+            config,
+            path,
+        }
+    }
+}
+
+/// Specifies the way that a file adapter will write to storage.
+#[derive(Debug)]
+#[repr(C)]
+pub struct FileOutputAdapterConfig {
+    /// A configuration describing the file that the adapter should use. This member must come first
+    /// in order for the native adapter to use it.
+    config: gvox_sys::GvoxFileOutputAdapterConfig,
+    /// The name of this file. This must outlive `config`, which references the underlying buffer.
+    file_name: CString,
+    /// The path of this file, used by [`PositionedFile`].
+    path: PathBuf,
+    /// Where in the file the output of each blit is placed.
+    mode: FileOutputMode,
+}
+
+// The native configuration only points into the owned file name.
+unsafe impl Send for FileOutputAdapterConfig {}
+
+impl FileOutputAdapterConfig {
+    /// Create a new file output for the given file name.
+    pub fn new(file_name: impl Into<String>) -> Self {
+        let name = Into::<String>::into(file_name);
+        let file_name =
+            CString::new(name.clone()).expect("Could not convert file name to C string.");
+        Self::with_file_name(file_name, name.into())
+    }
+
+    /// Create a new file output for the given path. Unlike [`FileOutputAdapterConfig::new`],
+    /// this accepts paths which are not valid UTF-8 on Unix platforms, and returns an error rather than
+    /// panicking if the path contains a null byte.
+    ///
+    /// On Windows, the native adapter opens the path in the ANSI code page, so paths which are not ASCII
+    /// are rejected with [`ErrorType::InvalidParameter`]. Output for such files may be collected with
+    /// [`VecOutput`] and written with [`std::fs::write`] instead.
+    pub fn from_path(path: &Path) -> Result<Self, GvoxError> {
+        Ok(Self::with_file_name(
+            file_path_c_name(path)?,
+            path.to_path_buf(),
+        ))
+    }
+
+    /// Sets where in the file the output of each blit is placed. The native [`File`] adapter always
+    /// truncates the file, so adapter contexts created with any other mode blit with [`PositionedFile`] instead.
+    pub fn with_mode(mut self, mode: FileOutputMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Where in the file the output of each blit is placed.
+    pub fn mode(&self) -> FileOutputMode {
+        self.mode
+    }
+
+    /// The path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Replaces the contents of the file with the provided bytes, as the native [`File`] adapter would.
+    pub fn write_all(&self, data: &[u8]) -> Result<(), GvoxError> {
+        std::fs::write(&self.path, data).map_err(|error| {
+            GvoxError::new(
+                ErrorType::OutputAdapter,
+                format!("Failed to write file {:?}: {error}", self.path),
+            )
+        })
+    }
+
+    /// Creates a configuration which refers to the provided native file name.
+    fn with_file_name(file_name: CString, path: PathBuf) -> Self {
+        let config = gvox_sys::GvoxFileOutputAdapterConfig {
+            filepath: file_name.as_ptr(),
+        };
+
+        Self {
+            file_name,
+            config,
+            path,
+            mode: FileOutputMode::default(),
+        }
+    }
+}
+
+impl Clone for FileOutputAdapterConfig {
+    fn clone(&self) -> Self {
+        // The native configuration must point into the cloned file name, rather than the original.
+        Self::with_file_name(self.file_name.clone(), self.path.clone()).with_mode(self.mode)
+    }
+}
+
+/// Describes where in a file the output of each blit is placed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FileOutputMode {
+    /// The file is emptied before the output is written from its beginning.
+    #[default]
+    Truncate,
+    /// The output is written after the existing contents of the file.
+    Append,
+    /// The output is written beginning at the given position, overwriting any existing contents there.
+    /// Contents outside of the written range are preserved.
+    WriteAt {
+        /// The position in the file which corresponds to the beginning of the output.
+        byte_offset: usize,
+    },
+}
+
+/// Writes voxel data to a storage device like [`File`], but is implemented in Rust, so that it
+/// supports every [`FileOutputMode`]. Output positions are relative to the base position of the mode,
+/// so serializers which write headers at the beginning of their output place them correctly.
+pub struct PositionedFile;
+
+impl AdapterDescriptor<Output> for PositionedFile {
+    type Configuration<'a> = FileOutputAdapterConfig;
+    type Handler = PositionedFileHandler;
+}
+
+impl NamedAdapter for PositionedFile {
+    fn name() -> &'static str {
+        "positioned_file"
+    }
+}
+
+/// Handles the adapter context operations of [`PositionedFile`].
+pub struct PositionedFileHandler {
+    /// The path of the file to write.
+    path: PathBuf,
+    /// Where in the file the output of each blit is placed.
+    mode: FileOutputMode,
+    /// The open file and the position of the beginning of the output within it, during a blit.
+    file: Option<(std::fs::File, u64)>,
+}
+
+impl PositionedFileHandler {
+    /// Converts an error from the file system into an output adapter error.
+    fn io_error(&self, error: std::io::Error) -> GvoxError {
+        GvoxError::new(
+            ErrorType::OutputAdapter,
+            format!("Failed to write to file {:?}: {error}", self.path),
+        )
+    }
+
+    /// The open file and output base position of the current blit.
+    fn file(&mut self) -> Result<(&mut std::fs::File, u64), GvoxError> {
+        match &mut self.file {
+            Some((file, base)) => Ok((file, *base)),
+            None => Err(GvoxError::new(
+                ErrorType::OutputAdapter,
+                "The file output was written outside of a blit.",
+            )),
+        }
+    }
+}
+
+impl BaseAdapterHandler<Output, PositionedFile> for PositionedFileHandler {
+    fn create(config: &FileOutputAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            path: config.path.clone(),
+            mode: config.mode,
+            file: None,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &OutputBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(self.mode == FileOutputMode::Truncate)
+            .open(&self.path)
+            .map_err(|error| self.io_error(error))?;
+        let base = match self.mode {
+            FileOutputMode::Truncate => 0,
+            FileOutputMode::Append => file.metadata().map_err(|error| self.io_error(error))?.len(),
+            FileOutputMode::WriteAt { byte_offset } => byte_offset as u64,
+        };
+        self.file = Some((file, base));
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &OutputBlitContext) -> Result<(), GvoxError> {
+        if let Some((mut file, _)) = self.file.take() {
+            std::io::Write::flush(&mut file).map_err(|error| self.io_error(error))?;
+        }
+        Ok(())
+    }
+}
+
+impl OutputAdapterHandler<PositionedFile> for PositionedFileHandler {
+    fn write(
+        &mut self,
+        _: &OutputBlitContext,
+        position: usize,
+        data: &[u8],
+    ) -> Result<(), GvoxError> {
+        let (file, base) = self.file()?;
+        let result = std::io::Seek::seek(file, std::io::SeekFrom::Start(base + position as u64))
+            .and_then(|_| std::io::Write::write_all(file, data));
+        result.map_err(|error| self.io_error(error))
+    }
+
+    fn reserve(&mut self, _: &OutputBlitContext, size: usize) -> Result<(), GvoxError> {
+        let (file, base) = self.file()?;
+        let end = base + size as u64;
+        let result = file.metadata().and_then(|metadata| {
+            if metadata.len() < end {
+                file.set_len(end)
+            } else {
+                Ok(())
+            }
+        });
+        result.map_err(|error| self.io_error(error))
+    }
+}
+
+/// The length at which absolute Windows paths must be given the extended-length prefix.
+#[cfg(windows)]
+const WINDOWS_MAX_PATH: usize = 260;
+
+/// Converts a path to the null-terminated name expected by the native file adapters.
+/// On Unix platforms, the bytes of the path are passed through unchanged. Elsewhere, the path must
+/// be valid Unicode, and is encoded as UTF-8. On Windows, it must also be ASCII.
+fn file_path_c_name(path: &Path) -> Result<CString, GvoxError> {
+    #[cfg(unix)]
+    let bytes = std::os::unix::ffi::OsStrExt::as_bytes(path.as_os_str()).to_vec();
+    #[cfg(not(unix))]
+    let bytes = native_path_name(path)?.into_bytes();
+
+    CString::new(bytes).map_err(|_| {
+        GvoxError::new(
+            ErrorType::InvalidParameter,
+            format!("File path {path:?} contains a null byte."),
+        )
+    })
+}
+
+/// Encodes a path as UTF-8. On Windows, long absolute paths are given the `\\?\` prefix,
+/// so that they are not truncated by the legacy path length limit. The native adapters open
+/// files with `fopen`, which interprets names in the ANSI code page rather than as UTF-8, so
+/// other characters would name a different file, and paths which are not ASCII are rejected.
+#[cfg(not(unix))]
+fn native_path_name(path: &Path) -> Result<String, GvoxError> {
+    let name = path.to_str().ok_or_else(|| {
+        GvoxError::new(
+            ErrorType::InvalidParameter,
+            format!("File path {path:?} is not valid Unicode."),
+        )
+    })?;
+
+    #[cfg(windows)]
+    if !name.is_ascii() {
+        return Err(GvoxError::new(
+            ErrorType::InvalidParameter,
+            format!(
+                "File path {path:?} is not ASCII, so the native file adapters cannot open it on Windows."
+            ),
+        ));
+    }
+
+    #[cfg(windows)]
+    if path.is_absolute() && name.len() >= WINDOWS_MAX_PATH && !name.starts_with(r"\\") {
+        return Ok(format!(r"\\?\{}", name.replace('/', "\\")));
+    }
+
+    Ok(name.to_owned())
+}
+
+/// Converts voxels to a text visualization which may be displayed in a console.
+pub struct ColoredText;
+
+impl AdapterDescriptor<Serialize> for ColoredText {
+    type Configuration<'a> = ColoredTextSerializeAdapterConfig;
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for ColoredText {
+    fn name() -> &'static str {
+        "colored_text"
+    }
+}
+
+/// Describes how voxels should be downscaled when creating a visualization.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ColoredTextSerializeAdapterDownscaleMode {
+    /// The nearest voxel's value should be taken during filtering.
+    Nearest = 0,
+    /// Linear blending should be utilized to filter the voxels.
+    Linear = 1,
+}
+
+/// Describes how the colors of voxels are encoded when creating a visualization.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColoredTextSerializeAdapterColorMode {
+    /// Each voxel is drawn with a 24-bit color escape sequence.
+    #[default]
+    Truecolor,
+    /// Each voxel is drawn with the nearest color of the 256-color palette, which most terminals support.
+    Ansi256,
+    /// Each voxel is drawn as a character whose density reflects its brightness, without any escape sequences.
+    Plain,
+}
+
+/// Provides settings for controlling how voxels are visualized as colored text.
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct ColoredTextSerializeAdapterConfig {
+    /// The factor by which voxels should be downscaled.
+    pub downscale_factor: u32,
+    /// The filtering mode that should be employed during downscaling.
+    pub downscale_mode: ColoredTextSerializeAdapterDownscaleMode,
+    /// The value that should be considered greatest when handling non-color data.
+    pub non_color_max_value: u32,
+    /// Whether each layer should be printed below the last, as opposed to the right of the last
+    pub vertical: bool,
+}
+
+impl Default for ColoredTextSerializeAdapterConfig {
+    fn default() -> Self {
+        Self {
+            downscale_factor: 1,
+            downscale_mode: ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 0,
+            vertical: false,
+        }
+    }
+}
+
+/// Provides settings for controlling how voxels are visualized by [`RustColoredText`], which extend those
+/// that the native [`ColoredText`] adapter supports.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RustColoredTextConfig {
+    /// The settings shared with the native adapter.
+    text: ColoredTextSerializeAdapterConfig,
+    /// The channel to visualize, or `None` to visualize colors when they are blitted.
+    display_channel: Option<ChannelId>,
+    /// How the colors of voxels are encoded.
+    color_mode: ColoredTextSerializeAdapterColorMode,
+}
+
+impl RustColoredTextConfig {
+    /// Creates a configuration with the provided settings, which visualizes colors with truecolor escape sequences.
+    pub fn new(text: ColoredTextSerializeAdapterConfig) -> Self {
+        Self {
+            text,
+            display_channel: None,
+            color_mode: ColoredTextSerializeAdapterColorMode::Truecolor,
+        }
+    }
+
+    /// Sets the channel to visualize, or `None` to visualize colors when they are blitted. When set, blits
+    /// are restricted to this channel, which must be among their requested channels. Channels other
+    /// than [`ChannelId::COLOR`] are drawn as a gradient from zero to `non_color_max_value`.
+    pub fn with_display_channel(mut self, display_channel: Option<ChannelId>) -> Self {
+        self.display_channel = display_channel;
+        self
+    }
+
+    /// Sets how the colors of voxels are encoded.
+    pub fn with_color_mode(mut self, color_mode: ColoredTextSerializeAdapterColorMode) -> Self {
+        self.color_mode = color_mode;
+        self
+    }
+}
+
+impl From<ColoredTextSerializeAdapterConfig> for RustColoredTextConfig {
+    fn from(value: ColoredTextSerializeAdapterConfig) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Converts voxels to a text visualization like [`ColoredText`], but is implemented in Rust, so that it
+/// supports every [`ColoredTextSerializeAdapterColorMode`] and can visualize a chosen channel.
+///
+/// Each voxel is drawn as two characters, with rows of decreasing y and layers of increasing z. Layers are
+/// separated by a single space, or by an empty line when they are printed vertically.
+pub struct RustColoredText;
+
+impl AdapterDescriptor<Serialize> for RustColoredText {
+    type Configuration<'a> = RustColoredTextConfig;
+    type Handler = RustColoredTextHandler;
+
+    fn blit_channels(config: &RustColoredTextConfig) -> Option<ChannelFlags> {
+        config.display_channel.map(Into::into)
+    }
+}
+
+impl NamedAdapter for RustColoredText {
+    fn name() -> &'static str {
+        "rust_colored_text"
+    }
+}
+
+/// The characters with which plain visualizations draw voxels, from darkest to brightest.
+const PLAIN_TEXT_DENSITY: &[u8] = b".:-=+*#%@";
+
+/// Handles the adapter context operations of Rust colored text serializers.
+pub struct RustColoredTextHandler {
+    /// The settings with which voxels are visualized.
+    config: RustColoredTextConfig,
+    /// The range of the current blit, if one was provided.
+    range: Option<RegionRange>,
+    /// The smallest range which contains every range serialized so far during the current blit.
+    bounds: Option<RegionRange>,
+    /// The channel which is visualized during the current blit, if any was requested.
+    channel: Option<ChannelId>,
+    /// The value of every non-empty voxel serialized so far during the current blit.
+    voxels: HashMap<Offset3D, u32>,
+}
+
+impl RustColoredTextHandler {
+    /// Writes the visualization of every voxel serialized during the current blit to the output.
+    fn write_text(&self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        let cells = self.cells();
+        let reset = match self.config.color_mode {
+            ColoredTextSerializeAdapterColorMode::Plain => "",
+            _ => "\x1b[0m",
+        };
+
+        let text = lay_out_text_layers(
+            self.config.text.vertical,
+            cells.z,
+            cells.y,
+            reset,
+            |text, row, z| {
+                for x in 0..cells.x {
+                    self.draw_cell(text, self.cell(x, cells.y - 1 - row, z));
+                }
+            },
+        );
+        blit_ctx.output_reserve(text.len())?;
+        blit_ctx.output_write(0, text.as_bytes())
+    }
+
+    /// Records the values of the voxels within the range, for the visualization that is written when the blit ends.
+    fn record_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+    ) -> Result<(), GvoxError> {
+        if let Some(channel) = self.channel {
+            let region = blit_ctx.load_region_range(range, channel.into())?;
+            for voxel in region.iter(channel.into()) {
+                let (position, samples) = voxel?;
+                // Fully transparent colors are treated as empty voxels.
+                let value = samples
+                    .get(channel)
+                    .filter(|x| channel != ChannelId::COLOR || x >> 24 != 0);
+                if let Some(value) = value {
+                    self.voxels.insert(position, value);
+                }
+            }
+        }
+
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounding_range(&bounds, range),
+            None => *range,
+        });
+        Ok(())
+    }
+
+    /// The number of cells of downscaled voxels along each axis of the visualized range.
+    fn cells(&self) -> Extent3D {
+        let extent = self.range.or(self.bounds).unwrap_or_default().extent;
+        let factor = self.config.text.downscale_factor.max(1);
+        Extent3D {
+            x: extent.x.div_ceil(factor),
+            y: extent.y.div_ceil(factor),
+            z: extent.z.div_ceil(factor),
+        }
+    }
+
+    /// Determines the color of the cell of downscaled voxels with the provided coordinates, relative
+    /// to the beginning of the visualized range, or `None` if the cell is empty.
+    fn cell(&self, x: u32, y: u32, z: u32) -> Option<[u8; 3]> {
+        let offset = self.range.or(self.bounds).unwrap_or_default().offset;
+        let factor = self.config.text.downscale_factor.max(1);
+        let start = Offset3D {
+            x: offset.x.wrapping_add((x * factor) as i32),
+            y: offset.y.wrapping_add((y * factor) as i32),
+            z: offset.z.wrapping_add((z * factor) as i32),
+        };
+        self.cell_color(start, factor)
+    }
+
+    /// Determines the color of the cell of downscaled voxels which begins at the provided position,
+    /// or `None` if the cell is empty.
+    fn cell_color(&self, start: Offset3D, factor: u32) -> Option<[u8; 3]> {
+        if self.config.text.downscale_mode == ColoredTextSerializeAdapterDownscaleMode::Nearest {
+            return self
+                .voxels
+                .get(&start)
+                .map(|&value| self.voxel_color(value));
+        }
+
+        let mut sum = [0u64; 3];
+        let mut count = 0;
+        for z in 0..factor as i32 {
+            for y in 0..factor as i32 {
+                for x in 0..factor as i32 {
+                    let position = Offset3D {
+                        x: start.x.wrapping_add(x),
+                        y: start.y.wrapping_add(y),
+                        z: start.z.wrapping_add(z),
+                    };
+                    if let Some(&value) = self.voxels.get(&position) {
+                        for (sum, component) in sum.iter_mut().zip(self.voxel_color(value)) {
+                            *sum += component as u64;
+                        }
+                        count += 1;
+                    }
+                }
+            }
+        }
+
+        (count > 0).then(|| sum.map(|x| (x / count) as u8))
+    }
+
+    /// Converts the value of a voxel in the visualized channel to a color. Values of channels
+    /// other than [`ChannelId::COLOR`] become grays, which are brightest at `non_color_max_value`.
+    fn voxel_color(&self, value: u32) -> [u8; 3] {
+        if self.channel == Some(ChannelId::COLOR) {
+            let [r, g, b, _] = value.to_le_bytes();
+            [r, g, b]
+        } else {
+            let max = self.config.text.non_color_max_value.max(1) as u64;
+            let gray = (value as u64 * 255 / max).min(255) as u8;
+            [gray; 3]
+        }
+    }
+
+    /// Appends the two characters which represent a cell of the provided color.
+    fn draw_cell(&self, text: &mut String, color: Option<[u8; 3]>) {
+        use std::fmt::Write;
+
+        let Some([r, g, b]) = color else {
+            if self.config.color_mode != ColoredTextSerializeAdapterColorMode::Plain {
+                text.push_str("\x1b[0m");
+            }
+            text.push_str("  ");
+            return;
+        };
+
+        match self.config.color_mode {
+            ColoredTextSerializeAdapterColorMode::Truecolor => {
+                let _ = write!(text, "\x1b[48;2;{r};{g};{b}m  ");
+            }
+            ColoredTextSerializeAdapterColorMode::Ansi256 => {
+                let level = |x: u8| (x as u32 * 5 + 127) / 255;
+                let index = 16 + 36 * level(r) + 6 * level(g) + level(b);
+                let _ = write!(text, "\x1b[48;5;{index}m  ");
+            }
+            ColoredTextSerializeAdapterColorMode::Plain => {
+                let luma = (r as usize * 299 + g as usize * 587 + b as usize * 114) / 1000;
+                let glyph = PLAIN_TEXT_DENSITY[luma * PLAIN_TEXT_DENSITY.len() / 256] as char;
+                text.push(glyph);
+                text.push(glyph);
+            }
+        }
+    }
+}
+
+impl BaseAdapterHandler<Serialize, RustColoredText> for RustColoredTextHandler {
+    fn create(config: &RustColoredTextConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            config: *config,
+            range: None,
+            bounds: None,
+            channel: None,
+            voxels: HashMap::new(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.range = range.copied();
+        self.bounds = None;
+        self.channel = self.config.display_channel.or_else(|| {
+            if channel_flags.contains(ChannelId::COLOR) {
+                Some(ChannelId::COLOR)
+            } else {
+                channel_flags.into_iter().next()
+            }
+        });
+        self.voxels.clear();
+        Ok(())
+    }
+
+    /// Writes the text once every region of the blit has been recorded, if any was.
+    fn blit_end(&mut self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        match self.bounds {
+            Some(_) => self.write_text(blit_ctx),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SerializeAdapterHandler<RustColoredText> for RustColoredTextHandler {
+    type RegionData = ();
+
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.record_region(blit_ctx, range)
+    }
+}
+
+/// Arranges the rows of each layer of a text visualization, where rows are numbered from the top. Layers are
+/// printed beside one another, separated by a single space, or below one another, separated by an empty line.
+/// The reset sequence is written before each separator and line break, so that colors do not bleed into them.
+fn lay_out_text_layers(
+    vertical: bool,
+    layers: u32,
+    rows: u32,
+    reset: &str,
+    draw_row: impl Fn(&mut String, u32, u32),
+) -> String {
+    let mut text = String::new();
+    if vertical {
+        for layer in 0..layers {
+            if layer > 0 {
+                text.push('\n');
+            }
+            for row in 0..rows {
+                draw_row(&mut text, row, layer);
+                text.push_str(reset);
+                text.push('\n');
+            }
+        }
+    } else {
+        for row in 0..rows {
+            for layer in 0..layers {
+                if layer > 0 {
+                    text.push_str(reset);
+                    text.push(' ');
+                }
+                draw_row(&mut text, row, layer);
+            }
+            text.push_str(reset);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Converts voxels to a compact preview for display in a terminal, which packs several voxels into each
+/// character using Unicode block or braille characters. Rows have decreasing y, and layers have increasing z,
+/// as with [`RustColoredText`].
+pub struct TerminalPreview;
+
+impl AdapterDescriptor<Serialize> for TerminalPreview {
+    type Configuration<'a> = TerminalPreviewConfig;
+    type Handler = TerminalPreviewHandler;
+
+    fn blit_channels(config: &TerminalPreviewConfig) -> Option<ChannelFlags> {
+        config.display_channel.map(Into::into)
+    }
+}
+
+impl NamedAdapter for TerminalPreview {
+    fn name() -> &'static str {
+        "terminal_preview"
+    }
+}
+
+/// Describes how voxels are packed into the characters of a terminal preview.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TerminalPreviewMode {
+    /// Each character is an upper half block, whose foreground and background colors are those of two
+    /// vertically adjacent voxels. Every voxel is drawn as a single character column, and half of a row.
+    HalfBlock,
+    /// Each character is a braille pattern, which shows whether each voxel of a cell two voxels wide and
+    /// four voxels tall is present. No colors are drawn.
+    Braille,
+}
+
+/// Provides settings for controlling how voxels are previewed in a terminal.
+#[derive(Debug, Copy, Clone)]
+pub struct TerminalPreviewConfig {
+    /// How voxels are packed into characters.
+    pub mode: TerminalPreviewMode,
+    /// The factor by which voxels should be downscaled.
+    pub downscale_factor: u32,
+    /// The filtering mode that should be employed during downscaling.
+    pub downscale_mode: ColoredTextSerializeAdapterDownscaleMode,
+    /// The value that should be considered greatest when handling non-color data.
+    pub non_color_max_value: u32,
+    /// Whether each layer should be printed below the last, as opposed to the right of the last.
+    pub vertical: bool,
+    /// The channel to preview, or `None` to preview colors when they are blitted. When set, blits
+    /// are restricted to this channel, which must be among their requested channels.
+    pub display_channel: Option<ChannelId>,
+}
+
+impl Default for TerminalPreviewConfig {
+    fn default() -> Self {
+        Self {
+            mode: TerminalPreviewMode::HalfBlock,
+            downscale_factor: 1,
+            downscale_mode: ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 0,
+            vertical: false,
+            display_channel: None,
+        }
+    }
+}
+
+/// The bit of a braille pattern which represents each of its dots, by column and then by row from the top.
+const BRAILLE_DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// Handles the adapter context operations of terminal preview serializers.
+pub struct TerminalPreviewHandler {
+    /// How voxels are packed into characters.
+    mode: TerminalPreviewMode,
+    /// Collects the voxels of each blit, and determines the colors of downscaled cells.
+    text: RustColoredTextHandler,
+}
+
+impl TerminalPreviewHandler {
+    /// Writes the preview of every voxel serialized during the current blit to the output.
+    fn write_preview(&self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        use std::fmt::Write;
+
+        let cells = self.text.cells();
+        let cell = |x: u32, y: u32, z: u32| {
+            (x < cells.x && y < cells.y)
+                .then(|| self.text.cell(x, cells.y - 1 - y, z))
+                .flatten()
+        };
+
+        let text = match self.mode {
+            TerminalPreviewMode::HalfBlock => lay_out_text_layers(
+                self.text.config.text.vertical,
+                cells.z,
+                cells.y.div_ceil(2),
+                "\x1b[0m",
+                |text, row, z| {
+                    for x in 0..cells.x {
+                        let top = cell(x, row * 2, z);
+                        let bottom = cell(x, row * 2 + 1, z);
+                        let (glyph, foreground, background) = match (top, bottom) {
+                            (None, None) => (' ', None, None),
+                            (Some(top), None) => ('▀', Some(top), None),
+                            (None, Some(bottom)) => ('▄', Some(bottom), None),
+                            (Some(top), Some(bottom)) => ('▀', Some(top), Some(bottom)),
+                        };
+
+                        text.push_str("\x1b[0m");
+                        if let Some([r, g, b]) = foreground {
+                            let _ = write!(text, "\x1b[38;2;{r};{g};{b}m");
+                        }
+                        if let Some([r, g, b]) = background {
+                            let _ = write!(text, "\x1b[48;2;{r};{g};{b}m");
+                        }
+                        text.push(glyph);
+                    }
+                },
+            ),
+            TerminalPreviewMode::Braille => lay_out_text_layers(
+                self.text.config.text.vertical,
+                cells.z,
+                cells.y.div_ceil(4),
+                "",
+                |text, row, z| {
+                    for x in 0..cells.x.div_ceil(2) {
+                        let mut pattern = 0;
+                        for (dx, column) in BRAILLE_DOTS.iter().enumerate() {
+                            for (dy, bit) in column.iter().enumerate() {
+                                if cell(x * 2 + dx as u32, row * 4 + dy as u32, z).is_some() {
+                                    pattern |= bit;
+                                }
+                            }
+                        }
+                        text.push(char::from_u32(0x2800 + pattern).unwrap_or(' '));
+                    }
+                },
+            ),
+        };
+
+        blit_ctx.output_reserve(text.len())?;
+        blit_ctx.output_write(0, text.as_bytes())
+    }
+}
+
+impl BaseAdapterHandler<Serialize, TerminalPreview> for TerminalPreviewHandler {
+    fn create(config: &TerminalPreviewConfig) -> Result<Self, GvoxError> {
+        let text = RustColoredTextConfig::new(ColoredTextSerializeAdapterConfig {
+            downscale_factor: config.downscale_factor,
+            downscale_mode: config.downscale_mode,
+            non_color_max_value: config.non_color_max_value,
+            vertical: config.vertical,
+        })
+        .with_display_channel(config.display_channel);
+        Ok(Self {
+            mode: config.mode,
+            text: RustColoredTextHandler::create(&text)?,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.text.blit_begin(blit_ctx, range, channel_flags)
+    }
+
+    /// Writes the preview once every region of the blit has been recorded, if any was.
+    fn blit_end(&mut self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        match self.text.bounds {
+            Some(_) => self.write_preview(blit_ctx),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SerializeAdapterHandler<TerminalPreview> for TerminalPreviewHandler {
+    type RegionData = ();
+
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.text.record_region(blit_ctx, range)
+    }
+}
+
+/// Prints voxel data directly to the standard console output.
+pub struct StdOut;
+
+impl AdapterDescriptor<Output> for StdOut {
+    type Configuration<'a> = StdOutOutputAdapterConfig;
+    type Handler = ExternalHandler;
+
+    fn blit_substitute<'a>(
+        ctx: &Context,
+        config: &StdOutOutputAdapterConfig,
+    ) -> Result<Option<AdapterContext<'a, Output>>, GvoxError> {
+        if *config == StdOutOutputAdapterConfig::default() {
+            return Ok(None);
+        }
+
+        ctx.get_adapter::<Output, BufferedStdOut>()?
+            .create_adapter_context(*config)
+            .map(Some)
+    }
+}
+
+impl NamedAdapter for StdOut {
+    fn name() -> &'static str {
+        "stdout"
+    }
+}
+
+/// Provides settings for controlling how voxel data is written to the standard console output or error.
+/// The native [`StdOut`] adapter only supports the default settings, so adapter contexts created with
+/// any others blit with [`BufferedStdOut`] instead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StdOutOutputAdapterConfig {
+    /// Whether output should be collected in memory, and written once each blit ends. Serializers which
+    /// rewrite earlier parts of their output, such as headers, may only be used with buffered streams.
+    pub buffered: bool,
+    /// Whether the stream should be flushed once each blit ends.
+    pub flush_on_blit_end: bool,
+}
+
+/// Prints voxel data to the standard console output like [`StdOut`], but is implemented in Rust, so
+/// that it supports every [`StdOutOutputAdapterConfig`].
+pub struct BufferedStdOut;
+
+impl AdapterDescriptor<Output> for BufferedStdOut {
+    type Configuration<'a> = StdOutOutputAdapterConfig;
+    type Handler = StdStreamHandler;
+}
+
+impl NamedAdapter for BufferedStdOut {
+    fn name() -> &'static str {
+        "buffered_stdout"
+    }
+}
+
+/// Prints voxel data to the standard console error.
+pub struct StdErr;
+
+impl AdapterDescriptor<Output> for StdErr {
+    type Configuration<'a> = StdOutOutputAdapterConfig;
+    type Handler = StdStreamHandler;
+}
+
+impl NamedAdapter for StdErr {
+    fn name() -> &'static str {
+        "stderr"
+    }
+}
+
+/// An output adapter which writes to one of the standard streams through a [`StdStreamHandler`].
+pub trait StdStreamAdapter:
+    for<'a> AdapterDescriptor<
+    Output,
+    Configuration<'a> = StdOutOutputAdapterConfig,
+    Handler = StdStreamHandler,
+>
+{
+    /// Whether the standard error is written, as opposed to the standard output.
+    const STDERR: bool;
+}
+
+impl StdStreamAdapter for BufferedStdOut {
+    const STDERR: bool = false;
+}
+
+impl StdStreamAdapter for StdErr {
+    const STDERR: bool = true;
+}
+
+/// Handles the adapter context operations of Rust standard stream outputs.
+pub struct StdStreamHandler {
+    /// Whether the standard error is written, as opposed to the standard output.
+    stderr: bool,
+    /// How the stream is written.
+    config: StdOutOutputAdapterConfig,
+    /// The output of the current blit, if it is buffered.
+    buffer: Vec<u8>,
+    /// The number of bytes of the current blit which were written to the stream.
+    written: usize,
+}
+
+impl StdStreamHandler {
+    /// Creates a handler which writes to the provided stream.
+    fn new(stderr: bool, config: &StdOutOutputAdapterConfig) -> Self {
+        Self {
+            stderr,
+            config: *config,
+            buffer: Vec::new(),
+            written: 0,
+        }
+    }
+
+    /// Applies the operation to the stream, while it is locked. While [`testing::capture_std_streams`] runs on the
+    /// current thread, the operation is applied to the captured output instead.
+    fn with_stream(
+        &self,
+        f: impl FnOnce(&mut dyn std::io::Write) -> std::io::Result<()>,
+    ) -> Result<(), GvoxError> {
+        #[cfg(any(test, feature = "testing"))]
+        let captured = CAPTURED_STD_STREAMS.with_borrow_mut(Option::take);
+        #[cfg(not(any(test, feature = "testing")))]
+        let captured: Option<Vec<u8>> = None;
+
+        let result = match captured {
+            Some(mut captured) => {
+                let result = f(&mut captured);
+                #[cfg(any(test, feature = "testing"))]
+                CAPTURED_STD_STREAMS.set(Some(captured));
+                result
+            }
+            None if self.stderr => f(&mut std::io::stderr().lock()),
+            None => f(&mut std::io::stdout().lock()),
+        };
+        result.map_err(|error| {
+            GvoxError::new(
+                ErrorType::OutputAdapter,
+                format!("Failed to write to the standard stream: {error}"),
+            )
+        })
+    }
+
+    /// Writes the data at its position within the output of the current blit. Buffered writes are placed within
+    /// the buffer. Otherwise, streams cannot seek, so each write must begin at or after the end of the previous
+    /// one, and any gap between them is filled with zeros.
+    fn write_at(&mut self, position: usize, data: &[u8]) -> Result<(), GvoxError> {
+        if self.config.buffered {
+            write_at(&mut self.buffer, position, data);
+            return Ok(());
+        }
+
+        if position < self.written {
+            return Err(GvoxError::new(
+                ErrorType::OutputAdapter,
+                format!(
+                    "Could not write to position {position} of an unbuffered standard stream, since {} bytes were \
+                    already written. Enable buffering for serializers which rewrite their output.",
+                    self.written
+                ),
+            ));
+        }
+
+        let gap = position - self.written;
+        self.with_stream(|stream| {
+            stream.write_all(&vec![0; gap])?;
+            stream.write_all(data)
+        })?;
+        self.written = position + data.len();
+        Ok(())
+    }
+
+    /// Writes the buffered output of the current blit, and flushes the stream if requested.
+    fn finish(&mut self) -> Result<(), GvoxError> {
+        let buffer = take(&mut self.buffer);
+        let flush = self.config.flush_on_blit_end;
+        self.with_stream(|stream| {
+            stream.write_all(&buffer)?;
+            if flush {
+                stream.flush()?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<A: StdStreamAdapter> BaseAdapterHandler<Output, A> for StdStreamHandler {
+    fn create(config: &StdOutOutputAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self::new(A::STDERR, config))
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &OutputBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.buffer.clear();
+        self.written = 0;
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &OutputBlitContext) -> Result<(), GvoxError> {
+        self.finish()
+    }
+}
+
+impl<A: StdStreamAdapter> OutputAdapterHandler<A> for StdStreamHandler {
+    fn write(
+        &mut self,
+        _: &OutputBlitContext,
+        position: usize,
+        data: &[u8],
+    ) -> Result<(), GvoxError> {
+        self.write_at(position, data)
+    }
+
+    fn reserve(&mut self, _: &OutputBlitContext, _: usize) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+/// Sends written data to a consumer on another thread as a sequence of segments, with bounded buffering.
+pub struct ChannelOutput;
+
+impl AdapterDescriptor<Output> for ChannelOutput {
+    type Configuration<'a> = ChannelOutputAdapterConfig;
+    type Handler = ChannelOutputHandler;
+}
+
+impl NamedAdapter for ChannelOutput {
+    fn name() -> &'static str {
+        "channel"
+    }
+}
+
+/// Determines what a channel output adapter does when writing would exceed its buffer budget.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ChannelOutputBackPressure {
+    /// Wait inside of the adapter until the consumer has received enough segments. The blit,
+    /// and therefore the lock on its context, is held for the entire wait, so the consumer
+    /// must not use the same context.
+    #[default]
+    Block,
+    /// Fail the write, and therefore the blit, with an [`ErrorType::OutputAdapter`] error.
+    Fail,
+}
+
+/// A contiguous run of bytes which was written to a channel output adapter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelOutputSegment {
+    /// The offset within the output at which the bytes were written.
+    pub position: usize,
+    /// The bytes which were written.
+    pub data: Vec<u8>,
+}
+
+/// Describes where a channel output adapter sends its data, and how much it may buffer.
+#[derive(Clone, Debug)]
+pub struct ChannelOutputAdapterConfig {
+    /// The sending half of the segment channel.
+    sender: mpsc::Sender<ChannelOutputSegment>,
+    /// The budget that is shared with the receiver.
+    budget: Arc<ChannelOutputBudget>,
+    /// What to do when a write would exceed the budget.
+    back_pressure: ChannelOutputBackPressure,
+}
+
+impl ChannelOutputAdapterConfig {
+    /// Creates a new channel output which buffers at most `budget` bytes of segments that the receiver has
+    /// not yet received. A single write larger than the budget is still sent once all earlier segments have
+    /// been received.
+    pub fn new(
+        budget: usize,
+        back_pressure: ChannelOutputBackPressure,
+    ) -> (Self, ChannelOutputReceiver) {
+        let (sender, receiver) = mpsc::channel();
+        let budget = Arc::new(ChannelOutputBudget {
+            limit: budget,
+            in_flight: Mutex::new(0),
+            released: Condvar::new(),
+        });
+
+        (
+            Self {
+                sender,
+                budget: budget.clone(),
+                back_pressure,
+            },
+            ChannelOutputReceiver { receiver, budget },
+        )
+    }
+}
+
+/// Tracks the number of bytes which have been sent by a channel output but not yet received.
+#[derive(Debug)]
+struct ChannelOutputBudget {
+    /// The maximum number of bytes that may be in flight.
+    limit: usize,
+    /// The number of bytes currently in flight.
+    in_flight: Mutex<usize>,
+    /// Signaled whenever the receiver takes a segment.
+    released: Condvar,
+}
+
+/// Receives the segments written by a channel output adapter.
+#[derive(Debug)]
+pub struct ChannelOutputReceiver {
+    /// The receiving half of the segment channel.
+    receiver: mpsc::Receiver<ChannelOutputSegment>,
+    /// The budget that is shared with the sender.
+    budget: Arc<ChannelOutputBudget>,
+}
+
+impl ChannelOutputReceiver {
+    /// Waits for the next segment, returning `None` once every adapter context using this channel has been destroyed.
+    pub fn recv(&self) -> Option<ChannelOutputSegment> {
+        let segment = self.receiver.recv().ok()?;
+        *self
+            .budget
+            .in_flight
+            .lock()
+            .expect("Could not acquire channel budget mutex.") -= segment.data.len();
+        self.budget.released.notify_all();
+        Some(segment)
+    }
+
+    /// Receives every remaining segment, and assembles them into the complete output.
+    pub fn assemble(&self) -> Vec<u8> {
+        let mut assembler = ChannelOutputAssembler::default();
+        while let Some(segment) = self.recv() {
+            assembler.insert(&segment);
+        }
+        assembler.into_bytes()
+    }
+}
+
+impl Drop for ChannelOutputReceiver {
+    fn drop(&mut self) {
+        // Wake any blocked writers, so that they observe the disconnected channel instead of waiting forever.
+        if let Ok(mut in_flight) = self.budget.in_flight.lock() {
+            *in_flight = 0;
+        }
+        self.budget.released.notify_all();
+    }
+}
+
+/// Reconstructs the output of a channel output adapter from its segments. Serializers may write
+/// out of order, such as when filling in a header after the data that follows it, so each segment
+/// is placed at its position and later segments overwrite earlier ones.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChannelOutputAssembler {
+    /// The output assembled so far. Gaps which have not been written are zeroed.
+    bytes: Vec<u8>,
+}
+
+impl ChannelOutputAssembler {
+    /// Places the segment at its position within the output.
+    pub fn insert(&mut self, segment: &ChannelOutputSegment) {
+        write_at(&mut self.bytes, segment.position, &segment.data);
+    }
+
+    /// The output assembled so far.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the assembler, returning the assembled output.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Handles the adapter context operations of channel outputs.
+pub struct ChannelOutputHandler {
+    /// The sending half of the segment channel.
+    sender: mpsc::Sender<ChannelOutputSegment>,
+    /// The budget that is shared with the receiver.
+    budget: Arc<ChannelOutputBudget>,
+    /// What to do when a write would exceed the budget.
+    back_pressure: ChannelOutputBackPressure,
+}
+
+impl BaseAdapterHandler<Output, ChannelOutput> for ChannelOutputHandler {
+    fn create(config: &ChannelOutputAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            sender: config.sender.clone(),
+            budget: config.budget.clone(),
+            back_pressure: config.back_pressure,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl OutputAdapterHandler<ChannelOutput> for ChannelOutputHandler {
+    fn write(
+        &mut self,
+        _: &OutputBlitContext,
+        position: usize,
+        data: &[u8],
+    ) -> Result<(), GvoxError> {
+        let mut in_flight = self
+            .budget
+            .in_flight
+            .lock()
+            .expect("Could not acquire channel budget mutex.");
+        while *in_flight > 0 && *in_flight + data.len() > self.budget.limit {
+            match self.back_pressure {
+                ChannelOutputBackPressure::Block => {
+                    in_flight = self
+                        .budget
+                        .released
+                        .wait(in_flight)
+                        .expect("Could not acquire channel budget mutex.");
+                }
+                ChannelOutputBackPressure::Fail => {
+                    return Err(GvoxError::new(
+                        ErrorType::OutputAdapter,
+                        format!(
+                            "Channel output budget of {} bytes exceeded; {} bytes are waiting for the consumer.",
+                            self.budget.limit, *in_flight
+                        ),
+                    ))
+                }
+            }
+        }
+
+        self.sender
+            .send(ChannelOutputSegment {
+                position,
+                data: data.to_vec(),
+            })
+            .map_err(|_| {
+                GvoxError::new(
+                    ErrorType::OutputAdapter,
+                    "The channel output receiver was dropped.",
+                )
+            })?;
+        *in_flight += data.len();
+        Ok(())
+    }
+
+    fn reserve(&mut self, _: &OutputBlitContext, _: usize) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+/// Reads input data from a Rust closure, which may borrow local data for as long as the adapter context is alive.
+pub struct FnInput;
+
+impl AdapterDescriptor<Input> for FnInput {
+    type Configuration<'a> = FnInputAdapterConfig<'a>;
+    type Handler = FnInputHandler;
+}
+
+impl NamedAdapter for FnInput {
+    fn name() -> &'static str {
+        "fn"
+    }
+}
+
+/// A closure which fills the provided slice with input bytes, beginning at the specified offset.
+pub type FnInputCallback<'a> =
+    Box<dyn FnMut(usize, &mut [u8]) -> Result<(), GvoxError> + Send + 'a>;
+
+/// Describes the closure from which a function input adapter reads.
+pub struct FnInputAdapterConfig<'a> {
+    /// The closure, until it is moved into the adapter context that is created with this configuration.
+    read: MoveOnce<FnInputCallback<'a>>,
+}
+
+impl<'a> FnInputAdapterConfig<'a> {
+    /// Creates a configuration which reads from the provided closure.
+    pub fn new(read: impl FnMut(usize, &mut [u8]) -> Result<(), GvoxError> + Send + 'a) -> Self {
+        Self::from(Box::new(read) as FnInputCallback<'a>)
+    }
+}
+
+impl<'a> From<FnInputCallback<'a>> for FnInputAdapterConfig<'a> {
+    fn from(value: FnInputCallback<'a>) -> Self {
+        Self {
+            read: MoveOnce::new(value),
+        }
+    }
+}
+
+/// Handles the adapter context operations of function inputs.
+pub struct FnInputHandler {
+    /// The closure from which input is read. Its borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    read: FnInputCallback<'static>,
+}
+
+impl BaseAdapterHandler<Input, FnInput> for FnInputHandler {
+    fn create(config: &FnInputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            read: unsafe {
+                config
+                    .read
+                    .take_extended("closure of a function input configuration")?
+            },
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl InputAdapterHandler<FnInput> for FnInputHandler {
+    fn read(
+        &mut self,
+        _: &InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), GvoxError> {
+        (self.read)(position, data)
+    }
+}
+
+/// Writes output data to Rust closures, which may borrow local data for as long as the adapter context is alive.
+pub struct FnOutput;
+
+impl AdapterDescriptor<Output> for FnOutput {
+    type Configuration<'a> = FnOutputAdapterConfig<'a>;
+    type Handler = FnOutputHandler;
+}
+
+impl NamedAdapter for FnOutput {
+    fn name() -> &'static str {
+        "fn"
+    }
+}
+
+/// A closure which receives bytes written at the specified offset of the output. Offsets are absolute,
+/// and are not necessarily increasing; serializers may, for instance, write a header at offset zero after
+/// the data which follows it. Later writes to the same bytes replace earlier ones.
+pub type FnOutputWriteCallback<'a> =
+    Box<dyn FnMut(usize, &[u8]) -> Result<(), GvoxError> + Send + 'a>;
+
+/// A closure which is notified that the output will be at least the specified number of bytes long.
+pub type FnOutputReserveCallback<'a> = Box<dyn FnMut(usize) -> Result<(), GvoxError> + Send + 'a>;
+
+/// Describes the closures to which a function output adapter writes.
+pub struct FnOutputAdapterConfig<'a> {
+    /// The closures, until they are moved into the adapter context that is created with this configuration.
+    callbacks: MoveOnce<(FnOutputWriteCallback<'a>, FnOutputReserveCallback<'a>)>,
+}
+
+impl<'a> FnOutputAdapterConfig<'a> {
+    /// Creates a configuration which writes to the provided closures.
+    pub fn new(
+        write: impl FnMut(usize, &[u8]) -> Result<(), GvoxError> + Send + 'a,
+        reserve: impl FnMut(usize) -> Result<(), GvoxError> + Send + 'a,
+    ) -> Self {
+        Self {
+            callbacks: MoveOnce::new((Box::new(write), Box::new(reserve))),
+        }
+    }
+}
+
+/// Handles the adapter context operations of function outputs.
+pub struct FnOutputHandler {
+    /// The closure to which output is written. Its borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    write: FnOutputWriteCallback<'static>,
+    /// The closure which is notified of reservations, with borrows like those of `write`.
+    reserve: FnOutputReserveCallback<'static>,
+}
+
+impl BaseAdapterHandler<Output, FnOutput> for FnOutputHandler {
+    fn create(config: &FnOutputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        let (write, reserve) = unsafe {
+            config
+                .callbacks
+                .take_extended("closures of a function output configuration")?
+        };
+
+        Ok(Self { write, reserve })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl OutputAdapterHandler<FnOutput> for FnOutputHandler {
+    fn write(
+        &mut self,
+        _: &OutputBlitContext,
+        position: usize,
+        data: &[u8],
+    ) -> Result<(), GvoxError> {
+        (self.write)(position, data)
+    }
+
+    fn reserve(&mut self, _: &OutputBlitContext, size: usize) -> Result<(), GvoxError> {
+        (self.reserve)(size)
+    }
+}
+
+/// Writes output data directly into a Rust vector, which is borrowed for as long as the adapter context is alive.
+/// The vector is emptied when each blit begins, but its allocation is reused whenever it has enough capacity.
+pub struct VecOutput;
+
+impl AdapterDescriptor<Output> for VecOutput {
+    type Configuration<'a> = VecOutputAdapterConfig<'a>;
+    type Handler = VecOutputHandler;
+}
+
+impl NamedAdapter for VecOutput {
+    fn name() -> &'static str {
+        "vec"
+    }
+}
+
+/// Describes the vector to which a vector output adapter writes.
+pub struct VecOutputAdapterConfig<'a> {
+    /// The vector, until it is moved into the adapter context that is created with this configuration.
+    output: MoveOnce<&'a mut Vec<u8>>,
+}
+
+impl<'a> From<&'a mut Vec<u8>> for VecOutputAdapterConfig<'a> {
+    fn from(value: &'a mut Vec<u8>) -> Self {
+        Self {
+            output: MoveOnce::new(value),
+        }
+    }
+}
+
+/// Handles the adapter context operations of vector outputs.
+pub struct VecOutputHandler {
+    /// The vector to which output is written. Its borrow actually lasts only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    output: &'static mut Vec<u8>,
+}
+
+impl BaseAdapterHandler<Output, VecOutput> for VecOutputHandler {
+    fn create(config: &VecOutputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            output: unsafe {
+                config
+                    .output
+                    .take_extended("vector of a vector output configuration")?
+            },
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &OutputBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.output.clear();
+        Ok(())
+    }
+}
+
+impl OutputAdapterHandler<VecOutput> for VecOutputHandler {
+    fn write(
+        &mut self,
+        _: &OutputBlitContext,
+        position: usize,
+        data: &[u8],
+    ) -> Result<(), GvoxError> {
+        write_at(self.output, position, data);
+        Ok(())
+    }
+
+    fn reserve(&mut self, _: &OutputBlitContext, size: usize) -> Result<(), GvoxError> {
+        self.output.reserve(size.saturating_sub(self.output.len()));
+        Ok(())
+    }
+}
+
+/// Compresses output data with zstd, and writes it to a file or a Rust vector. Since serialize adapters
+/// may write to their output in any order, the uncompressed output of each blit is collected in memory,
+/// and compressed in a single pass once the blit ends.
+#[cfg(feature = "zstd")]
+pub struct ZstdOutput;
+
+#[cfg(feature = "zstd")]
+impl AdapterDescriptor<Output> for ZstdOutput {
+    type Configuration<'a> = ZstdOutputAdapterConfig<'a>;
+    type Handler = ZstdOutputHandler;
+}
+
+#[cfg(feature = "zstd")]
+impl NamedAdapter for ZstdOutput {
+    fn name() -> &'static str {
+        "zstd"
+    }
+}
+
+/// The destination to which a [`ZstdOutput`] adapter writes compressed data.
+#[cfg(feature = "zstd")]
+#[derive(Debug)]
+pub enum ZstdOutputSink<'a> {
+    /// The file at the given path is replaced with the compressed output.
+    File(PathBuf),
+    /// The contents of the vector are replaced with the compressed output.
+    Vec(&'a mut Vec<u8>),
+}
+
+/// Describes the way in which a [`ZstdOutput`] adapter compresses and writes its output.
+#[cfg(feature = "zstd")]
+pub struct ZstdOutputAdapterConfig<'a> {
+    /// The destination, until it is moved into the adapter context that is created with this configuration.
+    sink: MoveOnce<ZstdOutputSink<'a>>,
+    /// The zstd compression level, where zero selects the default level.
+    level: i32,
+}
+
+#[cfg(feature = "zstd")]
+impl<'a> ZstdOutputAdapterConfig<'a> {
+    /// Creates a configuration which compresses output with the given level, and writes it to the sink.
+    pub fn new(sink: ZstdOutputSink<'a>, level: i32) -> Self {
+        Self {
+            sink: MoveOnce::new(sink),
+            level,
+        }
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl<'a> From<&'a mut Vec<u8>> for ZstdOutputAdapterConfig<'a> {
+    fn from(value: &'a mut Vec<u8>) -> Self {
+        Self::new(ZstdOutputSink::Vec(value), zstd::DEFAULT_COMPRESSION_LEVEL)
+    }
+}
+
+/// Handles the adapter context operations of zstd outputs.
+#[cfg(feature = "zstd")]
+pub struct ZstdOutputHandler {
+    /// The destination of compressed output. Its borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    sink: ZstdOutputSink<'static>,
+    /// The zstd compression level.
+    level: i32,
+    /// The uncompressed output of the current blit.
+    buffer: Vec<u8>,
+}
+
+#[cfg(feature = "zstd")]
+impl ZstdOutputHandler {
+    /// Compresses the output of the current blit, and writes it to the sink.
+    fn finish(&mut self) -> Result<(), GvoxError> {
+        let buffer = take(&mut self.buffer);
+        let result = match &mut self.sink {
+            ZstdOutputSink::File(path) => std::fs::File::create(path)
+                .and_then(|file| zstd::stream::copy_encode(&buffer[..], file, self.level)),
+            ZstdOutputSink::Vec(output) => {
+                output.clear();
+                zstd::stream::copy_encode(&buffer[..], &mut **output, self.level)
+            }
+        };
+        result.map_err(|error| {
+            GvoxError::new(
+                ErrorType::OutputAdapter,
+                format!("Failed to write compressed output: {error}"),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl BaseAdapterHandler<Output, ZstdOutput> for ZstdOutputHandler {
+    fn create(config: &ZstdOutputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            sink: unsafe {
+                config
+                    .sink
+                    .take_extended("sink of a zstd output configuration")?
+            },
+            level: config.level,
+            buffer: Vec::new(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &OutputBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.buffer.clear();
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &OutputBlitContext) -> Result<(), GvoxError> {
+        self.finish()
+    }
+}
+
+#[cfg(feature = "zstd")]
+impl OutputAdapterHandler<ZstdOutput> for ZstdOutputHandler {
+    fn write(
+        &mut self,
+        _: &OutputBlitContext,
+        position: usize,
+        data: &[u8],
+    ) -> Result<(), GvoxError> {
+        write_at(&mut self.buffer, position, data);
+        Ok(())
+    }
+
+    fn reserve(&mut self, _: &OutputBlitContext, size: usize) -> Result<(), GvoxError> {
+        self.buffer.reserve(size.saturating_sub(self.buffer.len()));
+        Ok(())
+    }
+}
+
+/// Generates voxel data by calling a Rust closure for each sampled voxel, which may borrow local data
+/// for as long as the adapter context is alive.
+pub struct FnSampler;
+
+impl AdapterDescriptor<Parse> for FnSampler {
+    type Configuration<'a> = FnSamplerAdapterConfig<'a>;
+    type Handler = FnSamplerHandler;
+}
+
+impl NamedAdapter for FnSampler {
+    fn name() -> &'static str {
+        "fn"
+    }
+}
+
+/// A closure which determines the value of a channel for the voxel at the provided position,
+/// or returns `None` if the voxel has no value for the channel.
+pub type FnSamplerCallback<'a> = Arc<dyn Fn(Offset3D, ChannelId) -> Option<u32> + Send + Sync + 'a>;
+
+/// Describes how a function sampler adapter generates voxel data.
+#[derive(Clone)]
+pub struct FnSamplerAdapterConfig<'a> {
+    /// The closure which is called for each sampled voxel.
+    pub sampler: FnSamplerCallback<'a>,
+    /// The channels which the sampler generates. Other channels are never sampled.
+    pub channels: ChannelFlags,
+    /// The range outside of which no voxels are present, or `None` if the sampler is unbounded.
+    pub range: Option<RegionRange>,
+}
+
+impl<'a> FnSamplerAdapterConfig<'a> {
+    /// Creates an unbounded configuration which generates the provided channels with the sampler.
+    pub fn new(
+        sampler: impl Fn(Offset3D, ChannelId) -> Option<u32> + Send + Sync + 'a,
+        channels: ChannelFlags,
+    ) -> Self {
+        Self {
+            sampler: Arc::new(sampler),
+            channels,
+            range: None,
+        }
+    }
+}
+
+/// Handles the adapter context operations of function samplers.
+pub struct FnSamplerHandler {
+    /// The closure which is called for each sampled voxel. Its borrows actually last only as long
+    /// as the adapter context's configuration lifetime, which the adapter context never outlives.
+    sampler: FnSamplerCallback<'static>,
+    /// The channels which the sampler generates.
+    channels: ChannelFlags,
+    /// The range outside of which no voxels are present, if any.
+    range: Option<RegionRange>,
+}
+
+impl FnSamplerHandler {
+    /// Reports a warning if channels which the sampler does not generate were requested, and
+    /// returns the requested channels which it does generate.
+    fn available_channels(
+        &self,
+        blit_ctx: &ParseBlitContext,
+        requested: ChannelFlags,
+    ) -> ChannelFlags {
+        let missing = requested & !self.channels;
+        if missing != ChannelFlags::empty() {
+            blit_ctx.push_warning(GvoxError::new(
+                ErrorType::ParseAdapterRequestedChannelNotPresent,
+                format!(
+                    "The function sampler does not generate the requested channels {:?}.",
+                    missing.into_iter().collect::<Vec<_>>()
+                ),
+            ));
+        }
+        requested & self.channels
+    }
+}
+
+impl BaseAdapterHandler<Parse, FnSampler> for FnSamplerHandler {
+    fn create(config: &FnSamplerAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            sampler: unsafe {
+                transmute::<FnSamplerCallback<'_>, FnSamplerCallback<'static>>(
+                    config.sampler.clone(),
+                )
+            },
+            channels: config.channels,
+            range: config.range,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<FnSampler> for FnSamplerHandler {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range.unwrap_or_default()
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        Ok(Region::builder()
+            .range(*range)
+            .channels(self.available_channels(blit_ctx, channel_flags))
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let in_range = self.range.is_none_or(|range| range.contains(offset));
+        let value = (in_range && self.channels.contains(channel_id))
+            .then(|| (self.sampler)(*offset, channel_id))
+            .flatten();
+
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        blit_ctx.emit_region(
+            &Region::builder()
+                .range(*range)
+                .channels(self.available_channels(blit_ctx, channel_flags))
+                .build(),
+        )
+    }
+}
+
+/// Generates voxel data from a sparse set of voxels, which is suited to small or scattered sets that would
+/// be wasteful to store densely. Voxels which are not in the set have no value in any channel.
+pub struct SparseVoxels;
+
+impl AdapterDescriptor<Parse> for SparseVoxels {
+    type Configuration<'a> = SparseVoxelsAdapterConfig;
+    type Handler = SparseVoxelsHandler;
+}
+
+impl NamedAdapter for SparseVoxels {
+    fn name() -> &'static str {
+        "sparse_voxels"
+    }
+}
+
+/// Describes the voxels which a sparse voxel adapter generates.
+#[derive(Clone, Debug, Default)]
+pub struct SparseVoxelsAdapterConfig {
+    /// The values of each voxel, shared with any adapter contexts created with this configuration.
+    voxels: Arc<HashMap<Offset3D, VoxelSamples>>,
+    /// The smallest range which contains every voxel.
+    range: RegionRange,
+}
+
+impl SparseVoxelsAdapterConfig {
+    /// Creates a configuration from a list of voxel values. If the same channel of a voxel
+    /// is given more than once, the last value is used, although debug builds will assert.
+    pub fn new(entries: impl IntoIterator<Item = (Offset3D, ChannelId, u32)>) -> Self {
+        let mut voxels = HashMap::<Offset3D, VoxelSamples>::new();
+        for (position, channel_id, value) in entries {
+            let previous = voxels
+                .entry(position)
+                .or_default()
+                .insert(channel_id, value);
+            debug_assert!(
+                previous.is_none(),
+                "Channel {channel_id:?} of the sparse voxel at {position:?} was given more than once."
+            );
+        }
+        voxels.into()
+    }
+
+    /// The smallest range which contains every voxel.
+    pub fn range(&self) -> RegionRange {
+        self.range
+    }
+}
+
+impl From<HashMap<Offset3D, VoxelSamples>> for SparseVoxelsAdapterConfig {
+    fn from(value: HashMap<Offset3D, VoxelSamples>) -> Self {
+        let unit = |offset| RegionRange {
+            offset,
+            extent: Extent3D { x: 1, y: 1, z: 1 },
+        };
+        let range = value
+            .keys()
+            .map(|&x| unit(x))
+            .reduce(|acc, x| bounding_range(&acc, &x))
+            .unwrap_or_default();
+        Self {
+            voxels: Arc::new(value),
+            range,
+        }
+    }
+}
+
+impl FromIterator<(Offset3D, ChannelId, u32)> for SparseVoxelsAdapterConfig {
+    fn from_iter<T: IntoIterator<Item = (Offset3D, ChannelId, u32)>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Handles the adapter context operations of sparse voxel sets.
+pub struct SparseVoxelsHandler {
+    /// The values of each voxel.
+    voxels: Arc<HashMap<Offset3D, VoxelSamples>>,
+    /// The smallest range which contains every voxel.
+    range: RegionRange,
+}
+
+impl SparseVoxelsHandler {
+    /// Collects the voxels which lie within the range.
+    fn voxels_within(&self, range: &RegionRange) -> HashMap<Offset3D, VoxelSamples> {
+        self.voxels
+            .iter()
+            .filter(|(position, _)| range.contains(position))
+            .map(|(&position, &samples)| (position, samples))
+            .collect()
+    }
+}
+
+impl BaseAdapterHandler<Parse, SparseVoxels> for SparseVoxelsHandler {
+    fn create(config: &SparseVoxelsAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            voxels: config.voxels.clone(),
+            range: config.range,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<SparseVoxels> for SparseVoxelsHandler {
+    type RegionData = HashMap<Offset3D, VoxelSamples>;
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range
+    }
+
+    /// Ranges which contain no voxels are reported as uniform.
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        let empty = !self.voxels.keys().any(|x| range.contains(x));
+        Ok(if empty {
+            RegionFlags::UNIFORM
+        } else {
+            RegionFlags::empty()
+        })
+    }
+
+    fn load_region(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        let voxels = self.voxels_within(range);
+        let flags = if voxels.is_empty() {
+            RegionFlags::UNIFORM
+        } else {
+            RegionFlags::empty()
+        };
+        Ok(Region::builder()
+            .range(*range)
+            .channels(channel_flags)
+            .flags(flags)
+            .data(voxels)
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        region: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let value = region.get(offset).and_then(|x| x.get(channel_id));
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+/// Collects the voxels of a blit into a sparse set, which is suited to small or scattered sets that would
+/// be wasteful to store densely. Voxels which have no value in any of the blitted channels are skipped.
+pub struct SparseVoxelsOut;
+
+impl AdapterDescriptor<Serialize> for SparseVoxelsOut {
+    type Configuration<'a> = SparseVoxelsOutAdapterConfig;
+    type Handler = SparseVoxelsOutHandler;
+}
+
+impl NamedAdapter for SparseVoxelsOut {
+    fn name() -> &'static str {
+        "sparse_voxels_out"
+    }
+}
+
+/// Describes where a sparse voxel collector stores the voxels of each blit. The set is shared with any
+/// adapter contexts created with this configuration, and is cleared at the beginning of each blit.
+#[derive(Clone, Debug, Default)]
+pub struct SparseVoxelsOutAdapterConfig {
+    /// The values of each voxel which has been collected.
+    voxels: Arc<Mutex<HashMap<Offset3D, VoxelSamples>>>,
+}
+
+impl SparseVoxelsOutAdapterConfig {
+    /// Creates a configuration with an empty set of voxels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of voxels which have been collected.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no voxels have been collected.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Moves the collected voxels out of the set, leaving it empty. The result may be
+    /// converted into a [`SparseVoxelsAdapterConfig`] to parse the voxels again.
+    pub fn take(&self) -> HashMap<Offset3D, VoxelSamples> {
+        take(&mut *self.lock())
+    }
+
+    /// Acquires the set of collected voxels.
+    fn lock(&self) -> MutexGuard<'_, HashMap<Offset3D, VoxelSamples>> {
+        self.voxels
+            .lock()
+            .expect("Could not acquire sparse voxel mutex.")
+    }
+}
+
+/// Handles the adapter context operations of sparse voxel collectors.
+pub struct SparseVoxelsOutHandler {
+    /// Where the voxels of each blit are stored.
+    config: SparseVoxelsOutAdapterConfig,
+    /// The channels which the current blit collects.
+    channels: ChannelFlags,
+}
+
+impl SparseVoxelsOutHandler {
+    /// Adds the voxels of the region which have a value in any of the collected channels to the set.
+    fn collect_region(&mut self, region: &RegionRef<'_>) -> Result<(), GvoxError> {
+        let channels = region.channels() & self.channels;
+        if channels == ChannelFlags::empty() {
+            return Ok(());
+        }
+
+        let mut voxels = self.config.lock();
+        for entry in region.iter(channels) {
+            let (position, samples) = entry?;
+            if samples.channels() == ChannelFlags::empty() {
+                continue;
+            }
+
+            let existing = voxels.entry(position).or_default();
+            for (channel_id, value) in samples.iter() {
+                existing.insert(channel_id, value);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BaseAdapterHandler<Serialize, SparseVoxelsOut> for SparseVoxelsOutHandler {
+    fn create(config: &SparseVoxelsOutAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            config: config.clone(),
+            channels: ChannelFlags::empty(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        _: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.channels = channel_flags;
+        self.config.lock().clear();
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<SparseVoxelsOut> for SparseVoxelsOutHandler {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags & self.channels)?;
+        self.collect_region(&region)
+    }
+
+    fn receive_region(
+        &mut self,
+        _: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        self.collect_region(region)
+    }
+}
+
+/// Writes a line of text for the value of each voxel in each channel, for quick inspection and diffing.
+/// Voxels are visited with x varying fastest, then y, then z, and each line is written to the output as
+/// soon as it is formatted. Voxels which have no value in a channel produce no line for it.
+pub struct VoxelDump;
+
+impl AdapterDescriptor<Serialize> for VoxelDump {
+    type Configuration<'a> = VoxelDumpSerializeAdapterConfig;
+    type Handler = VoxelDumpHandler;
+}
+
+impl NamedAdapter for VoxelDump {
+    fn name() -> &'static str {
+        "voxel_dump"
+    }
+}
+
+/// The text format in which a [`VoxelDump`] adapter writes voxels.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum VoxelDumpFormat {
+    /// Comma-separated values, beginning with the header line `x,y,z,channel,value`.
+    #[default]
+    Csv,
+    /// A JSON array of objects with the fields `x`, `y`, `z`, `channel`, and `value`. The opening
+    /// bracket is on the first line, and each object is on a line of its own.
+    #[cfg(feature = "serde_json")]
+    Json,
+}
+
+/// Describes how a voxel dump is written.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct VoxelDumpSerializeAdapterConfig {
+    /// The text format in which voxels are written.
+    pub format: VoxelDumpFormat,
+}
+
+/// Handles the adapter context operations of voxel dumps.
+pub struct VoxelDumpHandler {
+    /// The configuration with which the adapter context was created.
+    config: VoxelDumpSerializeAdapterConfig,
+    /// The channels which the current blit writes.
+    channels: ChannelFlags,
+    /// The number of voxel values written so far during the current blit.
+    lines: usize,
+}
+
+impl VoxelDumpHandler {
+    /// Writes a line for the value of each voxel of the region in each channel of the blit.
+    fn write_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        let mut line = String::new();
+        for entry in region.iter(region.channels() & self.channels) {
+            let (position, samples) = entry?;
+            for (channel_id, value) in samples.iter() {
+                line.clear();
+                self.format_line(&mut line, &position, channel_id, value);
+                blit_ctx.output_append(line.as_bytes())?;
+                self.lines += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends the line which describes the value of a voxel in a channel.
+    fn format_line(
+        &self,
+        line: &mut String,
+        position: &Offset3D,
+        channel_id: ChannelId,
+        value: u32,
+    ) {
+        use std::fmt::Write;
+
+        let Offset3D { x, y, z } = *position;
+        let channel = u32::from(channel_id);
+        match self.config.format {
+            VoxelDumpFormat::Csv => {
+                let _ = writeln!(line, "{x},{y},{z},{channel},{value}");
+            }
+            // Separators precede each object, since whether it is the last is not yet known.
+            #[cfg(feature = "serde_json")]
+            VoxelDumpFormat::Json => {
+                let object = serde_json::json!({
+                    "x": x,
+                    "y": y,
+                    "z": z,
+                    "channel": channel,
+                    "value": value,
+                });
+                let separator = if self.lines == 0 { "\n" } else { ",\n" };
+                let _ = write!(line, "{separator}{object}");
+            }
+        }
+    }
+}
+
+impl BaseAdapterHandler<Serialize, VoxelDump> for VoxelDumpHandler {
+    fn create(config: &VoxelDumpSerializeAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            config: *config,
+            channels: ChannelFlags::empty(),
+            lines: 0,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        _: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.channels = channel_flags;
+        self.lines = 0;
+        let header = match self.config.format {
+            VoxelDumpFormat::Csv => "x,y,z,channel,value\n",
+            #[cfg(feature = "serde_json")]
+            VoxelDumpFormat::Json => "[",
+        };
+        blit_ctx.output_append(header.as_bytes()).map(drop)
+    }
+
+    /// Closes the JSON array, on the same line as its opening bracket if it is empty.
+    fn blit_end(&mut self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        let footer = match self.config.format {
+            VoxelDumpFormat::Csv => "",
+            #[cfg(feature = "serde_json")]
+            VoxelDumpFormat::Json if self.lines == 0 => "]\n",
+            #[cfg(feature = "serde_json")]
+            VoxelDumpFormat::Json => "\n]\n",
+        };
+        if !footer.is_empty() {
+            blit_ctx.output_append(footer.as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<VoxelDump> for VoxelDumpHandler {
+    type RegionData = ();
+
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags & self.channels)?;
+        self.write_region(blit_ctx, &region)
+    }
+
+    /// When the parse adapter drives the blit, voxels are written in the order in which its regions are received.
+    fn receive_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        self.write_region(blit_ctx, region)
+    }
+}
+
+/// Writes voxels into a plain array for each channel, suited to uploading to a 3D texture. The arrays
+/// are allocated once, for the range of the blit if it is known, or to fit every region once the blit ends.
+pub struct DenseArrays;
+
+impl AdapterDescriptor<Serialize> for DenseArrays {
+    type Configuration<'a> = Arc<Mutex<DenseOutput>>;
+    type Handler = DenseArraysHandler;
+}
+
+impl NamedAdapter for DenseArrays {
+    fn name() -> &'static str {
+        "dense_arrays"
+    }
+}
+
+/// The voxels which a [`DenseArrays`] adapter wrote during its most recent blit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DenseOutput {
+    /// The grid which holds the voxels, and records which of them have a value.
+    grid: VoxelGrid,
+}
+
+impl DenseOutput {
+    /// The range of voxels which the planes describe.
+    pub fn range(&self) -> RegionRange {
+        self.grid.range()
+    }
+
+    /// The set of channels which have a plane.
+    pub fn channels(&self) -> ChannelFlags {
+        self.grid.channels()
+    }
+
+    /// The plane of the provided channel, if it was written.
+    pub fn plane(&self, channel_id: ChannelId) -> Option<&DensePlane> {
+        self.grid.dense_plane(channel_id)
+    }
+
+    /// Moves the plane of the provided channel out of the output, if it was written.
+    pub fn take_plane(&mut self, channel_id: ChannelId) -> Option<DensePlane> {
+        self.grid.take_dense_plane(channel_id)
+    }
+
+    /// The value of the voxel at the provided position in the channel, or `None` if it
+    /// has no value, lies outside of the range, or the channel was not written.
+    pub fn get(&self, position: &Offset3D, channel_id: ChannelId) -> Option<u32> {
+        self.grid.get(position, channel_id)
+    }
+
+    /// The grid which holds the voxels.
+    pub fn grid(&self) -> &VoxelGrid {
+        &self.grid
+    }
+
+    /// Moves the grid which holds the voxels out of the output.
+    pub fn into_grid(self) -> VoxelGrid {
+        self.grid
+    }
+}
+
+impl Default for DenseOutput {
+    fn default() -> Self {
+        Self {
+            grid: VoxelGrid::with_presence(RegionRange::default(), ChannelFlags::empty()),
+        }
+    }
+}
+
+/// Handles the adapter context operations of dense array serializers.
+pub struct DenseArraysHandler {
+    /// Where the voxels of each blit are written.
+    output: Arc<Mutex<DenseOutput>>,
+    /// Collects the voxels of the current blit, which are moved into the output when it ends.
+    builder: VoxelGridBuilder,
+}
+
+impl BaseAdapterHandler<Serialize, DenseArrays> for DenseArraysHandler {
+    fn create(config: &Arc<Mutex<DenseOutput>>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            output: config.clone(),
+            builder: VoxelGridBuilder::default(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let output = take(
+            &mut *self
+                .output
+                .lock()
+                .expect("Could not acquire dense output mutex."),
+        );
+        self.builder = VoxelGridBuilder::begin(output.grid, range, channel_flags);
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &SerializeBlitContext) -> Result<(), GvoxError> {
+        self.output
+            .lock()
+            .expect("Could not acquire dense output mutex.")
+            .grid = take(&mut self.builder).finish();
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<DenseArrays> for DenseArraysHandler {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags & self.builder.channels())?;
+        self.builder.insert(&region)
+    }
+
+    fn receive_region(
+        &mut self,
+        _: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        self.builder.insert(region)
+    }
+}
+
+/// Generates voxel data from plain arrays for each channel, such as those written by [`DenseArrays`].
+/// Sub-ranges in which every voxel has the same values are reported as uniform, which allows
+/// serializers such as [`GvoxPalette`] to store them compactly.
+pub struct DenseArraysInput;
+
+impl AdapterDescriptor<Parse> for DenseArraysInput {
+    type Configuration<'a> = DenseArraysInputAdapterConfig<'a>;
+    type Handler = DenseArraysInputHandler;
+}
+
+impl NamedAdapter for DenseArraysInput {
+    fn name() -> &'static str {
+        "dense_arrays_input"
+    }
+}
+
+/// Describes the arrays from which a [`DenseArraysInput`] adapter generates voxels. Each array is indexed
+/// like [`DenseRegion::data`], and must hold exactly one value for every voxel within the range.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DenseArraysInputAdapterConfig<'a> {
+    /// The range of voxels which the arrays describe.
+    range: RegionRange,
+    /// The values of each channel, indexed by channel ID.
+    planes: [Option<&'a [u32]>; CHANNEL_ID_COUNT],
+    /// Whether each voxel has a value, if not every voxel does.
+    mask: Option<&'a [bool]>,
+}
+
+impl<'a> DenseArraysInputAdapterConfig<'a> {
+    /// Creates a configuration for arrays which describe the provided extent, beginning at the origin.
+    /// Voxels outside of the range have no value in any channel.
+    pub fn new(origin: Offset3D, extent: Extent3D) -> Self {
+        Self {
+            range: RegionRange {
+                offset: origin,
+                extent,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Sets the values of the provided channel.
+    pub fn with_channel(mut self, channel_id: ChannelId, data: &'a [u32]) -> Self {
+        self.planes[u32::from(channel_id) as usize] = Some(data);
+        self
+    }
+
+    /// Sets whether each voxel has a value. Voxels whose entry is `false` have no value in any channel.
+    pub fn with_mask(mut self, mask: &'a [bool]) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// The range of voxels which the arrays describe.
+    pub fn range(&self) -> RegionRange {
+        self.range
+    }
+}
+
+/// Handles the adapter context operations of dense array inputs.
+pub struct DenseArraysInputHandler {
+    /// The range of voxels which the arrays describe.
+    range: RegionRange,
+    /// The values of each channel. Their borrows actually last only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    planes: [Option<&'static [u32]>; CHANNEL_ID_COUNT],
+    /// Whether each voxel has a value, if not every voxel does.
+    mask: Option<&'static [bool]>,
+}
+
+impl DenseArraysInputHandler {
+    /// The channels for which arrays were provided.
+    fn channels(&self) -> ChannelFlags {
+        ChannelId::iter()
+            .filter(|&x| self.planes[u32::from(x) as usize].is_some())
+            .fold(ChannelFlags::empty(), |acc, x| acc | x)
+    }
+
+    /// The value of the voxel at the provided position in the channel, if it has one.
+    fn get(&self, position: &Offset3D, channel_id: ChannelId) -> Option<u32> {
+        let plane = self.planes[u32::from(channel_id) as usize]?;
+        let index = self
+            .range
+            .contains(position)
+            .then(|| dense_index(&self.range, position))?;
+        match self.mask {
+            Some(mask) if !mask[index] => None,
+            _ => Some(plane[index]),
+        }
+    }
+
+    /// Whether every voxel within the range has the same values in the provided channels.
+    fn is_uniform(&self, range: &RegionRange, channel_flags: ChannelFlags) -> bool {
+        let channels = (channel_flags & self.channels())
+            .into_iter()
+            .collect::<Vec<_>>();
+        let values = |position: Offset3D| channels.iter().map(move |&x| self.get(&position, x));
+
+        let RegionRange { offset, extent } = *range;
+        let mut positions = (0..extent.z as i64).flat_map(move |z| {
+            (0..extent.y as i64).flat_map(move |y| {
+                (0..extent.x as i64).map(move |x| Offset3D {
+                    x: (offset.x as i64 + x) as i32,
+                    y: (offset.y as i64 + y) as i32,
+                    z: (offset.z as i64 + z) as i32,
+                })
+            })
+        });
+        let Some(first) = positions.next() else {
+            return true;
+        };
+        positions.all(|position| values(position).eq(values(first)))
+    }
+}
+
+impl BaseAdapterHandler<Parse, DenseArraysInput> for DenseArraysInputHandler {
+    fn create(config: &DenseArraysInputAdapterConfig<'_>) -> Result<Self, GvoxError> {
+        let volume = config.range.volume();
+        let lengths = config
+            .planes
+            .iter()
+            .flatten()
+            .map(|x| ("channel", x.len()))
+            .chain(config.mask.map(|x| ("mask", x.len())));
+        for (name, len) in lengths {
+            if len as u64 != volume {
+                return Err(GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    format!("A dense {name} array held {len} values, but its extent contains {volume} voxels."),
+                ));
+            }
+        }
+
+        Ok(Self {
+            range: config.range,
+            planes: unsafe {
+                transmute::<
+                    [Option<&[u32]>; CHANNEL_ID_COUNT],
+                    [Option<&'static [u32]>; CHANNEL_ID_COUNT],
+                >(config.planes)
+            },
+            mask: unsafe { transmute::<Option<&[bool]>, Option<&'static [bool]>>(config.mask) },
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<DenseArraysInput> for DenseArraysInputHandler {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(if self.is_uniform(range, channel_flags) {
+            RegionFlags::UNIFORM
+        } else {
+            RegionFlags::empty()
+        })
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        Ok(Region::builder()
+            .range(*range)
+            .channels(channel_flags & self.channels())
+            .flags(self.query_region_flags(blit_ctx, range, channel_flags)?)
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let value = self.get(offset, channel_id);
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+/// Writes voxels into flat buffers of fixed-size bricks for GPU ray tracers. The range of the blit is split into
+/// cubic bricks, which are listed in an index grid. Bricks in which no voxel has a value are marked as empty, and
+/// bricks in which every voxel has the same value are stored as a single constant, while the voxels of every other
+/// brick are stored densely in the payload.
+pub struct BrickBuffer;
+
+impl AdapterDescriptor<Serialize> for BrickBuffer {
+    type Configuration<'a> = BrickBufferSerializeAdapterConfig;
+    type Handler = BrickBufferHandler;
+}
+
+impl NamedAdapter for BrickBuffer {
+    fn name() -> &'static str {
+        "brick_buffer"
+    }
+}
+
+/// Describes how a [`BrickBuffer`] adapter splits voxels into bricks, and where it stores them.
+#[derive(Clone, Debug)]
+pub struct BrickBufferSerializeAdapterConfig {
+    /// The number of voxels along each axis of a brick.
+    pub brick_size: u32,
+    /// The channel whose values are stored.
+    pub channel: ChannelId,
+    /// Where the buffers of each blit are stored, shared with any adapter contexts created with this configuration.
+    pub output: Arc<Mutex<BrickBufferOutput>>,
+}
+
+impl BrickBufferSerializeAdapterConfig {
+    /// Moves the buffers of the most recent blit out of the output, leaving it empty.
+    pub fn take(&self) -> BrickBufferOutput {
+        take(
+            &mut *self
+                .output
+                .lock()
+                .expect("Could not acquire brick buffer mutex."),
+        )
+    }
+}
+
+impl Default for BrickBufferSerializeAdapterConfig {
+    fn default() -> Self {
+        Self {
+            brick_size: 8,
+            channel: ChannelId::COLOR,
+            output: Arc::default(),
+        }
+    }
+}
+
+/// The buffers which a [`BrickBuffer`] adapter writes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BrickBufferOutput {
+    /// The position of the first voxel of the first brick, which is the offset of the range of the blit.
+    pub origin: Offset3D,
+    /// The number of voxels along each axis of a brick.
+    pub brick_size: u32,
+    /// The number of bricks along each axis.
+    pub grid_extent: Extent3D,
+    /// The entry of each brick, with x varying fastest, then y, then z. Each entry is [`BrickBufferOutput::EMPTY`],
+    /// the payload offset of a constant value combined with [`BrickBufferOutput::CONSTANT`], or the payload offset
+    /// of the first voxel of a dense brick.
+    pub index: Vec<u32>,
+    /// The values of the bricks. Dense bricks hold a value for every voxel with x varying fastest, then y, then z,
+    /// where voxels that have no value, including those which lie outside of the range of the blit, are zero.
+    pub payload: Vec<u32>,
+}
+
+impl BrickBufferOutput {
+    /// The index entry of a brick in which no voxel has a value.
+    pub const EMPTY: u32 = u32::MAX;
+    /// The flag of the index entry of a brick whose voxels all have the single value at its payload offset.
+    pub const CONSTANT: u32 = 1 << 31;
+
+    /// Creates an output for the provided range, in which every brick is empty.
+    fn new(range: &RegionRange, brick_size: u32) -> Self {
+        let bricks = |extent: u32| extent.div_ceil(brick_size);
+        let grid_extent = Extent3D {
+            x: bricks(range.extent.x),
+            y: bricks(range.extent.y),
+            z: bricks(range.extent.z),
+        };
+        let count = grid_extent.x as usize * grid_extent.y as usize * grid_extent.z as usize;
+        Self {
+            origin: range.offset,
+            brick_size,
+            grid_extent,
+            index: vec![Self::EMPTY; count],
+            payload: Vec::new(),
+        }
+    }
+
+    /// The contents of the brick at the provided position within the grid, or `None` if it lies outside of the grid.
+    pub fn brick(&self, x: u32, y: u32, z: u32) -> Option<Brick<'_>> {
+        let Extent3D { x: w, y: h, z: d } = self.grid_extent;
+        if x >= w || y >= h || z >= d {
+            return None;
+        }
+
+        let voxels = (self.brick_size as usize).pow(3);
+        let entry = self.index[x as usize + w as usize * (y as usize + h as usize * z as usize)];
+        Some(match entry {
+            Self::EMPTY => Brick::Empty,
+            _ if entry & Self::CONSTANT != 0 => {
+                Brick::Constant(self.payload[(entry & !Self::CONSTANT) as usize])
+            }
+            _ => Brick::Dense(&self.payload[entry as usize..entry as usize + voxels]),
+        })
+    }
+
+    /// The value of the voxel at the provided position, or `None` if it lies within an empty brick or outside
+    /// of the grid. Voxels of dense bricks which have no value are zero.
+    pub fn get(&self, position: &Offset3D) -> Option<u32> {
+        let size = self.brick_size as i64;
+        let local = |position: i32, origin: i32| {
+            let x = position as i64 - origin as i64;
+            (x >= 0).then(|| ((x / size) as u32, (x % size) as usize))
+        };
+        let (bx, x) = local(position.x, self.origin.x)?;
+        let (by, y) = local(position.y, self.origin.y)?;
+        let (bz, z) = local(position.z, self.origin.z)?;
+
+        match self.brick(bx, by, bz)? {
+            Brick::Empty => None,
+            Brick::Constant(value) => Some(value),
+            Brick::Dense(voxels) => {
+                let size = self.brick_size as usize;
+                Some(voxels[x + size * (y + size * z)])
+            }
+        }
+    }
+}
+
+/// The contents of a single brick of a [`BrickBufferOutput`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Brick<'a> {
+    /// No voxel of the brick has a value.
+    Empty,
+    /// Every voxel of the brick has the provided value.
+    Constant(u32),
+    /// The value of each voxel of the brick, with x varying fastest, then y, then z.
+    Dense(&'a [u32]),
+}
+
+/// Handles the adapter context operations of brick buffer serializers.
+pub struct BrickBufferHandler {
+    /// The configuration with which the adapter context was created.
+    config: BrickBufferSerializeAdapterConfig,
+    /// The range which the grid of the current blit covers, once it is known.
+    range: Option<RegionRange>,
+}
+
+impl BrickBufferHandler {
+    /// Writes the voxels of the provided range into each brick which it overlaps.
+    fn write_range(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+    ) -> Result<(), GvoxError> {
+        let mut output = self
+            .config
+            .output
+            .lock()
+            .expect("Could not acquire brick buffer mutex.");
+        let grid_range = *self.range.get_or_insert_with(|| {
+            *output = BrickBufferOutput::new(range, self.config.brick_size);
+            *range
+        });
+        let Some(range) = overlapping_range(&grid_range, range) else {
+            return Ok(());
+        };
+
+        // The bricks along an axis which the range overlaps, and the position at which one of them begins.
+        let size = self.config.brick_size as i64;
+        let bricks = |offset: i32, extent: u32, origin: i32| {
+            let start = offset as i64 - origin as i64;
+            (start / size) as u32..=((start + extent as i64 - 1) / size) as u32
+        };
+        let start = |brick: u32, origin: i32| (origin as i64 + brick as i64 * size) as i32;
+
+        let Extent3D { x: w, y: h, .. } = output.grid_extent;
+        let xs = bricks(range.offset.x, range.extent.x, grid_range.offset.x);
+        let ys = bricks(range.offset.y, range.extent.y, grid_range.offset.y);
+        let zs = bricks(range.offset.z, range.extent.z, grid_range.offset.z);
+        for bz in zs {
+            for by in ys.clone() {
+                for bx in xs.clone() {
+                    let cube = RegionRange {
+                        offset: Offset3D {
+                            x: start(bx, grid_range.offset.x),
+                            y: start(by, grid_range.offset.y),
+                            z: start(bz, grid_range.offset.z),
+                        },
+                        extent: Extent3D {
+                            x: size as u32,
+                            y: size as u32,
+                            z: size as u32,
+                        },
+                    };
+                    let Some(part) = overlapping_range(&cube, &range) else {
+                        continue;
+                    };
+                    let region = blit_ctx.load_region_range(&part, self.config.channel.into())?;
+                    let brick = bx as usize + w as usize * (by as usize + h as usize * bz as usize);
+                    self.write_brick(&mut output, brick, &cube, &region)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the voxels of the region, which lies within the cube of the provided brick, into the brick.
+    fn write_brick(
+        &self,
+        output: &mut BrickBufferOutput,
+        brick: usize,
+        cube: &RegionRange,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        let part = region.range();
+        let channel_id = self.config.channel;
+        let samples = if region.flags().contains(RegionFlags::UNIFORM) {
+            vec![region.sample(&part.offset, channel_id)?]
+        } else {
+            region.sample_range(&part, channel_id)?
+        };
+        let uniform = samples.windows(2).all(|x| x[0] == x[1]);
+        let entry = output.index[brick];
+
+        // A brick only becomes constant if the region covers the whole cube, so that no voxel lies outside of the range.
+        if entry == BrickBufferOutput::EMPTY {
+            if !samples.iter().any(|x| x.is_present) {
+                return Ok(());
+            }
+            if uniform && part == *cube {
+                output.index[brick] = BrickBufferOutput::CONSTANT | Self::offset(output)?;
+                output.payload.push(samples[0].data);
+                return Ok(());
+            }
+        }
+
+        let voxels = (self.config.brick_size as usize).pow(3);
+        let dense = entry != BrickBufferOutput::EMPTY && entry & BrickBufferOutput::CONSTANT == 0;
+        let start = if dense {
+            entry as usize
+        } else {
+            // Empty and constant bricks are expanded into dense bricks, whose voxels start out with their former value.
+            let value = match entry {
+                BrickBufferOutput::EMPTY => 0,
+                _ => output.payload[(entry & !BrickBufferOutput::CONSTANT) as usize],
+            };
+            let start = Self::offset(output)?;
+            output.payload.resize(start as usize + voxels, value);
+            output.index[brick] = start;
+            start as usize
+        };
+
+        let RegionRange { offset, extent } = part;
+        let mut values = samples.iter().cycle();
+        for z in 0..extent.z as i32 {
+            for y in 0..extent.y as i32 {
+                for x in 0..extent.x as i32 {
+                    let position = Offset3D {
+                        x: offset.x + x,
+                        y: offset.y + y,
+                        z: offset.z + z,
+                    };
+                    let sample = values.next().expect("Samples were empty.");
+                    output.payload[start + dense_index(cube, &position)] =
+                        if sample.is_present { sample.data } else { 0 };
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The payload offset at which the next brick is stored, which must fit within an index entry.
+    fn offset(output: &BrickBufferOutput) -> Result<u32, GvoxError> {
+        u32::try_from(output.payload.len())
+            .ok()
+            .filter(|&x| x < BrickBufferOutput::CONSTANT)
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::SerializeAdapterUnrepresentableData,
+                    "The payload of the brick buffer grew beyond the range of its index entries.",
+                )
+            })
+    }
+}
+
+impl BaseAdapterHandler<Serialize, BrickBuffer> for BrickBufferHandler {
+    fn create(config: &BrickBufferSerializeAdapterConfig) -> Result<Self, GvoxError> {
+        if config.brick_size == 0 {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                "The brick size of a brick buffer must be greater than zero.",
+            ));
+        }
+
+        Ok(Self {
+            config: config.clone(),
+            range: None,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    /// When the range of the blit is known, the grid covers it. Otherwise, it covers the first region which is serialized,
+    /// which in serialize-driven blits is the whole parsable range.
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.range = range.copied();
+        *self
+            .config
+            .output
+            .lock()
+            .expect("Could not acquire brick buffer mutex.") = match range {
+            Some(range) => BrickBufferOutput::new(range, self.config.brick_size),
+            None => BrickBufferOutput::default(),
+        };
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<BrickBuffer> for BrickBufferHandler {
+    type RegionData = ();
+
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.write_range(blit_ctx, range)
+    }
+
+    /// Parse-driven blits deliver the voxels in many regions, so the grid cannot cover them unless the range
+    /// of the blit is known as it begins.
+    fn receive_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        if self.range.is_none() {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                "Brick buffers require a range for parse-driven blits.",
+            ));
+        }
+        self.write_range(blit_ctx, &region.range())
+    }
+}
+
+/// Reads and writes run-length encoded containers with the stable layout described by [`format::RleHeader`].
+/// This adapter is implemented in Rust, so unlike [`GvoxRunLengthEncoding`], its containers do not depend on the
+/// version of the native library, and they record which voxels have no value. Serialization requires the whole
+/// blit to be held in memory, since the runs of each channel are only written once every voxel is known.
+pub struct RustRunLengthEncoding;
+
+impl AdapterDescriptor<Parse> for RustRunLengthEncoding {
+    type Configuration<'a> = ();
+    type Handler = RustRunLengthEncodingParser;
+}
+
+impl AdapterDescriptor<Serialize> for RustRunLengthEncoding {
+    type Configuration<'a> = ();
+    type Handler = RustRunLengthEncodingSerializer;
+}
+
+impl NamedAdapter for RustRunLengthEncoding {
+    fn name() -> &'static str {
+        "rust_run_length_encoding"
+    }
+}
+
+/// A list of runs which covers every voxel of a run-length encoded container.
+#[derive(Clone, Debug, Default)]
+struct RleRuns {
+    /// The index of the voxel just past the end of each run.
+    ends: Vec<u64>,
+    /// The value of each run.
+    values: Vec<u32>,
+}
+
+impl RleRuns {
+    /// Reads runs beginning at the provided position until they cover the volume, and advances the position past them.
+    fn read(
+        volume: u64,
+        position: &mut usize,
+        mut word: impl FnMut(usize) -> Result<u32, GvoxError>,
+    ) -> Result<Self, GvoxError> {
+        let mut runs = Self::default();
+        let mut end = 0;
+        while end < volume {
+            let count = word(*position)?;
+            let value = word(*position + size_of::<u32>())?;
+            *position += 2 * size_of::<u32>();
+            if count == 0 || end + count as u64 > volume {
+                return Err(GvoxError::new(
+                    ErrorType::ParseAdapterInvalidInput,
+                    "Run-length encoded container had a run which was empty or exceeded its volume.",
+                ));
+            }
+
+            end += count as u64;
+            runs.ends.push(end);
+            runs.values.push(value);
+        }
+        Ok(runs)
+    }
+
+    /// The index of the run which contains the voxel at the provided index.
+    fn run(&self, index: u64) -> usize {
+        self.ends.partition_point(|&end| end <= index)
+    }
+}
+
+/// The runs of a single channel of a run-length encoded container.
+#[derive(Clone, Debug, Default)]
+struct RleChannel {
+    /// Whether each voxel has a value in the channel.
+    presence: RleRuns,
+    /// The value of each voxel in the channel.
+    values: RleRuns,
+}
+
+/// Handles the adapter context operations of Rust run-length encoding parsers.
+pub struct RustRunLengthEncodingParser {
+    /// The range of the input of the current blit.
+    range: RegionRange,
+    /// The runs of each channel of the input of the current blit, indexed by channel ID.
+    channels: [Option<RleChannel>; CHANNEL_ID_COUNT],
+}
+
+impl RustRunLengthEncodingParser {
+    /// The channels which the input of the current blit holds.
+    fn channels(&self) -> ChannelFlags {
+        ChannelId::iter()
+            .filter(|&x| self.channels[u32::from(x) as usize].is_some())
+            .fold(ChannelFlags::empty(), |acc, x| acc | x)
+    }
+
+    /// Decodes the whole container, reading each little-endian word through the provided function.
+    fn read(
+        &mut self,
+        header: &RleHeader,
+        mut word: impl FnMut(usize) -> Result<u32, GvoxError>,
+    ) -> Result<(), GvoxError> {
+        let mut position = RLE_HEADER_SIZE;
+        let mut channel_ids = Vec::new();
+        for _ in 0..header.channel_n {
+            let id = word(position)?;
+            position += size_of::<u32>();
+            ChannelId::try_from(id)
+                .ok()
+                .filter(|_| channel_ids.last().is_none_or(|&last| last < id))
+                .ok_or_else(|| {
+                    GvoxError::new(
+                        ErrorType::ParseAdapterInvalidInput,
+                        format!("Run-length encoded container listed invalid or unordered channel {id}."),
+                    )
+                })?;
+            channel_ids.push(id);
+        }
+
+        let volume = header.range.volume();
+        for id in channel_ids {
+            self.channels[id as usize] = Some(RleChannel {
+                presence: RleRuns::read(volume, &mut position, &mut word)?,
+                values: RleRuns::read(volume, &mut position, &mut word)?,
+            });
+        }
+        self.range = header.range;
+        Ok(())
+    }
+
+    /// The runs of the channel, and the index of the voxel at the provided position, if it lies within the range.
+    fn locate(&self, position: &Offset3D, channel_id: ChannelId) -> Option<(&RleChannel, u64)> {
+        let channel = self.channels[u32::from(channel_id) as usize].as_ref()?;
+        let index = self
+            .range
+            .contains(position)
+            .then(|| dense_index(&self.range, position))?;
+        Some((channel, index as u64))
+    }
+
+    /// Whether every voxel within the range has the same values in the provided channels. Runs follow the order in
+    /// which voxels are stored, so this is the case if the first and last voxels of the range lie in the same runs.
+    fn is_uniform(&self, range: &RegionRange, channel_flags: ChannelFlags) -> bool {
+        let RegionRange { offset, extent } = *range;
+        if extent.x == 0 || extent.y == 0 || extent.z == 0 {
+            return true;
+        }
+
+        let last = Offset3D {
+            x: (offset.x as i64 + extent.x as i64 - 1) as i32,
+            y: (offset.y as i64 + extent.y as i64 - 1) as i32,
+            z: (offset.z as i64 + extent.z as i64 - 1) as i32,
+        };
+        (channel_flags & self.channels())
+            .into_iter()
+            .all(|channel_id| {
+                match (
+                    self.locate(&offset, channel_id),
+                    self.locate(&last, channel_id),
+                ) {
+                    (Some((channel, first)), Some((_, last))) => {
+                        channel.presence.run(first) == channel.presence.run(last)
+                            && channel.values.run(first) == channel.values.run(last)
+                    }
+                    _ => false,
+                }
+            })
+    }
+}
+
+impl BaseAdapterHandler<Parse, RustRunLengthEncoding> for RustRunLengthEncodingParser {
+    fn create(_: &()) -> Result<Self, GvoxError> {
+        Ok(Self {
+            range: RegionRange::default(),
+            channels: Default::default(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    /// The whole input is decoded when the blit begins. When the size of the input is known, it is read
+    /// all at once, and otherwise it is read one word at a time.
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let truncated = || {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                "Run-length encoded container ended unexpectedly.",
+            )
+        };
+
+        match blit_ctx.input_size() {
+            Some(size) => {
+                let bytes = blit_ctx.read_vec(0, size)?;
+                let header = RleHeader::read(&bytes)?;
+                self.read(&header, |position| {
+                    let word = bytes.get(position..position + size_of::<u32>());
+                    Ok(u32::from_le_bytes(
+                        word.ok_or_else(truncated)?
+                            .try_into()
+                            .expect("Word had the wrong length."),
+                    ))
+                })
+            }
+            None => {
+                let header = RleHeader::read(&blit_ctx.read_vec(0, RLE_HEADER_SIZE)?)?;
+                self.read(&header, |position| {
+                    blit_ctx
+                        .read_exact_array(position)
+                        .map(u32::from_le_bytes)
+                        .map_err(|_| truncated())
+                })
+            }
+        }
+    }
+
+    fn blit_end(&mut self, _: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.range = RegionRange::default();
+        self.channels = Default::default();
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<RustRunLengthEncoding> for RustRunLengthEncodingParser {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(if self.is_uniform(range, channel_flags) {
+            RegionFlags::UNIFORM
+        } else {
+            RegionFlags::empty()
+        })
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        Ok(Region::builder()
+            .range(*range)
+            .channels(channel_flags & self.channels())
+            .flags(self.query_region_flags(blit_ctx, range, channel_flags)?)
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let value = self
+            .locate(offset, channel_id)
+            .filter(|(channel, index)| channel.presence.values[channel.presence.run(*index)] != 0)
+            .map(|(channel, index)| channel.values.values[channel.values.run(index)]);
+
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+/// Handles the adapter context operations of Rust run-length encoding serializers.
+pub struct RustRunLengthEncodingSerializer {
+    /// Collects the voxels of the current blit, which are encoded when it ends.
+    voxels: DenseArraysHandler,
+    /// The voxels of the current blit.
+    output: Arc<Mutex<DenseOutput>>,
+}
+
+impl BaseAdapterHandler<Serialize, RustRunLengthEncoding> for RustRunLengthEncodingSerializer {
+    fn create(_: &()) -> Result<Self, GvoxError> {
+        let output = Arc::default();
+        Ok(Self {
+            voxels: DenseArraysHandler::create(&output)?,
+            output,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.voxels.blit_begin(blit_ctx, range, channel_flags)
+    }
+
+    /// Writes the whole container, and releases the voxels of the blit.
+    fn blit_end(&mut self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        self.voxels.blit_end(blit_ctx)?;
+        let mut voxels = take(
+            &mut *self
+                .output
+                .lock()
+                .expect("Could not acquire dense output mutex."),
+        );
+        let channels = voxels.channels().into_iter().collect::<Vec<_>>();
+
+        let mut bytes = Vec::new();
+        RleHeader::new(voxels.range(), channels.len() as u32).write(&mut bytes);
+        for &channel_id in &channels {
+            bytes.extend_from_slice(&u32::from(channel_id).to_le_bytes());
+        }
+        for channel_id in channels {
+            let plane = voxels
+                .take_plane(channel_id)
+                .expect("Dense output did not hold one of its channels.");
+            write_runs(plane.mask.iter().map(|&x| (1, x as u32)), &mut bytes);
+            write_runs(
+                plane
+                    .mask
+                    .iter()
+                    .zip(&plane.data)
+                    .map(|(&present, &x)| (1, if present { x } else { 0 })),
+                &mut bytes,
+            );
+        }
+
+        blit_ctx.output_append(&bytes).map(drop)
+    }
+}
+
+impl SerializeAdapterHandler<RustRunLengthEncoding> for RustRunLengthEncodingSerializer {
+    type RegionData = ();
+
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.voxels.serialize_region(blit_ctx, range, channel_flags)
+    }
+
+    fn receive_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        self.voxels.receive_region(blit_ctx, region)
+    }
+}
+
+/// Wraps the Rust parse adapter `P`, so that the values of some of its channels are transformed by functions,
+/// such as to rescale them or to replace them with a constant. Channels without a transform, and voxels which have
+/// no value, are passed through untouched. Each transform is a pure function of a single value, so regions which
+/// `P` reports as uniform remain uniform. Like [`channel_remap::RemappedParse`], only Rust parse adapters may be
+/// wrapped.
+///
+/// This adapter has no name of its own, so it must be registered with [`Context::register_adapter_named`].
+pub struct TransformParse<P>(PhantomData<P>);
+
+impl<P: AdapterDescriptor<Parse>> AdapterDescriptor<Parse> for TransformParse<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    type Configuration<'a> = TransformParseAdapterConfig<'a, P>;
+    type Handler = TransformParseHandler<P>;
+}
+
+/// A function which transforms the value of a channel.
+pub type ChannelTransform<'a> = Arc<dyn Fn(u32) -> u32 + Send + Sync + 'a>;
+
+/// A table of the functions which transform the values of each channel.
+#[derive(Clone)]
+pub struct ChannelTransforms<'a> {
+    /// The transform of each channel, indexed by channel ID.
+    transforms: [Option<ChannelTransform<'a>>; CHANNEL_ID_COUNT],
+}
+
+impl<'a> ChannelTransforms<'a> {
+    /// Creates a table which leaves every channel unchanged.
+    pub fn new() -> Self {
+        Self {
+            transforms: std::array::from_fn(|_| None),
+        }
+    }
+
+    /// Transforms the values of the channel with the provided function, replacing any previous transform.
+    pub fn insert(
+        &mut self,
+        channel_id: ChannelId,
+        transform: impl Fn(u32) -> u32 + Send + Sync + 'a,
+    ) {
+        self.transforms[u32::from(channel_id) as usize] = Some(Arc::new(transform));
+    }
+
+    /// Transforms the values of the channel with the provided function, replacing any previous transform.
+    pub fn with(
+        mut self,
+        channel_id: ChannelId,
+        transform: impl Fn(u32) -> u32 + Send + Sync + 'a,
+    ) -> Self {
+        self.insert(channel_id, transform);
+        self
+    }
+
+    /// The transform of the channel, or `None` if its values are unchanged.
+    pub fn get(&self, channel_id: ChannelId) -> Option<&ChannelTransform<'a>> {
+        self.transforms[u32::from(channel_id) as usize].as_ref()
+    }
+
+    /// Transforms a value of the channel.
+    pub fn apply(&self, channel_id: ChannelId, value: u32) -> u32 {
+        self.get(channel_id)
+            .map_or(value, |transform| transform(value))
+    }
+}
+
+impl Default for ChannelTransforms<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The configuration for a [`TransformParse`] adapter context.
+pub struct TransformParseAdapterConfig<'a, P: AdapterDescriptor<Parse>> {
+    /// The functions which transform the values of the wrapped adapter.
+    pub transforms: ChannelTransforms<'a>,
+    /// The configuration with which to create the wrapped adapter context.
+    pub config: P::Configuration<'a>,
+}
+
+/// Handles the callbacks of a [`TransformParse`] adapter context by forwarding them to the wrapped handler.
+pub struct TransformParseHandler<P: AdapterDescriptor<Parse>>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    /// The functions which transform the values of the wrapped adapter. Their borrows actually last only as long
+    /// as the adapter context's configuration lifetime, which the adapter context never outlives.
+    transforms: ChannelTransforms<'static>,
+    /// The handler of the wrapped adapter.
+    handler: P::Handler,
+}
+
+impl<P: AdapterDescriptor<Parse>> BaseAdapterHandler<Parse, TransformParse<P>>
+    for TransformParseHandler<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    fn create(config: &TransformParseAdapterConfig<'_, P>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            transforms: unsafe {
+                transmute::<ChannelTransforms<'_>, ChannelTransforms<'static>>(
+                    config.transforms.clone(),
+                )
+            },
+            handler: P::Handler::create(&config.config)?,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        self.handler.destroy()
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.handler.blit_begin(blit_ctx, range, channel_flags)
+    }
+
+    fn blit_end(&mut self, blit_ctx: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.handler.blit_end(blit_ctx)
+    }
+}
+
+impl<P: AdapterDescriptor<Parse>> ParseAdapterHandler<TransformParse<P>>
+    for TransformParseHandler<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    type RegionData = <P::Handler as ParseAdapterHandler<P>>::RegionData;
+
+    fn query_details() -> ParseAdapterDetails {
+        P::Handler::query_details()
+    }
+
+    fn query_parsable_range(&mut self, blit_ctx: &ParseBlitContext) -> RegionRange {
+        self.handler.query_parsable_range(blit_ctx)
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        self.handler
+            .query_region_flags(blit_ctx, range, channel_flags)
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        self.handler.load_region(blit_ctx, range, channel_flags)
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        region: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        self.handler.unload_region(blit_ctx, region)
+    }
+
+    /// The values of uniform regions are sampled like any other, so they are transformed here as well.
+    fn sample_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        region: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let sample = self
+            .handler
+            .sample_region(blit_ctx, region, offset, channel_id)?;
+        Ok(Sample {
+            data: if sample.is_present {
+                self.transforms.apply(channel_id, sample.data)
+            } else {
+                sample.data
+            },
+            ..sample
+        })
+    }
+
+    /// Regions emitted by the wrapped adapter hold the same data as those of this adapter, so they are
+    /// sampled through this adapter, which transforms their values.
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.handler.parse_region(blit_ctx, range, channel_flags)
+    }
+}
+
+/// A transform of voxel positions which is limited to permutations of the axes, sign flips, and translation, so
+/// that every voxel maps onto exactly one other. Each component of a transformed position is a component of the
+/// original position, possibly negated, plus the translation. Negating a component maps the voxel at `x` onto the
+/// voxel at `-x`, rather than mirroring the voxel's extent about the origin.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VoxelTransform {
+    /// The axis of the original position from which each component of the transformed position is taken.
+    axes: [usize; 3],
+    /// The sign of each component of the transformed position, which is either one or negative one.
+    signs: [i32; 3],
+    /// The offset which is added to each component of the transformed position.
+    translation: [i32; 3],
+}
+
+impl VoxelTransform {
+    /// Creates a transform which leaves every position unchanged.
+    pub const fn identity() -> Self {
+        Self {
+            axes: [0, 1, 2],
+            signs: [1; 3],
+            translation: [0; 3],
+        }
+    }
+
+    /// Creates a transform which takes each component of the transformed position from the provided axis of the
+    /// original position, where x, y, and z are zero, one, and two, multiplies it by the provided sign, and then adds
+    /// the translation. Returns an error if the axes are not a permutation, or a sign is not one or negative one.
+    pub fn new(
+        axes: [usize; 3],
+        signs: [i32; 3],
+        translation: Offset3D,
+    ) -> Result<Self, GvoxError> {
+        let mut sorted = axes;
+        sorted.sort_unstable();
+        if sorted != [0, 1, 2] || signs.iter().any(|x| x.abs() != 1) {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Axes {axes:?} with signs {signs:?} do not describe a rotation or reflection."
+                ),
+            ));
+        }
+
+        Ok(Self {
+            axes,
+            signs,
+            translation: Self::components(&translation),
+        })
+    }
+
+    /// Rotates positions counter-clockwise about the x axis by the provided number of quarter turns, after this transform.
+    pub fn rotate_x(self, quarter_turns: i32) -> Self {
+        self.turn([0, 2, 1], [1, -1, 1], quarter_turns)
+    }
+
+    /// Rotates positions counter-clockwise about the y axis by the provided number of quarter turns, after this transform.
+    pub fn rotate_y(self, quarter_turns: i32) -> Self {
+        self.turn([2, 1, 0], [1, 1, -1], quarter_turns)
+    }
+
+    /// Rotates positions counter-clockwise about the z axis by the provided number of quarter turns, after this transform.
+    pub fn rotate_z(self, quarter_turns: i32) -> Self {
+        self.turn([1, 0, 2], [-1, 1, 1], quarter_turns)
+    }
+
+    /// Negates the x component of positions, after this transform.
+    pub fn mirror_x(self) -> Self {
+        self.turn([0, 1, 2], [-1, 1, 1], 1)
+    }
+
+    /// Negates the y component of positions, after this transform.
+    pub fn mirror_y(self) -> Self {
+        self.turn([0, 1, 2], [1, -1, 1], 1)
+    }
+
+    /// Negates the z component of positions, after this transform.
+    pub fn mirror_z(self) -> Self {
+        self.turn([0, 1, 2], [1, 1, -1], 1)
+    }
+
+    /// Moves positions by the provided offset, after this transform.
+    pub fn translate(self, offset: Offset3D) -> Self {
+        self.then(&Self {
+            translation: Self::components(&offset),
+            ..Self::identity()
+        })
+    }
+
+    /// Combines this transform with another, which is applied after it.
+    pub fn then(&self, next: &Self) -> Self {
+        Self {
+            axes: next.axes.map(|x| self.axes[x]),
+            signs: std::array::from_fn(|i| next.signs[i] * self.signs[next.axes[i]]),
+            translation: std::array::from_fn(|i| {
+                next.signs[i]
+                    .wrapping_mul(self.translation[next.axes[i]])
+                    .wrapping_add(next.translation[i])
+            }),
+        }
+    }
+
+    /// The transform which undoes this one.
+    pub fn inverse(&self) -> Self {
+        let mut result = Self::identity();
+        for i in 0..3 {
+            result.axes[self.axes[i]] = i;
+            result.signs[self.axes[i]] = self.signs[i];
+            result.translation[self.axes[i]] = self.signs[i]
+                .wrapping_mul(self.translation[i])
+                .wrapping_neg();
+        }
+        result
+    }
+
+    /// Transforms a position.
+    pub fn apply(&self, position: &Offset3D) -> Offset3D {
+        let position = Self::components(position);
+        let [x, y, z] = std::array::from_fn(|i| {
+            self.signs[i]
+                .wrapping_mul(position[self.axes[i]])
+                .wrapping_add(self.translation[i])
+        });
+        Offset3D { x, y, z }
+    }
+
+    /// The range which contains exactly the transformed positions of the voxels within the provided range.
+    pub fn apply_range(&self, range: &RegionRange) -> RegionRange {
+        let offset = Self::components(&range.offset);
+        let extent = [range.extent.x, range.extent.y, range.extent.z];
+        let [(x, w), (y, h), (z, d)] = std::array::from_fn(|i| {
+            let axis = self.axes[i];
+            let start = match self.signs[i] {
+                1 => offset[axis] as i64,
+                _ => 1 - offset[axis] as i64 - extent[axis] as i64,
+            };
+            ((start + self.translation[i] as i64) as i32, extent[axis])
+        });
+        RegionRange {
+            offset: Offset3D { x, y, z },
+            extent: Extent3D { x: w, y: h, z: d },
+        }
+    }
+
+    /// Rotates a normal vector whose components are stored in the lowest three bytes of the value, where zero and
+    /// 255 denote negative and positive one. The highest byte is left unchanged. Translation does not affect normals.
+    pub fn apply_normal(&self, normal: u32) -> u32 {
+        let component = |i: usize| (normal >> (8 * i)) & 0xff;
+        (0..3).fold(normal & 0xff000000, |acc, i| {
+            let value = match self.signs[i] {
+                1 => component(self.axes[i]),
+                _ => 0xff - component(self.axes[i]),
+            };
+            acc | (value << (8 * i))
+        })
+    }
+
+    /// Applies a signed permutation the provided number of times, after this transform.
+    fn turn(self, axes: [usize; 3], signs: [i32; 3], times: i32) -> Self {
+        let step = Self {
+            axes,
+            signs,
+            translation: [0; 3],
+        };
+        (0..times.rem_euclid(4)).fold(self, |acc, _| acc.then(&step))
+    }
+
+    /// The components of an offset, in the order x, y, z.
+    fn components(offset: &Offset3D) -> [i32; 3] {
+        [offset.x, offset.y, offset.z]
+    }
+}
+
+impl Default for VoxelTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Wraps the Rust parse adapter `P`, so that its voxels are moved by a [`VoxelTransform`], such as to convert
+/// models between coordinate systems with different handedness or vertical axes. The parsable range is the
+/// transformed range of `P`, and positions are transformed back before they are sampled from `P`. Normals in the
+/// [`ChannelId::NORMAL`] channel are rotated along with the voxels. Like [`channel_remap::RemappedParse`], only
+/// Rust parse adapters may be wrapped.
+///
+/// This adapter has no name of its own, so it must be registered with [`Context::register_adapter_named`].
+pub struct TransformedParse<P>(PhantomData<P>);
+
+impl<P: AdapterDescriptor<Parse>> AdapterDescriptor<Parse> for TransformedParse<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    type Configuration<'a> = TransformedParseAdapterConfig<'a, P>;
+    type Handler = TransformedParseHandler<P>;
+}
+
+/// The configuration for a [`TransformedParse`] adapter context.
+pub struct TransformedParseAdapterConfig<'a, P: AdapterDescriptor<Parse>> {
+    /// The transform which moves the voxels of the wrapped adapter.
+    pub transform: VoxelTransform,
+    /// The configuration with which to create the wrapped adapter context.
+    pub config: P::Configuration<'a>,
+}
+
+/// Handles the callbacks of a [`TransformedParse`] adapter context by forwarding them to the wrapped handler.
+pub struct TransformedParseHandler<P: AdapterDescriptor<Parse>>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    /// The transform which moves the voxels of the wrapped adapter.
+    transform: VoxelTransform,
+    /// The transform which moves positions back into the space of the wrapped adapter.
+    inverse: VoxelTransform,
+    /// The handler of the wrapped adapter.
+    handler: P::Handler,
+}
+
+impl<P: AdapterDescriptor<Parse>> BaseAdapterHandler<Parse, TransformedParse<P>>
+    for TransformedParseHandler<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    fn create(config: &TransformedParseAdapterConfig<'_, P>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            transform: config.transform,
+            inverse: config.transform.inverse(),
+            handler: P::Handler::create(&config.config)?,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        self.handler.destroy()
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let range = range.map(|x| self.inverse.apply_range(x));
+        self.handler
+            .blit_begin(blit_ctx, range.as_ref(), channel_flags)
+    }
+
+    fn blit_end(&mut self, blit_ctx: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.handler.blit_end(blit_ctx)
+    }
+}
+
+impl<P: AdapterDescriptor<Parse>> ParseAdapterHandler<TransformedParse<P>>
+    for TransformedParseHandler<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    type RegionData = Region<<P::Handler as ParseAdapterHandler<P>>::RegionData>;
+
+    fn query_details() -> ParseAdapterDetails {
+        P::Handler::query_details()
+    }
+
+    fn query_parsable_range(&mut self, blit_ctx: &ParseBlitContext) -> RegionRange {
+        self.transform
+            .apply_range(&self.handler.query_parsable_range(blit_ctx))
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        self.handler
+            .query_region_flags(blit_ctx, &self.inverse.apply_range(range), channel_flags)
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        let region =
+            self.handler
+                .load_region(blit_ctx, &self.inverse.apply_range(range), channel_flags)?;
+        Ok(Region::new(*range, region.channels, region.flags, region))
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        region: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        self.handler.unload_region(blit_ctx, region.into_data())
+    }
+
+    fn sample_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        region: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let sample = self.handler.sample_region(
+            blit_ctx,
+            &**region,
+            &self.inverse.apply(offset),
+            channel_id,
+        )?;
+        Ok(Sample {
+            data: if sample.is_present && channel_id == ChannelId::NORMAL {
+                self.transform.apply_normal(sample.data)
+            } else {
+                sample.data
+            },
+            ..sample
+        })
+    }
+
+    /// Regions emitted by the wrapped adapter lie in its own space, so the requested range is instead
+    /// loaded and emitted as a single region.
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        let result = blit_ctx.emit_region(&region);
+        self.unload_region(blit_ctx, region)?;
+        result
+    }
+}
+
+/// Handles conversions from the tagged gvox format.
+pub struct GvoxPalette;
+
+impl AdapterDescriptor<Parse> for GvoxPalette {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl AdapterDescriptor<Serialize> for GvoxPalette {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for GvoxPalette {
+    fn name() -> &'static str {
+        "gvox_palette"
+    }
+}
+
+/// Handles conversions from the raw gvox format.
+pub struct GvoxRaw;
+
+impl AdapterDescriptor<Parse> for GvoxRaw {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl AdapterDescriptor<Serialize> for GvoxRaw {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for GvoxRaw {
+    fn name() -> &'static str {
+        "gvox_raw"
+    }
+}
+
+/// Handles conversions for Gvox Brickmap files.
+pub struct GvoxBrickmap;
+
+impl AdapterDescriptor<Parse> for GvoxBrickmap {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl AdapterDescriptor<Serialize> for GvoxBrickmap {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for GvoxBrickmap {
+    fn name() -> &'static str {
+        "gvox_brickmap"
+    }
+}
+
+/// Handles conversions for Gvox Global Palette files.
+pub struct GvoxGlobalPalette;
+
+impl AdapterDescriptor<Parse> for GvoxGlobalPalette {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl AdapterDescriptor<Serialize> for GvoxGlobalPalette {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for GvoxGlobalPalette {
+    fn name() -> &'static str {
+        "gvox_global_palette"
+    }
+}
+
+/// Handles conversions for Gvox Octree files.
+pub struct GvoxOctree;
+
+impl AdapterDescriptor<Parse> for GvoxOctree {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl AdapterDescriptor<Serialize> for GvoxOctree {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for GvoxOctree {
+    fn name() -> &'static str {
+        "gvox_octree"
+    }
+}
+
+/// Handles conversions for Gvox RLE files.
+pub struct GvoxRunLengthEncoding;
+
+impl AdapterDescriptor<Parse> for GvoxRunLengthEncoding {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl AdapterDescriptor<Serialize> for GvoxRunLengthEncoding {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for GvoxRunLengthEncoding {
+    fn name() -> &'static str {
+        "gvox_run_length_encoding"
+    }
+}
+
+/// Handles conversions for MagicaVoxel files.
+pub struct MagicaVoxel;
+
+impl AdapterDescriptor<Parse> for MagicaVoxel {
+    type Configuration<'a> = ();
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for MagicaVoxel {
+    fn name() -> &'static str {
+        "magicavoxel"
+    }
+}
+
+/// Handles conversions for Voxlap and Ace of Spades files.
+pub struct Voxlap;
+
+impl AdapterDescriptor<Parse> for Voxlap {
+    type Configuration<'a> = VoxlapParseAdapterConfig;
+    type Handler = ExternalHandler;
+}
+
+impl NamedAdapter for Voxlap {
+    fn name() -> &'static str {
+        "voxlap"
+    }
+}
+
+/// Describes how Voxlap data should be parsed. This is passed to the native adapter as-is,
+/// so its layout must match that of [`gvox_sys::GvoxVoxlapParseAdapterConfig`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct VoxlapParseAdapterConfig {
+    /// The dimensions of the input data.
+    pub size: Extent3D,
+    /// Whether to fill in the inside of objects, or leave them hollow.
+    pub make_solid: bool,
+    /// Whether this an Ace of Spades file. Ace of Spades files do not have a header.
+    pub is_ace_of_spades: bool,
+}
+
+impl Default for VoxlapParseAdapterConfig {
+    fn default() -> Self {
+        Self {
+            size: Extent3D {
+                x: 1024,
+                y: 1024,
+                z: 256,
+            },
+            make_solid: true,
+            is_ace_of_spades: Default::default(),
+        }
+    }
+}
+
+/// Handles conversions for Voxlap and Ace of Spades files.
+pub struct Kvx;
+
+impl AdapterDescriptor<Parse> for Kvx {
+    type Configuration<'a> = KvxParseAdapterConfig;
+    type Handler = ExternalHandler;
+
+    fn blit_substitute<'a>(
+        ctx: &Context,
+        config: &KvxParseAdapterConfig,
+    ) -> Result<Option<AdapterContext<'a, Parse>>, GvoxError> {
+        if config.level_to_parse == 0 {
+            return Ok(None);
+        }
+        config.check_level()?;
+
+        // Blits present the selected level to the native adapter as a file with a single level.
+        ctx.get_adapter::<Parse, Kvx>()?
+            .create_adapter_context(KvxParseAdapterConfig {
+                mipmaplevels: 1,
+                level_to_parse: 0,
+            })
+            .map(Some)
+    }
+
+    fn input_substitute(
+        ctx: &Context,
+        config: &KvxParseAdapterConfig,
+        read: &mut InputReader<'_>,
+    ) -> Result<Option<AdapterContext<'static, Input>>, GvoxError> {
+        if config.level_to_parse == 0 {
+            return Ok(None);
+        }
+
+        // The selected level is read ahead of the blit, and given to the native adapter as its whole input.
+        let bytes = config.read_level(read)?;
+        ctx.get_adapter::<Input, ByteBufferOwned>()?
+            .create_adapter_context(bytes.into())
+            .map(Some)
+    }
+}
+
+impl NamedAdapter for Kvx {
+    fn name() -> &'static str {
+        "kvx"
+    }
+}
+
+/// The size of the palette which follows the last mip level of a KVX file.
+const KVX_PALETTE_SIZE: usize = 768;
+
+/// Describes how Kvx data should be parsed. This is passed to the native adapter as-is, so its
+/// leading fields must match those of [`gvox_sys::GvoxKvxParseAdapterConfig`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct KvxParseAdapterConfig {
+    /// The number of mip levels stored in the file.
+    pub mipmaplevels: u8,
+    /// The mip level which is parsed into voxel data, where zero is the full-resolution level. The native adapter
+    /// only parses the first level, so for any other level, blits read just that level, followed by the palette,
+    /// as if it were the only level of the file. Levels that the file does not contain are rejected with
+    /// [`ErrorType::ParseAdapterInvalidInput`].
+    pub level_to_parse: u8,
+}
+
+impl KvxParseAdapterConfig {
+    /// Reads the selected level of a KVX file, followed by its palette, as a file with a single level. The provided
+    /// function fills a slice with the bytes of the file that begin at a position, like [`AdapterContext::read`].
+    pub fn read_level(
+        &self,
+        mut read: impl FnMut(usize, &mut [u8]) -> Result<(), GvoxError>,
+    ) -> Result<Vec<u8>, GvoxError> {
+        self.check_level()?;
+        let level = self.level_to_parse;
+        let invalid = |error: GvoxError| {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!("Could not read level {level} of the KVX file: {error}"),
+            )
+        };
+
+        // Each level begins with the number of bytes which follow it, and the palette follows the last level.
+        let mut position = 0usize;
+        let mut selected = 0..0;
+        for index in 0..self.mipmaplevels {
+            let mut size = [0; 4];
+            read(position, &mut size).map_err(invalid)?;
+            let end = position
+                .checked_add(4)
+                .and_then(|x| x.checked_add(u32::from_le_bytes(size) as usize))
+                .ok_or_else(|| {
+                    GvoxError::new(
+                        ErrorType::ParseAdapterInvalidInput,
+                        format!("Level {index} of the KVX file was too large."),
+                    )
+                })?;
+            if index == level {
+                selected = position..end;
+            }
+            position = end;
+        }
+
+        let mut bytes = vec![0; selected.len() + KVX_PALETTE_SIZE];
+        let (data, palette) = bytes.split_at_mut(selected.len());
+        read(selected.start, data).map_err(invalid)?;
+        read(position, palette).map_err(invalid)?;
+        Ok(bytes)
+    }
+
+    /// Returns an error if the selected level is not one of the levels stored in the file.
+    fn check_level(&self) -> Result<(), GvoxError> {
+        if self.level_to_parse >= self.mipmaplevels {
+            return Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!(
+                    "Level {} was requested, but the file only contains {} levels.",
+                    self.level_to_parse, self.mipmaplevels
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for KvxParseAdapterConfig {
+    fn default() -> Self {
+        Self {
+            mipmaplevels: 5,
+            level_to_parse: 0,
+        }
+    }
+}
+
+/// Writes the color channel as a MagicaVoxel `.vox` file containing a single model. Voxels whose color
+/// has no alpha are left empty. MagicaVoxel palettes hold at most 255 colors, so if more are present,
+/// the most common colors are kept and every other color is replaced by the nearest of them.
+pub struct MagicaVoxelOut;
+
+impl AdapterDescriptor<Serialize> for MagicaVoxelOut {
+    type Configuration<'a> = ();
+    type Handler = MagicaVoxelOutHandler;
+}
+
+impl NamedAdapter for MagicaVoxelOut {
+    fn name() -> &'static str {
+        "magicavoxel"
+    }
+}
+
+/// The largest number of voxels that a MagicaVoxel model may have along each axis.
+const MAGICAVOXEL_MAX_SIZE: u32 = 256;
+
+/// The number of colors in a MagicaVoxel palette. Palette index zero denotes an empty voxel, so it has no color.
+const MAGICAVOXEL_PALETTE_SIZE: usize = 255;
+
+/// The version number written to MagicaVoxel files.
+const MAGICAVOXEL_VERSION: u32 = 150;
+
+/// Handles the adapter context operations of MagicaVoxel serializers.
+pub struct MagicaVoxelOutHandler {
+    /// The range of the current blit, if one was provided.
+    range: Option<RegionRange>,
+    /// The smallest range which contains every range serialized so far during the current blit.
+    bounds: Option<RegionRange>,
+    /// The position and color of every non-empty voxel serialized so far during the current blit.
+    voxels: Vec<(Offset3D, u32)>,
+}
+
+impl MagicaVoxelOutHandler {
+    /// Writes a complete file, containing every voxel serialized during the current blit, to the output.
+    fn write_model(&self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        let range = self.range.or(self.bounds).unwrap_or_default();
+        let Extent3D { x, y, z } = range.extent;
+        if x > MAGICAVOXEL_MAX_SIZE || y > MAGICAVOXEL_MAX_SIZE || z > MAGICAVOXEL_MAX_SIZE {
+            return Err(GvoxError::new(
+                ErrorType::SerializeAdapterUnrepresentableData,
+                format!(
+                    "MagicaVoxel models may have at most {MAGICAVOXEL_MAX_SIZE} voxels along each axis, \
+                    but the range has an extent of {x}x{y}x{z}. Blit smaller ranges to separate files instead."
+                ),
+            ));
+        }
+
+        let voxels = self
+            .voxels
+            .iter()
+            .filter(|(position, _)| range.contains(position))
+            .collect::<Vec<_>>();
+        let (palette, indices) = quantize_palette(voxels.iter().map(|(_, color)| *color));
+
+        let mut size = Vec::with_capacity(3 * size_of::<u32>());
+        for n in [x, y, z] {
+            size.extend_from_slice(&n.to_le_bytes());
+        }
+
+        let mut xyzi = Vec::with_capacity((voxels.len() + 1) * size_of::<u32>());
+        xyzi.extend_from_slice(&(voxels.len() as u32).to_le_bytes());
+        for (position, color) in voxels {
+            let local = |position: i32, offset: i32| (position as i64 - offset as i64) as u8;
+            xyzi.extend_from_slice(&[
+                local(position.x, range.offset.x),
+                local(position.y, range.offset.y),
+                local(position.z, range.offset.z),
+                indices[color],
+            ]);
+        }
+
+        let mut rgba = vec![0; (MAGICAVOXEL_PALETTE_SIZE + 1) * size_of::<u32>()];
+        for (entry, color) in rgba.chunks_exact_mut(size_of::<u32>()).zip(palette) {
+            entry.copy_from_slice(&color.to_le_bytes());
+        }
+
+        let children = [
+            magicavoxel_chunk(b"SIZE", &size, &[]),
+            magicavoxel_chunk(b"XYZI", &xyzi, &[]),
+            magicavoxel_chunk(b"RGBA", &rgba, &[]),
+        ]
+        .concat();
+        let bytes = [
+            &b"VOX "[..],
+            &MAGICAVOXEL_VERSION.to_le_bytes(),
+            &magicavoxel_chunk(b"MAIN", &[], &children),
+        ]
+        .concat();
+
+        blit_ctx.output_reserve(bytes.len())?;
+        blit_ctx.output_write(0, &bytes)
+    }
+}
+
+impl BaseAdapterHandler<Serialize, MagicaVoxelOut> for MagicaVoxelOutHandler {
+    fn create(_: &()) -> Result<Self, GvoxError> {
+        Ok(Self {
+            range: None,
+            bounds: None,
+            voxels: Vec::new(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.range = range.copied();
+        self.bounds = None;
+        self.voxels.clear();
+        Ok(())
+    }
+
+    /// Writes the file once every region of the blit has been collected, if any was.
+    fn blit_end(&mut self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        match self.bounds {
+            Some(_) => self.write_model(blit_ctx),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SerializeAdapterHandler<MagicaVoxelOut> for MagicaVoxelOutHandler {
+    type RegionData = ();
+
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = blit_ctx.load_region_range(range, ChannelId::COLOR.into())?;
+        for voxel in region.iter(ChannelId::COLOR.into()) {
+            let (position, samples) = voxel?;
+            if let Some(color) = samples.get(ChannelId::COLOR).filter(|x| x >> 24 != 0) {
+                self.voxels.push((position, color));
+            }
+        }
+
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounding_range(&bounds, range),
+            None => *range,
+        });
+        Ok(())
+    }
+}
+
+/// Encodes a MagicaVoxel chunk with the provided identifier, content, and children.
+fn magicavoxel_chunk(id: &[u8; 4], content: &[u8], children: &[u8]) -> Vec<u8> {
+    [
+        &id[..],
+        &(content.len() as u32).to_le_bytes(),
+        &(children.len() as u32).to_le_bytes(),
+        content,
+        children,
+    ]
+    .concat()
+}
+
+/// Chooses a palette of at most [`MAGICAVOXEL_PALETTE_SIZE`] colors, preferring those which occur most often.
+/// Returns the palette, along with the palette index (beginning at one) which represents each provided color.
+fn quantize_palette(colors: impl Iterator<Item = u32>) -> (Vec<u32>, HashMap<u32, u8>) {
+    let mut counts = HashMap::new();
+    for color in colors {
+        *counts.entry(color).or_insert(0usize) += 1;
+    }
+
+    let mut palette = counts
+        .iter()
+        .map(|(&color, &count)| (color, count))
+        .collect::<Vec<_>>();
+    palette.sort_unstable_by_key(|&(color, count)| (std::cmp::Reverse(count), color));
+    let palette = palette
+        .into_iter()
+        .take(MAGICAVOXEL_PALETTE_SIZE)
+        .map(|(color, _)| color)
+        .collect::<Vec<_>>();
+
+    let distance = |a: u32, b: u32| {
+        a.to_le_bytes()
+            .into_iter()
+            .zip(b.to_le_bytes())
+            .map(|(a, b)| (a as i32 - b as i32).pow(2))
+            .sum::<i32>()
+    };
+    let indices = counts
+        .into_keys()
+        .map(|color| {
+            let (index, _) = palette
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, &entry)| distance(color, entry))
+                .expect("Palette of a non-empty model was empty.");
+            (color, index as u8 + 1)
+        })
+        .collect();
+
+    (palette, indices)
+}
+
+/// Parses the models of a MagicaVoxel `.vox` file individually, rather than the flattened scene that
+/// [`MagicaVoxel`] parses. This adapter is implemented in Rust, and generates the [`ChannelId::COLOR`]
+/// channel, along with the [`ChannelId::MATERIAL_ID`] channel holding the palette index of each voxel.
+pub struct MagicaVoxelModel;
+
+impl AdapterDescriptor<Parse> for MagicaVoxelModel {
+    type Configuration<'a> = MagicaVoxelParseAdapterConfig;
+    type Handler = MagicaVoxelModelHandler;
+}
+
+impl NamedAdapter for MagicaVoxelModel {
+    fn name() -> &'static str {
+        "magicavoxel_model"
+    }
+}
+
+/// Describes which models of a MagicaVoxel file should be parsed, and where they are placed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MagicaVoxelParseAdapterConfig {
+    /// The index of the model to parse, in the order that the models are stored in the file,
+    /// or `None` to parse every model. Where models overlap, the earliest model takes precedence.
+    pub model_index: Option<u32>,
+    /// Whether each model is centered on the translation of the scene graph node which places it.
+    /// Otherwise, every model begins at the origin. Rotations are never applied, and a model that
+    /// is placed more than once uses its first placement.
+    pub apply_scene_transforms: bool,
+}
+
+/// The voxels of one model from a MagicaVoxel file.
+struct MagicaVoxelModelData {
+    /// The volume which the model occupies.
+    range: RegionRange,
+    /// The palette index of each voxel, with x varying fastest, where zero denotes an empty voxel.
+    indices: Vec<u8>,
+}
+
+impl MagicaVoxelModelData {
+    /// The palette index of the voxel at the provided position, or zero if it is empty or lies outside of the model.
+    fn get(&self, position: &Offset3D) -> u8 {
+        if !self.range.contains(position) {
+            return 0;
+        }
+
+        let RegionRange { offset, extent } = self.range;
+        let x = (position.x as i64 - offset.x as i64) as usize;
+        let y = (position.y as i64 - offset.y as i64) as usize;
+        let z = (position.z as i64 - offset.z as i64) as usize;
+        self.indices[x + extent.x as usize * (y + extent.y as usize * z)]
+    }
+}
+
+/// Handles the adapter context operations of MagicaVoxel model parsers.
+pub struct MagicaVoxelModelHandler {
+    /// The configuration with which the adapter context was created.
+    config: MagicaVoxelParseAdapterConfig,
+    /// The selected models of the input of the current blit.
+    models: Vec<MagicaVoxelModelData>,
+    /// The color of each palette index.
+    palette: [u32; 256],
+}
+
+impl MagicaVoxelModelHandler {
+    /// The channels that this adapter generates.
+    fn channels() -> ChannelFlags {
+        ChannelId::COLOR | ChannelId::MATERIAL_ID
+    }
+}
+
+impl BaseAdapterHandler<Parse, MagicaVoxelModel> for MagicaVoxelModelHandler {
+    fn create(config: &MagicaVoxelParseAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            config: *config,
+            models: Vec::new(),
+            palette: [0; 256],
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        if blit_ctx.read_exact_array(0)? != *b"VOX " {
+            return Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                "The input is not a MagicaVoxel file.",
+            ));
+        }
+
+        // The MAIN chunk header follows the magic and version, and every other chunk is its child.
+        let children_size = blit_ctx.read_u32_le(16)? as usize;
+        let file = MagicaVoxelFile::read(&blit_ctx.read_vec(20, children_size)?)?;
+
+        let placements = if self.config.apply_scene_transforms {
+            file.model_translations()
+        } else {
+            HashMap::new()
+        };
+        let selected = match self.config.model_index {
+            Some(index) if index as usize >= file.models.len() => {
+                return Err(GvoxError::new(
+                    ErrorType::ParseAdapterInvalidInput,
+                    format!(
+                        "Model {index} was requested, but the file only contains {} models.",
+                        file.models.len()
+                    ),
+                ));
+            }
+            Some(index) => index as usize..index as usize + 1,
+            None => 0..file.models.len(),
+        };
+
+        self.palette = file.palette;
+        self.models = file.models[selected.clone()]
+            .iter()
+            .zip(selected)
+            .map(|((extent, voxels), index)| {
+                let offset = placements
+                    .get(&(index as u32))
+                    .map_or_else(Offset3D::default, |t| Offset3D {
+                        x: t.x - (extent.x / 2) as i32,
+                        y: t.y - (extent.y / 2) as i32,
+                        z: t.z - (extent.z / 2) as i32,
+                    });
+                let (sx, sy, sz) = (extent.x as usize, extent.y as usize, extent.z as usize);
+                let mut indices = vec![0; sx * sy * sz];
+                for &[x, y, z, i] in voxels {
+                    let (x, y, z) = (x as usize, y as usize, z as usize);
+                    if x < sx && y < sy && z < sz {
+                        indices[x + sx * (y + sy * z)] = i;
+                    }
+                }
+
+                MagicaVoxelModelData {
+                    range: RegionRange {
+                        offset,
+                        extent: *extent,
+                    },
+                    indices,
+                }
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.models.clear();
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<MagicaVoxelModel> for MagicaVoxelModelHandler {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.models
+            .iter()
+            .map(|model| model.range)
+            .reduce(|a, b| bounding_range(&a, &b))
+            .unwrap_or_default()
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        Ok(Region::builder()
+            .range(*range)
+            .channels(channel_flags & Self::channels())
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let index = self
+            .models
+            .iter()
+            .map(|model| model.get(offset))
+            .find(|&index| index != 0)
+            .unwrap_or_default();
+        let value = match channel_id {
+            _ if index == 0 => None,
+            ChannelId::COLOR => Some(self.palette[index as usize]),
+            ChannelId::MATERIAL_ID => Some(index as u32),
+            _ => None,
+        };
+
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        blit_ctx.emit_region(
+            &Region::builder()
+                .range(*range)
+                .channels(channel_flags & Self::channels())
+                .build(),
+        )
+    }
+}
+
+/// A node of the scene graph of a MagicaVoxel file.
+enum MagicaVoxelSceneNode {
+    /// Translates its child node.
+    Transform {
+        /// The identifier of the child node.
+        child: u32,
+        /// The translation of the first animation frame.
+        translation: Offset3D,
+    },
+    /// Contains the provided child nodes.
+    Group(Vec<u32>),
+    /// Places the provided models.
+    Shape(Vec<u32>),
+}
+
+/// The contents of a MagicaVoxel file which this crate understands.
+struct MagicaVoxelFile {
+    /// The size and voxels of each model, where each voxel is its position followed by its palette index.
+    models: Vec<(Extent3D, Vec<[u8; 4]>)>,
+    /// The color of each palette index.
+    palette: [u32; 256],
+    /// The materials declared by the MATL chunks, in the order in which they appear.
+    materials: Vec<inspect::Material>,
+    /// The nodes of the scene graph, by identifier.
+    nodes: HashMap<u32, MagicaVoxelSceneNode>,
+}
+
+impl MagicaVoxelFile {
+    /// Reads the provided children of the MAIN chunk. Unknown chunks are skipped.
+    fn read(bytes: &[u8]) -> Result<Self, GvoxError> {
+        let mut result = Self {
+            models: Vec::new(),
+            palette: magicavoxel_default_palette(),
+            materials: Vec::new(),
+            nodes: HashMap::new(),
+        };
+
+        let mut reader = MagicaVoxelReader { bytes, position: 0 };
+        let mut size = None;
+        while reader.position < bytes.len() {
+            let id = reader.take(4)?;
+            let content_size = reader.u32()? as usize;
+            let children_size = reader.u32()? as usize;
+            let mut content = MagicaVoxelReader {
+                bytes: reader.take(content_size)?,
+                position: 0,
+            };
+            reader.take(children_size)?;
+
+            match id {
+                b"SIZE" => {
+                    let extent = content.extent()?;
+                    let Extent3D { x, y, z } = extent;
+                    if x > MAGICAVOXEL_MAX_SIZE
+                        || y > MAGICAVOXEL_MAX_SIZE
+                        || z > MAGICAVOXEL_MAX_SIZE
+                    {
+                        return Err(GvoxError::new(
+                            ErrorType::ParseAdapterInvalidInput,
+                            format!(
+                                "MagicaVoxel models may have at most {MAGICAVOXEL_MAX_SIZE} voxels along each axis, \
+                                but a SIZE chunk had an extent of {x}x{y}x{z}."
+                            ),
+                        ));
+                    }
+                    size = Some(extent);
+                }
+                b"XYZI" => {
+                    let extent = size.take().ok_or_else(|| {
+                        GvoxError::new(
+                            ErrorType::ParseAdapterInvalidInput,
+                            "A MagicaVoxel model had no SIZE chunk.",
+                        )
+                    })?;
+                    let count = content.u32()? as usize;
+                    let voxels = content
+                        .take(count.saturating_mul(4))?
+                        .chunks_exact(4)
+                        .map(|x| [x[0], x[1], x[2], x[3]])
+                        .collect();
+                    result.models.push((extent, voxels));
+                }
+                b"RGBA" => {
+                    for entry in result.palette[1..].iter_mut() {
+                        *entry = content.u32()?;
+                    }
+                }
+                b"MATL" => {
+                    let id = content.u32()?;
+                    result
+                        .materials
+                        .push(inspect::Material::new(id, content.dict()?));
+                }
+                b"nTRN" => {
+                    let id = content.u32()?;
+                    content.dict()?;
+                    let child = content.u32()?;
+                    // The reserved identifier and layer identifier are unused.
+                    content.take(8)?;
+                    let frames = content.u32()?;
+                    let translation = if frames > 0 {
+                        content
+                            .dict()?
+                            .get("_t")
+                            .map_or(Ok(Offset3D::default()), |x| {
+                                parse_magicavoxel_translation(x)
+                            })?
+                    } else {
+                        Offset3D::default()
+                    };
+                    result
+                        .nodes
+                        .insert(id, MagicaVoxelSceneNode::Transform { child, translation });
+                }
+                b"nGRP" => {
+                    let id = content.u32()?;
+                    content.dict()?;
+                    let count = content.u32()?;
+                    let children = (0..count)
+                        .map(|_| content.u32())
+                        .collect::<Result<_, _>>()?;
+                    result
+                        .nodes
+                        .insert(id, MagicaVoxelSceneNode::Group(children));
+                }
+                b"nSHP" => {
+                    let id = content.u32()?;
+                    content.dict()?;
+                    let count = content.u32()?;
+                    let models = (0..count)
+                        .map(|_| {
+                            let model = content.u32()?;
+                            content.dict()?;
+                            Ok(model)
+                        })
+                        .collect::<Result<_, GvoxError>>()?;
+                    result.nodes.insert(id, MagicaVoxelSceneNode::Shape(models));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Determines the translation of the first placement of each model within the scene graph.
+    fn model_translations(&self) -> HashMap<u32, Offset3D> {
+        let mut result = HashMap::new();
+        let mut visited = FxHashSet::default();
+        let mut pending = vec![(0, Offset3D::default())];
+        while let Some((id, translation)) = pending.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+
+            match self.nodes.get(&id) {
+                Some(MagicaVoxelSceneNode::Transform {
+                    child,
+                    translation: t,
+                }) => pending.push((
+                    *child,
+                    Offset3D {
+                        x: translation.x.wrapping_add(t.x),
+                        y: translation.y.wrapping_add(t.y),
+                        z: translation.z.wrapping_add(t.z),
+                    },
+                )),
+                // Children are pushed in reverse, so that they are visited in order.
+                Some(MagicaVoxelSceneNode::Group(children)) => {
+                    pending.extend(children.iter().rev().map(|&child| (child, translation)))
+                }
+                Some(MagicaVoxelSceneNode::Shape(models)) => {
+                    for &model in models {
+                        result.entry(model).or_insert(translation);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        result
+    }
+}
+
+/// Reads the palette and materials of a complete MagicaVoxel file, with the same reader as the [`MagicaVoxelModel`]
+/// adapter. Returns an error if the bytes do not begin a MagicaVoxel file, or if any of its chunks are malformed.
+pub fn magicavoxel_metadata(bytes: &[u8]) -> Result<inspect::ParseMetadata, GvoxError> {
+    let invalid = || {
+        GvoxError::new(
+            ErrorType::ParseAdapterInvalidInput,
+            "The input is not a MagicaVoxel file.",
+        )
+    };
+    if bytes.get(..4) != Some(b"VOX ") {
+        return Err(invalid());
+    }
+
+    // The MAIN chunk header follows the magic and version, and every other chunk is its child.
+    let mut header = MagicaVoxelReader {
+        bytes,
+        position: 16,
+    };
+    let children_size = header.u32().map_err(|_| invalid())? as usize;
+    let file = MagicaVoxelFile::read(header.take(children_size)?)?;
+    Ok(inspect::ParseMetadata {
+        palette: file.palette,
+        materials: file.materials,
+    })
+}
+
+/// Reads little-endian values from the content of a MagicaVoxel chunk.
+struct MagicaVoxelReader<'a> {
+    /// The bytes being read.
+    bytes: &'a [u8],
+    /// The position of the next byte to read.
+    position: usize,
+}
+
+impl<'a> MagicaVoxelReader<'a> {
+    /// Reads the provided number of bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8], GvoxError> {
+        let result = self
+            .position
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::ParseAdapterInvalidInput,
+                    "A MagicaVoxel chunk ended unexpectedly.",
+                )
+            })?;
+        self.position += len;
+        Ok(result)
+    }
+
+    /// Reads an unsigned integer.
+    fn u32(&mut self) -> Result<u32, GvoxError> {
+        let bytes = self.take(size_of::<u32>())?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads the dimensions of a model.
+    fn extent(&mut self) -> Result<Extent3D, GvoxError> {
+        Ok(Extent3D {
+            x: self.u32()?,
+            y: self.u32()?,
+            z: self.u32()?,
+        })
+    }
+
+    /// Reads a length-prefixed string.
+    fn string(&mut self) -> Result<String, GvoxError> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    /// Reads a dictionary of strings.
+    fn dict(&mut self) -> Result<HashMap<String, String>, GvoxError> {
+        let count = self.u32()?;
+        (0..count)
+            .map(|_| Ok((self.string()?, self.string()?)))
+            .collect()
+    }
+}
+
+/// Parses the `_t` attribute of a MagicaVoxel transform node, which holds three integers separated by spaces.
+fn parse_magicavoxel_translation(value: &str) -> Result<Offset3D, GvoxError> {
+    let invalid = || {
+        GvoxError::new(
+            ErrorType::ParseAdapterInvalidInput,
+            format!("The MagicaVoxel translation '{value}' was invalid."),
+        )
+    };
+    let mut components = value
+        .split_whitespace()
+        .map(|x| x.parse::<i32>().map_err(|_| invalid()));
+    let mut next = || components.next().unwrap_or_else(|| Err(invalid()));
+
+    Ok(Offset3D {
+        x: next()?,
+        y: next()?,
+        z: next()?,
+    })
+}
+
+/// The palette which MagicaVoxel uses for files without an RGBA chunk, indexed by palette index. It consists of
+/// a cube of colors without black, followed by ramps of red, green, blue, and gray.
+pub fn magicavoxel_default_palette() -> [u32; 256] {
+    const CUBE: [u8; 6] = [0xff, 0xcc, 0x99, 0x66, 0x33, 0x00];
+    const RAMP: [u8; 10] = [0xee, 0xdd, 0xbb, 0xaa, 0x88, 0x77, 0x55, 0x44, 0x22, 0x11];
+
+    let cube = CUBE
+        .into_iter()
+        .flat_map(|r| CUBE.into_iter().flat_map(move |g| CUBE.map(|b| [r, g, b])))
+        .take(CUBE.len().pow(3) - 1);
+    let ramps = RAMP
+        .map(|x| [x, 0, 0])
+        .into_iter()
+        .chain(RAMP.map(|x| [0, x, 0]))
+        .chain(RAMP.map(|x| [0, 0, x]))
+        .chain(RAMP.map(|x| [x, x, x]));
+
+    let mut palette = [0; 256];
+    for (entry, [r, g, b]) in palette[1..].iter_mut().zip(cube.chain(ramps)) {
+        *entry = u32::from_le_bytes([r, g, b, 0xff]);
+    }
+    palette
+}
+
+/// Parses `.binvox` files, which store the occupancy of a volume as run-length encoded bytes after an ASCII
+/// header. This adapter is implemented in Rust. Occupied voxels are given the configured color in the
+/// [`ChannelId::COLOR`] channel and a value of one in the [`ChannelId::MATERIAL_ID`] channel, while empty
+/// voxels have no value. The volume begins at the origin, and the axes of the file are used as-is.
+pub struct Binvox;
+
+impl AdapterDescriptor<Parse> for Binvox {
+    type Configuration<'a> = BinvoxParseAdapterConfig;
+    type Handler = BinvoxHandler;
+}
+
+impl NamedAdapter for Binvox {
+    fn name() -> &'static str {
+        "binvox"
+    }
+}
+
+/// Describes how binvox files should be parsed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BinvoxParseAdapterConfig {
+    /// The color of every occupied voxel, with red in the lowest byte.
+    pub fill_color: u32,
+}
+
+impl Default for BinvoxParseAdapterConfig {
+    fn default() -> Self {
+        Self {
+            fill_color: 0xffffffff,
+        }
+    }
+}
+
+/// The ASCII header of a binvox file, which precedes its voxel data.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BinvoxHeader {
+    /// The version of the format, which is one for every known file.
+    pub version: u32,
+    /// The number of voxels along each axis. The `dim` line of the header lists the
+    /// x, z, and y extents in that order, matching the order in which voxels are stored.
+    pub extent: Extent3D,
+    /// The position of the corner of the volume within the model from which it was voxelized.
+    pub translate: [f32; 3],
+    /// The length of the longest side of the volume within the model from which it was voxelized.
+    pub scale: f32,
+    /// The byte offset of the voxel data, which directly follows the `data` line.
+    pub data_offset: usize,
+}
+
+impl BinvoxHeader {
+    /// The greatest number of bytes which are searched for the end of the header.
+    const MAX_SIZE: usize = 1024;
+
+    /// Reads the header from the beginning of the provided bytes, which may also contain the voxel data.
+    /// Invalid or incomplete headers produce an error which reports the byte offset of the problem.
+    pub fn read(bytes: &[u8]) -> Result<Self, GvoxError> {
+        let mut version = None;
+        let mut extent = None;
+        let mut translate = [0.0; 3];
+        let mut scale = 1.0;
+
+        let mut position = 0;
+        loop {
+            let start = position;
+            let len = bytes[start..]
+                .iter()
+                .position(|&x| x == b'\n')
+                .ok_or_else(|| {
+                    invalid_binvox_header(bytes.len(), "the header ended without a data line")
+                })?;
+            position += len + 1;
+
+            let line = std::str::from_utf8(&bytes[start..start + len])
+                .map_err(|_| invalid_binvox_header(start, "the line was not valid text"))?;
+            let mut words = line.split_whitespace();
+            let keyword = words.next();
+            let mut word = || words.next();
+
+            match keyword {
+                Some("#binvox") if start == 0 => version = Some(parse_binvox_value(word(), start)?),
+                _ if start == 0 => {
+                    return Err(invalid_binvox_header(
+                        0,
+                        "the file did not begin with #binvox",
+                    ));
+                }
+                Some("dim") => {
+                    let mut value = || parse_binvox_value(word(), start);
+                    let (x, z, y) = (value()?, value()?, value()?);
+                    extent = Some(Extent3D { x, y, z });
+                }
+                Some("translate") => {
+                    let mut value = || parse_binvox_value(word(), start);
+                    translate = [value()?, value()?, value()?];
+                }
+                Some("scale") => scale = parse_binvox_value(word(), start)?,
+                Some("data") => break,
+                None => {}
+                Some(keyword) => {
+                    return Err(invalid_binvox_header(
+                        start,
+                        &format!("the keyword '{keyword}' was not recognized"),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            version: version.unwrap_or_default(),
+            extent: extent
+                .ok_or_else(|| invalid_binvox_header(position, "the header had no dim line"))?,
+            translate,
+            scale,
+            data_offset: position,
+        })
+    }
+
+    /// The range of voxels which the file describes.
+    pub fn range(&self) -> RegionRange {
+        RegionRange {
+            offset: Offset3D::default(),
+            extent: self.extent,
+        }
+    }
+}
+
+/// Creates an error which describes a problem with the binvox header at the provided byte offset.
+fn invalid_binvox_header(offset: usize, message: &str) -> GvoxError {
+    GvoxError::new(
+        ErrorType::ParseAdapterInvalidInput,
+        format!("The binvox header was invalid at byte {offset}: {message}."),
+    )
+}
+
+/// Parses a value from a line of the binvox header which begins at the provided byte offset.
+fn parse_binvox_value<T: std::str::FromStr>(
+    word: Option<&str>,
+    offset: usize,
+) -> Result<T, GvoxError> {
+    word.and_then(|x| x.parse().ok()).ok_or_else(|| {
+        invalid_binvox_header(
+            offset,
+            &format!("the value '{}' was invalid", word.unwrap_or_default()),
+        )
+    })
+}
+
+/// Decodes the voxel data of a binvox file into a set of bits, with one bit per voxel. The data consists
+/// of pairs of bytes, each a value of zero or one followed by the number of voxels which have that value.
+/// The provided function reads the pair at a byte offset, or returns `None` if the input has ended.
+fn decode_binvox_runs(
+    header: &BinvoxHeader,
+    mut read_run: impl FnMut(usize) -> Option<[u8; 2]>,
+) -> Result<Vec<u64>, GvoxError> {
+    let Extent3D { x, y, z } = header.extent;
+    let volume = x as u64 * y as u64 * z as u64;
+    let invalid = |message| GvoxError::new(ErrorType::ParseAdapterInvalidInput, message);
+
+    // Empty runs never allocate, so corrupt dimensions cannot cause more to be allocated than the data describes.
+    let mut bits = Vec::new();
+    let mut filled = 0;
+    let mut position = header.data_offset;
+    while filled < volume {
+        let [value, count] = read_run(position).ok_or_else(|| {
+            invalid(format!(
+                "The binvox voxel data ended at byte {position}, after {filled} of {volume} voxels."
+            ))
+        })?;
+        let end = filled + count as u64;
+        if end > volume {
+            return Err(invalid(format!(
+                "The binvox run at byte {position} extended {} voxels past the end of the volume.",
+                end - volume
+            )));
+        }
+
+        match value {
+            0 => {}
+            1 => {
+                bits.resize(end.div_ceil(64) as usize, 0);
+                for i in filled..end {
+                    bits[(i / 64) as usize] |= 1 << (i % 64);
+                }
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "The binvox run at byte {position} had the value {value}, rather than zero or one."
+                )));
+            }
+        }
+
+        filled = end;
+        position += 2;
+    }
+
+    bits.resize(volume.div_ceil(64) as usize, 0);
+    Ok(bits)
+}
+
+/// Handles the adapter context operations of binvox parsers.
+pub struct BinvoxHandler {
+    /// The configuration with which the adapter context was created.
+    config: BinvoxParseAdapterConfig,
+    /// The range of the input of the current blit.
+    range: RegionRange,
+    /// Whether each voxel of the input of the current blit is occupied, with y varying fastest, then z, then x.
+    occupancy: Vec<u64>,
+}
+
+impl BinvoxHandler {
+    /// The channels that this adapter generates.
+    fn channels() -> ChannelFlags {
+        ChannelId::COLOR | ChannelId::MATERIAL_ID
+    }
+
+    /// Reads the header from the beginning of the input. If the size of the input is
+    /// unknown, it is read one byte at a time until the end of the header is found.
+    fn read_header(blit_ctx: &ParseBlitContext) -> Result<BinvoxHeader, GvoxError> {
+        let bytes = match blit_ctx.input_size() {
+            Some(size) => blit_ctx.read_vec(0, size.min(BinvoxHeader::MAX_SIZE))?,
+            None => {
+                let mut bytes = Vec::new();
+                while bytes.len() < BinvoxHeader::MAX_SIZE
+                    && !bytes.ends_with(b"\ndata\n")
+                    && !bytes.ends_with(b"\ndata\r\n")
+                {
+                    match blit_ctx.read_exact_array::<1>(bytes.len()) {
+                        Ok([x]) => bytes.push(x),
+                        Err(_) => break,
+                    }
+                }
+                bytes
+            }
+        };
+
+        BinvoxHeader::read(&bytes)
+    }
+
+    /// Whether the voxel at the provided position is occupied.
+    fn occupied(&self, position: &Offset3D) -> bool {
+        if !self.range.contains(position) {
+            return false;
+        }
+
+        let Extent3D { y: ey, z: ez, .. } = self.range.extent;
+        let (x, y, z) = (position.x as u64, position.y as u64, position.z as u64);
+        let index = (x * ez as u64 + z) * ey as u64 + y;
+        self.occupancy[(index / 64) as usize] & (1 << (index % 64)) != 0
+    }
+}
+
+impl BaseAdapterHandler<Parse, Binvox> for BinvoxHandler {
+    fn create(config: &BinvoxParseAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            config: *config,
+            range: RegionRange::default(),
+            occupancy: Vec::new(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    /// The whole input is decoded when the blit begins. When the size of the input is known, the voxel
+    /// data is read all at once, and otherwise it is read one run at a time.
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let header = Self::read_header(blit_ctx)?;
+        let start = header.data_offset;
+        self.occupancy = match blit_ctx.input_size() {
+            Some(size) => {
+                let data = blit_ctx.read_vec(start, size.saturating_sub(start))?;
+                decode_binvox_runs(&header, |position| {
+                    let run = data.get(position - start..position - start + 2)?;
+                    Some([run[0], run[1]])
+                })?
+            }
+            None => {
+                decode_binvox_runs(&header, |position| blit_ctx.read_exact_array(position).ok())?
+            }
+        };
+        self.range = header.range();
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.range = RegionRange::default();
+        self.occupancy = Vec::new();
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<Binvox> for BinvoxHandler {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        Ok(Region::builder()
+            .range(*range)
+            .channels(channel_flags & Self::channels())
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let value = match channel_id {
+            _ if !self.occupied(offset) => None,
+            ChannelId::COLOR => Some(self.config.fill_color),
+            ChannelId::MATERIAL_ID => Some(1),
+            _ => None,
+        };
+
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        blit_ctx.emit_region(
+            &Region::builder()
+                .range(*range)
+                .channels(channel_flags & Self::channels())
+                .build(),
+        )
+    }
+}
+
+/// Parses classic Minecraft `.schematic` files, which store a volume of block IDs as NBT data that is usually
+/// gzip compressed. This adapter is implemented in Rust. Each block is given its ID in the [`ChannelId::MATERIAL_ID`]
+/// channel and its color from the configured table in the [`ChannelId::COLOR`] channel, while air has no value.
+/// The volume begins at the origin, with y as the vertical axis, and block data values and entities are ignored.
+/// The size of the input must be known, as it is for byte buffer and file inputs.
+#[cfg(feature = "minecraft")]
+pub struct Schematic;
+
+#[cfg(feature = "minecraft")]
+impl AdapterDescriptor<Parse> for Schematic {
+    type Configuration<'a> = SchematicParseAdapterConfig;
+    type Handler = SchematicHandler;
+}
+
+#[cfg(feature = "minecraft")]
+impl NamedAdapter for Schematic {
+    fn name() -> &'static str {
+        "schematic"
+    }
+}
+
+/// Describes how the blocks of a Minecraft schematic are colored.
+#[cfg(feature = "minecraft")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchematicParseAdapterConfig {
+    /// The color of each block ID, with red in the lowest byte.
+    pub block_colors: HashMap<u16, u32>,
+    /// The color of blocks whose IDs have no entry in the table.
+    pub default_color: u32,
+}
+
+#[cfg(feature = "minecraft")]
+impl Default for SchematicParseAdapterConfig {
+    fn default() -> Self {
+        Self {
+            block_colors: HashMap::new(),
+            default_color: 0xffffffff,
+        }
+    }
+}
+
+/// The blocks of a Minecraft schematic.
+#[cfg(feature = "minecraft")]
+struct SchematicData {
+    /// The number of blocks along each axis.
+    extent: Extent3D,
+    /// The ID of each block, with x varying fastest, then z, then y, where zero denotes air.
+    blocks: Vec<u16>,
+}
+
+#[cfg(feature = "minecraft")]
+impl SchematicData {
+    /// The greatest depth of nested NBT tags which are skipped.
+    const MAX_DEPTH: usize = 512;
+
+    /// Reads the schematic from the provided NBT data, which may be gzip compressed.
+    fn read(bytes: &[u8]) -> Result<Self, GvoxError> {
+        let inflated;
+        let bytes = if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut data = Vec::new();
+            std::io::Read::read_to_end(&mut flate2::read::MultiGzDecoder::new(bytes), &mut data)
+                .map_err(|error| {
+                    GvoxError::new(
+                        ErrorType::ParseAdapterInvalidInput,
+                        format!("The schematic could not be decompressed: {error}"),
+                    )
+                })?;
+            inflated = data;
+            &inflated[..]
+        } else {
+            bytes
+        };
+
+        let mut reader = NbtReader { bytes, position: 0 };
+        if reader.u8()? != NbtReader::COMPOUND {
+            return Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                "The schematic did not begin with an NBT compound.",
+            ));
+        }
+        reader.string()?;
+
+        let (mut width, mut height, mut length) = (None, None, None);
+        let mut blocks = None;
+        let mut add_blocks = None;
+        loop {
+            let tag = reader.u8()?;
+            if tag == NbtReader::END {
+                break;
+            }
+
+            match (tag, reader.string()?) {
+                (NbtReader::SHORT, "Width") => width = Some(reader.dimension()?),
+                (NbtReader::SHORT, "Height") => height = Some(reader.dimension()?),
+                (NbtReader::SHORT, "Length") => length = Some(reader.dimension()?),
+                (NbtReader::BYTE_ARRAY, "Blocks") => blocks = Some(reader.byte_array()?),
+                (NbtReader::BYTE_ARRAY, "AddBlocks") => add_blocks = Some(reader.byte_array()?),
+                _ => reader.skip(tag, Self::MAX_DEPTH)?,
+            }
+        }
+
+        let missing = |name| {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!("The schematic had no {name} tag."),
+            )
+        };
+        let extent = Extent3D {
+            x: width.ok_or_else(|| missing("Width"))?,
+            y: height.ok_or_else(|| missing("Height"))?,
+            z: length.ok_or_else(|| missing("Length"))?,
+        };
+        let blocks = blocks.ok_or_else(|| missing("Blocks"))?;
+        let volume = extent.x as usize * extent.y as usize * extent.z as usize;
+        if blocks.len() != volume {
+            return Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!(
+                    "The schematic had {} blocks, but its dimensions describe {volume}.",
+                    blocks.len()
+                ),
+            ));
+        }
+
+        // Each byte of AddBlocks holds the upper four bits of the IDs of two blocks, the first in its lower half.
+        let add = |index: usize| {
+            let byte = add_blocks.and_then(|x: &[u8]| x.get(index / 2)).copied();
+            let nibble = (byte.unwrap_or_default() >> (index % 2 * 4)) & 0x0f;
+            (nibble as u16) << 8
+        };
+
+        Ok(Self {
+            extent,
+            blocks: blocks
+                .iter()
+                .enumerate()
+                .map(|(index, &id)| add(index) | id as u16)
+                .collect(),
+        })
+    }
+}
+
+/// Reads big-endian values from NBT data.
+#[cfg(feature = "minecraft")]
+struct NbtReader<'a> {
+    /// The bytes being read.
+    bytes: &'a [u8],
+    /// The position of the next byte to read.
+    position: usize,
+}
+
+#[cfg(feature = "minecraft")]
+impl<'a> NbtReader<'a> {
+    /// The type of the tag which ends a compound.
+    const END: u8 = 0;
+    /// The type of a 16-bit integer tag.
+    const SHORT: u8 = 2;
+    /// The type of a byte array tag.
+    const BYTE_ARRAY: u8 = 7;
+    /// The type of a list tag.
+    const LIST: u8 = 9;
+    /// The type of a compound tag.
+    const COMPOUND: u8 = 10;
+
+    /// Reads the provided number of bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8], GvoxError> {
+        let result = self
+            .position
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::ParseAdapterInvalidInput,
+                    format!(
+                        "The schematic NBT data ended unexpectedly at byte {}.",
+                        self.position
+                    ),
+                )
+            })?;
+        self.position += len;
+        Ok(result)
+    }
+
+    /// Reads an unsigned byte.
+    fn u8(&mut self) -> Result<u8, GvoxError> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Reads a signed 32-bit integer.
+    fn i32(&mut self) -> Result<i32, GvoxError> {
+        let bytes = self.take(size_of::<i32>())?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Reads the length of an array or list, which must not be negative.
+    fn len(&mut self) -> Result<usize, GvoxError> {
+        let position = self.position;
+        usize::try_from(self.i32()?).map_err(|_| {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!("The schematic NBT length at byte {position} was negative."),
+            )
+        })
+    }
+
+    /// Reads a 16-bit integer which holds the size of the schematic along an axis.
+    fn dimension(&mut self) -> Result<u32, GvoxError> {
+        let bytes = self.take(size_of::<u16>())?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+    }
+
+    /// Reads a length-prefixed string.
+    fn string(&mut self) -> Result<&'a str, GvoxError> {
+        let position = self.position;
+        let bytes = self.take(size_of::<u16>())?;
+        let len = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+        std::str::from_utf8(self.take(len)?).map_err(|_| {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!("The schematic NBT string at byte {position} was not valid text."),
+            )
+        })
+    }
+
+    /// Reads a length-prefixed array of bytes.
+    fn byte_array(&mut self) -> Result<&'a [u8], GvoxError> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    /// Skips the payload of a tag of the provided type, which may contain at most `depth` levels of nested tags.
+    fn skip(&mut self, tag: u8, depth: usize) -> Result<(), GvoxError> {
+        let position = self.position;
+        let depth = depth.checked_sub(1).ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!("The schematic NBT tags at byte {position} were nested too deeply."),
+            )
+        })?;
+
+        match tag {
+            1 => self.take(1).map(drop),
+            2 => self.take(2).map(drop),
+            3 | 5 => self.take(4).map(drop),
+            4 | 6 => self.take(8).map(drop),
+            7 => self.byte_array().map(drop),
+            8 => self.string().map(drop),
+            Self::LIST => {
+                let tag = self.u8()?;
+                for _ in 0..self.len()? {
+                    self.skip(tag, depth)?;
+                }
+                Ok(())
+            }
+            Self::COMPOUND => loop {
+                match self.u8()? {
+                    Self::END => return Ok(()),
+                    tag => {
+                        self.string()?;
+                        self.skip(tag, depth)?;
+                    }
+                }
+            },
+            11 => {
+                let len = self.len()?;
+                self.take(len.saturating_mul(4)).map(drop)
+            }
+            12 => {
+                let len = self.len()?;
+                self.take(len.saturating_mul(8)).map(drop)
+            }
+            _ => Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!(
+                    "The schematic NBT tag type {tag} before byte {position} was not recognized."
+                ),
+            )),
+        }
+    }
+}
+
+/// Handles the adapter context operations of Minecraft schematic parsers.
+#[cfg(feature = "minecraft")]
+pub struct SchematicHandler {
+    /// The configuration with which the adapter context was created.
+    config: SchematicParseAdapterConfig,
+    /// The blocks of the input of the current blit.
+    schematic: SchematicData,
+}
+
+#[cfg(feature = "minecraft")]
+impl SchematicHandler {
+    /// The channels that this adapter generates.
+    fn channels() -> ChannelFlags {
+        ChannelId::COLOR | ChannelId::MATERIAL_ID
+    }
+
+    /// The range of the input of the current blit.
+    fn range(&self) -> RegionRange {
+        RegionRange {
+            offset: Offset3D::default(),
+            extent: self.schematic.extent,
+        }
+    }
+
+    /// The ID of the block at the provided position, or zero if it is air or lies outside of the schematic.
+    fn block(&self, position: &Offset3D) -> u16 {
+        if !self.range().contains(position) {
+            return 0;
+        }
+
+        let Extent3D { x: ex, z: ez, .. } = self.schematic.extent;
+        let (x, y, z) = (
+            position.x as usize,
+            position.y as usize,
+            position.z as usize,
+        );
+        self.schematic.blocks[(y * ez as usize + z) * ex as usize + x]
+    }
+}
+
+#[cfg(feature = "minecraft")]
+impl BaseAdapterHandler<Parse, Schematic> for SchematicHandler {
+    fn create(config: &SchematicParseAdapterConfig) -> Result<Self, GvoxError> {
+        Ok(Self {
+            config: config.clone(),
+            schematic: SchematicData {
+                extent: Extent3D::default(),
+                blocks: Vec::new(),
+            },
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let size = blit_ctx.input_size().ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::ParseAdapter,
+                "Schematics can only be parsed from inputs whose size is known.",
+            )
+        })?;
+        self.schematic = SchematicData::read(&blit_ctx.read_vec(0, size)?)?;
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.schematic.extent = Extent3D::default();
+        self.schematic.blocks = Vec::new();
+        Ok(())
+    }
+}
+
+#[cfg(feature = "minecraft")]
+impl ParseAdapterHandler<Schematic> for SchematicHandler {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range()
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        Ok(Region::builder()
+            .range(*range)
+            .channels(channel_flags & Self::channels())
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        let id = self.block(offset);
+        let value = match channel_id {
+            _ if id == 0 => None,
+            ChannelId::COLOR => Some(
+                self.config
+                    .block_colors
+                    .get(&id)
+                    .copied()
+                    .unwrap_or(self.config.default_color),
+            ),
+            ChannelId::MATERIAL_ID => Some(id as u32),
+            _ => None,
+        };
+
+        Ok(Sample {
+            data: value.unwrap_or_default(),
+            is_present: value.is_some(),
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        blit_ctx.emit_region(
+            &Region::builder()
+                .range(*range)
+                .channels(channel_flags & Self::channels())
+                .build(),
+        )
     }
 }
@@ -0,0 +1,265 @@
+use crate::*;
+
+/// A table which moves voxel data from source channels to destination channels.
+/// Channels which are not remapped keep their IDs, unless another channel is remapped onto them,
+/// in which case their original data is hidden.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ChannelRemap {
+    /// The destination of each explicitly remapped channel, indexed by source channel ID.
+    destinations: [Option<ChannelId>; CHANNEL_ID_COUNT],
+    /// The source of each explicitly remapped channel, indexed by destination channel ID.
+    sources: [Option<ChannelId>; CHANNEL_ID_COUNT],
+    /// Whether every channel keeps its own ID.
+    identity: bool,
+}
+
+impl ChannelRemap {
+    /// Creates a remapping which leaves every channel unchanged.
+    pub const fn identity() -> Self {
+        Self {
+            destinations: [None; CHANNEL_ID_COUNT],
+            sources: [None; CHANNEL_ID_COUNT],
+            identity: true,
+        }
+    }
+
+    /// Creates a remapping from the provided pairs of source and destination channels.
+    pub fn new(
+        mapping: impl IntoIterator<Item = (ChannelId, ChannelId)>,
+    ) -> Result<Self, GvoxError> {
+        let mut result = Self::identity();
+        for (source, destination) in mapping {
+            result.insert(source, destination)?;
+        }
+        Ok(result)
+    }
+
+    /// Moves the data of the source channel to the destination channel. Returns an error if
+    /// either channel has already been remapped.
+    pub fn insert(&mut self, source: ChannelId, destination: ChannelId) -> Result<(), GvoxError> {
+        if let Some(existing) = self.sources[u32::from(destination) as usize] {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Channels {existing:?} and {source:?} were both remapped to {destination:?}."
+                ),
+            ));
+        }
+        if let Some(existing) = self.destinations[u32::from(source) as usize] {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Channel {source:?} was remapped to both {existing:?} and {destination:?}."
+                ),
+            ));
+        }
+
+        self.destinations[u32::from(source) as usize] = Some(destination);
+        self.sources[u32::from(destination) as usize] = Some(source);
+        self.identity &= source == destination;
+        Ok(())
+    }
+
+    /// Whether this remapping leaves every channel unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.identity
+    }
+
+    /// The channel to which the data of the source channel is moved, or `None` if it is hidden.
+    pub fn destination(&self, source: ChannelId) -> Option<ChannelId> {
+        if self.identity {
+            return Some(source);
+        }
+
+        let index = u32::from(source) as usize;
+        self.destinations[index].or_else(|| self.sources[index].is_none().then_some(source))
+    }
+
+    /// The channel from which the data of the destination channel is taken, or `None` if it has no data.
+    pub fn source(&self, destination: ChannelId) -> Option<ChannelId> {
+        if self.identity {
+            return Some(destination);
+        }
+
+        let index = u32::from(destination) as usize;
+        self.sources[index].or_else(|| self.destinations[index].is_none().then_some(destination))
+    }
+
+    /// Determines the set of destination channels which hold the data of the provided source channels.
+    pub fn map_flags(&self, sources: ChannelFlags) -> ChannelFlags {
+        if self.identity {
+            return sources;
+        }
+
+        sources
+            .into_iter()
+            .filter_map(|x| self.destination(x))
+            .fold(ChannelFlags::empty(), |acc, x| acc | x)
+    }
+
+    /// Determines the set of source channels whose data is required for the provided destination channels.
+    pub fn unmap_flags(&self, destinations: ChannelFlags) -> ChannelFlags {
+        if self.identity {
+            return destinations;
+        }
+
+        destinations
+            .into_iter()
+            .filter_map(|x| self.source(x))
+            .fold(ChannelFlags::empty(), |acc, x| acc | x)
+    }
+}
+
+impl Default for ChannelRemap {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Wraps the Rust parse adapter `P`, so that its voxel data appears in different channels.
+/// Channel flags requested by the serialize adapter are translated to the channels of `P`,
+/// and samples are taken from the corresponding source channel. Since the native library
+/// provides no way to forward callbacks to another adapter, only Rust parse adapters may be wrapped.
+///
+/// This adapter has no name of its own, so it must be registered with [`Context::register_adapter_named`].
+pub struct RemappedParse<P>(PhantomData<P>);
+
+impl<P: AdapterDescriptor<Parse>> AdapterDescriptor<Parse> for RemappedParse<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    type Configuration<'a> = RemappedParseAdapterConfig<'a, P>;
+    type Handler = RemappedParseHandler<P>;
+}
+
+/// The configuration for a [`RemappedParse`] adapter context.
+pub struct RemappedParseAdapterConfig<'a, P: AdapterDescriptor<Parse>> {
+    /// The channels to move the data of the wrapped adapter between.
+    pub remap: ChannelRemap,
+    /// The configuration with which to create the wrapped adapter context.
+    pub config: P::Configuration<'a>,
+}
+
+/// Handles the callbacks of a [`RemappedParse`] adapter context by forwarding them to the wrapped handler.
+pub struct RemappedParseHandler<P: AdapterDescriptor<Parse>>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    /// The channels to move the data of the wrapped adapter between.
+    remap: ChannelRemap,
+    /// The handler of the wrapped adapter.
+    handler: P::Handler,
+}
+
+impl<P: AdapterDescriptor<Parse>> BaseAdapterHandler<Parse, RemappedParse<P>>
+    for RemappedParseHandler<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    fn create(config: &RemappedParseAdapterConfig<'_, P>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            remap: config.remap,
+            handler: P::Handler::create(&config.config)?,
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        self.handler.destroy()
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.handler
+            .blit_begin(blit_ctx, range, self.remap.unmap_flags(channel_flags))
+    }
+
+    fn blit_end(&mut self, blit_ctx: &ParseBlitContext) -> Result<(), GvoxError> {
+        self.handler.blit_end(blit_ctx)
+    }
+}
+
+impl<P: AdapterDescriptor<Parse>> ParseAdapterHandler<RemappedParse<P>> for RemappedParseHandler<P>
+where
+    P::Handler: ParseAdapterHandler<P>,
+{
+    type RegionData = Region<<P::Handler as ParseAdapterHandler<P>>::RegionData>;
+
+    fn query_details() -> ParseAdapterDetails {
+        P::Handler::query_details()
+    }
+
+    fn query_parsable_range(&mut self, blit_ctx: &ParseBlitContext) -> RegionRange {
+        self.handler.query_parsable_range(blit_ctx)
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        self.handler
+            .query_region_flags(blit_ctx, range, self.remap.unmap_flags(channel_flags))
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<Self::RegionData>, GvoxError> {
+        let region =
+            self.handler
+                .load_region(blit_ctx, range, self.remap.unmap_flags(channel_flags))?;
+        Ok(Region::new(
+            region.range,
+            self.remap.map_flags(region.channels),
+            region.flags,
+            region,
+        ))
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        region: Region<Self::RegionData>,
+    ) -> Result<(), GvoxError> {
+        self.handler.unload_region(blit_ctx, region.into_data())
+    }
+
+    fn sample_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        region: &Region<Self::RegionData>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        match self.remap.source(channel_id) {
+            Some(source) => self
+                .handler
+                .sample_region(blit_ctx, &**region, offset, source),
+            None => Ok(Sample {
+                data: 0,
+                is_present: false,
+            }),
+        }
+    }
+
+    /// Regions emitted by the wrapped adapter could not have their channels translated, so
+    /// the requested range is instead loaded and emitted as a single region.
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        let result = blit_ctx.emit_region(&region);
+        self.unload_region(blit_ctx, region)?;
+        result
+    }
+}
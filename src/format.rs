@@ -0,0 +1,395 @@
+use crate::*;
+
+/// The magic bytes which begin every gvox_raw container.
+pub const RAW_MAGIC: [u8; 4] = *b"gvr\0";
+
+/// The magic bytes which begin every gvox_palette container.
+pub const PALETTE_MAGIC: [u8; 4] = *b"gvp\0";
+
+/// The size, in bytes, of a serialized [`RawHeader`].
+pub const RAW_HEADER_SIZE: usize = 32;
+
+/// The size, in bytes, of a serialized [`PaletteHeader`].
+pub const PALETTE_HEADER_SIZE: usize = 40;
+
+/// The size, in bytes, of a serialized [`PaletteChannelHeader`].
+pub const PALETTE_CHANNEL_HEADER_SIZE: usize = 8;
+
+/// The edge length of the cubic regions into which gvox_palette containers divide their volume.
+pub const PALETTE_REGION_SIZE: u32 = 8;
+
+/// The largest palette which a gvox_palette region will compress. Regions with more variants
+/// than this are stored as raw voxel values.
+pub const PALETTE_MAX_COMPRESSED_VARIANT_N: u32 = 367;
+
+/// The header at the beginning of a gvox_raw container. It is followed by one `u32` per
+/// channel per voxel, with voxels ordered x-first and channels in ascending order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct RawHeader {
+    /// The magic bytes, which must equal [`RAW_MAGIC`].
+    pub magic: [u8; 4],
+    /// The volume that the container describes.
+    pub range: RegionRange,
+    /// The bitmask of channels stored for each voxel.
+    pub channel_flags: u32,
+}
+
+impl RawHeader {
+    /// Creates a new header for the given volume and channels.
+    pub fn new(range: RegionRange, channel_flags: ChannelFlags) -> Self {
+        Self {
+            magic: RAW_MAGIC,
+            range,
+            channel_flags: channel_flags.into(),
+        }
+    }
+
+    /// The set of channels stored for each voxel.
+    pub fn channels(&self) -> ChannelFlags {
+        self.channel_flags.into()
+    }
+
+    /// The number of bytes of voxel data which follow this header.
+    pub fn data_size(&self) -> usize {
+        let RegionRange { extent, .. } = self.range;
+        extent.x as usize
+            * extent.y as usize
+            * extent.z as usize
+            * self.channel_flags.count_ones() as usize
+            * size_of::<u32>()
+    }
+}
+
+/// The header at the beginning of a gvox_palette container. It is followed by one
+/// [`PaletteChannelHeader`] per channel per region, and then by `blob_size` bytes of palette data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct PaletteHeader {
+    /// The magic bytes, which must equal [`PALETTE_MAGIC`].
+    pub magic: [u8; 4],
+    /// The volume that the container describes.
+    pub range: RegionRange,
+    /// The size of the palette data which follows the channel headers.
+    pub blob_size: u32,
+    /// The bitmask of channels stored for each voxel.
+    pub channel_flags: u32,
+    /// The number of channels in `channel_flags`.
+    pub channel_n: u32,
+}
+
+impl PaletteHeader {
+    /// The set of channels stored for each voxel.
+    pub fn channels(&self) -> ChannelFlags {
+        self.channel_flags.into()
+    }
+
+    /// The number of regions along each axis of the container.
+    pub fn region_counts(&self) -> Extent3D {
+        let Extent3D { x, y, z } = self.range.extent;
+        Extent3D {
+            x: x.div_ceil(PALETTE_REGION_SIZE),
+            y: y.div_ceil(PALETTE_REGION_SIZE),
+            z: z.div_ceil(PALETTE_REGION_SIZE),
+        }
+    }
+
+    /// The total size of the channel headers which follow this header.
+    pub fn channel_headers_size(&self) -> usize {
+        let Extent3D { x, y, z } = self.region_counts();
+        self.channel_header_offset(x as usize * y as usize * z as usize, 0)
+    }
+
+    /// The byte offset, relative to the end of this header, of the channel header for the given
+    /// region index and channel index. Regions are ordered x-first.
+    pub fn channel_header_offset(&self, region_index: usize, channel_index: usize) -> usize {
+        (region_index * self.channel_n as usize + channel_index) * PALETTE_CHANNEL_HEADER_SIZE
+    }
+}
+
+/// Describes how a single channel of a single region is stored within a gvox_palette container.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct PaletteChannelHeader {
+    /// The number of distinct values within the region.
+    pub variant_n: u32,
+    /// The offset of the region's palette data, relative to the end of the channel headers.
+    /// If `variant_n` is one, this is instead the value of every voxel in the region.
+    pub blob_offset: u32,
+}
+
+impl PaletteChannelHeader {
+    /// Reads the channel header for the given region and channel from a complete gvox_palette container.
+    pub fn read(
+        bytes: &[u8],
+        header: &PaletteHeader,
+        region_index: usize,
+        channel_index: usize,
+    ) -> Result<Self, GvoxError> {
+        let offset = PALETTE_HEADER_SIZE + header.channel_header_offset(region_index, channel_index);
+        let mut reader = HeaderReader::new(bytes, offset, PALETTE_CHANNEL_HEADER_SIZE)?;
+        Ok(Self {
+            variant_n: reader.u32(),
+            blob_offset: reader.u32(),
+        })
+    }
+}
+
+/// The header of any supported gvox container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ContainerHeader {
+    /// The header of a gvox_raw container.
+    Raw(RawHeader),
+    /// The header of a gvox_palette container.
+    Palette(PaletteHeader),
+}
+
+impl ContainerHeader {
+    /// The volume that the container describes.
+    pub fn range(&self) -> RegionRange {
+        match self {
+            Self::Raw(x) => x.range,
+            Self::Palette(x) => x.range,
+        }
+    }
+
+    /// The set of channels stored for each voxel.
+    pub fn channels(&self) -> ChannelFlags {
+        match self {
+            Self::Raw(x) => x.channels(),
+            Self::Palette(x) => x.channels(),
+        }
+    }
+
+    /// The size, in bytes, of this header when serialized.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Raw(_) => RAW_HEADER_SIZE,
+            Self::Palette(_) => PALETTE_HEADER_SIZE,
+        }
+    }
+}
+
+/// Reads the container header at the beginning of the provided bytes. Containers are stored in
+/// the native byte order of the machine that wrote them, which is little-endian on every supported platform.
+pub fn read_header(bytes: &[u8]) -> Result<ContainerHeader, GvoxError> {
+    let mut reader = HeaderReader::new(bytes, 0, size_of::<[u8; 4]>())?;
+    match reader.magic() {
+        RAW_MAGIC => {
+            let mut reader = HeaderReader::new(bytes, 0, RAW_HEADER_SIZE)?;
+            Ok(ContainerHeader::Raw(RawHeader {
+                magic: reader.magic(),
+                range: reader.range(),
+                channel_flags: reader.u32(),
+            }))
+        }
+        PALETTE_MAGIC => {
+            let mut reader = HeaderReader::new(bytes, 0, PALETTE_HEADER_SIZE)?;
+            Ok(ContainerHeader::Palette(PaletteHeader {
+                magic: reader.magic(),
+                range: reader.range(),
+                blob_size: reader.u32(),
+                channel_flags: reader.u32(),
+                channel_n: reader.u32(),
+            }))
+        }
+        _ => Err(GvoxError::new(
+            ErrorType::ParseAdapterInvalidInput,
+            "Unrecognized gvox container magic.",
+        )),
+    }
+}
+
+/// Appends the serialized form of the provided header to the output.
+pub fn write_header(header: &ContainerHeader, output: &mut Vec<u8>) {
+    output.reserve(header.size());
+    match header {
+        ContainerHeader::Raw(x) => {
+            output.extend_from_slice(&x.magic);
+            write_range(&x.range, output);
+            output.extend_from_slice(&x.channel_flags.to_le_bytes());
+        }
+        ContainerHeader::Palette(x) => {
+            output.extend_from_slice(&x.magic);
+            write_range(&x.range, output);
+            output.extend_from_slice(&x.blob_size.to_le_bytes());
+            output.extend_from_slice(&x.channel_flags.to_le_bytes());
+            output.extend_from_slice(&x.channel_n.to_le_bytes());
+        }
+    }
+}
+
+/// Appends the serialized form of a region range to the output.
+fn write_range(range: &RegionRange, output: &mut Vec<u8>) {
+    let RegionRange { offset, extent } = range;
+    for value in [offset.x, offset.y, offset.z] {
+        output.extend_from_slice(&value.to_le_bytes());
+    }
+    for value in [extent.x, extent.y, extent.z] {
+        output.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// The magic bytes which begin every container written by [`adapters::RustRunLengthEncoding`].
+pub const RLE_MAGIC: [u8; 4] = *b"grl\0";
+
+/// The version of the run-length encoded layout which this crate reads and writes.
+pub const RLE_VERSION: u32 = 1;
+
+/// The size, in bytes, of a serialized [`RleHeader`], excluding the channel list which follows it.
+pub const RLE_HEADER_SIZE: usize = 36;
+
+/// The header at the beginning of a container written by [`adapters::RustRunLengthEncoding`]. Unlike the native
+/// containers, every field is little-endian regardless of the machine, and the layout only changes along with
+/// [`RLE_VERSION`].
+///
+/// The header is followed by the ID of each of its `channel_n` channels as a `u32`, in ascending order. Then, for
+/// each channel in that order, come two lists of runs, which each cover every voxel of the range with x varying
+/// fastest, then y, then z. Each run is a `u32` count of voxels followed by a `u32` value. The runs of the first
+/// list have a value of one for voxels which have a value in the channel and zero otherwise, while the runs of the
+/// second list hold the values themselves, where voxels without a value are zero. Runs are never empty, and runs
+/// of more than `u32::MAX` voxels are split.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RleHeader {
+    /// The magic bytes, which must equal [`RLE_MAGIC`].
+    pub magic: [u8; 4],
+    /// The version of the layout, which must equal [`RLE_VERSION`].
+    pub version: u32,
+    /// The volume that the container describes.
+    pub range: RegionRange,
+    /// The number of channels in the channel list.
+    pub channel_n: u32,
+}
+
+impl RleHeader {
+    /// Creates a new header for the given volume and number of channels.
+    pub fn new(range: RegionRange, channel_n: u32) -> Self {
+        Self {
+            magic: RLE_MAGIC,
+            version: RLE_VERSION,
+            range,
+            channel_n,
+        }
+    }
+
+    /// Reads the header at the beginning of the provided bytes. Returns an error if the magic bytes do not
+    /// match, or if the container was written with a version of the layout which this crate does not read.
+    pub fn read(bytes: &[u8]) -> Result<Self, GvoxError> {
+        let mut reader = HeaderReader::new(bytes, 0, RLE_HEADER_SIZE)?;
+        let header = Self {
+            magic: reader.magic(),
+            version: reader.u32(),
+            range: reader.range(),
+            channel_n: reader.u32(),
+        };
+
+        if header.magic != RLE_MAGIC {
+            Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                "Unrecognized run-length encoded container magic.",
+            ))
+        } else if header.version != RLE_VERSION {
+            Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                format!(
+                    "Unsupported run-length encoded container version {}, expected {RLE_VERSION}.",
+                    header.version
+                ),
+            ))
+        } else {
+            Ok(header)
+        }
+    }
+
+    /// Appends the serialized form of this header to the output.
+    pub fn write(&self, output: &mut Vec<u8>) {
+        output.reserve(RLE_HEADER_SIZE);
+        output.extend_from_slice(&self.magic);
+        output.extend_from_slice(&self.version.to_le_bytes());
+        write_range(&self.range, output);
+        output.extend_from_slice(&self.channel_n.to_le_bytes());
+    }
+}
+
+/// Appends a list of runs to the output, given as pairs of voxel counts and values. Adjacent runs with the
+/// same value are merged, empty runs are dropped, and runs of more than `u32::MAX` voxels are split.
+pub fn write_runs(runs: impl IntoIterator<Item = (u64, u32)>, output: &mut Vec<u8>) {
+    let mut write = |mut count: u64, value: u32| {
+        while count > 0 {
+            let length = count.min(u32::MAX as u64);
+            output.extend_from_slice(&(length as u32).to_le_bytes());
+            output.extend_from_slice(&value.to_le_bytes());
+            count -= length;
+        }
+    };
+
+    let mut current = None;
+    for (count, value) in runs {
+        current = match current {
+            Some((length, x)) if x == value => Some((length + count, x)),
+            Some((length, x)) => {
+                write(length, x);
+                Some((count, value))
+            }
+            None => Some((count, value)),
+        };
+    }
+    if let Some((length, value)) = current {
+        write(length, value);
+    }
+}
+
+/// Sequentially reads fixed-size fields from a bounds-checked section of a container.
+struct HeaderReader<'a> {
+    /// The remaining bytes of the section.
+    bytes: &'a [u8],
+}
+
+impl<'a> HeaderReader<'a> {
+    /// Creates a reader over `len` bytes starting at `offset`, or returns an error if the container is too short.
+    fn new(bytes: &'a [u8], offset: usize, len: usize) -> Result<Self, GvoxError> {
+        offset
+            .checked_add(len)
+            .and_then(|end| bytes.get(offset..end))
+            .map(|bytes| Self { bytes })
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::ParseAdapterInvalidInput,
+                    "Gvox container is too short to contain its header.",
+                )
+            })
+    }
+
+    /// Reads the next four bytes.
+    fn magic(&mut self) -> [u8; 4] {
+        let (value, rest) = self.bytes.split_at(size_of::<[u8; 4]>());
+        self.bytes = rest;
+        value.try_into().expect("Header section was not bounds-checked.")
+    }
+
+    /// Reads the next little-endian unsigned integer.
+    fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.magic())
+    }
+
+    /// Reads the next little-endian signed integer.
+    fn i32(&mut self) -> i32 {
+        i32::from_le_bytes(self.magic())
+    }
+
+    /// Reads the next region range.
+    fn range(&mut self) -> RegionRange {
+        RegionRange {
+            offset: Offset3D {
+                x: self.i32(),
+                y: self.i32(),
+                z: self.i32(),
+            },
+            extent: Extent3D {
+                x: self.u32(),
+                y: self.u32(),
+                z: self.u32(),
+            },
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::format::*;
+use crate::*;
+use std::io::Read;
+
+/// The number of leading bytes of a file which [`inspect`] needs in order to recognize every
+/// supported format. Shorter prefixes are accepted, but may not be recognized.
+pub const HEADER_PREFIX_SIZE: usize = 64;
+
+/// The magic bytes which begin every MagicaVoxel file.
+const MAGICAVOXEL_MAGIC: [u8; 4] = *b"VOX ";
+
+/// The largest edge length that a plausible KVX model may declare.
+const KVX_MAX_SIZE: u32 = 1024;
+
+/// A voxel file format which can be recognized from its leading bytes alone.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// A gvox_raw container.
+    GvoxRaw,
+    /// A gvox_palette container.
+    GvoxPalette,
+    /// A MagicaVoxel `.vox` file.
+    MagicaVoxel,
+    /// A Build engine `.kvx` model.
+    Kvx,
+}
+
+impl Format {
+    /// The name of the built-in parse adapter which handles this format.
+    pub fn parse_adapter_name(&self) -> &'static str {
+        match self {
+            Self::GvoxRaw => <adapters::GvoxRaw as NamedAdapter>::name(),
+            Self::GvoxPalette => <adapters::GvoxPalette as NamedAdapter>::name(),
+            Self::MagicaVoxel => <adapters::MagicaVoxel as NamedAdapter>::name(),
+            Self::Kvx => <adapters::Kvx as NamedAdapter>::name(),
+        }
+    }
+}
+
+/// Describes what could be determined about a file from its leading bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Inspection {
+    /// The format of the file, if it was recognized.
+    pub format: Option<Format>,
+    /// The size of the volume, as declared by the file's header.
+    pub declared_extent: Option<Extent3D>,
+    /// The channels stored for each voxel, if the format declares them in its header.
+    pub declared_channels: Option<ChannelFlags>,
+}
+
+/// Determines the format of a file from its leading bytes, without parsing any voxel data.
+pub fn detect_format(header_bytes: &[u8]) -> Option<Format> {
+    inspect(header_bytes).format
+}
+
+/// Determines the format of a file, along with the volume and channels that its header declares,
+/// from its leading bytes. No [`Context`] is required, and the native library is never invoked.
+pub fn inspect(header_bytes: &[u8]) -> Inspection {
+    if let Ok(header) = read_header(header_bytes) {
+        Inspection {
+            format: Some(match header {
+                ContainerHeader::Raw(_) => Format::GvoxRaw,
+                ContainerHeader::Palette(_) => Format::GvoxPalette,
+            }),
+            declared_extent: Some(header.range().extent),
+            declared_channels: Some(header.channels()),
+        }
+    } else if let Some(extent) = magicavoxel_size(header_bytes) {
+        Inspection {
+            format: Some(Format::MagicaVoxel),
+            declared_extent: extent,
+            declared_channels: None,
+        }
+    } else if let Some(extent) = kvx_size(header_bytes) {
+        Inspection {
+            format: Some(Format::Kvx),
+            declared_extent: Some(extent),
+            declared_channels: None,
+        }
+    } else {
+        Inspection::default()
+    }
+}
+
+/// Reads the leading bytes of a file from the provided reader, and inspects them.
+pub fn inspect_reader(reader: impl Read) -> std::io::Result<Inspection> {
+    let mut header_bytes = Vec::with_capacity(HEADER_PREFIX_SIZE);
+    reader
+        .take(HEADER_PREFIX_SIZE as u64)
+        .read_to_end(&mut header_bytes)?;
+    Ok(inspect(&header_bytes))
+}
+
+/// Determines whether the bytes begin a MagicaVoxel file. If so, returns the size of the first
+/// model, if its SIZE chunk lies within the provided bytes.
+fn magicavoxel_size(bytes: &[u8]) -> Option<Option<Extent3D>> {
+    if bytes.get(..4)? != MAGICAVOXEL_MAGIC {
+        return None;
+    }
+
+    // The MAIN chunk header occupies bytes 8..20, and its children follow immediately.
+    let mut position = 20;
+    while let Some(id) = bytes.get(position..position + 4) {
+        let content_size = read_u32(bytes, position + 4)? as usize;
+        let children_size = read_u32(bytes, position + 8)? as usize;
+        if id == b"SIZE" {
+            return Some(Some(Extent3D {
+                x: read_u32(bytes, position + 12)?,
+                y: read_u32(bytes, position + 16)?,
+                z: read_u32(bytes, position + 20)?,
+            }));
+        }
+        position = position
+            .checked_add(12)?
+            .checked_add(content_size)?
+            .checked_add(children_size)?;
+    }
+
+    Some(None)
+}
+
+/// Determines whether the bytes plausibly begin a KVX model, and returns its size if so. KVX
+/// files have no magic, so the size of the first mip level must agree with its dimensions.
+fn kvx_size(bytes: &[u8]) -> Option<Extent3D> {
+    let mip_size = read_u32(bytes, 0)? as u64;
+    let extent = Extent3D {
+        x: read_u32(bytes, 4)?,
+        y: read_u32(bytes, 8)?,
+        z: read_u32(bytes, 12)?,
+    };
+    let first_offset = read_u32(bytes, 28)? as u64;
+
+    let Extent3D { x, y, z } = extent;
+    let in_range = |size| (1..=KVX_MAX_SIZE).contains(&size);
+    if !(in_range(x) && in_range(y) && in_range(z)) {
+        return None;
+    }
+
+    // The mip begins with 24 bytes of dimensions and pivots, followed by the x and xy offset tables.
+    let tables_size = (x as u64 + 1) * 4 + x as u64 * (y as u64 + 1) * 2;
+    (first_offset == tables_size && mip_size >= 24 + tables_size).then_some(extent)
+}
+
+/// Reads the little-endian unsigned integer at the given offset, if it lies within the bytes.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes
+        .get(offset..offset.checked_add(4)?)
+        .map(|x| u32::from_le_bytes(x.try_into().expect("Slice was not four bytes long.")))
+}
+
+/// The palette and materials of a MagicaVoxel file, which are not represented by the voxel channels of a blit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseMetadata {
+    /// The color of each palette index, in the same encoding as [`ChannelId::COLOR`]. Index zero is
+    /// always empty. If the file has no RGBA chunk, this is [`adapters::magicavoxel_default_palette`].
+    pub palette: [u32; 256],
+    /// The materials declared by the MATL chunks of the file, in the order in which they appear.
+    pub materials: Vec<Material>,
+}
+
+/// The properties of a single MagicaVoxel material, as declared by a MATL chunk.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Material {
+    /// The palette index to which the material applies.
+    pub id: u32,
+    /// The type of the material, such as `_diffuse`, `_metal`, `_glass`, or `_emit`.
+    pub kind: Option<String>,
+    /// The roughness of the material.
+    pub roughness: Option<f32>,
+    /// The metalness of the material.
+    pub metalness: Option<f32>,
+    /// The emission strength of the material.
+    pub emission: Option<f32>,
+    /// The index of refraction of the material.
+    pub ior: Option<f32>,
+    /// The transparency of the material.
+    pub transparency: Option<f32>,
+    /// Every property of the material, including those above, as they were written to the file.
+    pub properties: HashMap<String, String>,
+}
+
+impl Material {
+    /// Creates a material from the properties of a MATL chunk. Properties that cannot be parsed are left as `None`.
+    pub fn new(id: u32, properties: HashMap<String, String>) -> Self {
+        let number = |key: &str| properties.get(key).and_then(|x| x.parse().ok());
+        Self {
+            id,
+            kind: properties.get("_type").cloned(),
+            roughness: number("_rough"),
+            metalness: number("_metal"),
+            emission: number("_emit"),
+            ior: number("_ior"),
+            transparency: number("_trans"),
+            properties,
+        }
+    }
+}
+
+/// Reads the palette and materials of a complete MagicaVoxel file. Returns `None` if the bytes do not
+/// begin a MagicaVoxel file, or if any of its chunks are malformed. No [`Context`] is required.
+pub fn magicavoxel_metadata(bytes: &[u8]) -> Option<ParseMetadata> {
+    adapters::magicavoxel_metadata(bytes).ok()
+}
+
+/// Reads the palette and materials of the MagicaVoxel file provided by the input adapter context.
+/// Returns `None` if the input is not a MagicaVoxel file. The input is read directly with
+/// [`AdapterContext::read`], so no blit is performed.
+pub fn input_metadata(
+    input_ctx: &mut AdapterContext<'_, Input>,
+) -> Result<Option<ParseMetadata>, GvoxError> {
+    let mut header = [0; 20];
+    input_ctx.read(0, &mut header[..4])?;
+    if header[..4] != MAGICAVOXEL_MAGIC {
+        return Ok(None);
+    }
+
+    // The MAIN chunk header follows the magic and version, and every other chunk is its child.
+    input_ctx.read(4, &mut header[4..])?;
+    let children_size = read_u32(&header, 16).expect("Header was not 20 bytes long.") as usize;
+    let mut bytes = vec![0; header.len() + children_size];
+    bytes[..header.len()].copy_from_slice(&header);
+    input_ctx.read(header.len(), &mut bytes[header.len()..])?;
+    adapters::magicavoxel_metadata(&bytes).map(Some)
+}
@@ -7,11 +7,10 @@
 //! For additional examples, see the tests in `src/tests.rs`.
 //!
 //! ```rust
-//! const BYTES: &[u8] = include_bytes!("palette.gvox");
+//! const PALETTE_BYTES: &[u8] = include_bytes!("palette.gvox");
 //! let mut o_buffer = Box::default();
 //! {
 //!     let gvox_ctx = gvox_rs::Context::new();
-//!     gvox_ctx.register_adapter::<gvox_rs::Parse, procedural_parse::Procedural>();
 //!     let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
 //!     let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
 //!         downscale_factor: 1,
@@ -39,16 +38,19 @@
 //!         .expect("Failed to get colored text serialize adapter.")
 //!         .create_adapter_context(s_config)
 //!         .expect("Failed to create adapter context.");
-//!     gvox_rs::blit_region(
-//!         Some(&mut i_ctx),
-//!         Some(&mut o_ctx),
-//!         &mut p_ctx,
-//!         &mut s_ctx,
-//!         None, // Parse whole file!
-//!         gvox_rs::ChannelId::COLOR
-//!             | gvox_rs::ChannelId::NORMAL
-//!             | gvox_rs::ChannelId::MATERIAL_ID,
-//!     )
+//!     gvox_ctx
+//!         .blit()
+//!         .input(&mut i_ctx)
+//!         .output(&mut o_ctx)
+//!         .parse(&mut p_ctx)
+//!         .serialize(&mut s_ctx)
+//!         // No range is set, so the whole file is parsed!
+//!         .channels(
+//!             gvox_rs::ChannelId::COLOR
+//!                 | gvox_rs::ChannelId::NORMAL
+//!                 | gvox_rs::ChannelId::MATERIAL_ID,
+//!         )
+//!         .execute()
 //!     .expect("Error while translating.");
 //! }
 //! assert_eq!(
@@ -72,6 +74,23 @@
 
 /// The set of default adapters that come built-in.
 pub mod adapters;
+/// Moving voxel data between channels while it is parsed.
+pub mod channel_remap;
+/// The raw bindings to the native library which this crate was built against.
+#[cfg(feature = "raw-sys")]
+pub use gvox_sys as sys;
+/// Low-level layouts and constants for the gvox container formats.
+pub mod format;
+/// Fast recognition of voxel files from their headers, without a context.
+pub mod inspect;
+/// Tools for editing the palettes of gvox_palette containers without decoding their voxel data.
+pub mod palette_meta;
+/// A dense, in-memory voxel container which may be the source or destination of blits.
+pub mod voxel_grid;
+pub use voxel_grid::{DensePlane, VoxelGrid, VoxelGridBuilder};
+/// Utilities for detecting memory leaks in code that uses the library.
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 // Comment out to test the Wasm support
 #[cfg(test)]
@@ -85,13 +104,16 @@ mod tests;
 use bitflags::*;
 use fxhash::*;
 use std::any::*;
+use std::cell::*;
 use std::collections::hash_map::*;
 use std::error::*;
 use std::ffi::*;
 use std::marker::*;
 use std::mem::*;
 use std::ops::*;
+use std::panic::*;
 use std::slice::*;
+use std::sync::atomic::*;
 use std::sync::*;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -119,119 +141,1145 @@ pub fn get_version() -> Version {
 
 /// Copies a range of voxel data from the specified input
 /// to the specified output, parsing and then serializing
-/// the data using the provided format adapters.
-pub fn blit_region(
-    input_ctx: Option<&mut AdapterContext<'_, Input>>,
-    output_ctx: Option<&mut AdapterContext<'_, Output>>,
-    parse_ctx: &mut AdapterContext<'_, Parse>,
-    serialize_ctx: &mut AdapterContext<'_, Serialize>,
-    range: Option<&RegionRange>,
+/// the data using the provided format adapters. All of
+/// the adapter contexts must belong to the same context.
+pub fn blit_region<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    range: impl Into<Option<&'a RegionRange>>,
     channel_flags: ChannelFlags,
 ) -> Result<(), GvoxError> {
-    unsafe {
-        parse_ctx.context().execute_inner(|ctx| {
-            gvox_sys::gvox_blit_region(
-                if input_ctx.is_some() {
-                    input_ctx.unwrap().as_mut_ptr()
-                } else {
-                    std::ptr::null_mut() as *mut gvox_sys::GvoxAdapterContext
-                },
-                if output_ctx.is_some() {
-                    output_ctx.unwrap().as_mut_ptr()
-                } else {
-                    std::ptr::null_mut() as *mut gvox_sys::GvoxAdapterContext
-                },
-                parse_ctx.as_mut_ptr(),
-                serialize_ctx.as_mut_ptr(),
-                if range.is_some() {
-                    range.unwrap() as *const RegionRange as *const gvox_sys::GvoxRegionRange
-                } else {
-                    std::ptr::null() as *const gvox_sys::GvoxRegionRange
-                },
-                channel_flags.into(),
-            );
+    BlitOperation::from_parts(
+        input_ctx.into(),
+        output_ctx.into(),
+        parse_ctx,
+        serialize_ctx,
+        range.into(),
+        channel_flags,
+    )
+    .execute()
+}
 
-            ctx.get_error()
-        })
+/// Does the same as blit_region, but explicitly sets the blit mode to prefer parse-driven
+pub fn blit_region_parse_driven<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    range: impl Into<Option<&'a RegionRange>>,
+    channel_flags: ChannelFlags,
+) -> Result<(), GvoxError> {
+    BlitOperation::from_parts(
+        input_ctx.into(),
+        output_ctx.into(),
+        parse_ctx,
+        serialize_ctx,
+        range.into(),
+        channel_flags,
+    )
+    .mode(BlitMode::ParseDriven)
+    .execute()
+}
+
+/// Does the same as blit_region, but explicitly sets the blit mode to prefer serialize-driven
+pub fn blit_region_serialize_driven<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    range: impl Into<Option<&'a RegionRange>>,
+    channel_flags: ChannelFlags,
+) -> Result<(), GvoxError> {
+    BlitOperation::from_parts(
+        input_ctx.into(),
+        output_ctx.into(),
+        parse_ctx,
+        serialize_ctx,
+        range.into(),
+        channel_flags,
+    )
+    .mode(BlitMode::SerializeDriven)
+    .execute()
+}
+
+/// Does the same as blit_region, but reports progress to the provided callback as voxels are blitted.
+/// The callback receives the number of voxels blitted so far, and the total number of voxels to blit.
+/// The total is the volume of the range, or of the parse adapter's parsable range when no range is given.
+///
+/// Progress can only be observed for regions which pass through an adapter implemented in Rust. When
+/// both the parse and serialize adapters are native, the input is therefore parsed into memory first,
+/// and progress is reported as its regions arrive there. The voxels are then serialized from memory.
+/// The whole blitted volume is held in memory in the meantime, along with a record of which voxels
+/// have a value, so for large models this costs far more memory than [`blit_region`]; prefer it when
+/// progress is not needed. When no range is given and the parse adapter is native, the total is taken
+/// from the range with which the native library begins the blit.
+/// The callback is invoked while the blit holds its context, so it must not use that context.
+pub fn blit_region_with_progress<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    range: impl Into<Option<&'a RegionRange>>,
+    channel_flags: ChannelFlags,
+    mut progress: impl FnMut(u64, u64),
+) -> Result<(), GvoxError> {
+    let range = range.into();
+    let _scope = BlitProgress::begin(range, &mut progress);
+    if parse_ctx.is_native() && serialize_ctx.is_native() {
+        let ctx = parse_ctx.context();
+        let mut grid = VoxelGrid::with_presence(range.copied().unwrap_or_default(), channel_flags);
+        {
+            let mut s_ctx = ctx
+                .get_or_register_adapter::<Serialize, voxel_grid::VoxelGridAdapter>()?
+                .create_adapter_context(voxel_grid::VoxelGridTarget::from(&mut grid))?;
+            blit_region(
+                input_ctx.into(),
+                None,
+                parse_ctx,
+                &mut s_ctx,
+                range,
+                channel_flags,
+            )?;
+        }
+
+        let mut p_ctx = ctx
+            .get_or_register_adapter::<Parse, voxel_grid::VoxelGridAdapter>()?
+            .create_adapter_context(&grid)?;
+        return blit_region(
+            None,
+            output_ctx.into(),
+            &mut p_ctx,
+            serialize_ctx,
+            range,
+            channel_flags,
+        );
+    }
+
+    BlitOperation::from_parts(
+        input_ctx.into(),
+        output_ctx.into(),
+        parse_ctx,
+        serialize_ctx,
+        range,
+        channel_flags,
+    )
+    .execute()
+}
+
+/// Does the same as blit_region, but returns statistics about the work that the blit did.
+/// See [`BlitStats`] for which parts of a blit can be measured.
+pub fn blit_region_with_stats<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    range: impl Into<Option<&'a RegionRange>>,
+    channel_flags: ChannelFlags,
+) -> Result<BlitStats, GvoxError> {
+    BlitOperation::from_parts(
+        input_ctx.into(),
+        output_ctx.into(),
+        parse_ctx,
+        serialize_ctx,
+        range.into(),
+        channel_flags,
+    )
+    .execute_with_stats()
+}
+
+/// Does the same as blit_region, but stops early once the cancellation flag is set, returning
+/// an error of type [`ErrorType::Cancelled`]. The flag may be set from any thread.
+///
+/// Cancellation is observed whenever the native library calls into an adapter implemented in Rust,
+/// so a blit between native adapters alone runs to completion. Once cancelled, Rust adapters are no
+/// longer invoked, except to unload their regions and end the blit, so that they release what they
+/// allocated. Native adapters may finish their current work. Whatever the output adapter
+/// received before then is left in place, so the output of a cancelled blit is incomplete and should be discarded.
+pub fn blit_region_cancellable<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    range: impl Into<Option<&'a RegionRange>>,
+    channel_flags: ChannelFlags,
+    cancelled: &AtomicBool,
+) -> Result<(), GvoxError> {
+    let _scope = BlitCancellation::begin(cancelled);
+    if BlitCancellation::is_cancelled() {
+        return Err(BlitCancellation::error());
+    }
+
+    let result = BlitOperation::from_parts(
+        input_ctx.into(),
+        output_ctx.into(),
+        parse_ctx,
+        serialize_ctx,
+        range.into(),
+        channel_flags,
+    )
+    .execute();
+
+    match result {
+        Err(_) if BlitCancellation::is_cancelled() => Err(BlitCancellation::error()),
+        result => result,
     }
 }
 
-/// Does the same as blit_region, but explicitly sets the blit mode to prefer parse-driven
-pub fn blit_region_parse_driven(
-    input_ctx: Option<&mut AdapterContext<'_, Input>>,
-    output_ctx: Option<&mut AdapterContext<'_, Output>>,
-    parse_ctx: &mut AdapterContext<'_, Parse>,
-    serialize_ctx: &mut AdapterContext<'_, Serialize>,
-    range: Option<&RegionRange>,
+/// Blits each of the ranges in turn, and writes the concatenation of their serialized outputs to the output.
+/// The result is the same as blitting each range to a separate output, and concatenating the outputs.
+/// Voxels which lie within more than one range are serialized once for each range that contains them.
+///
+/// The input is parsed by a single blit over the smallest range which contains every range, so the parse adapter
+/// begins and ends one blit regardless of the number of ranges. Only the voxels within the ranges are loaded or kept,
+/// in one [`VoxelGrid`] per range, through [`voxel_grid::VoxelGridListAdapter`]. Each range is then serialized from
+/// memory, and the outputs of the ranges are written to the output at once with [`AdapterContext::write_all`],
+/// so the output must support it. This is checked before the input is parsed.
+pub fn blit_regions<'a, 'i: 'a, 'o: 'a>(
+    input_ctx: impl Into<Option<&'a mut AdapterContext<'i, Input>>>,
+    output_ctx: impl Into<Option<&'a mut AdapterContext<'o, Output>>>,
+    parse_ctx: &'a mut AdapterContext<'_, Parse>,
+    serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+    ranges: &[RegionRange],
     channel_flags: ChannelFlags,
 ) -> Result<(), GvoxError> {
-    unsafe {
-        parse_ctx.context().execute_inner(|ctx| {
-            gvox_sys::gvox_blit_region_parse_driven(
-                if input_ctx.is_some() {
-                    input_ctx.unwrap().as_mut_ptr()
-                } else {
-                    std::ptr::null_mut() as *mut gvox_sys::GvoxAdapterContext
-                },
-                if output_ctx.is_some() {
-                    output_ctx.unwrap().as_mut_ptr()
-                } else {
-                    std::ptr::null_mut() as *mut gvox_sys::GvoxAdapterContext
-                },
-                parse_ctx.as_mut_ptr(),
-                serialize_ctx.as_mut_ptr(),
-                if range.is_some() {
-                    range.unwrap() as *const RegionRange as *const gvox_sys::GvoxRegionRange
-                } else {
-                    std::ptr::null() as *const gvox_sys::GvoxRegionRange
-                },
-                channel_flags.into(),
-            );
+    let Some(bounds) = ranges.iter().copied().reduce(|a, b| bounding_range(&a, &b)) else {
+        return Ok(());
+    };
+    let output_ctx = output_ctx.into();
+    if let Some(output_ctx) = &output_ctx {
+        output_ctx.check_writable()?;
+    }
+    for range in ranges {
+        if usize::try_from(range.volume()).is_err() {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!("The range {range:?} holds too many voxels to blit in memory."),
+            ));
+        }
+    }
 
-            ctx.get_error()
+    let input_ctx = input_ctx.into();
+    let ctx = parse_ctx.context();
+    let mut grids = ranges
+        .iter()
+        .map(|range| VoxelGrid::with_presence(*range, channel_flags))
+        .collect::<Vec<_>>();
+    {
+        let mut s_ctx = ctx
+            .get_or_register_adapter::<Serialize, voxel_grid::VoxelGridListAdapter>()?
+            .create_adapter_context(voxel_grid::VoxelGridListTarget::from(&mut grids[..]))?;
+        blit_region(
+            input_ctx,
+            None,
+            parse_ctx,
+            &mut s_ctx,
+            &bounds,
+            channel_flags,
+        )?;
+    }
+
+    let mut bytes = Vec::new();
+    for grid in &grids {
+        let mut p_ctx = ctx
+            .get_or_register_adapter::<Parse, voxel_grid::VoxelGridAdapter>()?
+            .create_adapter_context(grid)?;
+        let mut buffer = Box::default();
+        {
+            let mut o_ctx = ctx
+                .get_adapter::<Output, adapters::ByteBuffer>()?
+                .create_adapter_context(adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut buffer,
+                ))?;
+            blit_region(
+                None,
+                &mut o_ctx,
+                &mut p_ctx,
+                serialize_ctx,
+                &grid.range(),
+                channel_flags,
+            )?;
+        }
+        bytes.extend_from_slice(&buffer);
+    }
+
+    match output_ctx {
+        Some(output_ctx) => output_ctx.write_all(&bytes),
+        None => Ok(()),
+    }
+}
+
+thread_local! {
+    /// The state of the blit which is running on the current thread.
+    static BLIT_STATE: RefCell<BlitState> = const {
+        RefCell::new(BlitState {
+            warnings: None,
+            input_size: None,
+            output_position: OutputPosition {
+                bytes_written: 0,
+                max_position: 0,
+            },
+            progress: None,
+            stats: None,
+            cancelled: None,
         })
+    };
+}
+
+/// The state which the functions that begin a blit share with the Rust adapters that it invokes.
+/// Each part is installed by a [`BlitStateScope`], so blits which nest on a thread keep their own state.
+struct BlitState {
+    /// The warnings reported by the adapters of the blit, if they are being collected.
+    warnings: Option<Vec<GvoxError>>,
+    /// The number of bytes that the input of the blit can read, if known.
+    input_size: Option<usize>,
+    /// The output written by the serialize adapter of the blit.
+    output_position: OutputPosition,
+    /// The progress of the blit, if it reports progress.
+    progress: Option<BlitProgress>,
+    /// The statistics of the blit, if they are being collected.
+    stats: Option<BlitStatsRecorder>,
+    /// The cancellation flag of the blit, if it is cancellable.
+    cancelled: Option<*const AtomicBool>,
+}
+
+impl BlitState {
+    /// Replaces a part of the state of blits on the current thread, until the returned scope is finished or dropped.
+    fn begin<'a, T>(part: fn(&mut Self) -> &mut T, value: T) -> BlitStateScope<'a, T> {
+        BlitStateScope {
+            part,
+            previous: Some(Self::with(|x| replace(part(x), value))),
+            data: PhantomData,
+        }
+    }
+
+    /// Applies an operation to the state of blits on the current thread.
+    fn with<R>(f: impl FnOnce(&mut Self) -> R) -> R {
+        BLIT_STATE.with(|x| f(&mut x.borrow_mut()))
     }
 }
 
-/// Does the same as blit_region, but explicitly sets the blit mode to prefer serialize-driven
-pub fn blit_region_serialize_driven(
-    input_ctx: Option<&mut AdapterContext<'_, Input>>,
-    output_ctx: Option<&mut AdapterContext<'_, Output>>,
-    parse_ctx: &mut AdapterContext<'_, Parse>,
-    serialize_ctx: &mut AdapterContext<'_, Serialize>,
-    range: Option<&RegionRange>,
+/// Replaces a part of the state of blits on the current thread while it is alive, and restores
+/// the previous value of that part when finished or dropped.
+struct BlitStateScope<'a, T> {
+    /// Selects the part of the state which this scope replaced.
+    part: fn(&mut BlitState) -> &mut T,
+    /// The value of the part before this scope began, until the scope is finished.
+    previous: Option<T>,
+    /// Marks that the scope borrows the data that the part refers to.
+    data: PhantomData<&'a mut ()>,
+}
+
+impl<'a, T> BlitStateScope<'a, T> {
+    /// Restores the previous value of the part, and returns the value that it had within this scope.
+    fn finish(mut self) -> T {
+        let previous = self
+            .previous
+            .take()
+            .expect("Blit state scope was already finished.");
+        BlitState::with(|x| replace((self.part)(x), previous))
+    }
+}
+
+impl<'a, T> Drop for BlitStateScope<'a, T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            BlitState::with(|x| *(self.part)(x) = previous);
+        }
+    }
+}
+
+/// Collects the warnings which adapters report during blits.
+struct BlitWarnings;
+
+impl BlitWarnings {
+    /// Collects the warnings of blits on the current thread until the returned scope is finished or dropped.
+    fn begin() -> BlitStateScope<'static, Option<Vec<GvoxError>>> {
+        BlitState::begin(|x| &mut x.warnings, Some(Vec::new()))
+    }
+
+    /// Records a warning for the blit which is running on the current thread. Warnings which
+    /// are reported outside of a blit are discarded.
+    fn push(warning: GvoxError) {
+        BlitState::with(|x| {
+            if let Some(warnings) = &mut x.warnings {
+                warnings.push(warning);
+            }
+        });
+    }
+}
+
+/// Makes the size of the input available to the parse adapters of blits.
+struct BlitInputSize;
+
+impl BlitInputSize {
+    /// Reports the provided input size for blits on the current thread until the returned scope is dropped.
+    fn begin(size: Option<usize>) -> BlitStateScope<'static, Option<usize>> {
+        BlitState::begin(|x| &mut x.input_size, size)
+    }
+
+    /// The number of bytes that the input of the blit which is running on the current thread can read, if known.
+    fn get() -> Option<usize> {
+        BlitState::with(|x| x.input_size)
+    }
+}
+
+/// Tracks how much output the serialize adapter of a blit has written.
+#[derive(Copy, Clone, Debug, Default)]
+struct OutputPosition {
+    /// The total number of bytes written, including those which overwrote earlier writes.
+    bytes_written: usize,
+    /// The position one past the last byte that has been written.
+    max_position: usize,
+}
+
+impl OutputPosition {
+    /// Tracks the output of blits on the current thread from the beginning until the returned scope is dropped.
+    fn begin() -> BlitStateScope<'static, Self> {
+        BlitState::begin(|x| &mut x.output_position, Self::default())
+    }
+
+    /// The output that the blit which is running on the current thread has written so far.
+    fn get() -> Self {
+        BlitState::with(|x| x.output_position)
+    }
+
+    /// Records that the given number of bytes were written at the provided position.
+    fn record(position: usize, size: usize) {
+        BlitState::with(|x| {
+            let current = &mut x.output_position;
+            current.bytes_written += size;
+            current.max_position = current.max_position.max(position + size);
+        });
+    }
+}
+
+/// Converts voxel data between formats in memory, using the built-in adapters. The whole parsable
+/// range of the input is converted. A temporary context is created for the conversion, so this is best
+/// suited to one-off conversions; repeated conversions should create their adapter contexts once, and blit them.
+pub fn convert(
+    input: &[u8],
+    parse: ParseFormat,
+    serialize: SerializeFormat,
     channel_flags: ChannelFlags,
-) -> Result<(), GvoxError> {
-    unsafe {
-        parse_ctx.context().execute_inner(|ctx| {
-            gvox_sys::gvox_blit_region_serialize_driven(
-                if input_ctx.is_some() {
-                    input_ctx.unwrap().as_mut_ptr()
-                } else {
-                    std::ptr::null_mut() as *mut gvox_sys::GvoxAdapterContext
-                },
-                if output_ctx.is_some() {
-                    output_ctx.unwrap().as_mut_ptr()
-                } else {
-                    std::ptr::null_mut() as *mut gvox_sys::GvoxAdapterContext
-                },
-                parse_ctx.as_mut_ptr(),
-                serialize_ctx.as_mut_ptr(),
-                if range.is_some() {
-                    range.unwrap() as *const RegionRange as *const gvox_sys::GvoxRegionRange
-                } else {
-                    std::ptr::null() as *const gvox_sys::GvoxRegionRange
-                },
-                channel_flags.into(),
-            );
+) -> Result<Vec<u8>, GvoxError> {
+    let mut o_buffer = Box::default();
 
-            ctx.get_error()
+    {
+        let ctx = Context::new();
+        let mut i_ctx = ctx
+            .get_adapter::<Input, adapters::ByteBuffer>()?
+            .create_adapter_context(input)?;
+        let mut o_ctx = ctx
+            .get_adapter::<Output, adapters::ByteBuffer>()?
+            .create_adapter_context(adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer))?;
+        let mut p_ctx = parse.create_adapter_context(&ctx)?;
+        let mut s_ctx = serialize.create_adapter_context(&ctx)?;
+
+        blit_region(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            channel_flags,
+        )?;
+    }
+
+    Ok(o_buffer.into_vec())
+}
+
+/// A built-in format from which [`convert`] can read voxel data.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseFormat {
+    /// A gvox_palette container.
+    GvoxPalette,
+    /// A gvox_raw container.
+    GvoxRaw,
+    /// A gvox_brickmap container.
+    GvoxBrickmap,
+    /// A gvox_global_palette container.
+    GvoxGlobalPalette,
+    /// A gvox_octree container.
+    GvoxOctree,
+    /// A gvox_run_length_encoding container.
+    GvoxRunLengthEncoding,
+    /// A run-length encoded container with the stable layout of [`adapters::RustRunLengthEncoding`].
+    RustRunLengthEncoding,
+    /// A MagicaVoxel `.vox` file.
+    MagicaVoxel,
+    /// Individual models of a MagicaVoxel `.vox` file, selected by the given configuration.
+    MagicaVoxelModel(adapters::MagicaVoxelParseAdapterConfig),
+    /// A Voxlap or Ace of Spades map, parsed with the given configuration.
+    Voxlap(adapters::VoxlapParseAdapterConfig),
+    /// A Build engine `.kvx` model, parsed with the given configuration.
+    Kvx(adapters::KvxParseAdapterConfig),
+    /// A `.binvox` file, parsed with the given configuration.
+    Binvox(adapters::BinvoxParseAdapterConfig),
+}
+
+impl ParseFormat {
+    /// Creates a context for the parse adapter of this format.
+    fn create_adapter_context(
+        &self,
+        ctx: &Context,
+    ) -> Result<AdapterContext<'static, Parse>, GvoxError> {
+        match *self {
+            Self::GvoxPalette => ctx
+                .get_adapter::<Parse, adapters::GvoxPalette>()?
+                .create_adapter_context(()),
+            Self::GvoxRaw => ctx
+                .get_adapter::<Parse, adapters::GvoxRaw>()?
+                .create_adapter_context(()),
+            Self::GvoxBrickmap => ctx
+                .get_adapter::<Parse, adapters::GvoxBrickmap>()?
+                .create_adapter_context(()),
+            Self::GvoxGlobalPalette => ctx
+                .get_adapter::<Parse, adapters::GvoxGlobalPalette>()?
+                .create_adapter_context(()),
+            Self::GvoxOctree => ctx
+                .get_adapter::<Parse, adapters::GvoxOctree>()?
+                .create_adapter_context(()),
+            Self::GvoxRunLengthEncoding => ctx
+                .get_adapter::<Parse, adapters::GvoxRunLengthEncoding>()?
+                .create_adapter_context(()),
+            Self::RustRunLengthEncoding => ctx
+                .get_adapter::<Parse, adapters::RustRunLengthEncoding>()?
+                .create_adapter_context(()),
+            Self::MagicaVoxel => ctx
+                .get_adapter::<Parse, adapters::MagicaVoxel>()?
+                .create_adapter_context(()),
+            Self::MagicaVoxelModel(config) => ctx
+                .get_adapter::<Parse, adapters::MagicaVoxelModel>()?
+                .create_adapter_context(config),
+            Self::Voxlap(config) => ctx
+                .get_adapter::<Parse, adapters::Voxlap>()?
+                .create_adapter_context(config),
+            Self::Kvx(config) => ctx
+                .get_adapter::<Parse, adapters::Kvx>()?
+                .create_adapter_context(config),
+            Self::Binvox(config) => ctx
+                .get_adapter::<Parse, adapters::Binvox>()?
+                .create_adapter_context(config),
+        }
+    }
+}
+
+/// A built-in format to which [`convert`] can write voxel data.
+#[derive(Copy, Clone, Debug)]
+pub enum SerializeFormat {
+    /// A gvox_palette container.
+    GvoxPalette,
+    /// A gvox_raw container.
+    GvoxRaw,
+    /// A gvox_brickmap container.
+    GvoxBrickmap,
+    /// A gvox_global_palette container.
+    GvoxGlobalPalette,
+    /// A gvox_octree container.
+    GvoxOctree,
+    /// A gvox_run_length_encoding container.
+    GvoxRunLengthEncoding,
+    /// A run-length encoded container with the stable layout of [`adapters::RustRunLengthEncoding`].
+    RustRunLengthEncoding,
+    /// A MagicaVoxel `.vox` file, containing the color channel as a single model.
+    MagicaVoxel,
+    /// Colored text for display in a terminal, written with the given configuration.
+    ColoredText(adapters::ColoredTextSerializeAdapterConfig),
+    /// Colored text for display in a terminal, written by [`adapters::RustColoredText`] with the given configuration.
+    RustColoredText(adapters::RustColoredTextConfig),
+    /// A compact preview for display in a terminal, written with the given configuration.
+    TerminalPreview(adapters::TerminalPreviewConfig),
+}
+
+impl SerializeFormat {
+    /// Creates a context for the serialize adapter of this format.
+    fn create_adapter_context(
+        &self,
+        ctx: &Context,
+    ) -> Result<AdapterContext<'static, Serialize>, GvoxError> {
+        match *self {
+            Self::GvoxPalette => ctx
+                .get_adapter::<Serialize, adapters::GvoxPalette>()?
+                .create_adapter_context(()),
+            Self::GvoxRaw => ctx
+                .get_adapter::<Serialize, adapters::GvoxRaw>()?
+                .create_adapter_context(()),
+            Self::GvoxBrickmap => ctx
+                .get_adapter::<Serialize, adapters::GvoxBrickmap>()?
+                .create_adapter_context(()),
+            Self::GvoxGlobalPalette => ctx
+                .get_adapter::<Serialize, adapters::GvoxGlobalPalette>()?
+                .create_adapter_context(()),
+            Self::GvoxOctree => ctx
+                .get_adapter::<Serialize, adapters::GvoxOctree>()?
+                .create_adapter_context(()),
+            Self::GvoxRunLengthEncoding => ctx
+                .get_adapter::<Serialize, adapters::GvoxRunLengthEncoding>()?
+                .create_adapter_context(()),
+            Self::RustRunLengthEncoding => ctx
+                .get_adapter::<Serialize, adapters::RustRunLengthEncoding>()?
+                .create_adapter_context(()),
+            Self::MagicaVoxel => ctx
+                .get_adapter::<Serialize, adapters::MagicaVoxelOut>()?
+                .create_adapter_context(()),
+            Self::ColoredText(config) => ctx
+                .get_adapter::<Serialize, adapters::ColoredText>()?
+                .create_adapter_context(config),
+            Self::RustColoredText(config) => ctx
+                .get_adapter::<Serialize, adapters::RustColoredText>()?
+                .create_adapter_context(config),
+            Self::TerminalPreview(config) => ctx
+                .get_adapter::<Serialize, adapters::TerminalPreview>()?
+                .create_adapter_context(config),
+        }
+    }
+}
+
+/// Describes a single blit operation, which copies a range of voxel data from an input
+/// to an output, parsing and then serializing the data using the provided format adapters.
+/// Blit operations are created with [`Context::blit`].
+#[derive(Debug)]
+pub struct BlitOperation<'a> {
+    /// The context on which the blit will execute.
+    ctx: Context,
+    /// The input adapter context, if any.
+    input: Option<*mut gvox_sys::GvoxAdapterContext>,
+    /// The input adapter context, if any, through which the input may be read before the blit begins.
+    input_ctx: Option<&'a mut dyn DirectInput>,
+    /// The parse adapter context, if any, which may substitute the input before the blit begins.
+    parse_ctx: Option<&'a dyn InputSubstitution>,
+    /// The number of bytes that the input adapter context can read, if it is known.
+    input_size: Option<usize>,
+    /// The output adapter context, if any.
+    output: Option<*mut gvox_sys::GvoxAdapterContext>,
+    /// The output adapter context, if any, through which the size of the output may be measured after the blit.
+    output_ctx: Option<&'a dyn MeasuredOutput>,
+    /// The parse adapter context, which is required.
+    parse: Option<*mut gvox_sys::GvoxAdapterContext>,
+    /// The serialize adapter context, which is required.
+    serialize: Option<*mut gvox_sys::GvoxAdapterContext>,
+    /// The channels to which the serialize adapter context restricts the blit, if any.
+    serialize_channels: Option<ChannelFlags>,
+    /// The adapters from which the parse and serialize adapter contexts were created.
+    adapters: [Option<*mut gvox_sys::GvoxAdapter>; 2],
+    /// The range to blit, or `None` to blit the whole parsable range.
+    range: Option<RegionRange>,
+    /// The channels to blit.
+    channel_flags: ChannelFlags,
+    /// The blit mode that should be preferred.
+    mode: BlitMode,
+    /// Whether any of the adapter contexts belongs to a different context than the operation.
+    mixed_contexts: bool,
+    /// Marks that the operation mutably borrows its adapter contexts.
+    data: PhantomData<&'a mut ()>,
+}
+
+impl<'a> BlitOperation<'a> {
+    /// Creates a new blit operation, with no adapter contexts, on the given context.
+    fn new(ctx: Context) -> Self {
+        Self {
+            ctx,
+            input: None,
+            input_ctx: None,
+            parse_ctx: None,
+            input_size: None,
+            output: None,
+            output_ctx: None,
+            parse: None,
+            serialize: None,
+            serialize_channels: None,
+            adapters: [None; 2],
+            range: None,
+            channel_flags: ChannelFlags::empty(),
+            mode: BlitMode::DontCare,
+            mixed_contexts: false,
+            data: PhantomData,
+        }
+    }
+
+    /// Creates a blit operation from the arguments of the free blit functions, executing on the parse context's context.
+    fn from_parts(
+        input_ctx: Option<&'a mut AdapterContext<'_, Input>>,
+        output_ctx: Option<&'a mut AdapterContext<'_, Output>>,
+        parse_ctx: &'a mut AdapterContext<'_, Parse>,
+        serialize_ctx: &'a mut AdapterContext<'_, Serialize>,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Self {
+        let mut res = Self::new(parse_ctx.context())
+            .input(input_ctx)
+            .output(output_ctx)
+            .parse(parse_ctx)
+            .serialize(serialize_ctx)
+            .channels(channel_flags);
+        res.range = range.copied();
+        res
+    }
+
+    /// Sets the adapter context from which voxel data will be read. Parse adapters which
+    /// generate their own data, such as procedural ones, do not require an input.
+    pub fn input<'b: 'a>(
+        mut self,
+        input_ctx: impl Into<Option<&'a mut AdapterContext<'b, Input>>>,
+    ) -> Self {
+        let input_ctx = input_ctx.into();
+        let blitted = input_ctx
+            .as_deref()
+            .map(|x| x.substitute.as_deref().unwrap_or(x));
+        self.input_size = blitted.and_then(|x| x.input_size);
+        self.input = blitted.map(|x| self.adapter_context_ptr(x));
+        self.input_ctx = input_ctx.map(|x| x as &mut dyn DirectInput);
+        self
+    }
+
+    /// Sets the adapter context to which voxel data will be written. Serialize adapters which
+    /// do not produce output may be used without one.
+    pub fn output<'b: 'a>(
+        mut self,
+        output_ctx: impl Into<Option<&'a mut AdapterContext<'b, Output>>>,
+    ) -> Self {
+        let output_ctx = output_ctx.into();
+        self.output = output_ctx
+            .as_deref()
+            .map(|x| self.adapter_context_ptr(x.substitute.as_deref().unwrap_or(x)));
+        self.output_ctx = output_ctx.map(|x| x as &dyn MeasuredOutput);
+        self
+    }
+
+    /// Sets the adapter context which will decode the input.
+    pub fn parse(mut self, parse_ctx: &'a mut AdapterContext<'_, Parse>) -> Self {
+        let blitted = parse_ctx.substitute.as_deref().unwrap_or(parse_ctx);
+        self.parse = Some(self.adapter_context_ptr(blitted));
+        self.adapters[0] = Some(blitted.adapter);
+        self.parse_ctx = Some(parse_ctx);
+        self
+    }
+
+    /// Sets the adapter context which will encode voxel data to the output.
+    pub fn serialize(mut self, serialize_ctx: &'a mut AdapterContext<'_, Serialize>) -> Self {
+        let serialize_ctx = serialize_ctx.substitute.as_deref().unwrap_or(serialize_ctx);
+        self.serialize = Some(self.adapter_context_ptr(serialize_ctx));
+        self.serialize_channels = serialize_ctx.blit_channels;
+        self.adapters[1] = Some(serialize_ctx.adapter);
+        self
+    }
+
+    /// Restricts the blit to the given range of voxels. By default, the entire parsable range is blitted.
+    pub fn range(mut self, range: RegionRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the channels which will be blitted.
+    pub fn channels(mut self, channel_flags: impl Into<ChannelFlags>) -> Self {
+        self.channel_flags = channel_flags.into();
+        self
+    }
+
+    /// Sets the blit mode that should be preferred. By default, the parse adapter's preference is used,
+    /// unless it is a Rust adapter without a preference, in which case the serialize adapter's preference is used.
+    pub fn mode(mut self, mode: BlitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Records whether the adapter context belongs to this operation's context, and returns its raw handle.
+    fn adapter_context_ptr<K: AdapterKind>(
+        &mut self,
+        adapter_ctx: &AdapterContext<'_, K>,
+    ) -> *mut gvox_sys::GvoxAdapterContext {
+        self.mixed_contexts |= adapter_ctx.ctx != self.ctx;
+        adapter_ctx.ptr
+    }
+
+    /// Performs the blit like [`BlitOperation::execute`], and returns statistics about the work that it did.
+    pub fn execute_with_stats(mut self) -> Result<BlitStats, GvoxError> {
+        let output_ctx = self.output_ctx.take();
+        let scope = BlitStatsRecorder::begin();
+        let start = std::time::Instant::now();
+        self.execute()?;
+        let mut stats = BlitStats {
+            duration: start.elapsed(),
+            ..scope.finish().map(|x| x.stats).unwrap_or_default()
+        };
+        if let Some(size) = output_ctx.and_then(MeasuredOutput::output_size) {
+            stats.bytes_written = size;
+        }
+        Ok(stats)
+    }
+
+    /// Performs the blit, or returns an error if the parse or serialize adapter context was not provided,
+    /// or if the adapter contexts belong to different contexts.
+    pub fn execute(self) -> Result<(), GvoxError> {
+        let missing = |kind| {
+            GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!("A {kind} adapter context is required to blit."),
+            )
+        };
+        let parse = self.parse.ok_or_else(|| missing("parse"))?;
+        let serialize = self.serialize.ok_or_else(|| missing("serialize"))?;
+        if self.mixed_contexts {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                "All adapter contexts of a blit must belong to the same context.",
+            ));
+        }
+
+        let channel_flags = match self.serialize_channels {
+            Some(channels) if channels & !self.channel_flags != ChannelFlags::empty() => {
+                return Err(GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    format!(
+                        "The serialize adapter context requires the channels {:?}, but the blit only requested {:?}.",
+                        channels, self.channel_flags
+                    ),
+                ))
+            }
+            Some(channels) => channels,
+            None => self.channel_flags,
+        };
+
+        // The input substitute of the parse adapter context is prepared from the input before the blit begins.
+        let input_substitute = match (self.parse_ctx, self.input_ctx) {
+            (Some(parse_ctx), Some(input_ctx)) => {
+                parse_ctx.input_substitute(&mut |position, data| input_ctx.read(position, data))?
+            }
+            _ => None,
+        };
+        let (input, input_size) = match &input_substitute {
+            Some(x) => (Some(x.ptr), x.input_size),
+            None => (self.input, self.input_size),
+        };
+
+        unsafe {
+            self.ctx.execute_inner(|ctx| {
+                let blit = match ctx.resolve_blit_mode(self.mode, self.adapters) {
+                    BlitMode::DontCare => gvox_sys::gvox_blit_region,
+                    BlitMode::ParseDriven => gvox_sys::gvox_blit_region_parse_driven,
+                    BlitMode::SerializeDriven => gvox_sys::gvox_blit_region_serialize_driven,
+                };
+
+                let warnings = BlitWarnings::begin();
+                let _input_size = BlitInputSize::begin(input_size);
+                let _output_position = OutputPosition::begin();
+                blit(
+                    input.unwrap_or(std::ptr::null_mut()),
+                    self.output.unwrap_or(std::ptr::null_mut()),
+                    parse,
+                    serialize,
+                    self.range
+                        .as_ref()
+                        .map(|x| x as *const RegionRange as *const gvox_sys::GvoxRegionRange)
+                        .unwrap_or(std::ptr::null()),
+                    channel_flags.into(),
+                );
+
+                ctx.warnings.extend(warnings.finish().unwrap_or_default());
+                ctx.get_error()
+            })
+        }
+    }
+}
+
+/// The kind of adapter whose Rust code observed an event during a blit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BlitSide {
+    /// Data was read by a Rust input adapter.
+    Input,
+    /// Data was written by a Rust output adapter.
+    Output,
+    /// Data was read, or regions were loaded or emitted, by a Rust parse adapter.
+    Parse,
+    /// Data was written, or regions were loaded or received, by a Rust serialize adapter.
+    Serialize,
+}
+
+impl BlitSide {
+    /// Determines whether this side may count an event. When both ends of a call are implemented
+    /// in Rust, the event is observed at both, so only the first side to observe one counts them.
+    fn claim(self, claimant: &mut Option<BlitSide>) -> bool {
+        *claimant.get_or_insert(self) == self
+    }
+}
+
+/// Records that a region was processed by the given side, for any progress or statistics being collected.
+fn observe_region(side: BlitSide, range: &RegionRange) {
+    BlitProgress::region(side, range);
+    BlitStatsRecorder::region(side, range);
+}
+
+/// Tracks the number of voxels which a blit has processed, and reports them to a callback.
+struct BlitProgress {
+    /// The callback to which progress is reported. Its lifetime is bounded by the [`BlitStateScope`] that installed it.
+    callback: *mut dyn FnMut(u64, u64),
+    /// The number of voxels processed so far.
+    done: u64,
+    /// The total number of voxels to process, or zero if it is not yet known.
+    total: u64,
+    /// The side which is counting regions.
+    source: Option<BlitSide>,
+}
+
+impl BlitProgress {
+    /// Begins reporting the progress of blits on the current thread to the callback, until the returned scope is dropped.
+    fn begin<'a>(
+        range: Option<&RegionRange>,
+        callback: &'a mut dyn FnMut(u64, u64),
+    ) -> BlitStateScope<'a, Option<BlitProgress>> {
+        let callback = unsafe {
+            transmute::<*mut (dyn FnMut(u64, u64) + 'a), *mut dyn FnMut(u64, u64)>(callback)
+        };
+        let progress = Self {
+            callback,
+            done: 0,
+            total: range.map(RegionRange::volume).unwrap_or_default(),
+            source: None,
+        };
+        BlitState::begin(|x| &mut x.progress, Some(progress))
+    }
+
+    /// Sets the total number of voxels from the parsable range, if it was not already known.
+    fn parsable_range(range: &RegionRange) {
+        BlitState::with(|x| {
+            if let Some(progress) = &mut x.progress {
+                if progress.total == 0 {
+                    progress.total = range.volume();
+                }
+            }
+        });
+    }
+
+    /// Records that the given side has processed a region, and invokes the callback.
+    fn region(side: BlitSide, range: &RegionRange) {
+        let report = BlitState::with(|x| {
+            let progress = x.progress.as_mut()?;
+            if !side.claim(&mut progress.source) {
+                return None;
+            }
+
+            progress.done = progress.done.saturating_add(range.volume());
+            if progress.total != 0 {
+                progress.done = progress.done.min(progress.total);
+            }
+            Some((progress.callback, progress.done, progress.total))
+        });
+
+        if let Some((callback, done, total)) = report {
+            unsafe { (*callback)(done, total) }
+        }
+    }
+}
+
+/// Statistics about the work that a blit did. Regions and bytes can only be observed when they pass
+/// through an adapter implemented in Rust, so a counter stays zero when both ends of the traffic that
+/// it measures are native. The exception is the output of the built-in [`adapters::ByteBuffer`] and
+/// [`adapters::File`] output adapters, which is measured once the blit ends. For instance, regions
+/// passed from a native parse adapter to a native serialize adapter are not counted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlitStats {
+    /// The number of regions which were loaded or emitted.
+    pub regions: u64,
+    /// The total number of voxels covered by the loaded or emitted regions. Voxels which are loaded more than once are counted each time.
+    pub voxels: u64,
+    /// The number of bytes read from the input.
+    pub bytes_read: u64,
+    /// The number of bytes written to the output. For the built-in [`adapters::ByteBuffer`] and [`adapters::File`]
+    /// output adapters, this is the size of the output once the blit ends.
+    pub bytes_written: u64,
+    /// The wall-clock time that the blit took.
+    pub duration: std::time::Duration,
+}
+
+/// Collects the statistics of blits on the current thread.
+struct BlitStatsRecorder {
+    /// The statistics collected so far.
+    stats: BlitStats,
+    /// The side which is counting regions.
+    region_side: Option<BlitSide>,
+    /// The side which is counting bytes read.
+    read_side: Option<BlitSide>,
+    /// The side which is counting bytes written.
+    write_side: Option<BlitSide>,
+}
+
+impl BlitStatsRecorder {
+    /// Begins collecting the statistics of blits on the current thread, until the returned scope is finished or dropped.
+    fn begin() -> BlitStateScope<'static, Option<Self>> {
+        let recorder = Self {
+            stats: BlitStats::default(),
+            region_side: None,
+            read_side: None,
+            write_side: None,
+        };
+        BlitState::begin(|x| &mut x.stats, Some(recorder))
+    }
+
+    /// Applies an operation to the recorder of the current thread, if there is one.
+    fn record(f: impl FnOnce(&mut Self)) {
+        BlitState::with(|x| {
+            if let Some(recorder) = &mut x.stats {
+                f(recorder);
+            }
+        });
+    }
+
+    /// Records that the given side has loaded or emitted a region.
+    fn region(side: BlitSide, range: &RegionRange) {
+        Self::record(|x| {
+            if side.claim(&mut x.region_side) {
+                x.stats.regions += 1;
+                x.stats.voxels = x.stats.voxels.saturating_add(range.volume());
+            }
+        });
+    }
+
+    /// Records that the given side has read bytes from the input.
+    fn read(side: BlitSide, size: usize) {
+        Self::record(|x| {
+            if side.claim(&mut x.read_side) {
+                x.stats.bytes_read += size as u64;
+            }
+        });
+    }
+
+    /// Records that the given side has written bytes to the output.
+    fn written(side: BlitSide, size: usize) {
+        Self::record(|x| {
+            if side.claim(&mut x.write_side) {
+                x.stats.bytes_written += size as u64;
+            }
+        });
+    }
+}
+
+/// Allows the adapters of a blit to determine whether it was cancelled.
+struct BlitCancellation;
+
+impl BlitCancellation {
+    /// Makes blits on the current thread cancellable with the flag, until the returned scope is dropped.
+    fn begin(cancelled: &AtomicBool) -> BlitStateScope<'_, Option<*const AtomicBool>> {
+        BlitState::begin(|x| &mut x.cancelled, Some(cancelled))
+    }
+
+    /// Whether the blit running on the current thread has been cancelled.
+    fn is_cancelled() -> bool {
+        BlitState::with(|x| x.cancelled).is_some_and(|x| unsafe { (*x).load(Ordering::Acquire) })
+    }
+
+    /// The error with which cancelled blits fail.
+    fn error() -> GvoxError {
+        GvoxError::new(ErrorType::Cancelled, "The blit was cancelled.")
+    }
+}
+
+/// The serialized output of a single chunk of a [`blit_region_chunked`] operation.
+#[cfg(feature = "rayon")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlitChunk {
+    /// The range of voxels that this chunk covers.
+    pub range: RegionRange,
+    /// The bytes which the serialize adapter wrote for this chunk.
+    pub data: Box<[u8]>,
+}
+
+/// Splits the range into chunks of at most the given extent, and blits them in parallel.
+/// Chunks are blitted on separate contexts, so adapters which are not built-in must be
+/// registered by the factories. The factories are called on a new context each time rayon
+/// splits off a batch of chunks, which may happen several times per worker thread, and the
+/// adapter contexts that they create are reused for every chunk in that batch. Chunks are
+/// returned in x-fastest, then y, then z order, regardless of which worker blitted them.
+#[cfg(feature = "rayon")]
+pub fn blit_region_chunked<'a>(
+    range: &RegionRange,
+    chunk_extent: Extent3D,
+    channel_flags: ChannelFlags,
+    create_input: impl Fn(&Context) -> Result<Option<AdapterContext<'a, Input>>, GvoxError> + Sync,
+    create_parse: impl Fn(&Context) -> Result<AdapterContext<'a, Parse>, GvoxError> + Sync,
+    create_serialize: impl Fn(&Context) -> Result<AdapterContext<'a, Serialize>, GvoxError> + Sync,
+) -> Result<Vec<BlitChunk>, GvoxError> {
+    use rayon::prelude::*;
+
+    if chunk_extent.x == 0 || chunk_extent.y == 0 || chunk_extent.z == 0 {
+        return Err(GvoxError::new(
+            ErrorType::InvalidParameter,
+            "The chunk extent must be non-zero along every axis.",
+        ));
+    }
+
+    let create_worker = || {
+        let ctx = Context::new();
+        Ok::<_, GvoxError>((
+            create_input(&ctx)?,
+            create_parse(&ctx)?,
+            create_serialize(&ctx)?,
+            ctx,
+        ))
+    };
+
+    split_region_range(range, chunk_extent)
+        .into_par_iter()
+        .map_init(create_worker, |worker, range| {
+            let (i_ctx, p_ctx, s_ctx, ctx) = worker.as_mut().map_err(|e| e.clone())?;
+            let mut data = Box::default();
+            {
+                let mut o_ctx = ctx
+                    .get_adapter::<Output, adapters::ByteBuffer>()?
+                    .create_adapter_context(adapters::ByteBufferOutputAdapterConfig::from(
+                        &mut data,
+                    ))?;
+                blit_region(
+                    i_ctx.as_mut(),
+                    &mut o_ctx,
+                    p_ctx,
+                    s_ctx,
+                    &range,
+                    channel_flags,
+                )?;
+            }
+            Ok(BlitChunk { range, data })
+        })
+        .collect()
+}
+
+/// Splits the range into sub-ranges of at most the given extent, in x-fastest, then y, then z order.
+#[cfg(feature = "rayon")]
+fn split_region_range(range: &RegionRange, chunk_extent: Extent3D) -> Vec<RegionRange> {
+    let axis = |offset: i32, extent: u32, chunk: u32| {
+        (0..extent).step_by(chunk as usize).map(move |start| {
+            (
+                (offset as i64 + start as i64) as i32,
+                chunk.min(extent - start),
+            )
         })
+    };
+
+    let mut res = Vec::new();
+    for (z, extent_z) in axis(range.offset.z, range.extent.z, chunk_extent.z) {
+        for (y, extent_y) in axis(range.offset.y, range.extent.y, chunk_extent.y) {
+            for (x, extent_x) in axis(range.offset.x, range.extent.x, chunk_extent.x) {
+                res.push(RegionRange {
+                    offset: Offset3D { x, y, z },
+                    extent: Extent3D {
+                        x: extent_x,
+                        y: extent_y,
+                        z: extent_z,
+                    },
+                });
+            }
+        }
     }
+    res
 }
 
 /// Stores the capabilities, information, and state about a set of voxel blitting operations.
 /// Adapters can be created or obtained from contexts.
+///
+/// A context may be shared between threads, but the native library reports errors through a
+/// single stack on each context, so operations on the same context run one at a time. Blits
+/// which should run in parallel must use separate contexts, as `blit_region_chunked` does.
 #[derive(Clone, Debug, Default)]
 pub struct Context(Arc<Mutex<ContextInner>>);
 
@@ -241,19 +1289,150 @@ impl Context {
         Self::default()
     }
 
+    /// Wraps a native context which was created outside of this crate. The built-in adapters of the
+    /// native context are made available, but any other adapters must be added with [`Adapter::from_raw`]
+    /// before they can be retrieved. If `owned` is true, the native context is destroyed once the
+    /// returned context and all of its adapters and adapter contexts have been dropped. If an error is
+    /// returned, the native context is never destroyed.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must refer to a valid native context, which remains valid for as long as the returned
+    /// context or any of its adapters and adapter contexts are alive. If `owned` is true, nothing else may
+    /// destroy the native context. The native context must not be used by any other means while an operation
+    /// on the returned context is in progress. If the native context has input, output, or parse adapters named
+    /// `fn`, or an output adapter named `channel`, they must be the ones that this crate registers for
+    /// [`adapters::FnInput`], [`adapters::FnOutput`], [`adapters::FnSampler`], and [`adapters::ChannelOutput`].
+    pub unsafe fn from_raw(
+        ptr: *mut gvox_sys::GvoxContext,
+        owned: bool,
+    ) -> Result<Self, GvoxError> {
+        let mut inner = ContextInner {
+            ptr,
+            registered_adapter_types: FxHashMap::default(),
+            adapter_names: FxHashSet::default(),
+            unavailable_adapters: FxHashSet::default(),
+            preferred_blit_modes: FxHashMap::default(),
+            input_readers: FxHashMap::default(),
+            output_writers: FxHashMap::default(),
+            handler_creators: FxHashMap::default(),
+            warnings: Vec::new(),
+            owned: false,
+        };
+        inner.add_default_adapters()?;
+        inner.owned = owned;
+        Ok(Self(Arc::new(Mutex::new(inner))))
+    }
+
+    /// Releases this context, and returns its native context without destroying it. The caller becomes
+    /// responsible for destroying the native context if this context owned it. Adapters registered from
+    /// Rust remain registered on the native context, and may be added to a new context with [`Adapter::from_raw`].
+    ///
+    /// The context is returned unchanged if it is still in use by a clone, adapter, or adapter context.
+    pub fn into_raw(self) -> Result<*mut gvox_sys::GvoxContext, Self> {
+        Arc::try_unwrap(self.0).map_err(Self).map(|inner| {
+            let mut inner = inner.into_inner().unwrap_or_else(PoisonError::into_inner);
+            inner.owned = false;
+            inner.ptr
+        })
+    }
+
+    /// Flushes the error stack of this context, and returns every error that it held. Blits and other
+    /// operations already flush the stack when they fail, so this only returns errors which were reported
+    /// outside of them.
+    pub fn drain_errors(&self) -> Vec<GvoxError> {
+        self.execute_inner(|ctx| ctx.drain_errors())
+    }
+
+    /// Takes every warning which adapters reported during blits on this context, since the warnings were
+    /// last taken.
+    pub fn take_warnings(&self) -> Vec<GvoxError> {
+        self.execute_inner(|ctx| take(&mut ctx.warnings))
+    }
+
+    /// Begins describing a blit operation which will execute on this context.
+    pub fn blit<'a>(&self) -> BlitOperation<'a> {
+        BlitOperation::new(self.clone())
+    }
+
     /// Gets the adapter of the provided type and description, or returns an error if it could not be found.
     pub fn get_adapter<K: AdapterKind, A: AdapterDescriptor<K> + NamedAdapter>(
         &self,
     ) -> Result<Adapter<K, A>, GvoxError> {
-        let ptr = self.execute_inner(|ctx| ctx.get_raw_adapter::<K, A>())?;
+        self.get_adapter_named::<K, A>(A::name())
+    }
+
+    /// Gets the adapter of the provided type that was registered under the given name, or returns an error if it could not be found.
+    pub fn get_adapter_named<K: AdapterKind, A: AdapterDescriptor<K>>(
+        &self,
+        name: &str,
+    ) -> Result<Adapter<K, A>, GvoxError> {
+        let ptr = self.execute_inner(|ctx| ctx.get_raw_adapter::<K, A>(name))?;
 
         Ok(Adapter {
             ctx: self.clone(),
             ptr,
+            name: name.into(),
             data: PhantomData::default(),
         })
     }
 
+    /// Gets the adapter of the given kind that was registered under the provided name, without knowing its type
+    /// in advance, or returns an error if it could not be found.
+    pub fn get_adapter_by_name<K: AdapterKind>(
+        &self,
+        name: &str,
+    ) -> Result<DynAdapter<K>, GvoxError> {
+        let (ptr, adapter_type) =
+            self.execute_inner(|ctx| ctx.get_raw_adapter_untyped::<K>(name))?;
+
+        Ok(DynAdapter {
+            ctx: self.clone(),
+            ptr,
+            adapter_type,
+            name: name.into(),
+            data: PhantomData,
+        })
+    }
+
+    /// Whether the adapter of the provided type is available on this context under its own name.
+    pub fn has_adapter<K: AdapterKind, A: AdapterDescriptor<K> + NamedAdapter>(&self) -> bool {
+        self.execute_inner(|ctx| {
+            ctx.adapter_names.get(A::name()).is_some_and(|name| {
+                ctx.registered_adapter_types
+                    .get(&AdapterIdentifier::new::<K>(name.clone()))
+                    == Some(&TypeId::of::<A>())
+            })
+        })
+    }
+
+    /// Gets the adapter of the provided type, registering it first if it has not been registered yet.
+    /// The lookup and registration happen under a single lock, so concurrent calls register the adapter once.
+    pub fn get_or_register_adapter<
+        K: AdapterKind,
+        A: AdapterDescriptor<K> + NamedAdapter + private::RegisterableAdapter<K>,
+    >(
+        &self,
+    ) -> Result<Adapter<K, A>, GvoxError> {
+        self.get_or_register_adapter_named::<K, A>(A::name())
+    }
+
+    /// Gets the adapter of the provided type that was registered under the given name, registering it first
+    /// if no adapter has that name yet. Returns an error if the name belongs to an adapter of a different type.
+    pub fn get_or_register_adapter_named<K: AdapterKind, A: private::RegisterableAdapter<K>>(
+        &self,
+        name: &str,
+    ) -> Result<Adapter<K, A>, GvoxError> {
+        let ptr = self.execute_inner(|ctx| ctx.get_or_register_adapter::<K, A>(name))?;
+
+        Ok(Adapter {
+            ctx: self.clone(),
+            ptr,
+            name: name.into(),
+            data: PhantomData,
+        })
+    }
+
     /// Registers an adapter for future use, or returns an error if it could not be added.
     pub fn register_adapter<
         K: AdapterKind,
@@ -261,18 +1440,74 @@ impl Context {
     >(
         &self,
     ) -> Result<Adapter<K, A>, GvoxError> {
-        self.execute_inner(|ctx| ctx.register_adapter::<K, A>())?;
-        self.get_adapter::<K, A>()
+        self.register_adapter_named::<K, A>(A::name())
+    }
+
+    /// Registers an adapter for future use under the given name, or returns an error if it could not be added.
+    /// The same adapter type may be registered under several different names.
+    pub fn register_adapter_named<K: AdapterKind, A: private::RegisterableAdapter<K>>(
+        &self,
+        name: &str,
+    ) -> Result<Adapter<K, A>, GvoxError> {
+        self.execute_inner(|ctx| ctx.register_adapter::<K, A>(name))?;
+        self.get_adapter_named::<K, A>(name)
+    }
+
+    /// Gets the names of all adapters of the provided kind which are available on this context, in sorted order.
+    /// Built-in adapters that were omitted from the native library at build time are not included.
+    pub fn adapters<K: AdapterKind>(&self) -> Vec<String> {
+        self.adapter_names(K::ID)
+    }
+
+    /// Gets the names of all adapters of the provided runtime kind which are available on this context, in
+    /// sorted order. Built-in adapters that were omitted from the native library at build time are not included.
+    pub fn adapter_names(&self, kind: AdapterKindId) -> Vec<String> {
+        self.execute_inner(|ctx| {
+            let mut names = ctx
+                .registered_adapter_types
+                .keys()
+                .filter(|id| id.kind == kind)
+                .map(|id| id.name.to_string())
+                .collect::<Vec<_>>();
+            names.sort_unstable();
+            names
+        })
     }
 
     /// Retrieves a raw handle to the context.
+    #[cfg(feature = "raw-handles")]
+    #[deprecated(note = "The returned pointer is not synchronized with other context operations. Use `Context::with_raw` instead.")]
     pub fn as_mut_ptr(&self) -> *mut gvox_sys::GvoxContext {
         self.execute_inner(|ctx| ctx.ptr)
     }
 
+    /// Invokes the provided function with a raw handle to the underlying native context, and returns the result.
+    ///
+    /// The context lock is held for the entire duration of the call, so no other operation on this context
+    /// (including blits running on other threads) can observe or modify the native context concurrently.
+    /// Within the function, the handle may be passed to any `gvox_sys` function that operates on a context,
+    /// such as querying or popping the error stack, looking up adapters, or registering new ones. The handle
+    /// must not be destroyed, and it must not be retained or used after the function returns. Adapters
+    /// registered directly through the handle are not known to this context unless they are also added via
+    /// the typed registration API.
+    pub fn with_raw<R>(&self, f: impl FnOnce(*mut gvox_sys::GvoxContext) -> R) -> R {
+        self.execute_inner(|ctx| f(ctx.ptr))
+    }
+
     /// Executes the provided function synchronously on the context's inner data, and returns the result.
+    ///
+    /// If a previous operation panicked while holding the lock (for instance, inside of a `with_raw` callback),
+    /// the lock is recovered rather than propagating the panic. The inner state is only modified by operations
+    /// which cannot panic partway through, so it remains consistent. Any errors which the interrupted operation
+    /// left on the native error stack are discarded so that they are not reported by unrelated operations.
     fn execute_inner<T>(&self, f: impl FnOnce(&mut ContextInner) -> T) -> T {
-        f(&mut self.0.lock().expect("Could not acquire context mutex."))
+        let mut inner = self.0.lock().unwrap_or_else(|poisoned| {
+            self.0.clear_poison();
+            let inner = poisoned.into_inner();
+            let _ = inner.get_error();
+            inner
+        });
+        f(&mut inner)
     }
 }
 
@@ -294,46 +1529,72 @@ struct ContextInner {
     ptr: *mut gvox_sys::GvoxContext,
     /// All of the known adapter names, and their associated type handlers.
     registered_adapter_types: FxHashMap<AdapterIdentifier, TypeId>,
+    /// The interned names of all adapters that have been registered with this context.
+    adapter_names: FxHashSet<Arc<str>>,
+    /// The built-in adapters which the native library was built without.
+    unavailable_adapters: FxHashSet<AdapterIdentifier>,
+    /// The blit modes which the Rust-handled parse and serialize adapters of this context prefer.
+    preferred_blit_modes: FxHashMap<*mut gvox_sys::GvoxAdapter, BlitMode>,
+    /// The read functions of the Rust-handled input adapters of this context, which may be invoked outside of blits.
+    input_readers: FxHashMap<*mut gvox_sys::GvoxAdapter, InputReadFn>,
+    /// The functions of the Rust-handled output adapters of this context, which may be invoked outside of blits.
+    output_writers: FxHashMap<*mut gvox_sys::GvoxAdapter, private::OutputWriter>,
+    /// The functions which create the handlers of the Rust-handled adapters of this context, before their native adapter contexts.
+    handler_creators: FxHashMap<*mut gvox_sys::GvoxAdapter, HandlerCreateFn>,
+    /// The warnings which adapters reported during blits, until they are taken.
+    warnings: Vec<GvoxError>,
+    /// Whether the native context should be destroyed along with this one.
+    owned: bool,
 }
 
 impl ContextInner {
     /// Gets a raw, non-null pointer to the adapter of the given type and name. Returns an
     /// error if the adapter could not be found or was not of the correct type.
-    pub fn get_raw_adapter<K: AdapterKind, A: NamedAdapter>(
+    pub fn get_raw_adapter<K: AdapterKind, A: 'static>(
         &self,
+        name: &str,
     ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
+        let (adapter, adapter_type) = self.get_raw_adapter_untyped::<K>(name)?;
+        if adapter_type == TypeId::of::<A>() {
+            Ok(adapter)
+        } else {
+            Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                "The provided adapter was not of the correct type.".to_string(),
+            ))
+        }
+    }
+
+    /// Gets a pointer to the adapter of the given kind which was registered under the provided name,
+    /// along with the type of the adapter, or returns an error if it could not be found.
+    pub fn get_raw_adapter_untyped<K: AdapterKind>(
+        &self,
+        name: &str,
+    ) -> Result<(*mut gvox_sys::GvoxAdapter, TypeId), GvoxError> {
         unsafe {
             let adapter_type = self
-                .registered_adapter_types
-                .get(&AdapterIdentifier::new::<K, A>());
-            if adapter_type == Some(&TypeId::of::<A>()) {
-                let c_name =
-                    CString::new(A::name()).expect("Failed to convert Rust string to C string");
-                let kind = TypeId::of::<K>();
-
-                let adapter = if kind == TypeId::of::<Input>() {
-                    gvox_sys::gvox_get_input_adapter(self.ptr, c_name.as_ptr())
-                } else if kind == TypeId::of::<Output>() {
-                    gvox_sys::gvox_get_output_adapter(self.ptr, c_name.as_ptr())
-                } else if kind == TypeId::of::<Parse>() {
-                    gvox_sys::gvox_get_parse_adapter(self.ptr, c_name.as_ptr())
-                } else if kind == TypeId::of::<Serialize>() {
-                    gvox_sys::gvox_get_serialize_adapter(self.ptr, c_name.as_ptr())
-                } else {
-                    return Err(GvoxError::new(
-                        ErrorType::Unknown,
-                        "Unrecognized adapter type.".to_string(),
-                    ));
-                };
+                .adapter_names
+                .get(name)
+                .and_then(|name| {
+                    self.registered_adapter_types
+                        .get(&AdapterIdentifier::new::<K>(name.clone()))
+                });
+            if let Some(&adapter_type) = adapter_type {
+                let adapter = self.find_raw_adapter::<K>(&adapter_c_name(name)?)?;
 
                 self.get_error()
                     .and((!adapter.is_null()).then_some(adapter).ok_or_else(|| {
                         GvoxError::new(ErrorType::Unknown, "Adapter not found.".to_string())
                     }))
-            } else if adapter_type.is_some() {
+                    .map(|adapter| (adapter, adapter_type))
+            } else if self.adapter_names.get(name).is_some_and(|name| {
+                self.unavailable_adapters
+                    .contains(&AdapterIdentifier::new::<K>(name.clone()))
+            }) {
                 Err(GvoxError::new(
                     ErrorType::InvalidParameter,
-                    "The provided adapter was not of the correct type.".to_string(),
+                    "The provided adapter is not available in this build of the native library."
+                        .to_string(),
                 ))
             } else {
                 Err(GvoxError::new(
@@ -344,17 +1605,99 @@ impl ContextInner {
         }
     }
 
-    /// Registers an adapter for voxel conversion operations, and returns a raw pointer to the adapter.
+    /// Looks up the native adapter of the given kind and name, returning a null pointer if the native context does not have one.
+    unsafe fn find_raw_adapter<K: AdapterKind>(
+        &self,
+        c_name: &CStr,
+    ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
+        Ok(match K::ID {
+            AdapterKindId::Input => gvox_sys::gvox_get_input_adapter(self.ptr, c_name.as_ptr()),
+            AdapterKindId::Output => gvox_sys::gvox_get_output_adapter(self.ptr, c_name.as_ptr()),
+            AdapterKindId::Parse => gvox_sys::gvox_get_parse_adapter(self.ptr, c_name.as_ptr()),
+            AdapterKindId::Serialize => {
+                gvox_sys::gvox_get_serialize_adapter(self.ptr, c_name.as_ptr())
+            }
+        })
+    }
+
+    /// Registers an adapter for voxel conversion operations under the given name, and returns a raw pointer to the adapter.
     fn register_adapter<K: AdapterKind, A: private::RegisterableAdapter<K>>(
         &mut self,
+        name: &str,
     ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
         unsafe {
-            let adapter = A::register_adapter(self.ptr)?;
-            self.add_external_adapter::<K, A>()?;
+            let c_name = adapter_c_name(name)?;
+            if self
+                .registered_adapter_types
+                .contains_key(&AdapterIdentifier::new::<K>(name.into()))
+            {
+                return Err(GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    format!("An adapter named {name:?} is already registered."),
+                ));
+            }
+
+            let adapter = A::register_adapter(self.ptr, &c_name)?;
+            self.add_external_adapter_named::<K, A>(name)?;
+            self.record_rust_adapter::<K, A>(adapter);
             Ok(adapter)
         }
     }
 
+    /// Records the blit modes which the Rust-handled adapter supports and prefers, and the function which creates its
+    /// handlers, along with its read function if it is an input adapter, or its writing functions if it is an output adapter.
+    fn record_rust_adapter<K: AdapterKind, A: private::RegisterableAdapter<K>>(
+        &mut self,
+        adapter: *mut gvox_sys::GvoxAdapter,
+    ) {
+        self.preferred_blit_modes
+            .insert(adapter, A::preferred_blit_mode());
+        if let Some(read) = A::input_reader() {
+            self.input_readers.insert(adapter, read);
+        }
+        if let Some(writer) = A::output_writer() {
+            self.output_writers.insert(adapter, writer);
+        }
+        self.handler_creators.insert(adapter, A::handler_creator());
+    }
+
+    /// Determines the blit mode to use for the provided parse and serialize adapters. The requested mode
+    /// is used unless it is [`BlitMode::DontCare`], and the serialize adapter's preference only applies
+    /// if the parse adapter is known to have none. Otherwise, the native library decides.
+    fn resolve_blit_mode(
+        &self,
+        mode: BlitMode,
+        [parse, serialize]: [Option<*mut gvox_sys::GvoxAdapter>; 2],
+    ) -> BlitMode {
+        let preference =
+            |adapter: Option<_>| adapter.and_then(|x| self.preferred_blit_modes.get(&x).copied());
+
+        match (mode, preference(parse)) {
+            (BlitMode::DontCare, Some(BlitMode::DontCare)) => {
+                preference(serialize).unwrap_or(BlitMode::DontCare)
+            }
+            _ => mode,
+        }
+    }
+
+    /// Gets a pointer to the adapter of the provided type that was registered under the given name,
+    /// registering it first if no adapter has that name yet.
+    fn get_or_register_adapter<K: AdapterKind, A: private::RegisterableAdapter<K>>(
+        &mut self,
+        name: &str,
+    ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
+        let registered = self.adapter_names.get(name).is_some_and(|name| {
+            self.registered_adapter_types
+                .contains_key(&AdapterIdentifier::new::<K>(name.clone()))
+        });
+
+        if registered {
+            self.get_raw_adapter::<K, A>(name)
+        } else {
+            self.register_adapter::<K, A>(name)
+        }
+    }
+
     /// Obtains a raw pointer to a new adapter context, using the given adapter and configuration.
     ///
     /// # Safety
@@ -383,12 +1726,24 @@ impl ContextInner {
     pub unsafe fn add_external_adapter<K: AdapterKind, A: AdapterDescriptor<K> + NamedAdapter>(
         &mut self,
     ) -> Result<(), GvoxError> {
-        match self
-            .registered_adapter_types
-            .entry(AdapterIdentifier::new::<K, A>())
-        {
+        self.add_external_adapter_named::<K, A>(A::name())
+    }
+
+    /// Adds an external adapter under the given name to this context, so that it may be safely retrieved and used.
+    ///
+    /// # Safety
+    ///
+    /// The same invariants as `add_external_adapter` apply, with the adapter having been registered
+    /// on the underlying context under the provided name.
+    pub unsafe fn add_external_adapter_named<K: AdapterKind, A: AdapterDescriptor<K>>(
+        &mut self,
+        name: &str,
+    ) -> Result<(), GvoxError> {
+        let id = AdapterIdentifier::new::<K>(self.intern_name(name));
+        match self.registered_adapter_types.entry(id.clone()) {
             Entry::Vacant(v) => {
                 v.insert(TypeId::of::<A>());
+                self.unavailable_adapters.remove(&id);
                 Ok(())
             }
             Entry::Occupied(_) => Err(GvoxError::new(
@@ -398,30 +1753,116 @@ impl ContextInner {
         }
     }
 
-    /// Adds all builtin adapters to the context, so that they may be queried and used.
-    fn add_default_adapters(&mut self) -> Result<(), GvoxError> {
-        unsafe {
-            self.add_external_adapter::<Input, adapters::ByteBuffer>()?;
-            self.add_external_adapter::<Output, adapters::ByteBuffer>()?;
-            self.add_external_adapter::<Output, adapters::StdOut>()?;
-            self.add_external_adapter::<Parse, adapters::GvoxPalette>()?;
-            self.add_external_adapter::<Parse, adapters::GvoxRaw>()?;
-            self.add_external_adapter::<Parse, adapters::GvoxBrickmap>()?;
-            self.add_external_adapter::<Parse, adapters::GvoxGlobalPalette>()?;
-            self.add_external_adapter::<Parse, adapters::GvoxOctree>()?;
-            self.add_external_adapter::<Parse, adapters::GvoxRunLengthEncoding>()?;
-            self.add_external_adapter::<Parse, adapters::MagicaVoxel>()?;
-            self.add_external_adapter::<Parse, adapters::Voxlap>()?;
-            self.add_external_adapter::<Parse, adapters::Kvx>()?;
-            self.add_external_adapter::<Serialize, adapters::ColoredText>()?;
-            self.add_external_adapter::<Serialize, adapters::GvoxPalette>()?;
-            self.add_external_adapter::<Serialize, adapters::GvoxRaw>()?;
-            self.add_external_adapter::<Serialize, adapters::GvoxBrickmap>()?;
-            self.add_external_adapter::<Serialize, adapters::GvoxGlobalPalette>()?;
-            self.add_external_adapter::<Serialize, adapters::GvoxOctree>()?;
-            self.add_external_adapter::<Serialize, adapters::GvoxRunLengthEncoding>()?;
+    /// Retrieves the shared copy of the provided adapter name, creating it if it does not exist yet.
+    fn intern_name(&mut self, name: &str) -> Arc<str> {
+        if let Some(interned) = self.adapter_names.get(name) {
+            interned.clone()
+        } else {
+            let interned = Arc::<str>::from(name);
+            self.adapter_names.insert(interned.clone());
+            interned
+        }
+    }
+
+    /// Adds all builtin adapters that the native library provides to the context, along with the adapters
+    /// implemented in Rust, so that they may be queried and used.
+    fn add_default_adapters(&mut self) -> Result<(), GvoxError> {
+        unsafe {
+            self.add_default_adapter::<Input, adapters::ByteBuffer>()?;
+            self.add_default_adapter::<Input, adapters::File>()?;
+            self.add_default_adapter::<Output, adapters::ByteBuffer>()?;
+            self.add_default_adapter::<Output, adapters::File>()?;
+            self.add_default_adapter::<Output, adapters::StdOut>()?;
+            self.add_default_adapter::<Parse, adapters::GvoxPalette>()?;
+            self.add_default_adapter::<Parse, adapters::GvoxRaw>()?;
+            self.add_default_adapter::<Parse, adapters::GvoxBrickmap>()?;
+            self.add_default_adapter::<Parse, adapters::GvoxGlobalPalette>()?;
+            self.add_default_adapter::<Parse, adapters::GvoxOctree>()?;
+            self.add_default_adapter::<Parse, adapters::GvoxRunLengthEncoding>()?;
+            self.add_default_adapter::<Parse, adapters::MagicaVoxel>()?;
+            self.add_default_adapter::<Parse, adapters::Voxlap>()?;
+            self.add_default_adapter::<Parse, adapters::Kvx>()?;
+            self.add_default_adapter::<Serialize, adapters::ColoredText>()?;
+            self.add_default_adapter::<Serialize, adapters::GvoxPalette>()?;
+            self.add_default_adapter::<Serialize, adapters::GvoxRaw>()?;
+            self.add_default_adapter::<Serialize, adapters::GvoxBrickmap>()?;
+            self.add_default_adapter::<Serialize, adapters::GvoxGlobalPalette>()?;
+            self.add_default_adapter::<Serialize, adapters::GvoxOctree>()?;
+            self.add_default_adapter::<Serialize, adapters::GvoxRunLengthEncoding>()?;
+
+            self.add_rust_default_adapter::<Input, adapters::FnInput>()?;
+            self.add_rust_default_adapter::<Input, adapters::ByteBufferOwned>()?;
+            self.add_rust_default_adapter::<Input, adapters::IoReader>()?;
+            self.add_rust_default_adapter::<Input, adapters::ChainedInput>()?;
+            self.add_rust_default_adapter::<Input, adapters::WindowedInput>()?;
+            #[cfg(feature = "flate2")]
+            self.add_rust_default_adapter::<Input, adapters::GzipInput>()?;
+            self.add_rust_default_adapter::<Output, adapters::FnOutput>()?;
+            self.add_rust_default_adapter::<Output, adapters::VecOutput>()?;
+            #[cfg(feature = "zstd")]
+            self.add_rust_default_adapter::<Output, adapters::ZstdOutput>()?;
+            self.add_rust_default_adapter::<Parse, adapters::FnSampler>()?;
+            self.add_rust_default_adapter::<Parse, adapters::SparseVoxels>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::SparseVoxelsOut>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::VoxelDump>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::DenseArrays>()?;
+            self.add_rust_default_adapter::<Parse, adapters::DenseArraysInput>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::BrickBuffer>()?;
+            self.add_rust_default_adapter::<Parse, adapters::RustRunLengthEncoding>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::RustRunLengthEncoding>()?;
+            self.add_rust_default_adapter::<Output, adapters::ChannelOutput>()?;
+            self.add_rust_default_adapter::<Output, adapters::BufferedStdOut>()?;
+            self.add_rust_default_adapter::<Output, adapters::StdErr>()?;
+            self.add_rust_default_adapter::<Output, adapters::PositionedFile>()?;
+            self.add_rust_default_adapter::<Parse, adapters::MagicaVoxelModel>()?;
+            self.add_rust_default_adapter::<Parse, adapters::Binvox>()?;
+            #[cfg(feature = "minecraft")]
+            self.add_rust_default_adapter::<Parse, adapters::Schematic>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::MagicaVoxelOut>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::RustColoredText>()?;
+            self.add_rust_default_adapter::<Serialize, adapters::TerminalPreview>()?;
+            self.add_rust_default_adapter::<Parse, voxel_grid::VoxelGridAdapter>()?;
+            self.add_rust_default_adapter::<Serialize, voxel_grid::VoxelGridAdapter>()?;
+
+            Ok(())
+        }
+    }
+
+    /// Registers a builtin adapter which is implemented in Rust. A context that was previously wrapped
+    /// by this crate already has the adapter registered, so the existing registration is reused.
+    unsafe fn add_rust_default_adapter<
+        K: AdapterKind,
+        A: NamedAdapter + private::RegisterableAdapter<K>,
+    >(
+        &mut self,
+    ) -> Result<(), GvoxError> {
+        let adapter = self.find_raw_adapter::<K>(&adapter_c_name(A::name())?)?;
+        if adapter.is_null() {
+            self.register_adapter::<K, A>(A::name())?;
+        } else {
+            self.add_external_adapter::<K, A>()?;
+            self.record_rust_adapter::<K, A>(adapter);
+        }
+
+        Ok(())
+    }
 
+    /// Adds a builtin adapter to the context if the native library was built with it. Otherwise, the adapter
+    /// is recorded as unavailable so that attempts to retrieve it report why it is missing.
+    ///
+    /// # Safety
+    ///
+    /// The same invariants as `add_external_adapter` apply, should the native adapter exist.
+    unsafe fn add_default_adapter<K: AdapterKind, A: AdapterDescriptor<K> + NamedAdapter>(
+        &mut self,
+    ) -> Result<(), GvoxError> {
+        if self.find_raw_adapter::<K>(&adapter_c_name(A::name())?)?.is_null() {
+            let name = self.intern_name(A::name());
+            self.unavailable_adapters
+                .insert(AdapterIdentifier::new::<K>(name));
             Ok(())
+        } else {
+            self.add_external_adapter::<K, A>()
         }
     }
 
@@ -430,9 +1871,27 @@ impl ContextInner {
         unsafe { Self::get_error_from_raw_ptr(self.ptr) }
     }
 
+    /// Flushes the context error stack, and returns every error that it held.
+    fn drain_errors(&self) -> Vec<GvoxError> {
+        unsafe { Self::drain_errors_from_raw_ptr(self.ptr) }
+    }
+
     /// Flushes the error stack of the provided context, and returns the topmost error.
+    /// Any other errors on the stack are attached to it, and may be retrieved with [`GvoxError::related`].
     pub unsafe fn get_error_from_raw_ptr(ptr: *mut gvox_sys::GvoxContext) -> Result<(), GvoxError> {
-        let mut result = Ok(());
+        let mut errors = Self::drain_errors_from_raw_ptr(ptr);
+        match errors.pop() {
+            Some(mut error) => {
+                error.related = errors;
+                Err(error)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Flushes the error stack of the provided context, and returns every error that it held, in the order that they were popped.
+    pub unsafe fn drain_errors_from_raw_ptr(ptr: *mut gvox_sys::GvoxContext) -> Vec<GvoxError> {
+        let mut errors = Vec::new();
 
         let mut code = gvox_sys::gvox_get_result(ptr);
         let mut buf = Vec::new();
@@ -442,7 +1901,7 @@ impl ContextInner {
             buf.resize(msg_size, 0);
             gvox_sys::gvox_get_result_message(ptr, buf.as_mut_ptr() as *mut i8, &mut msg_size);
 
-            result = Err(GvoxError::new(ErrorType::from(code),
+            errors.push(GvoxError::new(ErrorType::from(code),
                 std::str::from_utf8(buf.as_slice())
                     .unwrap_or_default()
                     .to_string(),
@@ -452,52 +1911,77 @@ impl ContextInner {
             code = gvox_sys::gvox_get_result(ptr);
         }
 
-        result
+        errors
+    }
+}
+
+impl ContextInner {
+    /// Creates a new native context, without adding any adapters to the set of known adapters.
+    fn empty() -> Self {
+        unsafe {
+            Self {
+                ptr: gvox_sys::gvox_create_context(),
+                registered_adapter_types: FxHashMap::default(),
+                adapter_names: FxHashSet::default(),
+                unavailable_adapters: FxHashSet::default(),
+                preferred_blit_modes: FxHashMap::default(),
+                input_readers: FxHashMap::default(),
+                output_writers: FxHashMap::default(),
+                handler_creators: FxHashMap::default(),
+                warnings: Vec::new(),
+                owned: true,
+            }
+        }
     }
 }
 
 impl Default for ContextInner {
     fn default() -> Self {
-        unsafe {
-            let ptr = gvox_sys::gvox_create_context();
-            let registered_adapter_types = FxHashMap::default();
-            let mut res = Self {
-                ptr,
-                registered_adapter_types,
-            };
-            res.add_default_adapters()
-                .expect("Could not add default adapters to gvox context.");
+        let mut res = Self::empty();
+        res.add_default_adapters()
+            .expect("Could not add default adapters to gvox context.");
 
-            res
-        }
+        res
     }
 }
 
+// The native context is only accessed through the mutex of the owning context.
+unsafe impl Send for ContextInner {}
+
 impl Drop for ContextInner {
     fn drop(&mut self) {
-        unsafe { gvox_sys::gvox_destroy_context(self.ptr) }
+        if self.owned {
+            unsafe { gvox_sys::gvox_destroy_context(self.ptr) }
+        }
     }
 }
 
 /// Uniquely identifies an adapter registration by name and kind.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct AdapterIdentifier {
-    /// The name of this adapter.
-    name: &'static str,
+    /// The interned name of this adapter.
+    name: Arc<str>,
     /// The ID of the adapter kind.
-    kind: TypeId,
+    kind: AdapterKindId,
 }
 
 impl AdapterIdentifier {
     /// Creates a new identifier for the provided adapter name and kind.
-    pub fn new<K: AdapterKind, A: NamedAdapter>() -> Self {
-        Self {
-            name: A::name(),
-            kind: TypeId::of::<K>(),
-        }
+    pub fn new<K: AdapterKind>(name: Arc<str>) -> Self {
+        Self { name, kind: K::ID }
     }
 }
 
+/// Converts the provided adapter name to a C string, or returns an error if the name contains a null byte.
+fn adapter_c_name(name: &str) -> Result<CString, GvoxError> {
+    CString::new(name).map_err(|_| {
+        GvoxError::new(
+            ErrorType::InvalidParameter,
+            format!("Adapter name {name:?} contains a null byte."),
+        )
+    })
+}
+
 /// Acts as an abstract interface over the ability to read, write, parse, and serialize voxel data.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Adapter<K: AdapterKind, A: AdapterDescriptor<K>> {
@@ -505,16 +1989,73 @@ pub struct Adapter<K: AdapterKind, A: AdapterDescriptor<K>> {
     ctx: Context,
     /// A reference to the underlying adapter.
     ptr: *mut gvox_sys::GvoxAdapter,
+    /// The name under which the adapter was registered.
+    name: Arc<str>,
     /// Marks that this type uses its generic paramters.
     data: PhantomData<(K, A)>,
 }
 
 impl<K: AdapterKind, A: AdapterDescriptor<K>> Adapter<K, A> {
+    /// Wraps an adapter which was registered with the native context of `ctx` under the given name, outside
+    /// of the typed registration API. The adapter is added to the context, so that it may also be retrieved
+    /// with [`Context::get_adapter_named`] afterward. Returns an error if the adapter is not registered under
+    /// the name, or if the name is already associated with a different adapter type.
+    ///
+    /// # Safety
+    ///
+    /// The adapter must support operations for the selected adapter kind, and accept configurations with the
+    /// layout of `A::Configuration`. If it is a Rust adapter, it must have been registered by this crate for
+    /// the adapter type `A`.
+    pub unsafe fn from_raw(
+        ctx: &Context,
+        ptr: *mut gvox_sys::GvoxAdapter,
+        name: &str,
+    ) -> Result<Self, GvoxError> {
+        ctx.execute_inner(|inner| {
+            let found = inner.find_raw_adapter::<K>(&adapter_c_name(name)?)?;
+            inner.get_error()?;
+            if ptr.is_null() || found != ptr {
+                return Err(GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    "The provided adapter is not registered under the given name.",
+                ));
+            }
+
+            let id = AdapterIdentifier::new::<K>(inner.intern_name(name));
+            match inner.registered_adapter_types.get(&id) {
+                Some(&ty) if ty == TypeId::of::<A>() => Ok(()),
+                Some(_) => Err(GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    "The adapter name is already associated with a different adapter type.",
+                )),
+                None => inner.add_external_adapter_named::<K, A>(name),
+            }
+        })?;
+
+        Ok(Self {
+            ctx: ctx.clone(),
+            ptr,
+            name: name.into(),
+            data: PhantomData,
+        })
+    }
+
     /// The context to which this adapter belongs.
     pub fn context(&self) -> Context {
         self.ctx.clone()
     }
 
+    /// The name under which this adapter was registered. This is usually [`NamedAdapter::name`],
+    /// unless the adapter was registered or retrieved under another name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The kind of this adapter.
+    pub fn kind(&self) -> AdapterKindId {
+        K::ID
+    }
+
     /// Creates a new adapter context instance, with the given configuration, that can be utilized to perform voxel blitting operations.
     pub fn create_adapter_context<'a>(
         &self,
@@ -522,91 +2063,682 @@ impl<K: AdapterKind, A: AdapterDescriptor<K>> Adapter<K, A> {
     ) -> Result<AdapterContext<'a, K>, GvoxError> {
         unsafe {
             let ctx = self.context();
-            let ptr = self.ctx.execute_inner(|ctx| {
-                ctx.create_raw_adapter_context(
-                    self.ptr,
-                    &config as *const A::Configuration<'a> as *const c_void,
-                )
-            })?;
-
-            if !ExternalHandler::is_external::<K, A>() {
-                AdapterContextHolder::from_raw(ptr)
-                    .get_context_data()
-                    .expect("No user data was associated with context.")
-                    .ctx = self.ctx.as_mut_ptr();
-            }
+            let substitute = A::blit_substitute(&ctx, &config)?.map(Box::new);
+            // The configuration is boxed before creation, so that it stays at the same address
+            // for as long as the native adapter context may refer to it.
+            let config = Box::new(config);
+            let handler = self.create_handler(&config)?;
+            let ptr = self
+                .ctx
+                .execute_inner(|ctx| self.create_raw_adapter_context(ctx, &config, handler))?;
 
             Ok(AdapterContext {
                 ctx,
                 ptr,
+                adapter: self.ptr,
+                adapter_name: self.name.clone(),
+                adapter_type: TypeId::of::<A>(),
+                input_size: A::input_size(&config),
+                blit_channels: A::blit_channels(&config),
+                substitute,
+                input_substitute: Some(create_input_substitute::<K, A>),
+                config: Some(config),
                 data: PhantomData::default(),
             })
         }
     }
 
-    /// Retrieves a raw handle to the adapter.
-    pub fn as_mut_ptr(&mut self) -> *mut gvox_sys::GvoxAdapter {
-        self.ptr
+    /// Creates the handler for a new adapter context with the given configuration, if this is a Rust adapter. The
+    /// context is not locked while the handler is created, so that [`BaseAdapterHandler::create_with_context`]
+    /// may use it freely.
+    fn create_handler(
+        &self,
+        config: &A::Configuration<'_>,
+    ) -> Result<Option<Box<dyn Any + Send>>, GvoxError> {
+        let create = self
+            .ctx
+            .execute_inner(|ctx| ctx.handler_creators.get(&self.ptr).copied());
+        create
+            .map(|create| unsafe {
+                create(
+                    &self.ctx,
+                    config as *const A::Configuration<'_> as *const c_void,
+                )
+            })
+            .transpose()
+    }
+
+    /// Creates a new native adapter context with the given configuration and handler, on the provided locked context.
+    ///
+    /// # Safety
+    ///
+    /// The provided context must be the inner context of this adapter, and the handler must have been created
+    /// by [`Adapter::create_handler`] with the same configuration.
+    unsafe fn create_raw_adapter_context(
+        &self,
+        ctx: &mut ContextInner,
+        config: &A::Configuration<'_>,
+        handler: Option<Box<dyn Any + Send>>,
+    ) -> Result<*mut gvox_sys::GvoxAdapterContext, GvoxError> {
+        PREPARED_HANDLER.with(|x| *x.borrow_mut() = handler);
+        let result = ctx.create_raw_adapter_context(
+            self.ptr,
+            config as *const A::Configuration<'_> as *const c_void,
+        );
+        // The handler is left over if the native library failed before invoking the adapter's creation function.
+        PREPARED_HANDLER.with(|x| x.borrow_mut().take());
+        let ptr = result?;
+
+        if !ExternalHandler::is_external::<K, A>() {
+            match AdapterContextHolder::from_raw(ptr).get_context_data() {
+                Ok(data) => data.ctx = ctx.ptr,
+                Err(_) => {
+                    // The adapter did not create its contexts with this crate, so it is not the Rust adapter `A`.
+                    gvox_sys::gvox_destroy_adapter_context(ptr);
+                    ctx.drain_errors();
+                    return Err(GvoxError::new(
+                        K::ID.error_type(),
+                        "The adapter is not a Rust adapter of the expected type, so its context has no handler.",
+                    ));
+                }
+            }
+        }
+
+        Ok(ptr)
+    }
+
+    /// Retrieves a raw handle to the adapter.
+    #[cfg(feature = "raw-handles")]
+    #[deprecated(note = "Use `Adapter::with_raw` instead.")]
+    pub fn as_mut_ptr(&mut self) -> *mut gvox_sys::GvoxAdapter {
+        self.ptr
+    }
+
+    /// Invokes the provided function with a raw handle to the underlying native adapter, and returns the result.
+    ///
+    /// The owning context is locked for the entire duration of the call. Within the function, the handle may be
+    /// passed to `gvox_sys` functions that accept an adapter, such as `gvox_create_adapter_context`, using the
+    /// native context of this adapter. The handle must not be retained or used after the function returns.
+    pub fn with_raw<R>(&self, f: impl FnOnce(*mut gvox_sys::GvoxAdapter) -> R) -> R {
+        self.ctx.execute_inner(|_| f(self.ptr))
+    }
+}
+
+/// An adapter whose type is only known at runtime, such as one that was looked up by a name supplied by the user.
+/// Adapter contexts may be created from built-in adapters with a [`DynAdapterConfig`], while other adapters
+/// must first be converted into a typed [`Adapter`] with [`DynAdapter::downcast`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynAdapter<K: AdapterKind> {
+    /// The context that created this adapter.
+    ctx: Context,
+    /// A reference to the underlying adapter.
+    ptr: *mut gvox_sys::GvoxAdapter,
+    /// The type of the adapter descriptor.
+    adapter_type: TypeId,
+    /// The name under which the adapter was registered.
+    name: Arc<str>,
+    /// Marks that this type uses its generic parameter.
+    data: PhantomData<K>,
+}
+
+impl<K: AdapterKind> DynAdapter<K> {
+    /// The context to which this adapter belongs.
+    pub fn context(&self) -> Context {
+        self.ctx.clone()
+    }
+
+    /// The name under which this adapter was registered.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The kind of this adapter.
+    pub fn kind(&self) -> AdapterKindId {
+        K::ID
+    }
+
+    /// Whether this adapter has the provided descriptor type.
+    pub fn is<A: AdapterDescriptor<K>>(&self) -> bool {
+        self.adapter_type == TypeId::of::<A>()
+    }
+
+    /// Converts this into an adapter of the provided descriptor type, or returns an error if it has a different type.
+    pub fn downcast<A: AdapterDescriptor<K>>(self) -> Result<Adapter<K, A>, GvoxError> {
+        if self.is::<A>() {
+            Ok(Adapter {
+                ctx: self.ctx,
+                ptr: self.ptr,
+                name: self.name,
+                data: PhantomData,
+            })
+        } else {
+            Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                "The provided adapter was not of the correct type.",
+            ))
+        }
+    }
+
+    /// Creates a new adapter context with the provided configuration, if this adapter has the given descriptor type.
+    fn try_create_adapter_context<'a, A: AdapterDescriptor<K>>(
+        &self,
+        config: A::Configuration<'a>,
+    ) -> Option<Result<AdapterContext<'a, K>, GvoxError>> {
+        self.is::<A>().then(|| {
+            Adapter::<K, A> {
+                ctx: self.ctx.clone(),
+                ptr: self.ptr,
+                name: self.name.clone(),
+                data: PhantomData,
+            }
+            .create_adapter_context(config)
+        })
+    }
+}
+
+impl<K: private::AdapterKindAssociation> DynAdapter<K> {
+    /// Creates a new adapter context instance for this built-in adapter, with the given configuration, that can be
+    /// utilized to perform voxel blitting operations. Returns an error if the configuration does not belong to this
+    /// adapter, or if this adapter is not built-in.
+    pub fn create_adapter_context<'a>(
+        &self,
+        config: DynAdapterConfig<'a>,
+    ) -> Result<AdapterContext<'a, K>, GvoxError> {
+        K::create_dyn_adapter_context(self, config).unwrap_or_else(|| {
+            Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "The provided configuration does not belong to the {} adapter '{}'.",
+                    K::ID,
+                    self.name
+                ),
+            ))
+        })
+    }
+}
+
+/// The configuration of a built-in adapter, with which adapter contexts may be created from a [`DynAdapter`].
+#[derive(Debug)]
+pub enum DynAdapterConfig<'a> {
+    /// Configures the built-in adapters which take no configuration, such as [`adapters::MagicaVoxel`], along with
+    /// the standard stream outputs, such as [`adapters::StdOut`], which are given their default settings.
+    Unit,
+    /// Configures an [`adapters::ByteBuffer`] input adapter to read from the provided bytes.
+    Bytes(&'a [u8]),
+    /// Configures an [`adapters::ByteBufferOwned`] input adapter to read from the provided shared bytes.
+    OwnedBytes(Arc<[u8]>),
+    /// Configures an [`adapters::ByteBuffer`] output adapter.
+    ByteBufferOutput(adapters::ByteBufferOutputAdapterConfig<'a>),
+    /// Configures an [`adapters::File`] input adapter.
+    FileInput(adapters::FileInputAdapterConfig),
+    /// Configures an [`adapters::File`] output adapter.
+    FileOutput(adapters::FileOutputAdapterConfig),
+    /// Configures an [`adapters::StdOut`], [`adapters::BufferedStdOut`], or [`adapters::StdErr`] output adapter.
+    StdOutput(adapters::StdOutOutputAdapterConfig),
+    /// Configures an [`adapters::ColoredText`] serialize adapter.
+    ColoredText(adapters::ColoredTextSerializeAdapterConfig),
+    /// Configures an [`adapters::RustColoredText`] serialize adapter.
+    RustColoredText(adapters::RustColoredTextConfig),
+    /// Configures an [`adapters::TerminalPreview`] serialize adapter.
+    TerminalPreview(adapters::TerminalPreviewConfig),
+    /// Configures an [`adapters::MagicaVoxelModel`] parse adapter.
+    MagicaVoxelModel(adapters::MagicaVoxelParseAdapterConfig),
+    /// Configures an [`adapters::Voxlap`] parse adapter.
+    Voxlap(adapters::VoxlapParseAdapterConfig),
+    /// Configures an [`adapters::Kvx`] parse adapter.
+    Kvx(adapters::KvxParseAdapterConfig),
+    /// Configures an [`adapters::Binvox`] parse adapter.
+    Binvox(adapters::BinvoxParseAdapterConfig),
+}
+
+/// One specific instance of a configured adapter that can be used to perform blitting operations.
+/// Adapter contexts may be moved to other threads, and used there for blits. The configuration
+/// that an adapter context was created with is owned by it, and lives as long as the adapter context.
+pub struct AdapterContext<'a, K: AdapterKind> {
+    /// The associated context.
+    ctx: Context,
+    /// A reference to the underlying adapter context.
+    ptr: *mut gvox_sys::GvoxAdapterContext,
+    /// A reference to the adapter from which the adapter context was created.
+    adapter: *mut gvox_sys::GvoxAdapter,
+    /// The name under which the adapter was registered, when the adapter context was created.
+    adapter_name: Arc<str>,
+    /// The type of the adapter from which the adapter context was created.
+    adapter_type: TypeId,
+    /// The configuration that the adapter context was created with, which native adapters may
+    /// continue to reference. This is `None` if the adapter context was created outside of this crate.
+    config: Option<Box<dyn Send + 'a>>,
+    /// The number of bytes that the adapter context can read, if it is an input with a known size.
+    input_size: Option<usize>,
+    /// The channels to which blits using the adapter context are restricted, if it is a serialize
+    /// adapter context whose configuration requires it.
+    blit_channels: Option<ChannelFlags>,
+    /// The adapter context which performs blits in place of this one, if its adapter cannot handle its configuration.
+    substitute: Option<Box<AdapterContext<'a, K>>>,
+    /// The function which creates the input substitute of this adapter context from its configuration,
+    /// if the adapter context was created by this crate.
+    input_substitute: Option<InputSubstituteFn>,
+    /// Marks that this type makes use of its generic parameters.
+    data: PhantomData<(&'a (), K)>,
+}
+
+impl<'a, K: AdapterKind> AdapterContext<'a, K> {
+    /// Wraps a native adapter context which was created from the provided adapter outside of this crate.
+    /// The returned adapter context takes ownership of the native one, and destroys it when dropped.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must refer to a valid adapter context, created by `gvox_create_adapter_context` from
+    /// the native handle of `adapter` and the native context of its owning context. The configuration that
+    /// it was created with must have had the layout of `A::Configuration<'a>`, and any data which that
+    /// configuration referenced must remain valid for `'a`. Nothing else may use or destroy the native
+    /// adapter context afterward.
+    pub unsafe fn from_raw<A: AdapterDescriptor<K>>(
+        adapter: &Adapter<K, A>,
+        ptr: *mut gvox_sys::GvoxAdapterContext,
+    ) -> Self {
+        adapter.ctx.execute_inner(|ctx| {
+            if !ExternalHandler::is_external::<K, A>() {
+                if let Ok(data) = AdapterContextHolder::from_raw(ptr).get_context_data() {
+                    data.ctx = ctx.ptr;
+                }
+            }
+        });
+
+        Self {
+            ctx: adapter.ctx.clone(),
+            ptr,
+            adapter: adapter.ptr,
+            adapter_name: adapter.name.clone(),
+            adapter_type: TypeId::of::<A>(),
+            config: None,
+            input_size: None,
+            blit_channels: None,
+            substitute: None,
+            input_substitute: None,
+            data: PhantomData,
+        }
+    }
+
+    /// Releases this adapter context, and returns its native adapter context without destroying it.
+    /// The caller becomes responsible for destroying the native adapter context before its context
+    /// is destroyed. The configuration owned by this adapter context is dropped, so the native adapter
+    /// context must not be used afterward if its adapter continues to reference the configuration.
+    ///
+    /// Returns an error, and destroys this adapter context, if blits use a substitute in its place, since
+    /// the native adapter context would not behave as its configuration describes.
+    pub fn into_raw(self) -> Result<*mut gvox_sys::GvoxAdapterContext, GvoxError> {
+        self.check_raw_access()?;
+        let mut this = ManuallyDrop::new(self);
+        // The context, adapter name, and configuration must still be released, since nothing refers to them anymore.
+        drop(this.config.take());
+        drop(unsafe { std::ptr::read(&this.ctx) });
+        drop(unsafe { std::ptr::read(&this.adapter_name) });
+        Ok(this.ptr)
+    }
+
+    /// The context to which this adapter context belongs.
+    pub fn context(&self) -> Context {
+        self.ctx.clone()
+    }
+
+    /// The name under which the adapter of this adapter context was registered.
+    pub fn adapter_name(&self) -> &str {
+        &self.adapter_name
+    }
+
+    /// The kind of the adapter of this adapter context.
+    pub fn kind(&self) -> AdapterKindId {
+        K::ID
+    }
+
+    /// Retrieves a raw handle to the adapter context.
+    #[cfg(feature = "raw-handles")]
+    #[deprecated(note = "Use `AdapterContext::with_raw` instead.")]
+    pub fn as_mut_ptr(&mut self) -> *mut gvox_sys::GvoxAdapterContext {
+        self.ptr
+    }
+
+    /// Invokes the provided function with a raw handle to the underlying native adapter context, and returns the result.
+    ///
+    /// The owning context is locked for the entire duration of the call. Within the function, the handle may be
+    /// passed to `gvox_sys` functions that accept an adapter context, such as the user pointer accessors of
+    /// natively-handled adapters. The handle must not be destroyed, and it must not be retained or used after the
+    /// function returns. The user pointer of adapter contexts handled by `gvox_rs` must not be modified.
+    ///
+    /// Returns an error without invoking the function if blits use a substitute in place of this adapter context,
+    /// since the native adapter context would not behave as its configuration describes.
+    pub fn with_raw<R>(
+        &mut self,
+        f: impl FnOnce(*mut gvox_sys::GvoxAdapterContext) -> R,
+    ) -> Result<R, GvoxError> {
+        self.check_raw_access()?;
+        let ptr = self.ptr;
+        Ok(self.ctx.execute_inner(|_| f(ptr)))
+    }
+
+    /// The configuration that this adapter context was created with, if it was created by this crate from the adapter `A`.
+    fn config<A: AdapterDescriptor<K>>(&self) -> Option<&A::Configuration<'a>> {
+        let config = self.config.as_deref()?;
+        // The configuration was boxed from the configuration type of the adapter that created this adapter context.
+        (self.adapter_type == TypeId::of::<A>())
+            .then(|| unsafe { &*(config as *const (dyn Send + 'a) as *const A::Configuration<'a>) })
+    }
+
+    /// Whether blits with this adapter context invoke only native code, so that no adapter implemented in Rust observes them.
+    fn is_native(&self) -> bool {
+        let blitted = self.substitute.as_deref().unwrap_or(self);
+        !self
+            .ctx
+            .execute_inner(|ctx| ctx.handler_creators.contains_key(&blitted.adapter))
+    }
+
+    /// The mutable configuration that this adapter context was created with, if it was created by this crate from the adapter `A`.
+    fn config_mut<A: AdapterDescriptor<K>>(&mut self) -> Option<&mut A::Configuration<'a>> {
+        let config = self.config.as_deref_mut()?;
+        // The configuration was boxed from the configuration type of the adapter that created this adapter context.
+        (self.adapter_type == TypeId::of::<A>())
+            .then(|| unsafe { &mut *(config as *mut (dyn Send + 'a) as *mut A::Configuration<'a>) })
+    }
+
+    /// Returns an error if blits use a substitute in place of this adapter context, whose native
+    /// adapter context therefore cannot be used directly.
+    fn check_raw_access(&self) -> Result<(), GvoxError> {
+        if self.substitute.is_some() {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Adapter contexts of {:?} with this configuration are blitted through a substitute, so their native adapter context cannot be used directly.",
+                    self.adapter_name
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Replaces the underlying adapter context with a new one, created from the same adapter with the given
+    /// configuration. This discards any state that the adapter context accumulated during previous blits.
+    /// The adapter type must match the one from which this adapter context was created.
+    ///
+    /// The new adapter context is created before the old one is destroyed, so if creation fails, this adapter
+    /// context is left unchanged. If destroying the old adapter context fails, the error is returned, but
+    /// this adapter context still refers to the new one.
+    pub fn reset<A: AdapterDescriptor<K>>(
+        &mut self,
+        config: A::Configuration<'a>,
+    ) -> Result<(), GvoxError> {
+        if self.adapter_type != TypeId::of::<A>() {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                "The adapter type did not match the adapter from which the adapter context was created.",
+            ));
+        }
+
+        let adapter = Adapter::<K, A> {
+            ctx: self.ctx.clone(),
+            ptr: self.adapter,
+            name: self.adapter_name.clone(),
+            data: PhantomData,
+        };
+
+        let mut substitute = A::blit_substitute(&self.ctx, &config)?.map(Box::new);
+        let config = Box::new(config);
+        let handler = adapter.create_handler(&config)?;
+        unsafe {
+            self.ctx.execute_inner(|ctx| {
+                let old = replace(
+                    &mut self.ptr,
+                    adapter.create_raw_adapter_context(ctx, &config, handler)?,
+                );
+                gvox_sys::gvox_destroy_adapter_context(old);
+                // The old configuration is only released once nothing can refer to it anymore.
+                self.input_size = A::input_size(&config);
+                self.blit_channels = A::blit_channels(&config);
+                swap(&mut self.substitute, &mut substitute);
+                self.input_substitute = Some(create_input_substitute::<K, A>);
+                self.config = Some(config);
+                ctx.get_error()
+            })
+        }
+    }
+}
+
+impl<'a> AdapterContext<'a, Input> {
+    /// Reads data from this input adapter context into the provided slice, starting at the provided position,
+    /// without performing a blit. Rust-handled inputs are read through their handlers, while the built-in
+    /// [`adapters::ByteBuffer`] and [`adapters::File`] inputs are read from their configurations. Returns an
+    /// error for other native inputs, which can only be read during blits.
+    pub fn read(&mut self, position: usize, data: &mut [u8]) -> Result<(), GvoxError> {
+        if let Some(substitute) = &mut self.substitute {
+            return substitute.read(position, data);
+        }
+
+        if let Some(bytes) = self.config::<adapters::ByteBuffer>() {
+            let source = position
+                .checked_add(data.len())
+                .and_then(|end| bytes.get(position..end))
+                .ok_or_else(|| {
+                    GvoxError::new(
+                        ErrorType::InputAdapter,
+                        format!(
+                            "Could not read {} bytes at position {position} of a {} byte buffer.",
+                            data.len(),
+                            bytes.len()
+                        ),
+                    )
+                })?;
+            data.copy_from_slice(source);
+            return Ok(());
+        }
+
+        if let Some(config) = self.config::<adapters::File>() {
+            return config.read_at(position, data);
+        }
+
+        self.ctx.execute_inner(|ctx| unsafe {
+            let read = ctx.input_readers.get(&self.adapter).ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    format!(
+                        "The native input adapter {:?} can only be read during blits.",
+                        self.adapter_name
+                    ),
+                )
+            })?;
+            read(
+                self.ptr,
+                position,
+                data.len(),
+                data.as_mut_ptr() as *mut c_void,
+            );
+            ctx.get_error()
+        })
+    }
+}
+
+impl<'a> AdapterContext<'a, Output> {
+    /// Writes the provided bytes to this output adapter context as the complete output of a single blit,
+    /// without performing one. Rust-handled outputs are written through their handlers, while the built-in
+    /// [`adapters::ByteBuffer`] and [`adapters::File`] outputs are written through their configurations.
+    /// Returns an error for other native outputs, which can only be written during blits.
+    pub fn write_all(&mut self, data: &[u8]) -> Result<(), GvoxError> {
+        if let Some(substitute) = &mut self.substitute {
+            return substitute.write_all(data);
+        }
+
+        if let Some(config) = self.config_mut::<adapters::ByteBuffer>() {
+            config.replace_output(data.into());
+            return Ok(());
+        }
+
+        if let Some(config) = self.config::<adapters::File>() {
+            return config.write_all(data);
+        }
+
+        self.check_writable()?;
+        self.ctx.execute_inner(|ctx| unsafe {
+            let writer = ctx.output_writers[&self.adapter];
+            (writer.blit_begin)(std::ptr::null_mut(), self.ptr, std::ptr::null(), 0);
+            if ctx.get_error().is_ok() {
+                (writer.reserve)(self.ptr, data.len());
+                (writer.write)(self.ptr, 0, data.len(), data.as_ptr() as *const c_void);
+            }
+            (writer.blit_end)(std::ptr::null_mut(), self.ptr);
+            ctx.get_error()
+        })
+    }
+
+    /// Returns an error if this output adapter context cannot be written with [`AdapterContext::write_all`].
+    fn check_writable(&self) -> Result<(), GvoxError> {
+        if let Some(substitute) = &self.substitute {
+            return substitute.check_writable();
+        }
+
+        let writable = self.config::<adapters::ByteBuffer>().is_some()
+            || self.config::<adapters::File>().is_some()
+            || self
+                .ctx
+                .execute_inner(|ctx| ctx.output_writers.contains_key(&self.adapter));
+        if !writable {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "The native output adapter {:?} can only be written during blits.",
+                    self.adapter_name
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<'a> AdapterContext<'a, Parse> {
+    /// Reads the palette and materials of the file provided by the input adapter context, if this adapter
+    /// context parses a format which declares them. Currently, only the [`adapters::MagicaVoxel`] and
+    /// [`adapters::MagicaVoxelModel`] adapters do so, and `None` is returned for every other adapter.
+    /// The input is read directly rather than blitted, so this may be called before or after blitting.
+    pub fn query_metadata(
+        &self,
+        input_ctx: &mut AdapterContext<'_, Input>,
+    ) -> Result<Option<inspect::ParseMetadata>, GvoxError> {
+        if self.adapter_type == TypeId::of::<adapters::MagicaVoxel>()
+            || self.adapter_type == TypeId::of::<adapters::MagicaVoxelModel>()
+        {
+            inspect::input_metadata(input_ctx)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a, K: AdapterKind> Drop for AdapterContext<'a, K> {
+    fn drop(&mut self) {
+        // The adapter context may be dropped on a different thread than the one using its
+        // context, so the context is locked while destruction callbacks run.
+        self.ctx.execute_inner(|_| unsafe {
+            gvox_sys::gvox_destroy_adapter_context(self.ptr);
+        });
+    }
+}
+
+impl<'a, K: AdapterKind> std::fmt::Debug for AdapterContext<'a, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AdapterContext")
+            .field("ctx", &self.ctx)
+            .field("ptr", &self.ptr)
+            .field("adapter", &self.adapter)
+            .field("adapter_name", &self.adapter_name)
+            .field("adapter_type", &self.adapter_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, K: AdapterKind> PartialEq for AdapterContext<'a, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ctx == other.ctx && self.ptr == other.ptr
     }
 }
 
-/// One specific instance of a configured adapter that can be used to perform blitting operations.
-#[derive(Debug, PartialEq, Eq)]
-pub struct AdapterContext<'a, K: AdapterKind> {
-    /// The associated context.
-    ctx: Context,
-    /// A reference to the underlying adapter context.
-    ptr: *mut gvox_sys::GvoxAdapterContext,
-    /// Marks that this type makes use of its generic parameters.
-    data: PhantomData<(&'a (), K)>,
+impl<'a, K: AdapterKind> Eq for AdapterContext<'a, K> {}
+
+// The native adapter context is only accessed while its context is locked, and blits require
+// exclusive access to it. Its handler and configuration are both `Send`, so it may be moved to
+// and used from another thread.
+unsafe impl<'a, K: AdapterKind> Send for AdapterContext<'a, K> {}
+
+/// Describes the purpose of a particular adapter.
+pub trait AdapterKind: 'static + private::Sealed {
+    /// The runtime identifier of this adapter kind.
+    const ID: AdapterKindId;
 }
 
-impl<'a, K: AdapterKind> AdapterContext<'a, K> {
-    /// The context to which this adapter context belongs.
-    pub fn context(&self) -> Context {
-        self.ctx.clone()
-    }
+/// Identifies an adapter kind at runtime, so that adapters may be described without generics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AdapterKindId {
+    /// Identifies [`Input`] adapters.
+    Input,
+    /// Identifies [`Output`] adapters.
+    Output,
+    /// Identifies [`Parse`] adapters.
+    Parse,
+    /// Identifies [`Serialize`] adapters.
+    Serialize,
+}
 
-    /// Retrieves a raw handle to the adapter context.
-    pub fn as_mut_ptr(&mut self) -> *mut gvox_sys::GvoxAdapterContext {
-        self.ptr
+impl std::fmt::Display for AdapterKindId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Input => "input",
+            Self::Output => "output",
+            Self::Parse => "parse",
+            Self::Serialize => "serialize",
+        })
     }
 }
 
-impl<'a, K: AdapterKind> Drop for AdapterContext<'a, K> {
-    fn drop(&mut self) {
-        unsafe {
-            gvox_sys::gvox_destroy_adapter_context(self.as_mut_ptr());
+impl AdapterKindId {
+    /// The type of error which describes a failure within an adapter of this kind.
+    fn error_type(&self) -> ErrorType {
+        match self {
+            Self::Input => ErrorType::InputAdapter,
+            Self::Output => ErrorType::OutputAdapter,
+            Self::Parse => ErrorType::ParseAdapter,
+            Self::Serialize => ErrorType::SerializeAdapter,
         }
     }
 }
 
-/// Describes the purpose of a particular adapter.
-pub trait AdapterKind: 'static + private::Sealed {}
-
 /// Marks types that read voxel input data.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Input;
 
-impl AdapterKind for Input {}
+impl AdapterKind for Input {
+    const ID: AdapterKindId = AdapterKindId::Input;
+}
 
 /// Marks types that write voxel output data.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Output;
 
-impl AdapterKind for Output {}
+impl AdapterKind for Output {
+    const ID: AdapterKindId = AdapterKindId::Output;
+}
 
 /// Marks types that decode voxel data from a provided input stream.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Parse;
 
-impl AdapterKind for Parse {}
+impl AdapterKind for Parse {
+    const ID: AdapterKindId = AdapterKindId::Parse;
+}
 
 /// Marks types that encode voxel data from a provided parser.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Serialize;
 
-impl AdapterKind for Serialize {}
+impl AdapterKind for Serialize {
+    const ID: AdapterKindId = AdapterKindId::Serialize;
+}
 
 /// Marks types that which have blit callbacks handled externally.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -621,12 +2753,50 @@ impl ExternalHandler {
 
 /// Describes the layout of an adapter and its configuration type.
 pub trait AdapterDescriptor<K: AdapterKind>: 'static {
-    /// The datastructure that this adapter accepts during context creation.
-    type Configuration<'a>;
+    /// The datastructure that this adapter accepts during context creation. Adapter contexts
+    /// may be moved between threads, so configurations must be [`Send`].
+    type Configuration<'a>: Send;
     /// The datastructure that stores user state and handles adapter callbacks.
     type Handler: ?Sized;
+
+    /// The number of bytes that an input adapter context created with the given configuration can read,
+    /// if it is known. Parse adapters may retrieve it during blits with [`ParseBlitContext::input_size`].
+    fn input_size(_: &Self::Configuration<'_>) -> Option<usize> {
+        None
+    }
+
+    /// The channels to which blits are restricted when a serialize adapter context created with the given
+    /// configuration is used, if any. Blits which do not request all of these channels fail instead.
+    fn blit_channels(_: &Self::Configuration<'_>) -> Option<ChannelFlags> {
+        None
+    }
+
+    /// Creates an adapter context which performs blits in place of one created with the given configuration,
+    /// if this adapter cannot handle the configuration itself. The substitute is created alongside the adapter
+    /// context, and is used whenever the adapter context is provided to a blit.
+    fn blit_substitute<'a>(
+        _: &Context,
+        _: &Self::Configuration<'a>,
+    ) -> Result<Option<AdapterContext<'a, K>>, GvoxError> {
+        Ok(None)
+    }
+
+    /// Creates an input adapter context which blits read in place of their input, if a parse adapter context created
+    /// with the given configuration cannot read the input as it is. The provided function fills a slice with the bytes
+    /// of the blit's input which begin at a position, like [`AdapterContext::read`], and may only be used before the
+    /// blit begins. Blits without an input are not substituted.
+    fn input_substitute(
+        _: &Context,
+        _: &Self::Configuration<'_>,
+        _: &mut InputReader<'_>,
+    ) -> Result<Option<AdapterContext<'static, Input>>, GvoxError> {
+        Ok(None)
+    }
 }
 
+/// Fills a slice with the bytes of an input which begin at a position, like [`AdapterContext::read`].
+pub type InputReader<'a> = dyn FnMut(usize, &mut [u8]) -> Result<(), GvoxError> + 'a;
+
 /// Represents an adapter which may be queried by name from a context.
 pub trait NamedAdapter: 'static {
     /// The name of this adapter.
@@ -638,7 +2808,49 @@ struct AdapterContextData {
     /// The context with which this data is associated.
     pub ctx: *mut gvox_sys::GvoxContext,
     /// The user data that is associated with the current context.
-    pub user_data: Option<Box<dyn Any>>,
+    pub user_data: Option<Box<dyn Any + Send>>,
+}
+
+/// Pushes an error to the context of the provided adapter context.
+///
+/// # Safety
+///
+/// The pointer must refer to a valid adapter context.
+unsafe fn push_adapter_error(adapter_ctx: *mut gvox_sys::GvoxAdapterContext, error: &GvoxError) {
+    let message = CString::new(error.message.as_str()).unwrap_or_default();
+    gvox_sys::gvox_adapter_push_error(adapter_ctx, error.error_type() as i32, message.as_ptr());
+}
+
+/// Runs the body of an adapter callback, so that a panic does not unwind into the native library.
+/// If the body panics, an error containing the panic message is pushed to the context of the
+/// provided adapter context, and `None` is returned.
+///
+/// # Safety
+///
+/// The pointer must refer to a valid adapter context.
+unsafe fn catch_adapter_panic<R>(
+    adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
+    kind: AdapterKindId,
+    f: impl FnOnce() -> R,
+) -> Option<R> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => Some(value),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            push_adapter_error(
+                adapter_ctx,
+                &GvoxError::new(
+                    kind.error_type(),
+                    format!("{kind:?} adapter panicked: {message}"),
+                ),
+            );
+            None
+        }
+    }
 }
 
 /// Provides the ability to access adapter context data.
@@ -657,31 +2869,52 @@ impl AdapterContextHolder {
         Self(ctx)
     }
 
-    /// Retrieves a raw pointer to the underlying context, or panics if no data was associated with the provided adapter.
-    pub fn context_mut_ptr(&mut self) -> *mut gvox_sys::GvoxContext {
-        self.get_context_data()
-            .expect("No data was associated with the given adapter context.")
-            .ctx
+    /// Retrieves a raw pointer to the underlying context, or returns an error if no data was associated with the provided adapter.
+    pub fn context_mut_ptr(&mut self) -> Result<*mut gvox_sys::GvoxContext, GvoxError> {
+        Ok(self.get_context_data()?.ctx)
+    }
+
+    /// Creates the blit context with which the adapter's handler is invoked. If no data was associated with
+    /// the provided adapter, an error is pushed to the underlying context instead, and `None` is returned.
+    pub fn blit_context<B: private::BlitContextType>(
+        &mut self,
+        blit_ctx: *mut gvox_sys::GvoxBlitContext,
+    ) -> Option<B> {
+        match self.context_mut_ptr() {
+            Ok(ctx) => Some(unsafe { B::new(ctx, blit_ctx, self.0) }),
+            Err(error) => {
+                self.push_error(error);
+                None
+            }
+        }
+    }
+
+    /// Retrieves the raw pointer to the underlying adapter context.
+    pub fn as_ptr(&self) -> *mut gvox_sys::GvoxAdapterContext {
+        self.0
     }
 
     /// Pushes a new error to the underlying context.
     pub fn push_error(&mut self, error: GvoxError) {
-        unsafe {
-            let message = CString::new(error.message.as_str()).unwrap_or_default();
-            gvox_sys::gvox_adapter_push_error(self.0, error.error_type() as i32, message.as_ptr());
-        }
+        unsafe { push_adapter_error(self.0, &error) }
     }
 
-    /// Applies an operation to the held user data object, or panics if the user data object type did not match.
+    /// Applies an operation to the held user data object. If there is no user data object, or its type
+    /// did not match, an error is pushed to the underlying context instead. Once the blit has been
+    /// cancelled, the operation is skipped, and a cancellation error is pushed.
     pub fn user_data_operation<H: 'static>(
         &mut self,
         f: impl FnOnce(&mut H) -> Result<(), GvoxError>,
     ) {
-        let mut result = Ok(());
-        if let Some(data) = self.get_user_data_holder() {
-            result = f(data
-                .downcast_mut::<H>()
-                .expect("Context user data was not of correct type."));
+        if BlitCancellation::is_cancelled() {
+            self.push_error(BlitCancellation::error());
+            return;
+        }
+
+        let mut result = self.apply_user_data(f);
+
+        if BlitCancellation::is_cancelled() {
+            result = Err(BlitCancellation::error());
         }
 
         if let Err(error) = result {
@@ -689,19 +2922,49 @@ impl AdapterContextHolder {
         }
     }
 
-    /// Retrieves a reference to holder for adapter user data.
-    pub fn get_user_data_holder(&mut self) -> &mut Option<Box<dyn Any>> {
-        &mut self
-            .get_context_data()
-            .expect("No data was associated with the given adapter context.")
-            .user_data
+    /// Applies an operation which releases resources to the held user data object, like
+    /// [`AdapterContextHolder::user_data_operation`], but even once the blit has been cancelled.
+    pub fn cleanup_operation<H: 'static>(
+        &mut self,
+        f: impl FnOnce(&mut H) -> Result<(), GvoxError>,
+    ) {
+        if let Err(error) = self.apply_user_data(f) {
+            self.push_error(error);
+        }
+    }
+
+    /// Applies an operation to the held user data object, if there is one.
+    fn apply_user_data<H: 'static>(
+        &mut self,
+        f: impl FnOnce(&mut H) -> Result<(), GvoxError>,
+    ) -> Result<(), GvoxError> {
+        self.get_user_data_holder().and_then(|data| match data {
+            Some(data) => data.downcast_mut::<H>().map(f).unwrap_or_else(|| {
+                Err(GvoxError::new(
+                    ErrorType::Unknown,
+                    "Context user data was not of correct type.",
+                ))
+            }),
+            None => Ok(()),
+        })
+    }
+
+    /// Retrieves a reference to holder for adapter user data, or returns an error if no data was associated with the provided adapter.
+    pub fn get_user_data_holder(&mut self) -> Result<&mut Option<Box<dyn Any + Send>>, GvoxError> {
+        Ok(&mut self.get_context_data()?.user_data)
     }
 
-    /// Retrieves a reference to the context's data, if it is set.
-    fn get_context_data(&mut self) -> Option<&mut AdapterContextData> {
+    /// Retrieves a reference to the context's data, or returns an error if it is not set. Data is only
+    /// set for adapter contexts which were created by Rust adapters.
+    fn get_context_data(&mut self) -> Result<&mut AdapterContextData, GvoxError> {
         unsafe {
             let res = gvox_sys::gvox_adapter_get_user_pointer(self.0) as *mut AdapterContextData;
-            (!res.is_null()).then(|| &mut *res)
+            (!res.is_null()).then(|| &mut *res).ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::Unknown,
+                    "No data was associated with the given adapter context.",
+                )
+            })
         }
     }
 
@@ -736,10 +2999,48 @@ impl AdapterContextHolder {
             user_data: None,
         }));
 
-        match D::Handler::create(&*(config as *const D::Configuration<'_>)) {
-            Ok(value) => *ctx.get_user_data_holder() = Some(Box::new(value)),
-            Err(error) => ctx.push_error(error),
-        };
+        catch_adapter_panic(ptr, K::ID, || {
+            let prepared = PREPARED_HANDLER.with(|x| {
+                let mut x = x.borrow_mut();
+                match x.take() {
+                    Some(handler) if handler.is::<D::Handler>() => Some(handler),
+                    other => {
+                        *x = other;
+                        None
+                    }
+                }
+            });
+            let result = match prepared {
+                Some(handler) => Ok(handler),
+                None => D::Handler::create(&*(config as *const D::Configuration<'_>))
+                    .map(|value| Box::new(value) as Box<dyn Any + Send>),
+            };
+
+            let result = result.and_then(|value| {
+                *ctx.get_user_data_holder()? = Some(value);
+                Ok(())
+            });
+
+            if let Err(error) = result {
+                ctx.push_error(error);
+            }
+        });
+    }
+
+    /// Creates the handler of an adapter context of the given adapter type, before its native adapter context is created.
+    ///
+    /// # Safety
+    ///
+    /// Config must point to a valid configuration object.
+    unsafe fn create_handler<K: private::AdapterKindAssociation, D: AdapterDescriptor<K>>(
+        ctx: &Context,
+        config: *const c_void,
+    ) -> Result<Box<dyn Any + Send>, GvoxError>
+    where
+        D::Handler: BaseAdapterHandler<K, D>,
+    {
+        let config = &*(config as *const D::Configuration<'_>);
+        Ok(Box::new(D::Handler::create_with_context(ctx, config)?))
     }
 
     /// Invokes the adapter context deletion function for the given adapter type.
@@ -754,17 +3055,22 @@ impl AdapterContextHolder {
         D::Handler: BaseAdapterHandler<K, D>,
     {
         let mut ctx = Self::from_raw(ctx);
-        let data = take(ctx.get_user_data_holder());
-
-        if let Some(value) = data {
-            if let Err(error) = value
-                .downcast::<D::Handler>()
-                .expect("Context user data was not of correct type.")
-                .destroy()
-            {
+        let data = ctx.get_user_data_holder().map(take);
+
+        catch_adapter_panic(ctx.as_ptr(), K::ID, || {
+            let result = data.and_then(|data| match data.map(|x| x.downcast::<D::Handler>()) {
+                Some(Ok(value)) => value.destroy(),
+                Some(Err(_)) => Err(GvoxError::new(
+                    ErrorType::Unknown,
+                    "Context user data was not of correct type.",
+                )),
+                None => Ok(()),
+            });
+
+            if let Err(error) = result {
                 ctx.push_error(error);
             }
-        }
+        });
 
         ctx.set_context_data(None);
     }
@@ -782,21 +3088,28 @@ impl AdapterContextHolder {
     ) where
         D::Handler: BaseAdapterHandler<K, D>,
     {
-        use private::*;
+        catch_adapter_panic(ctx, K::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.blit_context::<K::BlitContext>(blit_ctx) else {
+                return;
+            };
 
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = K::BlitContext::new(ctx.context_mut_ptr(), blit_ctx);
+            let mut_range;
+            let opt_range = if range.is_null() {
+                None
+            } else {
+                mut_range = (*range).into();
+                Some(&mut_range)
+            };
 
-        let mut_range;
-        let opt_range = if range.is_null() {
-            None
-        } else {
-            mut_range = (*range).into();
-            Some(&mut_range)
-        };
+            // Blits begin with the parsable range when none was requested, which native parse adapters report no other way.
+            if let Some(range) = opt_range {
+                BlitProgress::parsable_range(range);
+            }
 
-        ctx.user_data_operation::<D::Handler>(|h| {
-            h.blit_begin(&blit_ctx, opt_range, channel_flags.into())
+            ctx.user_data_operation::<D::Handler>(|h| {
+                h.blit_begin(&blit_ctx, opt_range, channel_flags.into())
+            });
         });
     }
 
@@ -811,15 +3124,109 @@ impl AdapterContextHolder {
     ) where
         D::Handler: BaseAdapterHandler<K, D>,
     {
-        use private::*;
+        catch_adapter_panic(ctx, K::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.blit_context::<K::BlitContext>(blit_ctx) else {
+                return;
+            };
 
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = K::BlitContext::new(ctx.context_mut_ptr(), blit_ctx);
+            ctx.cleanup_operation::<D::Handler>(|h| h.blit_end(&blit_ctx));
+        });
+    }
+}
+
+thread_local! {
+    /// The handler which was created for the adapter context that the current thread is creating, until
+    /// the adapter's creation function takes it.
+    static PREPARED_HANDLER: RefCell<Option<Box<dyn Any + Send>>> = const { RefCell::new(None) };
+}
+
+/// The function with which the handler of an adapter context is created, before its native adapter context.
+type HandlerCreateFn = unsafe fn(&Context, *const c_void) -> Result<Box<dyn Any + Send>, GvoxError>;
+
+/// The function with which the input substitute of an adapter context is created from its configuration.
+type InputSubstituteFn = unsafe fn(
+    &Context,
+    *const c_void,
+    &mut InputReader<'_>,
+) -> Result<Option<AdapterContext<'static, Input>>, GvoxError>;
+
+/// Creates the input substitute of an adapter context with [`AdapterDescriptor::input_substitute`].
+///
+/// # Safety
+///
+/// The configuration must point to a valid configuration of the adapter.
+unsafe fn create_input_substitute<K: AdapterKind, A: AdapterDescriptor<K>>(
+    ctx: &Context,
+    config: *const c_void,
+    read: &mut InputReader<'_>,
+) -> Result<Option<AdapterContext<'static, Input>>, GvoxError> {
+    A::input_substitute(ctx, &*(config as *const A::Configuration<'_>), read)
+}
+
+/// Prepares the input of a blit for a parse adapter context, regardless of the lifetime of its configuration.
+trait InputSubstitution: std::fmt::Debug {
+    /// Creates the input adapter context which the blit reads in place of its input, if any.
+    fn input_substitute(
+        &self,
+        read: &mut InputReader<'_>,
+    ) -> Result<Option<AdapterContext<'static, Input>>, GvoxError>;
+}
+
+impl InputSubstitution for AdapterContext<'_, Parse> {
+    fn input_substitute(
+        &self,
+        read: &mut InputReader<'_>,
+    ) -> Result<Option<AdapterContext<'static, Input>>, GvoxError> {
+        match (self.input_substitute, self.config.as_deref()) {
+            (Some(create), Some(config)) => unsafe {
+                create(
+                    &self.ctx,
+                    config as *const (dyn Send + '_) as *const c_void,
+                    read,
+                )
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Reads from an input adapter context outside of blits, regardless of the lifetime of its configuration.
+trait DirectInput: std::fmt::Debug {
+    /// Fills the provided slice with the bytes of the input which begin at the provided position.
+    fn read(&mut self, position: usize, data: &mut [u8]) -> Result<(), GvoxError>;
+}
+
+impl DirectInput for AdapterContext<'_, Input> {
+    fn read(&mut self, position: usize, data: &mut [u8]) -> Result<(), GvoxError> {
+        AdapterContext::read(self, position, data)
+    }
+}
+
+/// Measures the output of an output adapter context after a blit, regardless of the lifetime of its configuration.
+trait MeasuredOutput: std::fmt::Debug {
+    /// The number of bytes which the output holds, if it is a native output whose size can be measured.
+    fn output_size(&self) -> Option<u64>;
+}
+
+impl MeasuredOutput for AdapterContext<'_, Output> {
+    fn output_size(&self) -> Option<u64> {
+        if let Some(config) = self.config::<adapters::ByteBuffer>() {
+            return Some(config.written().map_or(0, |x| x.len() as u64));
+        }
 
-        ctx.user_data_operation::<D::Handler>(|h| h.blit_end(&blit_ctx));
+        // File outputs which are not truncated are written by a Rust substitute, whose writes are counted.
+        self.config::<adapters::File>()
+            .filter(|_| self.substitute.is_none())
+            .and_then(|config| std::fs::metadata(config.path()).ok())
+            .map(|x| x.len())
     }
 }
 
+/// The native function with which an input adapter context is read.
+type InputReadFn =
+    unsafe extern "C" fn(*mut gvox_sys::GvoxAdapterContext, usize, usize, *mut c_void);
+
 /// Provides the ability to access input adapter context data.
 struct InputContextHolder(AdapterContextHolder);
 
@@ -849,15 +3256,22 @@ impl InputContextHolder {
     ) where
         D::Handler: InputAdapterHandler<D>,
     {
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = InputBlitContext {};
+        catch_adapter_panic(ctx, Input::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<InputBlitContext>(std::ptr::null_mut())
+            else {
+                return;
+            };
 
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            h.read(
-                &blit_ctx,
-                position,
-                from_raw_parts_mut(data as *mut u8, size),
-            )
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                h.read(
+                    &blit_ctx,
+                    position,
+                    from_raw_parts_mut(data as *mut u8, size),
+                )?;
+                BlitStatsRecorder::read(BlitSide::Input, size);
+                Ok(())
+            });
         });
     }
 }
@@ -891,11 +3305,20 @@ impl OutputContextHolder {
     ) where
         D::Handler: OutputAdapterHandler<D>,
     {
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = OutputBlitContext {};
+        catch_adapter_panic(ctx, Output::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx
+                .0
+                .blit_context::<OutputBlitContext>(std::ptr::null_mut())
+            else {
+                return;
+            };
 
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            h.write(&blit_ctx, position, from_raw_parts(data as *const u8, size))
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                h.write(&blit_ctx, position, from_raw_parts(data as *const u8, size))?;
+                BlitStatsRecorder::written(BlitSide::Output, size);
+                Ok(())
+            });
         });
     }
 
@@ -910,11 +3333,18 @@ impl OutputContextHolder {
     ) where
         D::Handler: OutputAdapterHandler<D>,
     {
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = OutputBlitContext {};
+        catch_adapter_panic(ctx, Output::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx
+                .0
+                .blit_context::<OutputBlitContext>(std::ptr::null_mut())
+            else {
+                return;
+            };
 
-        ctx.0
-            .user_data_operation::<D::Handler>(|h| h.reserve(&blit_ctx, size));
+            ctx.0
+                .user_data_operation::<D::Handler>(|h| h.reserve(&blit_ctx, size));
+        });
     }
 }
 
@@ -933,13 +3363,14 @@ impl ParseContextHolder {
         Self(AdapterContextHolder::from_raw(ctx))
     }
 
-    /// Invokes the adapter details querying function for the given adapter type.
+    /// Invokes the adapter details querying function for the given adapter type. No adapter context
+    /// is available to report a panic to, so the default details are used if the query panics.
     unsafe extern "C" fn query_details<D: AdapterDescriptor<Parse>>(
     ) -> gvox_sys::GvoxParseAdapterDetails
     where
         D::Handler: ParseAdapterHandler<D>,
     {
-        let details = D::Handler::query_details();
+        let details = catch_unwind(D::Handler::query_details).unwrap_or_default();
         gvox_sys::GvoxParseAdapterDetails {
             preferred_blit_mode: details.preferred_blit_mode as i32,
         }
@@ -958,16 +3389,20 @@ impl ParseContextHolder {
     where
         D::Handler: ParseAdapterHandler<D>,
     {
-        use private::*;
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = ParseBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
-
         let mut res = RegionRange::default();
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            res = h.query_parsable_range(&blit_ctx);
-            Ok(())
+        catch_adapter_panic(ctx, Parse::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<ParseBlitContext>(blit_ctx) else {
+                return;
+            };
+
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                res = h.query_parsable_range(&blit_ctx);
+                Ok(())
+            });
         });
 
+        BlitProgress::parsable_range(&res);
         res.into()
     }
 
@@ -986,17 +3421,19 @@ impl ParseContextHolder {
     where
         D::Handler: ParseAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = ParseBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
-
         let mut res = 0;
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            res = h
-                .query_region_flags(&blit_ctx, &(*range).into(), channel_flags.into())?
-                .bits();
-            Ok(())
+        catch_adapter_panic(ctx, Parse::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<ParseBlitContext>(blit_ctx) else {
+                return;
+            };
+
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                res = h
+                    .query_region_flags(&blit_ctx, &(*range).into(), channel_flags.into())?
+                    .bits();
+                Ok(())
+            });
         });
 
         res
@@ -1017,22 +3454,28 @@ impl ParseContextHolder {
     where
         D::Handler: ParseAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = ParseBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
-
         let mut res = gvox_sys::GvoxRegion {
             range: RegionRange::default().into(),
             channels: 0,
             flags: 0,
             data: std::ptr::null_mut(),
         };
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            res = h
-                .load_region(&blit_ctx, &(*range).into(), channel_flags.into())?
-                .into();
-            Ok(())
+        catch_adapter_panic(ctx, Parse::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<ParseBlitContext>(blit_ctx) else {
+                return;
+            };
+
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                let region = h.load_region(&blit_ctx, &(*range).into(), channel_flags.into())?;
+                if BlitCancellation::is_cancelled() {
+                    // The region is not returned once the load reports the cancellation, so it is unloaded here.
+                    return h.unload_region(&blit_ctx, region);
+                }
+                res = region.into();
+                observe_region(BlitSide::Parse, &(*range).into());
+                Ok(())
+            });
         });
         res
     }
@@ -1050,14 +3493,17 @@ impl ParseContextHolder {
     ) where
         D::Handler: ParseAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = ParseBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
+        catch_adapter_panic(ctx, Parse::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<ParseBlitContext>(blit_ctx) else {
+                return;
+            };
 
-        ctx.0
-            .user_data_operation::<D::Handler>(|h| h.unload_region(&blit_ctx, transmute(*region)));
-        (*region).range = RegionRange::default().into();
+            ctx.0.cleanup_operation::<D::Handler>(|h| {
+                h.unload_region(&blit_ctx, transmute(*region))
+            });
+            (*region).range = RegionRange::default().into();
+        });
     }
 
     /// Invokes the adapter context region sampling function for the given adapter type.
@@ -1076,25 +3522,28 @@ impl ParseContextHolder {
     where
         D::Handler: ParseAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = ParseBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
-
         let mut res = Sample {
             data: 0,
             is_present: false,
         };
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            res = h.sample_region(
-                &blit_ctx,
-                &*transmute::<_, *const Region<<D::Handler as ParseAdapterHandler<D>>::RegionData>>(
-                    region,
-                ),
-                &(*offset).into(),
-                ChannelId::try_from(channel_id)?,
-            )?;
-            Ok(())
+        catch_adapter_panic(ctx, Parse::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<ParseBlitContext>(blit_ctx) else {
+                return;
+            };
+
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                res = h.sample_region(
+                    &blit_ctx,
+                    &*transmute::<
+                        _,
+                        *const Region<<D::Handler as ParseAdapterHandler<D>>::RegionData>,
+                    >(region),
+                    &(*offset).into(),
+                    ChannelId::try_from(channel_id)?,
+                )?;
+                Ok(())
+            });
         });
         gvox_sys::GvoxSample {
             data: res.data,
@@ -1110,13 +3559,15 @@ impl ParseContextHolder {
     ) where
         D::Handler: ParseAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = ParseBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
+        catch_adapter_panic(ctx, Parse::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<ParseBlitContext>(blit_ctx) else {
+                return;
+            };
 
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            h.parse_region(&blit_ctx, &(*range).into(), channel_flags.into())
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                h.parse_region(&blit_ctx, &(*range).into(), channel_flags.into())
+            });
         });
     }
 }
@@ -1150,17 +3601,19 @@ impl SerializeContextHolder {
     ) where
         D::Handler: SerializeAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = SerializeBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
+        catch_adapter_panic(ctx, Serialize::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<SerializeBlitContext>(blit_ctx) else {
+                return;
+            };
 
-        ctx.0.user_data_operation::<D::Handler>(|h| {
-            h.serialize_region(
-                &blit_ctx,
-                &(*range).into(),
-                ChannelFlags::from(channel_flags),
-            )
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                h.serialize_region(
+                    &blit_ctx,
+                    &(*range).into(),
+                    ChannelFlags::from(channel_flags),
+                )
+            });
         });
     }
 
@@ -1177,28 +3630,67 @@ impl SerializeContextHolder {
     ) where
         D::Handler: SerializeAdapterHandler<D>,
     {
-        use private::*;
-
-        let mut ctx = Self::from_raw(ctx);
-        let blit_ctx = SerializeBlitContext::new(ctx.0.context_mut_ptr(), blit_ctx);
+        catch_adapter_panic(ctx, Serialize::ID, || {
+            let mut ctx = Self::from_raw(ctx);
+            let Some(blit_ctx) = ctx.0.blit_context::<SerializeBlitContext>(blit_ctx) else {
+                return;
+            };
 
-        let region_ref = RegionRef {
-            blit_ctx: &blit_ctx,
-            region: *region,
-        };
+            let region_ref = RegionRef {
+                blit_ctx: &blit_ctx,
+                region: *region,
+            };
 
-        ctx.0
-            .user_data_operation::<D::Handler>(|h| h.receive_region(&blit_ctx, &region_ref));
+            ctx.0.user_data_operation::<D::Handler>(|h| {
+                h.receive_region(&blit_ctx, &region_ref)?;
+                observe_region(BlitSide::Serialize, &(*region).range.into());
+                Ok(())
+            });
 
-        std::mem::forget(region_ref);
+            std::mem::forget(region_ref);
+        });
     }
 }
 
 /// Provides the ability for an input adapter to interact with other adapters during blit operations.
-pub struct InputBlitContext {}
+pub struct InputBlitContext {
+    /// A pointer to the adapter context that is being invoked.
+    adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
+}
+
+impl InputBlitContext {
+    /// Reports an error on behalf of the adapter, without returning from the current callback. The blit
+    /// fails with the error, although the next operation of this blit context may return it instead.
+    pub fn push_error(&self, error: GvoxError) {
+        unsafe { push_adapter_error(self.adapter_ctx, &error) }
+    }
+
+    /// Reports a warning on behalf of the adapter. Warnings do not cause the blit to fail, and may
+    /// be retrieved afterward with [`Context::take_warnings`].
+    pub fn push_warning(&self, warning: GvoxError) {
+        BlitWarnings::push(warning);
+    }
+}
 
 /// Provides the ability for an output adapter to interact with other adapters during blit operations.
-pub struct OutputBlitContext {}
+pub struct OutputBlitContext {
+    /// A pointer to the adapter context that is being invoked.
+    adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
+}
+
+impl OutputBlitContext {
+    /// Reports an error on behalf of the adapter, without returning from the current callback. The blit
+    /// fails with the error, although the next operation of this blit context may return it instead.
+    pub fn push_error(&self, error: GvoxError) {
+        unsafe { push_adapter_error(self.adapter_ctx, &error) }
+    }
+
+    /// Reports a warning on behalf of the adapter. Warnings do not cause the blit to fail, and may
+    /// be retrieved afterward with [`Context::take_warnings`].
+    pub fn push_warning(&self, warning: GvoxError) {
+        BlitWarnings::push(warning);
+    }
+}
 
 /// Provides the ability for a parse adapter to interact with other adapters during blit operations.
 pub struct ParseBlitContext {
@@ -1206,6 +3698,8 @@ pub struct ParseBlitContext {
     blit_ctx: *mut gvox_sys::GvoxBlitContext,
     /// A pointer to the underlying context.
     ctx: *mut gvox_sys::GvoxContext,
+    /// A pointer to the adapter context that is being invoked.
+    adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
 }
 
 impl ParseBlitContext {
@@ -1218,8 +3712,74 @@ impl ParseBlitContext {
                 data.len(),
                 data.as_mut_ptr() as *mut c_void,
             );
-            ContextInner::get_error_from_raw_ptr(self.ctx)
+            ContextInner::get_error_from_raw_ptr(self.ctx)?;
+        }
+
+        BlitStatsRecorder::read(BlitSide::Parse, data.len());
+        Ok(())
+    }
+
+    /// The number of bytes that the input adapter can read, if it is known. The size is known for the
+    /// built-in byte buffer and file inputs, and for other inputs whose [`AdapterDescriptor::input_size`]
+    /// reports it. Reads past the end of the input are still handled by the input adapter as usual.
+    pub fn input_size(&self) -> Option<usize> {
+        BlitInputSize::get()
+    }
+
+    /// Reads the provided number of bytes from the input adapter, starting at the provided source position.
+    pub fn read_vec(&self, position: usize, len: usize) -> Result<Vec<u8>, GvoxError> {
+        let mut data = vec![0; len];
+        self.read_exact(position, &mut data)?;
+        Ok(data)
+    }
+
+    /// Reads an array of bytes from the input adapter, starting at the provided source position.
+    pub fn read_exact_array<const N: usize>(&self, position: usize) -> Result<[u8; N], GvoxError> {
+        let mut data = [0; N];
+        self.read_exact(position, &mut data)?;
+        Ok(data)
+    }
+
+    /// Reads a little-endian unsigned integer from the input adapter at the provided source position.
+    pub fn read_u32_le(&self, position: usize) -> Result<u32, GvoxError> {
+        self.read_exact_array(position).map(u32::from_le_bytes)
+    }
+
+    /// Reads a little-endian signed integer from the input adapter at the provided source position.
+    pub fn read_i32_le(&self, position: usize) -> Result<i32, GvoxError> {
+        self.read_exact_array(position).map(i32::from_le_bytes)
+    }
+
+    /// Reads a little-endian floating-point number from the input adapter at the provided source position.
+    pub fn read_f32_le(&self, position: usize) -> Result<f32, GvoxError> {
+        self.read_exact_array(position).map(f32::from_le_bytes)
+    }
+
+    /// Reads a plain-old-data value from the input adapter at the provided source position, with the byte order of the target.
+    #[cfg(feature = "bytemuck")]
+    pub fn read_pod<T: bytemuck::Pod>(&self, position: usize) -> Result<T, GvoxError> {
+        let mut value = T::zeroed();
+        self.read_exact(position, bytemuck::bytes_of_mut(&mut value))?;
+        Ok(value)
+    }
+
+    /// Fills the provided slice from the input adapter, starting at the provided source position. If the size
+    /// of the input is known, reads which extend past its end fail without invoking the input adapter.
+    fn read_exact(&self, position: usize, data: &mut [u8]) -> Result<(), GvoxError> {
+        if let Some(size) = self.input_size() {
+            let end = position.checked_add(data.len());
+            if end.is_none_or(|end| end > size) {
+                return Err(GvoxError::new(
+                    ErrorType::InputAdapter,
+                    format!(
+                        "Could not read {} bytes at position {position} of an input of {size} bytes.",
+                        data.len()
+                    ),
+                ));
+            }
         }
+
+        self.input_read(position, data)
     }
 
     /// Supplies a parsable region directly to the serialize adapter, meant to only be called from parse_region
@@ -1229,8 +3789,23 @@ impl ParseBlitContext {
                 self.blit_ctx,
                 region as *const Region<T> as *const gvox_sys::GvoxRegion,
             );
-            ContextInner::get_error_from_raw_ptr(self.ctx)
+            ContextInner::get_error_from_raw_ptr(self.ctx)?;
         }
+
+        observe_region(BlitSide::Parse, &region.range);
+        Ok(())
+    }
+
+    /// Reports an error on behalf of the adapter, without returning from the current callback. The blit
+    /// fails with the error, although the next operation of this blit context may return it instead.
+    pub fn push_error(&self, error: GvoxError) {
+        unsafe { push_adapter_error(self.adapter_ctx, &error) }
+    }
+
+    /// Reports a warning on behalf of the adapter. Warnings do not cause the blit to fail, and may
+    /// be retrieved afterward with [`Context::take_warnings`].
+    pub fn push_warning(&self, warning: GvoxError) {
+        BlitWarnings::push(warning);
     }
 }
 
@@ -1240,6 +3815,8 @@ pub struct SerializeBlitContext {
     blit_ctx: *mut gvox_sys::GvoxBlitContext,
     /// A pointer to the underlying context.
     ctx: *mut gvox_sys::GvoxContext,
+    /// A pointer to the adapter context that is being invoked.
+    adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
 }
 
 impl SerializeBlitContext {
@@ -1272,7 +3849,9 @@ impl SerializeBlitContext {
                 &(*range).into(),
                 channel_flags.into(),
             );
-            ContextInner::get_error_from_raw_ptr(self.ctx).map(|()| RegionRef {
+            ContextInner::get_error_from_raw_ptr(self.ctx)?;
+            observe_region(BlitSide::Serialize, range);
+            Ok(RegionRef {
                 blit_ctx: self,
                 region,
             })
@@ -1288,8 +3867,32 @@ impl SerializeBlitContext {
                 data.len(),
                 data.as_ptr() as *const c_void,
             );
-            ContextInner::get_error_from_raw_ptr(self.ctx)
+            ContextInner::get_error_from_raw_ptr(self.ctx)?;
         }
+
+        BlitStatsRecorder::written(BlitSide::Serialize, data.len());
+        OutputPosition::record(position, data.len());
+        Ok(())
+    }
+
+    /// Writes the given slice of bytes to the output adapter, just past the last byte written so far
+    /// during this blit, and returns the position at which they were written.
+    pub fn output_append(&self, data: &[u8]) -> Result<usize, GvoxError> {
+        let position = self.max_position();
+        self.output_write(position, data)?;
+        Ok(position)
+    }
+
+    /// The total number of bytes written to the output adapter so far during this blit,
+    /// including any which overwrote previously written bytes.
+    pub fn bytes_written(&self) -> usize {
+        OutputPosition::get().bytes_written
+    }
+
+    /// The position one past the last byte written to the output adapter so far during this blit.
+    /// Space that was only reserved is not included.
+    pub fn max_position(&self) -> usize {
+        OutputPosition::get().max_position
     }
 
     /// Hints that the output adapter should make room for at least the given number of bytes.
@@ -1299,16 +3902,42 @@ impl SerializeBlitContext {
             ContextInner::get_error_from_raw_ptr(self.ctx)
         }
     }
+
+    /// Reports an error on behalf of the adapter, without returning from the current callback. The blit
+    /// fails with the error, although the next operation of this blit context may return it instead.
+    pub fn push_error(&self, error: GvoxError) {
+        unsafe { push_adapter_error(self.adapter_ctx, &error) }
+    }
+
+    /// Reports a warning on behalf of the adapter. Warnings do not cause the blit to fail, and may
+    /// be retrieved afterward with [`Context::take_warnings`].
+    pub fn push_warning(&self, warning: GvoxError) {
+        BlitWarnings::push(warning);
+    }
 }
 
 /// Represents the user data type that handles adapter context operations.
 pub trait BaseAdapterHandler<
     K: AdapterKind + private::AdapterKindAssociation,
     D: AdapterDescriptor<K, Handler = Self>,
->: 'static + Sized
+>: 'static + Sized + Send
 {
     /// Creates a new adapter context handler with the supplied configuration.
     fn create(config: &D::Configuration<'_>) -> Result<Self, GvoxError>;
+    /// Creates a new adapter context handler with the supplied configuration, for an adapter context
+    /// of the provided context. The context may be used to look up other adapters, or to create
+    /// adapter contexts of its own. By default, this delegates to [`BaseAdapterHandler::create`].
+    ///
+    /// The handler is created before its native adapter context, while the context is not locked. The context
+    /// must not be used from the handler's other callbacks, which run while an operation holds its lock.
+    /// Adapter contexts which are created directly through `gvox_sys` are created with [`BaseAdapterHandler::create`] instead.
+    fn create_with_context(
+        ctx: &Context,
+        config: &D::Configuration<'_>,
+    ) -> Result<Self, GvoxError> {
+        let _ = ctx;
+        Self::create(config)
+    }
     /// Destroys the provided adapter context handler.
     fn destroy(self) -> Result<(), GvoxError>;
 
@@ -1410,6 +4039,11 @@ pub trait SerializeAdapterHandler<D: AdapterDescriptor<Serialize, Handler = Self
     /// The loaded data associated with a given region of voxels.
     type RegionData;
 
+    /// Provides the adapter-wide information, such as whether the adapter prefers to blit as parse-driven or as serialize-driven.
+    fn query_details() -> SerializeAdapterDetails {
+        SerializeAdapterDetails::default()
+    }
+
     /// Serializes the provided range of voxels to the output stream.
     fn serialize_region(
         &mut self,
@@ -1418,12 +4052,20 @@ pub trait SerializeAdapterHandler<D: AdapterDescriptor<Serialize, Handler = Self
         channel_flags: ChannelFlags,
     ) -> Result<(), GvoxError>;
 
-    /// Serializes the provided range of voxels to the output stream.
+    /// Serializes one of the regions of voxels which the parse adapter emits during a parse-driven blit, which may
+    /// deliver the voxels of a single blit in any number of regions. By default, the range of the region is loaded
+    /// and serialized with [`SerializeAdapterHandler::serialize_region`], so adapters which only implement the
+    /// serialize-driven path may still be blitted parse-driven. That is called once for each region, so adapters
+    /// which write their whole output at once should collect the regions, and write the output when the blit ends.
     fn receive_region(
         &mut self,
         blit_ctx: &SerializeBlitContext,
         region: &RegionRef<'_>,
-    ) -> Result<(), GvoxError>;
+    ) -> Result<(), GvoxError> {
+        // The range stays loaded while it is serialized, so that the loads of serialize_region are served from it.
+        let _loaded = blit_ctx.load_region_range(&region.range(), region.channels())?;
+        self.serialize_region(blit_ctx, &region.range(), region.channels())
+    }
 }
 
 /// Stores data about the loaded state of a provided region of voxels.
@@ -1451,6 +4093,47 @@ impl<T> Region<T> {
             data,
         }
     }
+
+    /// Creates a new region for the provided range and channels, which has the same value
+    /// throughout and is therefore flagged as [`RegionFlags::UNIFORM`].
+    pub fn uniform(range: RegionRange, channels: impl Into<ChannelFlags>, data: T) -> Self {
+        Self::new(range, channels.into(), RegionFlags::UNIFORM, data)
+    }
+
+    /// The user data associated with this region.
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    /// The user data associated with this region, which may be modified.
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    /// Consumes this region, returning its user data.
+    pub fn into_data(self) -> T {
+        *self.data
+    }
+
+    /// Replaces the user data of this region with the result of the provided function, keeping
+    /// its range, channels, and flags. This allows wrapping adapters to re-tag the regions
+    /// of the adapters that they wrap.
+    pub fn map_data<U>(self, f: impl FnOnce(T) -> U) -> Region<U> {
+        Region::new(self.range, self.channels, self.flags, f(*self.data))
+    }
+}
+
+impl Region<()> {
+    /// Creates a builder for a region with an empty range, no channels, no flags, and no user data.
+    /// User data may be attached with [`RegionBuilder::data`].
+    pub fn builder() -> RegionBuilder<()> {
+        RegionBuilder {
+            range: RegionRange::default(),
+            channels: ChannelFlags::empty(),
+            flags: RegionFlags::empty(),
+            data: (),
+        }
+    }
 }
 
 impl<T> Deref for Region<T> {
@@ -1467,6 +4150,61 @@ impl<T> DerefMut for Region<T> {
     }
 }
 
+/// Describes a region step-by-step, before it is created with [`RegionBuilder::build`].
+/// Region builders are created with [`Region::builder`].
+#[derive(Clone, Debug)]
+pub struct RegionBuilder<T> {
+    /// The range of loaded voxels.
+    range: RegionRange,
+    /// The channels that have been loaded.
+    channels: ChannelFlags,
+    /// The flags of the region.
+    flags: RegionFlags,
+    /// The user data associated with the region.
+    data: T,
+}
+
+impl<T> RegionBuilder<T> {
+    /// Sets the range of loaded voxels.
+    pub fn range(mut self, range: RegionRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Sets the channels that have been loaded.
+    pub fn channels(mut self, channels: impl Into<ChannelFlags>) -> Self {
+        self.channels = channels.into();
+        self
+    }
+
+    /// Sets the flags of the region, replacing any that were set previously.
+    pub fn flags(mut self, flags: RegionFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Marks the region as having the same value throughout.
+    pub fn uniform(mut self) -> Self {
+        self.flags |= RegionFlags::UNIFORM;
+        self
+    }
+
+    /// Sets the user data associated with the region.
+    pub fn data<U>(self, data: U) -> RegionBuilder<U> {
+        RegionBuilder {
+            range: self.range,
+            channels: self.channels,
+            flags: self.flags,
+            data,
+        }
+    }
+
+    /// Creates the described region.
+    pub fn build(self) -> Region<T> {
+        Region::new(self.range, self.channels, self.flags, self.data)
+    }
+}
+
 impl<T> From<Region<T>> for gvox_sys::GvoxRegion {
     fn from(value: Region<T>) -> Self {
         unsafe { transmute(value) }
@@ -1512,6 +4250,224 @@ impl<'a> RegionRef<'a> {
             })
         }
     }
+
+    /// Samples the channel value of the voxel at each of the provided positions into the corresponding
+    /// element of the output. If the region is uniform, only a single voxel is sampled. Returns an error
+    /// if the number of positions and outputs differ.
+    pub fn sample_many(
+        &self,
+        offsets: &[Offset3D],
+        channel_id: ChannelId,
+        out: &mut [Sample],
+    ) -> Result<(), GvoxError> {
+        if offsets.len() != out.len() {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Could not sample {} positions into {} outputs.",
+                    offsets.len(),
+                    out.len()
+                ),
+            ));
+        }
+
+        if self.flags().contains(RegionFlags::UNIFORM) {
+            if let Some(offset) = offsets.first() {
+                out.fill(self.sample(offset, channel_id)?);
+            }
+        } else {
+            for (offset, sample) in offsets.iter().zip(out) {
+                *sample = self.sample(offset, channel_id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Samples the channel value of every voxel within the provided range, with x varying fastest, then y, then z.
+    pub fn sample_range(
+        &self,
+        range: &RegionRange,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Sample>, GvoxError> {
+        let RegionRange { offset, extent } = range;
+        let mut offsets = Vec::with_capacity(range.volume() as usize);
+        for z in 0..extent.z as i64 {
+            for y in 0..extent.y as i64 {
+                for x in 0..extent.x as i64 {
+                    offsets.push(Offset3D {
+                        x: (offset.x as i64 + x) as i32,
+                        y: (offset.y as i64 + y) as i32,
+                        z: (offset.z as i64 + z) as i32,
+                    });
+                }
+            }
+        }
+
+        let mut samples = vec![Sample::default(); offsets.len()];
+        self.sample_many(&offsets, channel_id, &mut samples)?;
+        Ok(samples)
+    }
+
+    /// Iterates over every voxel in this region, along with its values in the provided channels. Voxels are
+    /// visited with x varying fastest, then y, then z, matching the order in which the native serializers
+    /// store them. If sampling a voxel fails, the error is returned in its place.
+    pub fn iter(
+        &self,
+        channel_flags: ChannelFlags,
+    ) -> impl Iterator<Item = Result<(Offset3D, VoxelSamples), GvoxError>> + '_ {
+        let RegionRange { offset, extent } = self.range();
+        let channels = channel_flags.into_iter().collect::<Vec<_>>();
+        let uniform = self.flags().contains(RegionFlags::UNIFORM);
+        let mut first = None;
+
+        (0..extent.z as i64)
+            .flat_map(move |z| {
+                (0..extent.y as i64).flat_map(move |y| (0..extent.x as i64).map(move |x| (x, y, z)))
+            })
+            .map(move |(x, y, z)| {
+                let position = Offset3D {
+                    x: (offset.x as i64 + x) as i32,
+                    y: (offset.y as i64 + y) as i32,
+                    z: (offset.z as i64 + z) as i32,
+                };
+
+                let samples = match first {
+                    Some(samples) if uniform => samples,
+                    _ => {
+                        let mut samples = VoxelSamples::default();
+                        for &channel_id in &channels {
+                            let sample = self.sample(&position, channel_id)?;
+                            samples.values[u32::from(channel_id) as usize] =
+                                sample.is_present.then_some(sample.data);
+                        }
+                        *first.insert(samples)
+                    }
+                };
+
+                Ok((position, samples))
+            })
+    }
+
+    /// Copies the channel values of every voxel in this region into a new dense region.
+    /// Voxels which have no value in the channel are given the provided missing value.
+    pub fn to_dense(&self, channel_id: ChannelId, missing: u32) -> Result<DenseRegion, GvoxError> {
+        let range = self.range();
+        let mut data = vec![missing; range.volume() as usize];
+        self.read_into(channel_id, missing, &mut data)?;
+        Ok(DenseRegion { range, data })
+    }
+
+    /// Copies the channel values of every voxel in this region into the output, which is indexed like
+    /// [`DenseRegion::data`]. Voxels which have no value in the channel are given the provided missing value.
+    /// Returns an error if the length of the output does not match the volume of the region.
+    pub fn read_into(
+        &self,
+        channel_id: ChannelId,
+        missing: u32,
+        out: &mut [u32],
+    ) -> Result<(), GvoxError> {
+        let range = self.range();
+        if out.len() as u64 != range.volume() {
+            return Err(GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Could not read a region of {} voxels into {} outputs.",
+                    range.volume(),
+                    out.len()
+                ),
+            ));
+        }
+
+        let value = |sample: Sample| {
+            if sample.is_present {
+                sample.data
+            } else {
+                missing
+            }
+        };
+
+        if self.flags().contains(RegionFlags::UNIFORM) {
+            if !out.is_empty() {
+                out.fill(value(self.sample(&range.offset, channel_id)?));
+            }
+            return Ok(());
+        }
+
+        let RegionRange { offset, extent } = range;
+        let mut values = out.iter_mut();
+        for z in 0..extent.z as i64 {
+            for y in 0..extent.y as i64 {
+                for x in 0..extent.x as i64 {
+                    let position = Offset3D {
+                        x: (offset.x as i64 + x) as i32,
+                        y: (offset.y as i64 + y) as i32,
+                        z: (offset.z as i64 + z) as i32,
+                    };
+                    *values.next().expect("Output length was already checked.") =
+                        value(self.sample(&position, channel_id)?);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The values of a single voxel in each of the channels that were sampled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct VoxelSamples {
+    /// The value of the voxel in each channel, indexed by channel ID.
+    values: [Option<u32>; CHANNEL_ID_COUNT],
+}
+
+impl VoxelSamples {
+    /// The value of the voxel in the provided channel, or `None` if the channel has no value or was not sampled.
+    pub fn get(&self, channel_id: ChannelId) -> Option<u32> {
+        self.values[u32::from(channel_id) as usize]
+    }
+
+    /// Sets the value of the voxel in the provided channel, and returns its previous value, if any.
+    pub fn insert(&mut self, channel_id: ChannelId, value: u32) -> Option<u32> {
+        self.values[u32::from(channel_id) as usize].replace(value)
+    }
+
+    /// The set of channels in which the voxel has a value.
+    pub fn channels(&self) -> ChannelFlags {
+        self.iter()
+            .fold(ChannelFlags::empty(), |acc, (x, _)| acc | x)
+    }
+
+    /// Iterates over the channels in which the voxel has a value, along with those values.
+    pub fn iter(&self) -> impl Iterator<Item = (ChannelId, u32)> + '_ {
+        ChannelId::iter().filter_map(|x| self.get(x).map(|value| (x, value)))
+    }
+}
+
+/// The values of a single channel for every voxel within a range, copied out of a region.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DenseRegion {
+    /// The range of voxels that the values describe.
+    pub range: RegionRange,
+    /// The value of each voxel, indexed by `x + y * extent.x + z * extent.x * extent.y`,
+    /// where the coordinates are relative to the offset of the range.
+    pub data: Vec<u32>,
+}
+
+impl DenseRegion {
+    /// The value of the voxel at the provided position, or `None` if it lies outside of the range.
+    pub fn get(&self, position: &Offset3D) -> Option<u32> {
+        if !self.range.contains(position) {
+            return None;
+        }
+
+        let RegionRange { offset, extent } = self.range;
+        let x = (position.x as i64 - offset.x as i64) as usize;
+        let y = (position.y as i64 - offset.y as i64) as usize;
+        let z = (position.z as i64 - offset.z as i64) as usize;
+        let (w, h) = (extent.x as usize, extent.y as usize);
+        self.data.get(x + y * w + z * w * h).copied()
+    }
 }
 
 impl<'a> Drop for RegionRef<'a> {
@@ -1527,7 +4483,7 @@ impl<'a> Drop for RegionRef<'a> {
 }
 
 /// Represents an offset on a 3D grid.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[repr(C)]
 pub struct Offset3D {
     /// The x-component of the offset.
@@ -1588,6 +4544,133 @@ pub struct RegionRange {
     pub extent: Extent3D,
 }
 
+impl RegionRange {
+    /// The number of voxels within the region.
+    pub fn volume(&self) -> u64 {
+        self.extent.x as u64 * self.extent.y as u64 * self.extent.z as u64
+    }
+
+    /// Whether the voxel at the provided position lies within the region.
+    pub fn contains(&self, position: &Offset3D) -> bool {
+        let within = |position: i32, offset: i32, extent: u32| {
+            (offset as i64..offset as i64 + extent as i64).contains(&(position as i64))
+        };
+        within(position.x, self.offset.x, self.extent.x)
+            && within(position.y, self.offset.y, self.extent.y)
+            && within(position.z, self.offset.z, self.extent.z)
+    }
+}
+
+/// The smallest range which contains both of the provided ranges.
+fn bounding_range(a: &RegionRange, b: &RegionRange) -> RegionRange {
+    let max = |a_offset: i32, a_extent: u32, b_offset: i32, b_extent: u32| {
+        (a_offset as i64 + a_extent as i64).max(b_offset as i64 + b_extent as i64)
+    };
+    let offset = Offset3D {
+        x: a.offset.x.min(b.offset.x),
+        y: a.offset.y.min(b.offset.y),
+        z: a.offset.z.min(b.offset.z),
+    };
+    let extent = Extent3D {
+        x: (max(a.offset.x, a.extent.x, b.offset.x, b.extent.x) - offset.x as i64) as u32,
+        y: (max(a.offset.y, a.extent.y, b.offset.y, b.extent.y) - offset.y as i64) as u32,
+        z: (max(a.offset.z, a.extent.z, b.offset.z, b.extent.z) - offset.z as i64) as u32,
+    };
+    RegionRange { offset, extent }
+}
+
+/// The index of a position, which must lie within the range, in data indexed like [`DenseRegion::data`].
+fn dense_index(range: &RegionRange, position: &Offset3D) -> usize {
+    let x = (position.x as i64 - range.offset.x as i64) as usize;
+    let y = (position.y as i64 - range.offset.y as i64) as usize;
+    let z = (position.z as i64 - range.offset.z as i64) as usize;
+    let (w, h) = (range.extent.x as usize, range.extent.y as usize);
+    x + y * w + z * w * h
+}
+
+/// The range of voxels which lie within both of the provided ranges, or `None` if there are none.
+fn overlapping_range(a: &RegionRange, b: &RegionRange) -> Option<RegionRange> {
+    let axis = |a_offset: i32, a_extent: u32, b_offset: i32, b_extent: u32| {
+        let start = a_offset.max(b_offset) as i64;
+        let end = (a_offset as i64 + a_extent as i64).min(b_offset as i64 + b_extent as i64);
+        (start < end).then(|| (start as i32, (end - start) as u32))
+    };
+    let (x, w) = axis(a.offset.x, a.extent.x, b.offset.x, b.extent.x)?;
+    let (y, h) = axis(a.offset.y, a.extent.y, b.offset.y, b.extent.y)?;
+    let (z, d) = axis(a.offset.z, a.extent.z, b.offset.z, b.extent.z)?;
+    Some(RegionRange {
+        offset: Offset3D { x, y, z },
+        extent: Extent3D { x: w, y: h, z: d },
+    })
+}
+
+#[cfg(any(test, feature = "testing"))]
+thread_local! {
+    /// The output which the Rust standard stream outputs wrote on the current thread, while
+    /// [`testing::capture_std_streams`] runs.
+    static CAPTURED_STD_STREAMS: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// Writes the data at the provided position within the bytes, extending them with zeros as needed.
+/// Later writes to the same bytes replace earlier ones, as they do for outputs.
+fn write_at(bytes: &mut Vec<u8>, position: usize, data: &[u8]) {
+    let end = position + data.len();
+    if bytes.len() < end {
+        bytes.resize(end, 0);
+    }
+    bytes[position..end].copy_from_slice(data);
+}
+
+/// A value which a configuration holds until it is moved into the adapter context that is created with the
+/// configuration. Adapter contexts only borrow their configurations, so the value is moved out through a cell,
+/// and creating a second adapter context from the same configuration fails.
+struct MoveOnce<T>(Cell<Option<T>>);
+
+impl<T> MoveOnce<T> {
+    /// Holds the value until it is moved into an adapter context.
+    fn new(value: T) -> Self {
+        Self(Cell::new(Some(value)))
+    }
+
+    /// Moves the value out, or returns an error if it was already moved. The description names
+    /// the value and its configuration, such as "reader of a reader input configuration".
+    fn take(&self, description: &str) -> Result<T, GvoxError> {
+        self.0.take().ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!("The {description} had already been moved into another adapter context."),
+            )
+        })
+    }
+
+    /// Moves the value out like [`MoveOnce::take`], and extends the lifetimes of its borrows so that
+    /// a handler may keep it.
+    ///
+    /// # Safety
+    ///
+    /// The same invariants as `extend_lifetimes` apply.
+    unsafe fn take_extended<S>(&self, description: &str) -> Result<S, GvoxError> {
+        self.take(description).map(|value| extend_lifetimes(value))
+    }
+}
+
+/// Converts a value to the same type with longer lifetimes, such as when a handler keeps a value
+/// which borrows from the configuration lifetime of its adapter context.
+///
+/// # Safety
+///
+/// `S` must differ from `T` only in its lifetimes, and the result must not be used once the borrows
+/// of `T` end. Adapter contexts never outlive their configuration lifetime, so their handlers may
+/// keep values whose borrows last that long.
+unsafe fn extend_lifetimes<T, S>(value: T) -> S {
+    assert_eq!(
+        size_of::<T>(),
+        size_of::<S>(),
+        "Only the lifetimes of a type may be extended."
+    );
+    transmute_copy(&ManuallyDrop::new(value))
+}
+
 impl From<gvox_sys::GvoxRegionRange> for RegionRange {
     fn from(value: gvox_sys::GvoxRegionRange) -> Self {
         Self {
@@ -1630,8 +4713,14 @@ pub enum ErrorType {
     /// A serialize adapter's format did not support the output data type.
     SerializeAdapterUnrepresentableData =
         gvox_sys::GvoxResult_GVOX_RESULT_ERROR_SERIALIZE_ADAPTER_UNREPRESENTABLE_DATA,
+    /// A blit was cancelled before it completed.
+    Cancelled = RESULT_ERROR_CANCELLED,
 }
 
+/// The result code used for cancelled blits. The native library never produces it, so it lies
+/// well outside of the range of native result codes.
+const RESULT_ERROR_CANCELLED: i32 = -1000;
+
 impl From<i32> for ErrorType {
     fn from(value: i32) -> Self {
         match value {
@@ -1644,6 +4733,7 @@ impl From<i32> for ErrorType {
             gvox_sys::GvoxResult_GVOX_RESULT_ERROR_PARSE_ADAPTER_INVALID_INPUT => Self::ParseAdapterInvalidInput,
             gvox_sys::GvoxResult_GVOX_RESULT_ERROR_PARSE_ADAPTER_REQUESTED_CHANNEL_NOT_PRESENT => Self::ParseAdapterRequestedChannelNotPresent,
             gvox_sys::GvoxResult_GVOX_RESULT_ERROR_SERIALIZE_ADAPTER_UNREPRESENTABLE_DATA => Self::SerializeAdapterUnrepresentableData,
+            RESULT_ERROR_CANCELLED => Self::Cancelled,
             _ => Self::Unknown
         }
     }
@@ -1662,12 +4752,73 @@ pub enum BlitMode {
 }
 
 /// Describes basic info about a parse adapter
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ParseAdapterDetails {
     /// Allows the adapter to configure which blit mode to use, if using the default blit function
     preferred_blit_mode: BlitMode,
 }
 
+impl ParseAdapterDetails {
+    /// Creates the details of an adapter which prefers the provided blit mode.
+    pub fn new(preferred_blit_mode: BlitMode) -> Self {
+        Self {
+            preferred_blit_mode,
+        }
+    }
+
+    /// The blit mode which the adapter prefers, if using the default blit function.
+    pub fn preferred_blit_mode(&self) -> BlitMode {
+        self.preferred_blit_mode
+    }
+
+    /// Sets the blit mode which the adapter prefers, if using the default blit function.
+    pub fn with_preferred_blit_mode(mut self, preferred_blit_mode: BlitMode) -> Self {
+        self.preferred_blit_mode = preferred_blit_mode;
+        self
+    }
+}
+
+impl Default for ParseAdapterDetails {
+    fn default() -> Self {
+        Self::new(BlitMode::DontCare)
+    }
+}
+
+/// Describes basic info about a serialize adapter
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SerializeAdapterDetails {
+    /// Allows the adapter to configure which blit mode to use, if the parse adapter has no preference
+    preferred_blit_mode: BlitMode,
+}
+
+impl SerializeAdapterDetails {
+    /// Creates the details of an adapter which prefers the provided blit mode.
+    pub fn new(preferred_blit_mode: BlitMode) -> Self {
+        Self {
+            preferred_blit_mode,
+        }
+    }
+
+    /// The blit mode which the adapter prefers, if the parse adapter has no preference.
+    pub fn preferred_blit_mode(&self) -> BlitMode {
+        self.preferred_blit_mode
+    }
+
+    /// Sets the blit mode which the adapter prefers, if the parse adapter has no preference.
+    pub fn with_preferred_blit_mode(mut self, preferred_blit_mode: BlitMode) -> Self {
+        self.preferred_blit_mode = preferred_blit_mode;
+        self
+    }
+}
+
+impl Default for SerializeAdapterDetails {
+    fn default() -> Self {
+        Self::new(BlitMode::DontCare)
+    }
+}
+
 /// Describes an error that occurred during voxel conversion operations.
 #[derive(Clone, Debug)]
 pub struct GvoxError {
@@ -1675,19 +4826,31 @@ pub struct GvoxError {
     ty: ErrorType,
     /// The message describing the error.
     message: String,
+    /// The other errors which were reported alongside this one.
+    related: Vec<GvoxError>,
 }
 
 impl GvoxError {
     /// Creates a new error with the provided type and reason message.
     pub fn new(ty: ErrorType, message: impl Into<String>) -> Self {
         let message = Into::<String>::into(message);
-        Self { ty, message }
+        Self {
+            ty,
+            message,
+            related: Vec::new(),
+        }
     }
 
     /// The type of error that occurred.
     pub fn error_type(&self) -> ErrorType {
         self.ty
     }
+
+    /// The other errors which were on the context error stack when this error was retrieved, such as those
+    /// reported by other adapters during the same blit. They are in the order that they were popped from the stack.
+    pub fn related(&self) -> &[GvoxError] {
+        &self.related
+    }
 }
 
 impl Error for GvoxError {
@@ -1703,11 +4866,17 @@ impl std::fmt::Display for GvoxError {
 }
 
 /// Describes a sample that is supplied by the parse adapter.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct Sample {
+    /// The value of the voxel in the sampled channel.
     pub data: u32,
+    /// Whether the voxel has a value in the sampled channel.
     pub is_present: bool,
 }
 
+/// The number of distinct channel IDs.
+const CHANNEL_ID_COUNT: usize = gvox_sys::GVOX_CHANNEL_ID_LAST as usize + 1;
+
 /// Identifies a specific property associated with a voxel volume.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ChannelId(u32);
@@ -1977,27 +5146,37 @@ mod private {
 
     /// Provides an interface through which adapters can query other adapters for information.
     pub trait BlitContextType: 'static + Sized {
-        /// Creates a new blit context for the given context and blit pointers.
+        /// Creates a new blit context for the given context, blit, and adapter context pointers.
         ///
         /// # Safety
         ///
-        /// For this function call to be sound, both parameters must point to valid contexts
-        /// and this object must not outlive either of them.
+        /// For this function call to be sound, all parameters must point to valid contexts
+        /// and this object must not outlive any of them. The blit pointer may be null for
+        /// input and output blit contexts, which do not use it.
         unsafe fn new(
             ctx: *mut gvox_sys::GvoxContext,
             blit_ctx: *mut gvox_sys::GvoxBlitContext,
+            adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
         ) -> Self;
     }
 
     impl BlitContextType for InputBlitContext {
-        unsafe fn new(_: *mut gvox_sys::GvoxContext, _: *mut gvox_sys::GvoxBlitContext) -> Self {
-            Self {}
+        unsafe fn new(
+            _: *mut gvox_sys::GvoxContext,
+            _: *mut gvox_sys::GvoxBlitContext,
+            adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
+        ) -> Self {
+            Self { adapter_ctx }
         }
     }
 
     impl BlitContextType for OutputBlitContext {
-        unsafe fn new(_: *mut gvox_sys::GvoxContext, _: *mut gvox_sys::GvoxBlitContext) -> Self {
-            Self {}
+        unsafe fn new(
+            _: *mut gvox_sys::GvoxContext,
+            _: *mut gvox_sys::GvoxBlitContext,
+            adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
+        ) -> Self {
+            Self { adapter_ctx }
         }
     }
 
@@ -2005,8 +5184,13 @@ mod private {
         unsafe fn new(
             ctx: *mut gvox_sys::GvoxContext,
             blit_ctx: *mut gvox_sys::GvoxBlitContext,
+            adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
         ) -> Self {
-            Self { blit_ctx, ctx }
+            Self {
+                blit_ctx,
+                ctx,
+                adapter_ctx,
+            }
         }
     }
 
@@ -2014,95 +5198,272 @@ mod private {
         unsafe fn new(
             ctx: *mut gvox_sys::GvoxContext,
             blit_ctx: *mut gvox_sys::GvoxBlitContext,
+            adapter_ctx: *mut gvox_sys::GvoxAdapterContext,
         ) -> Self {
-            Self { blit_ctx, ctx }
+            Self {
+                blit_ctx,
+                ctx,
+                adapter_ctx,
+            }
         }
     }
 
     /// Describes the types associated with a given operation kind.
-    pub trait AdapterKindAssociation: AdapterKind {
+    pub trait AdapterKindAssociation: AdapterKind + Sized {
         /// The blitting context that is provided for adapters of this type.
         type BlitContext: BlitContextType;
+
+        /// Creates a new adapter context from the built-in adapter of this kind to which the configuration
+        /// belongs, or returns `None` if the adapter does not accept the configuration.
+        fn create_dyn_adapter_context<'a>(
+            adapter: &DynAdapter<Self>,
+            config: DynAdapterConfig<'a>,
+        ) -> Option<Result<AdapterContext<'a, Self>, GvoxError>>;
     }
 
     impl AdapterKindAssociation for Input {
         type BlitContext = InputBlitContext;
+
+        fn create_dyn_adapter_context<'a>(
+            adapter: &DynAdapter<Self>,
+            config: DynAdapterConfig<'a>,
+        ) -> Option<Result<AdapterContext<'a, Self>, GvoxError>> {
+            match config {
+                DynAdapterConfig::Bytes(x) => {
+                    adapter.try_create_adapter_context::<adapters::ByteBuffer>(x)
+                }
+                DynAdapterConfig::OwnedBytes(x) => {
+                    adapter.try_create_adapter_context::<adapters::ByteBufferOwned>(x)
+                }
+                DynAdapterConfig::FileInput(x) => {
+                    adapter.try_create_adapter_context::<adapters::File>(x)
+                }
+                _ => None,
+            }
+        }
     }
 
     impl AdapterKindAssociation for Output {
         type BlitContext = OutputBlitContext;
+
+        fn create_dyn_adapter_context<'a>(
+            adapter: &DynAdapter<Self>,
+            config: DynAdapterConfig<'a>,
+        ) -> Option<Result<AdapterContext<'a, Self>, GvoxError>> {
+            match config {
+                DynAdapterConfig::Unit => Self::create_dyn_adapter_context(
+                    adapter,
+                    DynAdapterConfig::StdOutput(Default::default()),
+                ),
+                DynAdapterConfig::StdOutput(x) => adapter
+                    .try_create_adapter_context::<adapters::StdOut>(x)
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::BufferedStdOut>(x))
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::StdErr>(x)),
+                DynAdapterConfig::ByteBufferOutput(x) => {
+                    adapter.try_create_adapter_context::<adapters::ByteBuffer>(x)
+                }
+                DynAdapterConfig::FileOutput(x) => {
+                    adapter.try_create_adapter_context::<adapters::File>(x)
+                }
+                _ => None,
+            }
+        }
     }
 
     impl AdapterKindAssociation for Parse {
         type BlitContext = ParseBlitContext;
+
+        fn create_dyn_adapter_context<'a>(
+            adapter: &DynAdapter<Self>,
+            config: DynAdapterConfig<'a>,
+        ) -> Option<Result<AdapterContext<'a, Self>, GvoxError>> {
+            match config {
+                DynAdapterConfig::Unit => adapter
+                    .try_create_adapter_context::<adapters::GvoxPalette>(())
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::GvoxRaw>(()))
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::GvoxBrickmap>(()))
+                    .or_else(|| {
+                        adapter.try_create_adapter_context::<adapters::GvoxGlobalPalette>(())
+                    })
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::GvoxOctree>(()))
+                    .or_else(|| {
+                        adapter.try_create_adapter_context::<adapters::GvoxRunLengthEncoding>(())
+                    })
+                    .or_else(|| {
+                        adapter.try_create_adapter_context::<adapters::RustRunLengthEncoding>(())
+                    })
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::MagicaVoxel>(())),
+                DynAdapterConfig::MagicaVoxelModel(x) => {
+                    adapter.try_create_adapter_context::<adapters::MagicaVoxelModel>(x)
+                }
+                DynAdapterConfig::Voxlap(x) => {
+                    adapter.try_create_adapter_context::<adapters::Voxlap>(x)
+                }
+                DynAdapterConfig::Kvx(x) => adapter.try_create_adapter_context::<adapters::Kvx>(x),
+                DynAdapterConfig::Binvox(x) => {
+                    adapter.try_create_adapter_context::<adapters::Binvox>(x)
+                }
+                _ => None,
+            }
+        }
     }
 
     impl AdapterKindAssociation for Serialize {
         type BlitContext = SerializeBlitContext;
+
+        fn create_dyn_adapter_context<'a>(
+            adapter: &DynAdapter<Self>,
+            config: DynAdapterConfig<'a>,
+        ) -> Option<Result<AdapterContext<'a, Self>, GvoxError>> {
+            match config {
+                DynAdapterConfig::Unit => adapter
+                    .try_create_adapter_context::<adapters::GvoxPalette>(())
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::GvoxRaw>(()))
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::GvoxBrickmap>(()))
+                    .or_else(|| {
+                        adapter.try_create_adapter_context::<adapters::GvoxGlobalPalette>(())
+                    })
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::GvoxOctree>(()))
+                    .or_else(|| {
+                        adapter.try_create_adapter_context::<adapters::GvoxRunLengthEncoding>(())
+                    })
+                    .or_else(|| {
+                        adapter.try_create_adapter_context::<adapters::RustRunLengthEncoding>(())
+                    })
+                    .or_else(|| adapter.try_create_adapter_context::<adapters::MagicaVoxelOut>(())),
+                DynAdapterConfig::ColoredText(x) => {
+                    adapter.try_create_adapter_context::<adapters::ColoredText>(x)
+                }
+                DynAdapterConfig::RustColoredText(x) => {
+                    adapter.try_create_adapter_context::<adapters::RustColoredText>(x)
+                }
+                DynAdapterConfig::TerminalPreview(x) => {
+                    adapter.try_create_adapter_context::<adapters::TerminalPreview>(x)
+                }
+                _ => None,
+            }
+        }
+    }
+
+    /// The native functions with which an output adapter context is written outside of blits.
+    #[derive(Copy, Clone, Debug)]
+    pub struct OutputWriter {
+        /// Begins the output of a blit.
+        pub blit_begin: unsafe extern "C" fn(
+            *mut gvox_sys::GvoxBlitContext,
+            *mut gvox_sys::GvoxAdapterContext,
+            *const gvox_sys::GvoxRegionRange,
+            u32,
+        ),
+        /// Reserves space for the output.
+        pub reserve: unsafe extern "C" fn(*mut gvox_sys::GvoxAdapterContext, usize),
+        /// Writes bytes to the output.
+        pub write:
+            unsafe extern "C" fn(*mut gvox_sys::GvoxAdapterContext, usize, usize, *const c_void),
+        /// Ends the output of a blit.
+        pub blit_end:
+            unsafe extern "C" fn(*mut gvox_sys::GvoxBlitContext, *mut gvox_sys::GvoxAdapterContext),
     }
 
     /// Provides the ability to register an adapter of the given type with a context. Automatically
     /// implemented for all adapter types with context handlers.
-    pub trait RegisterableAdapter<K: AdapterKind>: AdapterDescriptor<K> + NamedAdapter {
-        /// Registers the given adapter with the underlying context, and returns a pointer to it if the operation
-        /// was successful.
+    pub trait RegisterableAdapter<K: AdapterKind>: AdapterDescriptor<K> {
+        /// Registers the given adapter with the underlying context under the provided name, and returns
+        /// a pointer to it if the operation was successful.
         ///
         /// # Safety
         ///
         /// The provided pointer must be a valid reference to a context.
         unsafe fn register_adapter(
             ptr: *mut gvox_sys::GvoxContext,
+            name: &CStr,
         ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError>;
+
+        /// The function which creates the handlers of the adapter's contexts.
+        fn handler_creator() -> HandlerCreateFn;
+
+        /// The blit mode which the adapter declares as its preference.
+        fn preferred_blit_mode() -> BlitMode {
+            BlitMode::DontCare
+        }
+
+        /// The function which reads from the adapter's contexts, if it is an input adapter.
+        fn input_reader() -> Option<InputReadFn> {
+            None
+        }
+
+        /// The functions which write to the adapter's contexts, if it is an output adapter.
+        fn output_writer() -> Option<OutputWriter> {
+            None
+        }
     }
 
-    impl<T: AdapterDescriptor<Input> + NamedAdapter> RegisterableAdapter<Input> for T
+    impl<T: AdapterDescriptor<Input>> RegisterableAdapter<Input> for T
     where
         T::Handler: InputAdapterHandler<T>,
     {
         unsafe fn register_adapter(
             ptr: *mut gvox_sys::GvoxContext,
+            name: &CStr,
         ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
-            let name =
-                CString::new(Self::name()).expect("Failed to convert Rust string to C string");
             let adapter_info = gvox_sys::GvoxInputAdapterInfo {
-                base_info: create_base_adapter_info::<Input, Self>(&name),
-                read: Some(InputContextHolder::read::<Self>),
+                base_info: create_base_adapter_info::<Input, Self>(name),
+                read: Self::input_reader(),
             };
             let adapter = gvox_sys::gvox_register_input_adapter(ptr, &adapter_info);
             ContextInner::get_error_from_raw_ptr(ptr).map(|()| adapter)
         }
+
+        fn handler_creator() -> HandlerCreateFn {
+            AdapterContextHolder::create_handler::<Input, Self>
+        }
+
+        fn input_reader() -> Option<InputReadFn> {
+            Some(InputContextHolder::read::<Self>)
+        }
     }
 
-    impl<T: AdapterDescriptor<Output> + NamedAdapter> RegisterableAdapter<Output> for T
+    impl<T: AdapterDescriptor<Output>> RegisterableAdapter<Output> for T
     where
         T::Handler: OutputAdapterHandler<T>,
     {
         unsafe fn register_adapter(
             ptr: *mut gvox_sys::GvoxContext,
+            name: &CStr,
         ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
-            let name =
-                CString::new(Self::name()).expect("Failed to convert Rust string to C string");
             let adapter_info = gvox_sys::GvoxOutputAdapterInfo {
-                base_info: create_base_adapter_info::<Output, Self>(&name),
+                base_info: create_base_adapter_info::<Output, Self>(name),
                 write: Some(OutputContextHolder::write::<Self>),
                 reserve: Some(OutputContextHolder::reserve::<Self>),
             };
             let adapter = gvox_sys::gvox_register_output_adapter(ptr, &adapter_info);
             ContextInner::get_error_from_raw_ptr(ptr).map(|()| adapter)
         }
+
+        fn handler_creator() -> HandlerCreateFn {
+            AdapterContextHolder::create_handler::<Output, Self>
+        }
+
+        fn output_writer() -> Option<OutputWriter> {
+            Some(OutputWriter {
+                blit_begin: AdapterContextHolder::blit_begin::<Output, Self>,
+                reserve: OutputContextHolder::reserve::<Self>,
+                write: OutputContextHolder::write::<Self>,
+                blit_end: AdapterContextHolder::blit_end::<Output, Self>,
+            })
+        }
     }
 
-    impl<T: AdapterDescriptor<Parse> + NamedAdapter> RegisterableAdapter<Parse> for T
+    impl<T: AdapterDescriptor<Parse>> RegisterableAdapter<Parse> for T
     where
         T::Handler: ParseAdapterHandler<T>,
     {
         unsafe fn register_adapter(
             ptr: *mut gvox_sys::GvoxContext,
+            name: &CStr,
         ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
-            let name =
-                CString::new(Self::name()).expect("Failed to convert Rust string to C string");
             let adapter_info = gvox_sys::GvoxParseAdapterInfo {
-                base_info: create_base_adapter_info::<Parse, Self>(&name),
+                base_info: create_base_adapter_info::<Parse, Self>(name),
                 query_details: Some(ParseContextHolder::query_details::<Self>),
                 query_parsable_range: Some(ParseContextHolder::query_parsable_range::<Self>),
                 query_region_flags: Some(ParseContextHolder::query_region_flags::<Self>),
@@ -2114,25 +5475,40 @@ mod private {
             let adapter = gvox_sys::gvox_register_parse_adapter(ptr, &adapter_info);
             ContextInner::get_error_from_raw_ptr(ptr).map(|()| adapter)
         }
+
+        fn handler_creator() -> HandlerCreateFn {
+            AdapterContextHolder::create_handler::<Parse, Self>
+        }
+
+        fn preferred_blit_mode() -> BlitMode {
+            T::Handler::query_details().preferred_blit_mode()
+        }
     }
 
-    impl<T: AdapterDescriptor<Serialize> + NamedAdapter> RegisterableAdapter<Serialize> for T
+    impl<T: AdapterDescriptor<Serialize>> RegisterableAdapter<Serialize> for T
     where
         T::Handler: SerializeAdapterHandler<T>,
     {
         unsafe fn register_adapter(
             ptr: *mut gvox_sys::GvoxContext,
+            name: &CStr,
         ) -> Result<*mut gvox_sys::GvoxAdapter, GvoxError> {
-            let name =
-                CString::new(Self::name()).expect("Failed to convert Rust string to C string");
             let adapter_info = gvox_sys::GvoxSerializeAdapterInfo {
-                base_info: create_base_adapter_info::<Serialize, Self>(&name),
+                base_info: create_base_adapter_info::<Serialize, Self>(name),
                 serialize_region: Some(SerializeContextHolder::serialize_region::<Self>),
                 receive_region: Some(SerializeContextHolder::receive_region::<Self>),
             };
             let adapter = gvox_sys::gvox_register_serialize_adapter(ptr, &adapter_info);
             ContextInner::get_error_from_raw_ptr(ptr).map(|()| adapter)
         }
+
+        fn handler_creator() -> HandlerCreateFn {
+            AdapterContextHolder::create_handler::<Serialize, Self>
+        }
+
+        fn preferred_blit_mode() -> BlitMode {
+            T::Handler::query_details().preferred_blit_mode()
+        }
     }
 
     /// Creates the base adapter info for the adapter of the given name and type.
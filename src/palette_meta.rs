@@ -0,0 +1,349 @@
+use crate::format::*;
+use crate::*;
+
+/// Describes the changes that [`remap_colors`] made to a gvox_palette container.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RemapReport {
+    /// The number of regions whose color data was modified.
+    pub regions: usize,
+    /// The number of palette entries, or uniform region values, which were rewritten.
+    pub palette_entries: usize,
+    /// The number of modified regions which stored raw voxel values rather than a palette,
+    /// and whose voxel data was therefore rewritten in full.
+    pub reencoded_regions: usize,
+}
+
+/// Copies the gvox_palette container from the input to the output, replacing every color that
+/// appears as a key of the mapping with its associated value. Only the palettes of each region
+/// are rewritten; palette indices and all other channels are copied verbatim. Regions which
+/// store raw voxel values are re-encoded individually. Both adapter contexts must belong to the same context.
+pub fn remap_colors(
+    input_ctx: &mut AdapterContext<'_, Input>,
+    output_ctx: &mut AdapterContext<'_, Output>,
+    mapping: &HashMap<u32, u32>,
+) -> Result<RemapReport, GvoxError> {
+    let ctx = input_ctx.context();
+    if output_ctx.context() != ctx {
+        return Err(GvoxError::new(
+            ErrorType::InvalidParameter,
+            "The input and output adapter contexts belong to different contexts.",
+        ));
+    }
+
+    let job = Arc::new(Mutex::new(RemapJob {
+        mapping: mapping.clone(),
+        container: None,
+        report: None,
+    }));
+
+    let mut p_ctx = ctx
+        .get_or_register_adapter::<Parse, PaletteRemap>()?
+        .create_adapter_context(job.clone())?;
+    let mut s_ctx = ctx
+        .get_or_register_adapter::<Serialize, PaletteRemap>()?
+        .create_adapter_context(job.clone())?;
+
+    blit_region_serialize_driven(
+        Some(input_ctx),
+        Some(output_ctx),
+        &mut p_ctx,
+        &mut s_ctx,
+        None,
+        ChannelId::COLOR.into(),
+    )?;
+
+    let report = job
+        .lock()
+        .expect("Could not acquire remap job mutex.")
+        .report;
+    report.ok_or_else(|| {
+        GvoxError::new(
+            ErrorType::Unknown,
+            "The palette remap blit completed without reading its input.",
+        )
+    })
+}
+
+/// Rewrites the color palettes of a complete gvox_palette container in place.
+fn remap_container(
+    bytes: &mut [u8],
+    mapping: &HashMap<u32, u32>,
+) -> Result<RemapReport, GvoxError> {
+    let header = match read_header(bytes)? {
+        ContainerHeader::Palette(header) => header,
+        ContainerHeader::Raw(_) => {
+            return Err(GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                "Only gvox_palette containers can have their palettes remapped.",
+            ))
+        }
+    };
+
+    let mut report = RemapReport::default();
+    let channels = header.channels();
+    if !channels.contains(ChannelId::COLOR) {
+        return Ok(report);
+    }
+
+    let color_index = (u32::from(channels) & ((1 << u32::from(ChannelId::COLOR)) - 1)).count_ones();
+    let blob_start = PALETTE_HEADER_SIZE + header.channel_headers_size();
+    let Extent3D { x, y, z } = header.region_counts();
+    let region_voxel_n = PALETTE_REGION_SIZE.pow(3) as usize;
+
+    for region_index in 0..(x as usize * y as usize * z as usize) {
+        let channel =
+            PaletteChannelHeader::read(bytes, &header, region_index, color_index as usize)?;
+        let touched = if channel.variant_n == 1 {
+            let offset = PALETTE_HEADER_SIZE
+                + header.channel_header_offset(region_index, color_index as usize)
+                + size_of::<u32>();
+            remap_values(bytes, offset, 1, mapping)?
+        } else {
+            let offset = blob_start + channel.blob_offset as usize;
+            if channel.variant_n > PALETTE_MAX_COMPRESSED_VARIANT_N {
+                let touched = remap_values(bytes, offset, region_voxel_n, mapping)?;
+                report.reencoded_regions += (touched > 0) as usize;
+                touched
+            } else {
+                remap_values(bytes, offset, channel.variant_n as usize, mapping)?
+            }
+        };
+
+        report.regions += (touched > 0) as usize;
+        report.palette_entries += touched;
+    }
+
+    Ok(report)
+}
+
+/// Replaces each of the `count` little-endian values beginning at `offset` according to the mapping,
+/// and returns how many of them were changed.
+fn remap_values(
+    bytes: &mut [u8],
+    offset: usize,
+    count: usize,
+    mapping: &HashMap<u32, u32>,
+) -> Result<usize, GvoxError> {
+    let values = offset
+        .checked_add(count * size_of::<u32>())
+        .and_then(|end| bytes.get_mut(offset..end))
+        .ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::ParseAdapterInvalidInput,
+                "Gvox palette region data lies outside of the container.",
+            )
+        })?;
+
+    let mut touched = 0;
+    for value in values.chunks_exact_mut(size_of::<u32>()) {
+        let old = u32::from_le_bytes(value.try_into().expect("Chunk was not four bytes long."));
+        if let Some(new) = mapping.get(&old) {
+            value.copy_from_slice(&new.to_le_bytes());
+            touched += 1;
+        }
+    }
+
+    Ok(touched)
+}
+
+/// Reads the complete gvox_palette container from the input of the current blit.
+fn read_container(blit_ctx: &ParseBlitContext) -> Result<Vec<u8>, GvoxError> {
+    if blit_ctx.read_exact_array(0)? != PALETTE_MAGIC {
+        return Err(GvoxError::new(
+            ErrorType::ParseAdapterInvalidInput,
+            "Only gvox_palette containers can have their palettes remapped.",
+        ));
+    }
+
+    let mut bytes = blit_ctx.read_vec(0, PALETTE_HEADER_SIZE)?;
+    let ContainerHeader::Palette(header) = read_header(&bytes)? else {
+        unreachable!("Container magic was already checked.");
+    };
+
+    bytes.resize(
+        PALETTE_HEADER_SIZE + header.channel_headers_size() + header.blob_size as usize,
+        0,
+    );
+    blit_ctx.input_read(PALETTE_HEADER_SIZE, &mut bytes[PALETTE_HEADER_SIZE..])?;
+    Ok(bytes)
+}
+
+/// The state shared between the parse and serialize halves of a single remap operation.
+struct RemapJob {
+    /// The colors to replace, and their replacements.
+    mapping: HashMap<u32, u32>,
+    /// The remapped container, until it has been written to the output.
+    container: Option<Vec<u8>>,
+    /// The changes made to the container, once it has been read.
+    report: Option<RemapReport>,
+}
+
+/// Copies a gvox_palette container between adapters while rewriting its color palettes.
+/// The parse half reads and remaps the container, and the serialize half writes it out.
+struct PaletteRemap;
+
+impl AdapterDescriptor<Parse> for PaletteRemap {
+    type Configuration<'a> = Arc<Mutex<RemapJob>>;
+    type Handler = RemapReader;
+}
+
+impl AdapterDescriptor<Serialize> for PaletteRemap {
+    type Configuration<'a> = Arc<Mutex<RemapJob>>;
+    type Handler = RemapWriter;
+}
+
+impl NamedAdapter for PaletteRemap {
+    fn name() -> &'static str {
+        "gvox_rs_palette_remap"
+    }
+}
+
+/// Reads and remaps the input container at the beginning of each blit.
+struct RemapReader {
+    /// The remap operation that this context belongs to.
+    job: Arc<Mutex<RemapJob>>,
+    /// The volume of the container that was read.
+    range: RegionRange,
+}
+
+impl BaseAdapterHandler<Parse, PaletteRemap> for RemapReader {
+    fn create(config: &Arc<Mutex<RemapJob>>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            job: config.clone(),
+            range: RegionRange::default(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let mut bytes = read_container(blit_ctx)?;
+        let mut job = self.job.lock().expect("Could not acquire remap job mutex.");
+        let report = remap_container(&mut bytes, &job.mapping)?;
+        self.range = read_header(&bytes)?.range();
+        job.container = Some(bytes);
+        job.report = Some(report);
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<PaletteRemap> for RemapReader {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::new(BlitMode::SerializeDriven)
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.range
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<Region<()>, GvoxError> {
+        Ok(Region::new(
+            *range,
+            ChannelFlags::empty(),
+            RegionFlags::empty(),
+            (),
+        ))
+    }
+
+    fn unload_region(&mut self, _: &ParseBlitContext, _: Region<()>) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<()>,
+        _: &Offset3D,
+        _: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        Ok(Sample {
+            data: 0,
+            is_present: false,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        blit_ctx.emit_region(&Region::new(
+            *range,
+            ChannelFlags::empty(),
+            RegionFlags::empty(),
+            (),
+        ))
+    }
+}
+
+/// Writes the remapped container to the output, regardless of the blit mode.
+struct RemapWriter {
+    /// The remap operation that this context belongs to.
+    job: Arc<Mutex<RemapJob>>,
+}
+
+impl RemapWriter {
+    /// Writes the remapped container to the output, if it has not been written yet.
+    fn write_container(&mut self, blit_ctx: &SerializeBlitContext) -> Result<(), GvoxError> {
+        let container = self
+            .job
+            .lock()
+            .expect("Could not acquire remap job mutex.")
+            .container
+            .take();
+        if let Some(bytes) = container {
+            blit_ctx.output_reserve(bytes.len())?;
+            blit_ctx.output_write(0, &bytes)?;
+        }
+        Ok(())
+    }
+}
+
+impl BaseAdapterHandler<Serialize, PaletteRemap> for RemapWriter {
+    fn create(config: &Arc<Mutex<RemapJob>>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            job: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<PaletteRemap> for RemapWriter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.write_container(blit_ctx)
+    }
+}
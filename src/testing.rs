@@ -0,0 +1,218 @@
+use crate::format::*;
+use crate::*;
+use std::alloc::*;
+use std::cell::*;
+
+thread_local! {
+    /// The number of bytes allocated by the current thread, less those it has freed.
+    static LIVE_BYTES: Cell<isize> = const { Cell::new(0) };
+    /// The number of allocations made by the current thread, less those it has freed.
+    static LIVE_ALLOCATIONS: Cell<isize> = const { Cell::new(0) };
+}
+
+/// A global allocator which forwards to the system allocator, while counting the memory that
+/// each thread allocates and frees. It must be installed with `#[global_allocator]` for
+/// [`LeakCheck`] to observe any allocations.
+///
+/// Allocations made by the native library are counted only on WebAssembly targets, where `gvox-sys`
+/// routes `malloc` and `free` through the Rust allocator. Elsewhere, only Rust-side allocations
+/// (such as adapter context data and configurations) are observed.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// The number of bytes that the current thread has allocated and not yet freed.
+    pub fn live_bytes() -> isize {
+        LIVE_BYTES.with(Cell::get)
+    }
+
+    /// The number of allocations that the current thread has made and not yet freed.
+    pub fn live_allocations() -> isize {
+        LIVE_ALLOCATIONS.with(Cell::get)
+    }
+
+    /// Adjusts the counters of the current thread. Counting is skipped while the thread is being torn down.
+    fn record(bytes: isize, allocations: isize) {
+        let _ = LIVE_BYTES.try_with(|x| x.set(x.get() + bytes));
+        let _ = LIVE_ALLOCATIONS.try_with(|x| x.set(x.get() + allocations));
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record(-(layout.size() as isize), -1);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record(new_size as isize - layout.size() as isize, 0);
+        }
+        new_ptr
+    }
+}
+
+/// Repeatedly runs an operation, and determines whether the memory that it allocates is freed again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LeakCheck {
+    /// The number of times the operation runs before the baseline is recorded, so that one-time
+    /// initialization is not mistaken for a leak.
+    pub warmup: usize,
+    /// The number of times the operation runs after the baseline is recorded.
+    pub iterations: usize,
+    /// The number of bytes by which the current thread's live memory may exceed the baseline
+    /// after all iterations have run.
+    pub tolerance: usize,
+}
+
+impl Default for LeakCheck {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 100,
+            tolerance: 0,
+        }
+    }
+}
+
+impl LeakCheck {
+    /// Runs the operation, and reports how much memory remained allocated by the current thread afterward.
+    pub fn run(&self, mut f: impl FnMut()) -> LeakReport {
+        for _ in 0..self.warmup {
+            f();
+        }
+
+        let bytes = CountingAllocator::live_bytes();
+        let allocations = CountingAllocator::live_allocations();
+        for _ in 0..self.iterations {
+            f();
+        }
+
+        LeakReport {
+            iterations: self.iterations,
+            bytes: CountingAllocator::live_bytes() - bytes,
+            allocations: CountingAllocator::live_allocations() - allocations,
+        }
+    }
+
+    /// Runs the operation, and panics if more memory than the tolerance remained allocated afterward.
+    pub fn assert(&self, f: impl FnMut()) {
+        let report = self.run(f);
+        assert!(
+            report.bytes <= self.tolerance as isize,
+            "Operation leaked {} bytes in {} allocations over {} iterations.",
+            report.bytes,
+            report.allocations,
+            report.iterations
+        );
+    }
+}
+
+/// Describes the memory which remained allocated after a [`LeakCheck`] ran.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LeakReport {
+    /// The number of times the operation ran after the baseline was recorded.
+    pub iterations: usize,
+    /// The number of bytes which remained allocated, relative to the baseline.
+    pub bytes: isize,
+    /// The number of allocations which remained live, relative to the baseline.
+    pub allocations: isize,
+}
+
+/// Runs the operation, and returns everything that the Rust standard stream outputs, such as
+/// [`adapters::BufferedStdOut`] and [`adapters::StdErr`], wrote on the current thread in the meantime.
+/// The captured output is not written to the standard streams.
+pub fn capture_std_streams<R>(f: impl FnOnce() -> R) -> (R, Vec<u8>) {
+    let previous = CAPTURED_STD_STREAMS.replace(Some(Vec::new()));
+    let result = f();
+    let captured = CAPTURED_STD_STREAMS.replace(previous).unwrap_or_default();
+    (result, captured)
+}
+
+/// A pattern of voxel values for generating fixtures with [`make_gvox_raw`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FixturePreset {
+    /// Every voxel holds a distinct value, derived from its world-space position and the channel.
+    /// Since values depend only on position, any sub-range of a fixture matches the fixture generated for that sub-range.
+    Gradient,
+    /// Every voxel holds the same value in each channel.
+    Uniform,
+    /// Only the voxel at the maximum corner of the range is non-zero.
+    SingleVoxel,
+}
+
+impl FixturePreset {
+    /// The value of the given channel for the voxel at the provided position within the range.
+    pub fn sample(&self, range: &RegionRange, position: &Offset3D, channel_id: ChannelId) -> u32 {
+        let channel = u32::from(channel_id);
+        match self {
+            Self::Gradient => {
+                (position.x as u32).wrapping_mul(0x9e3779b1)
+                    ^ (position.y as u32).wrapping_mul(0x85ebca77)
+                    ^ (position.z as u32).wrapping_mul(0xc2b2ae3d)
+                    ^ channel.wrapping_mul(0x27d4eb2f)
+            }
+            Self::Uniform => 0x01000000 | channel,
+            Self::SingleVoxel => {
+                let RegionRange { offset, extent } = range;
+                let is_max = |position: i32, offset: i32, extent: u32| {
+                    position as i64 == offset as i64 + extent as i64 - 1
+                };
+                if is_max(position.x, offset.x, extent.x)
+                    && is_max(position.y, offset.y, extent.y)
+                    && is_max(position.z, offset.z, extent.z)
+                {
+                    0xff000000 | channel
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+/// Generates a gvox_raw container for the given range and channels, with voxel values
+/// determined by the preset. This is implemented entirely in Rust, so fixtures do not
+/// depend upon the native serializers that they are used to test.
+pub fn make_gvox_raw(preset: FixturePreset, range: RegionRange, channels: ChannelFlags) -> Vec<u8> {
+    let header = RawHeader::new(range, channels);
+    let mut bytes = Vec::with_capacity(RAW_HEADER_SIZE + header.data_size());
+    write_header(&ContainerHeader::Raw(header), &mut bytes);
+
+    let RegionRange { offset, extent } = range;
+    for z in 0..extent.z {
+        for y in 0..extent.y {
+            for x in 0..extent.x {
+                let position = Offset3D {
+                    x: (offset.x as i64 + x as i64) as i32,
+                    y: (offset.y as i64 + y as i64) as i32,
+                    z: (offset.z as i64 + z as i64) as i32,
+                };
+                for channel_id in channels {
+                    bytes.extend_from_slice(
+                        &preset.sample(&range, &position, channel_id).to_le_bytes(),
+                    );
+                }
+            }
+        }
+    }
+
+    bytes
+}
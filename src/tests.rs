@@ -12,8 +12,16 @@ macro_rules! cstr {
     };
 }
 
+#[global_allocator]
+static ALLOCATOR: gvox_rs::testing::CountingAllocator = gvox_rs::testing::CountingAllocator;
+
 const PALETTE_BYTES: &[u8] = include_bytes!("palette.gvox");
 const MAGICAVOXEL_BYTES: &[u8] = include_bytes!("magicavoxel.vox");
+const RAW_NEGATIVE_OFFSET_BYTES: &[u8] = include_bytes!("raw_negative_offset.gvox");
+const RAW_UNIFORM_BYTES: &[u8] = include_bytes!("raw_uniform.gvox");
+const RAW_EXTREME_VOXEL_BYTES: &[u8] = include_bytes!("raw_extreme_voxel.gvox");
+const RAW_ALL_CHANNELS_BYTES: &[u8] = include_bytes!("raw_all_channels.gvox");
+const BINVOX_BYTES: &[u8] = include_bytes!("occupancy.binvox");
 
 // Comment out to test the Wasm support
 #[test]
@@ -31,7 +39,6 @@ pub fn gvox_rs_test_procedural() {
 
     {
         let gvox_ctx = gvox_rs::Context::new();
-        gvox_ctx.register_adapter::<gvox_rs::Parse, procedural_parse::Procedural>();
 
         let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
 
@@ -49,9 +56,9 @@ pub fn gvox_rs_test_procedural() {
             .expect("Failed to create adapter context.");
 
         let mut p_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Parse, procedural_parse::Procedural>()
-            .expect("Failed to get procedural parse adapter.")
-            .create_adapter_context(())
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
             .expect("Failed to create adapter context.");
 
         let mut s_ctx = gvox_ctx
@@ -94,14 +101,12 @@ pub fn gvox_rs_test_procedural() {
 }
 
 #[test]
-pub fn gvox_rs_test_gvox_palette() {
-    let mut o_buffer = Box::default();
+pub fn gvox_rs_test_blit_region_argument_forms() {
+    let mut procedural_buffer = Box::default();
+    let mut palette_buffer = Box::default();
 
     {
         let gvox_ctx = gvox_rs::Context::new();
-        gvox_ctx.register_adapter::<gvox_rs::Parse, procedural_parse::Procedural>();
-
-        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
 
         let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
             downscale_factor: 1,
@@ -110,120 +115,97 @@ pub fn gvox_rs_test_gvox_palette() {
             vertical: false,
         };
 
-        let mut i_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
-            .expect("Failed to get byte buffer input adapter.")
-            .create_adapter_context(PALETTE_BYTES)
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+            .expect("Failed to get colored text serialize adapter.")
+            .create_adapter_context(s_config)
             .expect("Failed to create adapter context.");
 
         let mut o_ctx = gvox_ctx
             .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
             .expect("Failed to get byte buffer output adapter.")
-            .create_adapter_context(o_config)
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut procedural_buffer,
+            ))
             .expect("Failed to create adapter context.");
 
         let mut p_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
-            .expect("Failed to get gvox palette parse adapter.")
-            .create_adapter_context(())
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
             .expect("Failed to create adapter context.");
 
-        let mut s_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
-            .expect("Failed to get colored text serialize adapter.")
-            .create_adapter_context(s_config)
-            .expect("Failed to create adapter context.");
+        let region = gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: -4,
+                y: -4,
+                z: -4,
+            },
+            extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+        };
 
+        // The procedural parser generates its own data, so no input is needed.
         gvox_rs::blit_region(
-            Some(&mut i_ctx),
-            Some(&mut o_ctx),
+            None,
+            &mut o_ctx,
             &mut p_ctx,
             &mut s_ctx,
-            None, // Parse whole file!
+            &region,
             gvox_rs::ChannelId::COLOR
                 | gvox_rs::ChannelId::NORMAL
                 | gvox_rs::ChannelId::MATERIAL_ID,
         )
         .expect("Error while translating.");
-    }
-
-    assert_eq!(
-        33342,
-        o_buffer.len(),
-        "Buffer output length did not match expected."
-    );
-    println!(
-        "{}",
-        std::str::from_utf8(&o_buffer).expect("Bad string slice.")
-    );
-}
-
-#[test]
-pub fn gvox_rs_test_magicavoxel() {
-    let mut o_buffer = Box::default();
-
-    {
-        let gvox_ctx = gvox_rs::Context::new();
-        gvox_ctx.register_adapter::<gvox_rs::Parse, procedural_parse::Procedural>();
-
-        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
-
-        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
-            downscale_factor: 1,
-            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
-            non_color_max_value: 254,
-            vertical: false,
-        };
 
         let mut i_ctx = gvox_ctx
             .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
             .expect("Failed to get byte buffer input adapter.")
-            .create_adapter_context(MAGICAVOXEL_BYTES)
+            .create_adapter_context(PALETTE_BYTES)
             .expect("Failed to create adapter context.");
 
         let mut o_ctx = gvox_ctx
             .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
             .expect("Failed to get byte buffer output adapter.")
-            .create_adapter_context(o_config)
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut palette_buffer,
+            ))
             .expect("Failed to create adapter context.");
 
         let mut p_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
-            .expect("Failed to get magicavoxel parse adapter.")
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
             .create_adapter_context(())
             .expect("Failed to create adapter context.");
 
-        let mut s_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
-            .expect("Failed to get colored text serialize adapter.")
-            .create_adapter_context(s_config)
-            .expect("Failed to create adapter context.");
-
-        gvox_rs::blit_region(
-            Some(&mut i_ctx),
-            Some(&mut o_ctx),
+        gvox_rs::blit_region_parse_driven(
+            &mut i_ctx,
+            &mut o_ctx,
             &mut p_ctx,
             &mut s_ctx,
-            None, // Parse whole file!
-            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+            None,
+            gvox_rs::ChannelId::COLOR
+                | gvox_rs::ChannelId::NORMAL
+                | gvox_rs::ChannelId::MATERIAL_ID,
         )
         .expect("Error while translating.");
     }
 
     assert_eq!(
-        2892,
-        o_buffer.len(),
-        "Buffer output length did not match expected."
+        33342,
+        procedural_buffer.len(),
+        "Procedural buffer output length did not match expected."
     );
-    println!(
-        "{}",
-        std::str::from_utf8(&o_buffer).expect("Bad string slice.")
+    assert_eq!(
+        33342,
+        palette_buffer.len(),
+        "Palette buffer output length did not match expected."
     );
 }
 
 #[test]
-fn test_blit_error() {
-    let gvox_ctx = gvox_rs::Context::new();
+pub fn gvox_rs_test_blit_mixed_contexts() {
+    let ctx_a = gvox_rs::Context::new();
+    let ctx_b = gvox_rs::Context::new();
 
     let mut o_buffer = Box::default();
     let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
@@ -235,30 +217,57 @@ fn test_blit_error() {
         vertical: false,
     };
 
-    let mut i_ctx = gvox_ctx
+    let mut i_ctx = ctx_b
         .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
         .expect("Failed to get byte buffer input adapter.")
         .create_adapter_context(PALETTE_BYTES)
         .expect("Failed to create adapter context.");
 
-    let mut o_ctx = gvox_ctx
+    let mut o_ctx = ctx_a
         .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
-        .expect("Failed to get byte buffer input adapter.")
+        .expect("Failed to get byte buffer output adapter.")
         .create_adapter_context(o_config)
         .expect("Failed to create adapter context.");
 
-    let mut p_ctx = gvox_ctx
+    let mut p_ctx = ctx_a
         .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
-        .expect("Failed to get byte buffer input adapter.")
+        .expect("Failed to get gvox palette parse adapter.")
         .create_adapter_context(())
         .expect("Failed to create adapter context.");
 
-    let mut s_ctx = gvox_ctx
+    let mut s_ctx = ctx_b
         .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
-        .expect("Failed to get byte buffer input adapter.")
+        .expect("Failed to get colored text serialize adapter.")
         .create_adapter_context(s_config)
         .expect("Failed to create adapter context.");
 
+    let error = gvox_rs::blit_region(
+        &mut i_ctx,
+        &mut o_ctx,
+        &mut p_ctx,
+        &mut s_ctx,
+        None,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect_err("Blit with mixed contexts succeeded.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+
+    let error = ctx_b
+        .blit()
+        .input(&mut i_ctx)
+        .parse(&mut p_ctx)
+        .serialize(&mut s_ctx)
+        .channels(gvox_rs::ChannelId::COLOR)
+        .execute()
+        .expect_err("Blit with mixed contexts succeeded.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+pub fn gvox_rs_test_blit_region_chunked() {
+    let channels =
+        gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL | gvox_rs::ChannelId::MATERIAL_ID;
     let region = gvox_rs::RegionRange {
         offset: gvox_rs::Offset3D {
             x: -4,
@@ -268,73 +277,154 @@ fn test_blit_error() {
         extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
     };
 
-    let res = gvox_rs::blit_region(
-        Some(&mut i_ctx),
-        Some(&mut o_ctx),
-        &mut p_ctx,
-        &mut s_ctx,
-        Some(&region),
-        gvox_rs::ChannelId::TRANSPARENCY.into(),
-    )
-    .map_err(|e| e.error_type());
-
-    assert!(matches!(
-        res,
-        Err(gvox_rs::ErrorType::ParseAdapterRequestedChannelNotPresent)
-    ));
-}
+    let create_parse = |ctx: &gvox_rs::Context| {
+        ctx.get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()?
+            .create_adapter_context(procedural_parse::terrain())
+    };
+    let create_serialize = |ctx: &gvox_rs::Context| {
+        ctx.get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()?
+            .create_adapter_context(())
+    };
 
-pub struct CustomAdapter;
+    let mut o_buffer = Box::default();
+    {
+        let gvox_ctx = gvox_rs::Context::new();
 
-impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for CustomAdapter {
-    type Configuration<'a> = ();
-    type Handler = Self;
-}
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = create_parse(&gvox_ctx).expect("Failed to create adapter context.");
+        let mut s_ctx = create_serialize(&gvox_ctx).expect("Failed to create adapter context.");
 
-impl gvox_rs::NamedAdapter for CustomAdapter {
-    fn name() -> &'static str {
-        "palette_gvox_input_adapter"
+        gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, &region, channels)
+            .expect("Error while translating.");
     }
-}
 
-impl gvox_rs::BaseAdapterHandler<gvox_rs::Input, Self> for CustomAdapter {
-    fn create(config: &()) -> Result<Self, gvox_rs::GvoxError> {
-        Ok(Self)
-    }
+    let chunks = gvox_rs::blit_region_chunked(
+        &region,
+        gvox_rs::Extent3D { x: 3, y: 4, z: 5 },
+        channels,
+        |_| Ok(None),
+        create_parse,
+        create_serialize,
+    )
+    .expect("Error while translating chunks.");
 
-    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
-        Ok(())
-    }
-}
+    assert_eq!(
+        3 * 2 * 2,
+        chunks.len(),
+        "Chunk count did not match expected."
+    );
+    assert_eq!(
+        gvox_rs::Offset3D {
+            x: -1,
+            y: -4,
+            z: -4
+        },
+        chunks[1].range.offset,
+        "Chunks were not returned in x-fastest order."
+    );
 
-impl gvox_rs::InputAdapterHandler<CustomAdapter> for CustomAdapter {
-    fn read(
-        &mut self,
-        blit_ctx: &gvox_rs::InputBlitContext,
-        position: usize,
-        data: &mut [u8],
-    ) -> Result<(), gvox_rs::GvoxError> {
-        if position + data.len() <= PALETTE_BYTES.len() {
-            data.clone_from_slice(&PALETTE_BYTES[position..position + data.len()]);
-            Ok(())
-        } else {
-            Err(gvox_rs::GvoxError::new(
-                gvox_rs::ErrorType::InputAdapter,
-                "Tried reading past the end of the provided input buffer.",
-            ))
+    let voxel_size = channels.into_iter().count() * std::mem::size_of::<u32>();
+    let voxel_at = |bytes: &[u8], range: &gvox_rs::RegionRange, x: i32, y: i32, z: i32| {
+        let index = ((z - range.offset.z) as usize * range.extent.y as usize
+            + (y - range.offset.y) as usize)
+            * range.extent.x as usize
+            + (x - range.offset.x) as usize;
+        let start = gvox_rs::format::RAW_HEADER_SIZE + index * voxel_size;
+        bytes[start..start + voxel_size].to_vec()
+    };
+
+    for chunk in &chunks {
+        let gvox_rs::RegionRange { offset, extent } = chunk.range;
+        for z in offset.z..offset.z + extent.z as i32 {
+            for y in offset.y..offset.y + extent.y as i32 {
+                for x in offset.x..offset.x + extent.x as i32 {
+                    assert_eq!(
+                        voxel_at(&o_buffer, &region, x, y, z),
+                        voxel_at(&chunk.data, &chunk.range, x, y, z),
+                        "Chunked voxel did not match single blit at ({x}, {y}, {z})."
+                    );
+                }
+            }
         }
     }
 }
 
 #[test]
-pub fn gvox_rs_test_rust_adapter() {
+pub fn gvox_rs_test_blit_progress() {
     let mut o_buffer = Box::default();
+    let mut reports = Vec::new();
 
     {
         let gvox_ctx = gvox_rs::Context::new();
-        gvox_ctx.register_adapter::<gvox_rs::Input, CustomAdapter>();
 
-        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let region = gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: -4,
+                y: -4,
+                z: -4,
+            },
+            extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+        };
+
+        gvox_rs::blit_region_with_progress(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &region,
+            gvox_rs::ChannelId::COLOR.into(),
+            |done, total| reports.push((done, total)),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert!(!reports.is_empty(), "Progress callback was never invoked.");
+    for window in reports.windows(2) {
+        assert!(
+            window[0].0 <= window[1].0,
+            "Reported progress decreased from {} to {}.",
+            window[0].0,
+            window[1].0
+        );
+    }
+    for (done, total) in reports {
+        assert_eq!(512, total, "Reported total did not match the range volume.");
+        assert!(done <= total, "Reported progress exceeded the total.");
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_reset_adapter_context() {
+    let mut o_buffers: [Box<[u8]>; 2] = Default::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
 
         let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
             downscale_factor: 1,
@@ -343,22 +433,10 @@ pub fn gvox_rs_test_rust_adapter() {
             vertical: false,
         };
 
-        let mut i_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Input, CustomAdapter>()
-            .expect("Failed to get custom input adapter.")
-            .create_adapter_context(())
-            .expect("Failed to create adapter context.");
-
-        let mut o_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
-            .expect("Failed to get byte buffer output adapter.")
-            .create_adapter_context(o_config)
-            .expect("Failed to create adapter context.");
-
         let mut p_ctx = gvox_ctx
-            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
-            .expect("Failed to get gvox palette parse adapter.")
-            .create_adapter_context(())
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
             .expect("Failed to create adapter context.");
 
         let mut s_ctx = gvox_ctx
@@ -376,22 +454,8392 @@ pub fn gvox_rs_test_rust_adapter() {
             extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
         };
 
-        gvox_rs::blit_region(
-            Some(&mut i_ctx),
-            Some(&mut o_ctx),
-            &mut p_ctx,
-            &mut s_ctx,
-            Some(&region),
-            gvox_rs::ChannelId::COLOR
-                | gvox_rs::ChannelId::NORMAL
-                | gvox_rs::ChannelId::MATERIAL_ID,
-        )
-        .expect("Error while translating.");
+        for (index, o_buffer) in o_buffers.iter_mut().enumerate() {
+            if index > 0 {
+                s_ctx
+                    .reset::<gvox_rs::adapters::ColoredText>(s_config)
+                    .expect("Failed to reset adapter context.");
+            }
+
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                None,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                &region,
+                gvox_rs::ChannelId::COLOR
+                    | gvox_rs::ChannelId::NORMAL
+                    | gvox_rs::ChannelId::MATERIAL_ID,
+            )
+            .expect("Error while translating.");
+        }
+
+        let error = s_ctx
+            .reset::<gvox_rs::adapters::GvoxRaw>(())
+            .expect_err("Reset with a different adapter type succeeded.");
+        assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
     }
 
     assert_eq!(
         33342,
-        o_buffer.len(),
+        o_buffers[0].len(),
         "Buffer output length did not match expected."
     );
+    assert_eq!(
+        o_buffers[0], o_buffers[1],
+        "Output after reset did not match the first output."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_gvox_palette() {
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 5,
+            vertical: false,
+        };
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(PALETTE_BYTES)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+            .expect("Failed to get colored text serialize adapter.")
+            .create_adapter_context(s_config)
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            None, // Parse whole file!
+            gvox_rs::ChannelId::COLOR
+                | gvox_rs::ChannelId::NORMAL
+                | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        33342,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+    println!(
+        "{}",
+        std::str::from_utf8(&o_buffer).expect("Bad string slice.")
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_builder() {
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 5,
+            vertical: false,
+        };
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(PALETTE_BYTES)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+            .expect("Failed to get colored text serialize adapter.")
+            .create_adapter_context(s_config)
+            .expect("Failed to create adapter context.");
+
+        let missing = gvox_ctx
+            .blit()
+            .input(&mut i_ctx)
+            .parse(&mut p_ctx)
+            .execute()
+            .expect_err("Blit without a serialize adapter context succeeded.");
+        assert_eq!(gvox_rs::ErrorType::InvalidParameter, missing.error_type());
+
+        gvox_ctx
+            .blit()
+            .input(&mut i_ctx)
+            .output(&mut o_ctx)
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .channels(
+                gvox_rs::ChannelId::COLOR
+                    | gvox_rs::ChannelId::NORMAL
+                    | gvox_rs::ChannelId::MATERIAL_ID,
+            )
+            .mode(gvox_rs::BlitMode::ParseDriven)
+            .execute()
+            .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        33342,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_magicavoxel() {
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 254,
+            vertical: false,
+        };
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(MAGICAVOXEL_BYTES)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+            .expect("Failed to get magicavoxel parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+            .expect("Failed to get colored text serialize adapter.")
+            .create_adapter_context(s_config)
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            None, // Parse whole file!
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        2892,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+    println!(
+        "{}",
+        std::str::from_utf8(&o_buffer).expect("Bad string slice.")
+    );
+}
+
+#[test]
+fn test_blit_error() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut o_buffer = Box::default();
+    let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+    let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+        downscale_factor: 1,
+        downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+        non_color_max_value: 5,
+        vertical: false,
+    };
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.");
+
+    let mut o_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(o_config)
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(s_config)
+        .expect("Failed to create adapter context.");
+
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    let res = gvox_rs::blit_region(
+        Some(&mut i_ctx),
+        Some(&mut o_ctx),
+        &mut p_ctx,
+        &mut s_ctx,
+        Some(&region),
+        gvox_rs::ChannelId::TRANSPARENCY.into(),
+    )
+    .map_err(|e| e.error_type());
+
+    assert!(matches!(
+        res,
+        Err(gvox_rs::ErrorType::ParseAdapterRequestedChannelNotPresent)
+    ));
+}
+
+#[test]
+fn test_blit_related_errors() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let (o_config, _receiver) = gvox_rs::adapters::ChannelOutputAdapterConfig::new(
+        1,
+        gvox_rs::adapters::ChannelOutputBackPressure::Fail,
+    );
+
+    let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+        downscale_factor: 1,
+        downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+        non_color_max_value: 5,
+        vertical: false,
+    };
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.");
+
+    let mut o_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ChannelOutput>()
+        .expect("Failed to get channel output adapter.")
+        .create_adapter_context(o_config)
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get gvox palette parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+        .expect("Failed to get colored text serialize adapter.")
+        .create_adapter_context(s_config)
+        .expect("Failed to create adapter context.");
+
+    let error = gvox_rs::blit_region(
+        &mut i_ctx,
+        &mut o_ctx,
+        &mut p_ctx,
+        &mut s_ctx,
+        None,
+        gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::TRANSPARENCY,
+    )
+    .expect_err("Blit with failing adapters succeeded.");
+
+    assert!(
+        !error.related().is_empty(),
+        "Only one error was observable: {error}"
+    );
+    assert!(
+        gvox_ctx.drain_errors().is_empty(),
+        "Blit did not flush the context error stack."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_regions() {
+    let ranges = [
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: -4,
+                y: -4,
+                z: -4,
+            },
+            extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+        },
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+        },
+    ];
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut expected = Vec::new();
+    for range in &ranges {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, range, channels)
+                .expect("Error while translating.");
+        }
+        expected.extend_from_slice(&o_buffer);
+    }
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_regions(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, &ranges, channels)
+            .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        expected,
+        &o_buffer[..],
+        "Multi-range output did not match the separately blitted ranges."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_channel_remap() {
+    let duplicate = gvox_rs::channel_remap::ChannelRemap::new([
+        (gvox_rs::ChannelId::COLOR, gvox_rs::ChannelId::ROUGHNESS),
+        (
+            gvox_rs::ChannelId::MATERIAL_ID,
+            gvox_rs::ChannelId::ROUGHNESS,
+        ),
+    ])
+    .expect_err("Remapping two channels to one destination succeeded.");
+    assert_eq!(
+        gvox_rs::ErrorType::InvalidParameter,
+        duplicate.error_type(),
+        "Duplicate destination produced the wrong error type."
+    );
+
+    let remap = gvox_rs::channel_remap::ChannelRemap::new([(
+        gvox_rs::ChannelId::MATERIAL_ID,
+        gvox_rs::ChannelId::ROUGHNESS,
+    )])
+    .expect("Failed to create channel remap.");
+
+    type RemappedProcedural = gvox_rs::channel_remap::RemappedParse<gvox_rs::adapters::FnSampler>;
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter_named::<gvox_rs::Parse, RemappedProcedural>("procedural_remapped")
+        .expect("Failed to register remapped procedural parse adapter.");
+
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    let blit = |p_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Parse>,
+                channel_id: gvox_rs::ChannelId| {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                None,
+                &mut o_ctx,
+                p_ctx,
+                &mut s_ctx,
+                &region,
+                channel_id.into(),
+            )
+            .expect("Error while translating.");
+        }
+        o_buffer
+    };
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+    let original = blit(&mut p_ctx, gvox_rs::ChannelId::MATERIAL_ID);
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter_named::<gvox_rs::Parse, RemappedProcedural>("procedural_remapped")
+        .expect("Failed to get remapped procedural parse adapter.")
+        .create_adapter_context(gvox_rs::channel_remap::RemappedParseAdapterConfig {
+            remap,
+            config: procedural_parse::terrain(),
+        })
+        .expect("Failed to create adapter context.");
+    let remapped = blit(&mut p_ctx, gvox_rs::ChannelId::ROUGHNESS);
+
+    let header = gvox_rs::format::read_header(&remapped).expect("Failed to read remapped header.");
+    assert_eq!(
+        gvox_rs::ChannelFlags::from(gvox_rs::ChannelId::ROUGHNESS),
+        header.channels(),
+        "Remapped output did not store the destination channel."
+    );
+    assert_eq!(
+        &original[gvox_rs::format::RAW_HEADER_SIZE..],
+        &remapped[gvox_rs::format::RAW_HEADER_SIZE..],
+        "Remapped roughness did not match the original material IDs."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_on_worker_thread() {
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+    let (o_config, receiver) = gvox_rs::adapters::ChannelOutputAdapterConfig::new(
+        usize::MAX,
+        gvox_rs::adapters::ChannelOutputBackPressure::Block,
+    );
+
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.");
+
+    let mut o_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ChannelOutput>()
+        .expect("Failed to get channel output adapter.")
+        .create_adapter_context(o_config)
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get gvox palette parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    std::thread::spawn(move || {
+        gvox_rs::blit_region(
+            &mut i_ctx, &mut o_ctx, &mut p_ctx, &mut s_ctx, None, channels,
+        )
+    })
+    .join()
+    .expect("Worker thread panicked.")
+    .expect("Error while translating.");
+
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        channels,
+    )
+    .expect("Failed to convert on the current thread.");
+    assert_eq!(
+        expected,
+        receiver.assemble(),
+        "Output blitted on a worker thread did not match."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_shared_context_threads() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let workers = (0..2)
+        .map(|_| {
+            let gvox_ctx = gvox_ctx.clone();
+            std::thread::spawn(move || {
+                let mut o_buffer = Box::default();
+                {
+                    let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+                        downscale_factor: 1,
+                        downscale_mode:
+                            gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+                        non_color_max_value: 5,
+                        vertical: false,
+                    };
+
+                    let mut o_ctx = gvox_ctx
+                        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                        .expect("Failed to get byte buffer output adapter.")
+                        .create_adapter_context(
+                            gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer),
+                        )
+                        .expect("Failed to create adapter context.");
+
+                    let mut p_ctx = gvox_ctx
+                        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                        .expect("Failed to get function sampler parse adapter.")
+                        .create_adapter_context(procedural_parse::terrain())
+                        .expect("Failed to create adapter context.");
+
+                    let mut s_ctx = gvox_ctx
+                        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+                        .expect("Failed to get colored text serialize adapter.")
+                        .create_adapter_context(s_config)
+                        .expect("Failed to create adapter context.");
+
+                    let region = gvox_rs::RegionRange {
+                        offset: gvox_rs::Offset3D {
+                            x: -4,
+                            y: -4,
+                            z: -4,
+                        },
+                        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+                    };
+
+                    gvox_rs::blit_region(
+                        None,
+                        &mut o_ctx,
+                        &mut p_ctx,
+                        &mut s_ctx,
+                        &region,
+                        gvox_rs::ChannelId::COLOR
+                            | gvox_rs::ChannelId::NORMAL
+                            | gvox_rs::ChannelId::MATERIAL_ID,
+                    )
+                    .expect("Error while translating.");
+                }
+                o_buffer
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        let o_buffer = worker.join().expect("Worker thread panicked.");
+        assert_eq!(
+            33342,
+            o_buffer.len(),
+            "Buffer output length did not match expected."
+        );
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_raw_context_round_trip() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter_named::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>("procedural")
+        .expect("Failed to register procedural parse adapter.");
+
+    let clone = gvox_ctx.clone();
+    let gvox_ctx = gvox_ctx
+        .into_raw()
+        .expect_err("Released a context which was still in use.");
+    drop(clone);
+    let ptr = gvox_ctx.into_raw().expect("Failed to release context.");
+
+    let gvox_ctx =
+        unsafe { gvox_rs::Context::from_raw(ptr, true) }.expect("Failed to wrap raw context.");
+    let p_adapter = unsafe {
+        gvox_rs::Adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>::from_raw(
+            &gvox_ctx,
+            gvox_ctx.with_raw(|ptr| gvox_sys::gvox_get_parse_adapter(ptr, cstr!("procedural"))),
+            "procedural",
+        )
+    }
+    .expect("Failed to wrap raw procedural parse adapter.");
+
+    let mut o_buffer = Box::default();
+    {
+        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 5,
+            vertical: false,
+        };
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = p_adapter
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+            .expect("Failed to get colored text serialize adapter.")
+            .create_adapter_context(s_config)
+            .expect("Failed to create adapter context.");
+
+        let region = gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: -4,
+                y: -4,
+                z: -4,
+            },
+            extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+        };
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &region,
+            gvox_rs::ChannelId::COLOR
+                | gvox_rs::ChannelId::NORMAL
+                | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        33342,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+    assert!(
+        gvox_ctx
+            .get_adapter_named::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>("procedural")
+            .is_ok(),
+        "Wrapped adapter could not be retrieved by name."
+    );
+}
+
+#[test]
+#[cfg(feature = "raw-sys")]
+pub fn gvox_rs_test_raw_sys() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let i_ptr = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.")
+        .into_raw()
+        .expect("Failed to release adapter context.");
+    let mut i_ctx = unsafe {
+        gvox_rs::AdapterContext::from_raw(
+            &gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter."),
+            i_ptr,
+        )
+    };
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    let result = gvox_ctx.with_raw(|ptr| unsafe { gvox_rs::sys::gvox_get_result(ptr) });
+    assert_eq!(
+        gvox_rs::sys::GvoxResult_GVOX_RESULT_SUCCESS,
+        result,
+        "Native error stack was not empty after the blit."
+    );
+
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+    assert_eq!(
+        expected,
+        &o_buffer[..],
+        "Blit with a rewrapped input did not match."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_adapter_context_owns_config() {
+    let file_name = std::env::temp_dir().join("gvox_rs_test_adapter_context_owns_config.gvox");
+    std::fs::write(&file_name, PALETTE_BYTES).expect("Failed to write temporary file.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut i_ctx = {
+        let config = gvox_rs::adapters::FileInputAdapterConfig::new(
+            file_name.to_string_lossy().into_owned(),
+            0,
+        );
+        gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::File>()
+            .expect("Failed to get file input adapter.")
+            .create_adapter_context(config)
+            .expect("Failed to create adapter context.")
+    };
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    drop(i_ctx);
+    std::fs::remove_file(&file_name).expect("Failed to remove temporary file.");
+
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+    assert_eq!(
+        expected,
+        &o_buffer[..],
+        "Blit from a file whose configuration was dropped did not match."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_warnings() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+    };
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &range,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+    assert!(
+        gvox_ctx.take_warnings().is_empty(),
+        "Blitting generated channels produced warnings."
+    );
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &range,
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::ROUGHNESS,
+        )
+        .expect("Error while translating.");
+    }
+    let warnings = gvox_ctx.take_warnings();
+    assert!(
+        warnings
+            .iter()
+            .any(|x| x.error_type() == gvox_rs::ErrorType::ParseAdapterRequestedChannelNotPresent),
+        "Blitting a channel that is not generated did not produce a warning."
+    );
+    assert!(
+        gvox_ctx.take_warnings().is_empty(),
+        "Taking warnings did not clear them."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_convert() {
+    let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+        downscale_factor: 1,
+        downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+        non_color_max_value: 254,
+        vertical: false,
+    };
+
+    let o_buffer = gvox_rs::convert(
+        MAGICAVOXEL_BYTES,
+        gvox_rs::ParseFormat::MagicaVoxel,
+        gvox_rs::SerializeFormat::ColoredText(s_config),
+        gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+    )
+    .expect("Error while converting.");
+
+    assert_eq!(
+        2892,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+}
+
+pub struct CustomAdapter;
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for CustomAdapter {
+    type Configuration<'a> = ();
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for CustomAdapter {
+    fn name() -> &'static str {
+        "palette_gvox_input_adapter"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Input, Self> for CustomAdapter {
+    fn create(config: &()) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self)
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::InputAdapterHandler<CustomAdapter> for CustomAdapter {
+    fn read(
+        &mut self,
+        blit_ctx: &gvox_rs::InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), gvox_rs::GvoxError> {
+        if position + data.len() <= PALETTE_BYTES.len() {
+            data.clone_from_slice(&PALETTE_BYTES[position..position + data.len()]);
+            Ok(())
+        } else {
+            Err(gvox_rs::GvoxError::new(
+                gvox_rs::ErrorType::InputAdapter,
+                "Tried reading past the end of the provided input buffer.",
+            ))
+        }
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_rust_adapter() {
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx.register_adapter::<gvox_rs::Input, CustomAdapter>();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 5,
+            vertical: false,
+        };
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, CustomAdapter>()
+            .expect("Failed to get custom input adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+            .expect("Failed to get colored text serialize adapter.")
+            .create_adapter_context(s_config)
+            .expect("Failed to create adapter context.");
+
+        let region = gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: -4,
+                y: -4,
+                z: -4,
+            },
+            extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+        };
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            Some(&region),
+            gvox_rs::ChannelId::COLOR
+                | gvox_rs::ChannelId::NORMAL
+                | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        33342,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+}
+
+pub struct SlowInputAdapter {
+    started: Option<std::sync::mpsc::Sender<()>>,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for SlowInputAdapter {
+    type Configuration<'a> = std::sync::mpsc::Sender<()>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for SlowInputAdapter {
+    fn name() -> &'static str {
+        "slow_palette_gvox_input_adapter"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Input, Self> for SlowInputAdapter {
+    fn create(config: &std::sync::mpsc::Sender<()>) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            started: Some(config.clone()),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::InputAdapterHandler<SlowInputAdapter> for SlowInputAdapter {
+    fn read(
+        &mut self,
+        blit_ctx: &gvox_rs::InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), gvox_rs::GvoxError> {
+        if let Some(started) = self.started.take() {
+            let _ = started.send(());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(100));
+
+        if position + data.len() <= PALETTE_BYTES.len() {
+            data.clone_from_slice(&PALETTE_BYTES[position..position + data.len()]);
+            Ok(())
+        } else {
+            Err(gvox_rs::GvoxError::new(
+                gvox_rs::ErrorType::InputAdapter,
+                "Tried reading past the end of the provided input buffer.",
+            ))
+        }
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_runtime_named_adapters() {
+    let names = ["custom_a", "custom_b"];
+
+    let gvox_ctx = gvox_rs::Context::new();
+    for name in names {
+        gvox_ctx
+            .register_adapter_named::<gvox_rs::Input, CustomAdapter>(name)
+            .expect("Failed to register custom input adapter.");
+    }
+
+    let duplicate = gvox_ctx
+        .register_adapter_named::<gvox_rs::Input, CustomAdapter>(names[0])
+        .err()
+        .expect("Registered two adapters under the same name.");
+    assert_eq!(
+        gvox_rs::ErrorType::InvalidParameter,
+        duplicate.error_type(),
+        "Duplicate name produced the wrong error type."
+    );
+
+    let mismatched = gvox_ctx
+        .get_adapter_by_name::<gvox_rs::Input>(names[0])
+        .expect("Failed to get custom input adapter by name.")
+        .downcast::<gvox_rs::adapters::ByteBuffer>()
+        .err()
+        .expect("Downcast an adapter to the wrong type.");
+    assert_eq!(
+        gvox_rs::ErrorType::InvalidParameter,
+        mismatched.error_type(),
+        "Mismatched downcast produced the wrong error type."
+    );
+
+    for name in names {
+        let mut o_buffer = Box::default();
+        {
+            let adapter = gvox_ctx
+                .get_adapter_by_name::<gvox_rs::Input>(name)
+                .expect("Failed to get custom input adapter by name.");
+            assert_eq!(name, adapter.name(), "Adapter name did not match.");
+
+            let mut i_ctx = adapter
+                .downcast::<CustomAdapter>()
+                .expect("Failed to downcast custom input adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+                .expect("Failed to get colored text serialize adapter.")
+                .create_adapter_context(gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+                    downscale_factor: 1,
+                    downscale_mode:
+                        gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+                    non_color_max_value: 5,
+                    vertical: false,
+                })
+                .expect("Failed to create adapter context.");
+
+            let region = gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D {
+                    x: -4,
+                    y: -4,
+                    z: -4,
+                },
+                extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+            };
+
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                Some(&region),
+                gvox_rs::ChannelId::COLOR
+                    | gvox_rs::ChannelId::NORMAL
+                    | gvox_rs::ChannelId::MATERIAL_ID,
+            )
+            .expect("Error while translating.");
+        }
+
+        assert_eq!(
+            33342,
+            o_buffer.len(),
+            "Buffer output length did not match expected."
+        );
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_get_or_register_adapter() {
+    let registrations = |gvox_ctx: &gvox_rs::Context, name: &str| {
+        gvox_ctx
+            .adapter_names(gvox_rs::AdapterKindId::Input)
+            .iter()
+            .filter(|x| *x == name)
+            .count()
+    };
+
+    let gvox_ctx = gvox_rs::Context::new();
+    for _ in 0..2 {
+        gvox_ctx
+            .get_or_register_adapter::<gvox_rs::Input, CustomAdapter>()
+            .expect("Failed to get or register custom input adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+    }
+    assert_eq!(
+        1,
+        registrations(&gvox_ctx, "palette_gvox_input_adapter"),
+        "Repeated calls registered the adapter more than once."
+    );
+
+    let workers = (0..2)
+        .map(|_| {
+            let gvox_ctx = gvox_ctx.clone();
+            std::thread::spawn(move || {
+                gvox_ctx
+                    .get_or_register_adapter_named::<gvox_rs::Input, CustomAdapter>("custom_shared")
+                    .expect("Failed to get or register custom input adapter.")
+                    .create_adapter_context(())
+                    .expect("Failed to create adapter context.");
+            })
+        })
+        .collect::<Vec<_>>();
+    for worker in workers {
+        worker.join().expect("Worker thread panicked.");
+    }
+    assert_eq!(
+        1,
+        registrations(&gvox_ctx, "custom_shared"),
+        "Concurrent calls registered the adapter more than once."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_fn_input() {
+    let owned_bytes = PALETTE_BYTES.to_vec();
+    let sources: [&[u8]; 2] = [PALETTE_BYTES, &owned_bytes];
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let mut i_ctxs = sources.map(|bytes| {
+        gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::FnInput>()
+            .expect("Failed to get function input adapter.")
+            .create_adapter_context(gvox_rs::adapters::FnInputAdapterConfig::new(
+                move |position, data| {
+                    data.copy_from_slice(bytes.get(position..position + data.len()).ok_or_else(
+                        || {
+                            gvox_rs::GvoxError::new(
+                                gvox_rs::ErrorType::InputAdapter,
+                                "Tried reading past the end of the provided input buffer.",
+                            )
+                        },
+                    )?);
+                    Ok(())
+                },
+            ))
+            .expect("Failed to create adapter context.")
+    });
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get gvox palette parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+        .expect("Failed to get colored text serialize adapter.")
+        .create_adapter_context(gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 5,
+            vertical: false,
+        })
+        .expect("Failed to create adapter context.");
+
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    let mut outputs = Vec::new();
+    for i_ctx in &mut i_ctxs {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                Some(&region),
+                gvox_rs::ChannelId::COLOR
+                    | gvox_rs::ChannelId::NORMAL
+                    | gvox_rs::ChannelId::MATERIAL_ID,
+            )
+            .expect("Error while translating.");
+        }
+        outputs.push(o_buffer);
+    }
+
+    assert_eq!(
+        33342,
+        outputs[0].len(),
+        "Buffer output length did not match expected."
+    );
+    assert_eq!(
+        outputs[0], outputs[1],
+        "Function inputs with the same data produced different output."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_fn_output() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get gvox palette parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            Some(&region),
+            channels,
+        )
+        .expect("Error while translating.");
+    }
+
+    let mut writes = std::collections::BTreeMap::<usize, Vec<u8>>::new();
+    let mut reserved = 0;
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::FnOutput>()
+            .expect("Failed to get function output adapter.")
+            .create_adapter_context(gvox_rs::adapters::FnOutputAdapterConfig::new(
+                |position, data| {
+                    writes.insert(position, data.to_vec());
+                    Ok(())
+                },
+                |size| {
+                    reserved = reserved.max(size);
+                    Ok(())
+                },
+            ))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            Some(&region),
+            channels,
+        )
+        .expect("Error while translating.");
+    }
+
+    let mut flattened = Vec::with_capacity(reserved);
+    for (position, data) in &writes {
+        let end = position + data.len();
+        if flattened.len() < end {
+            flattened.resize(end, 0);
+        }
+        flattened[*position..end].copy_from_slice(data);
+    }
+
+    assert_eq!(
+        &o_buffer[..],
+        &flattened[..],
+        "Function output did not match byte buffer output."
+    );
+}
+
+pub struct ContextAwareAdapter;
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for ContextAwareAdapter {
+    type Configuration<'a> = std::sync::mpsc::Sender<gvox_rs::Context>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for ContextAwareAdapter {
+    fn name() -> &'static str {
+        "context_aware_input_adapter"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Input, Self> for ContextAwareAdapter {
+    fn create(_: &std::sync::mpsc::Sender<gvox_rs::Context>) -> Result<Self, gvox_rs::GvoxError> {
+        Err(gvox_rs::GvoxError::new(
+            gvox_rs::ErrorType::InvalidParameter,
+            "Context aware adapter was created without a context.",
+        ))
+    }
+
+    fn create_with_context(
+        ctx: &gvox_rs::Context,
+        config: &std::sync::mpsc::Sender<gvox_rs::Context>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        let palette = ctx.get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()?;
+        let _ = config.send(palette.context());
+        Ok(Self)
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::InputAdapterHandler<ContextAwareAdapter> for ContextAwareAdapter {
+    fn read(
+        &mut self,
+        _: &gvox_rs::InputBlitContext,
+        _: usize,
+        data: &mut [u8],
+    ) -> Result<(), gvox_rs::GvoxError> {
+        data.fill(0);
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_create_with_context() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let adapter = gvox_ctx
+        .register_adapter::<gvox_rs::Input, ContextAwareAdapter>()
+        .expect("Failed to register context aware input adapter.");
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let _i_ctx = adapter
+        .create_adapter_context(sender)
+        .expect("Failed to create adapter context.");
+
+    let palette_ctx = receiver
+        .try_recv()
+        .expect("Adapter handler did not look up the palette adapter.");
+    assert_eq!(
+        gvox_ctx, palette_ctx,
+        "Adapter handler was created with a different context."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_cancel_blit() {
+    let cancelled = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let (started_send, started_recv) = std::sync::mpsc::channel();
+
+    let canceller = {
+        let cancelled = cancelled.clone();
+        std::thread::spawn(move || {
+            started_recv
+                .recv()
+                .expect("Blit finished without reading its input.");
+            cancelled.store(true, std::sync::atomic::Ordering::Release);
+        })
+    };
+
+    let mut o_buffer = Box::default();
+    let start = std::time::Instant::now();
+    let res = {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let mut i_ctx = gvox_ctx
+            .register_adapter::<gvox_rs::Input, SlowInputAdapter>()
+            .expect("Failed to register slow input adapter.")
+            .create_adapter_context(started_send)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region_cancellable(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+            &cancelled,
+        )
+    };
+
+    canceller.join().expect("Canceller thread panicked.");
+    assert_eq!(
+        Some(gvox_rs::ErrorType::Cancelled),
+        res.err().map(|e| e.error_type()),
+        "Blit did not fail with a cancellation error."
+    );
+    assert!(
+        start.elapsed() < std::time::Duration::from_secs(5),
+        "Cancelled blit did not terminate promptly."
+    );
+}
+
+pub struct ParseDrivenAdapter {
+    parsed: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for ParseDrivenAdapter {
+    type Configuration<'a> = std::sync::Arc<std::sync::atomic::AtomicUsize>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for ParseDrivenAdapter {
+    fn name() -> &'static str {
+        "parse_driven_uniform"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for ParseDrivenAdapter {
+    fn create(
+        config: &std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            parsed: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::ParseAdapterHandler<Self> for ParseDrivenAdapter {
+    type RegionData = ();
+
+    fn query_details() -> gvox_rs::ParseAdapterDetails {
+        gvox_rs::ParseAdapterDetails::new(gvox_rs::BlitMode::ParseDriven)
+    }
+
+    fn query_parsable_range(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+    ) -> gvox_rs::RegionRange {
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 2, y: 2, z: 2 },
+        }
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
+        Ok(gvox_rs::RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::Region<()>, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Region::builder()
+            .range(*range)
+            .channels(channel_flags & gvox_rs::ChannelId::COLOR)
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: gvox_rs::Region<()>,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: &gvox_rs::Region<()>,
+        offset: &gvox_rs::Offset3D,
+        channel_id: gvox_rs::ChannelId,
+    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Sample {
+            data: 0xff0000ff,
+            is_present: true,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        self.parsed
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_parse_driven_adapter() {
+    assert_eq!(
+        gvox_rs::BlitMode::DontCare,
+        gvox_rs::ParseAdapterDetails::default().preferred_blit_mode(),
+        "Default parse adapter details did not leave the blit mode to the serializer."
+    );
+
+    let parsed = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx
+            .register_adapter::<gvox_rs::Parse, ParseDrivenAdapter>()
+            .expect("Failed to register parse-driven adapter.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, ParseDrivenAdapter>()
+            .expect("Failed to get parse-driven adapter.")
+            .create_adapter_context(parsed.clone())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert!(
+        parsed.load(std::sync::atomic::Ordering::Relaxed) > 0,
+        "Default blit did not use the parse-driven path."
+    );
+    assert_eq!(
+        gvox_rs::format::RAW_HEADER_SIZE + 8 * std::mem::size_of::<u32>(),
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+}
+
+pub struct SerializeDrivenAdapter {
+    written: usize,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Serialize> for SerializeDrivenAdapter {
+    type Configuration<'a> = ();
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for SerializeDrivenAdapter {
+    fn name() -> &'static str {
+        "serialize_driven_colors"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Serialize, Self> for SerializeDrivenAdapter {
+    fn create(_: &()) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self { written: 0 })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::SerializeAdapterHandler<Self> for SerializeDrivenAdapter {
+    type RegionData = ();
+
+    fn query_details() -> gvox_rs::SerializeAdapterDetails {
+        gvox_rs::SerializeAdapterDetails::new(gvox_rs::BlitMode::SerializeDriven)
+    }
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &gvox_rs::SerializeBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags)?;
+        for z in 0..range.extent.z as i32 {
+            for y in 0..range.extent.y as i32 {
+                for x in 0..range.extent.x as i32 {
+                    let offset = gvox_rs::Offset3D {
+                        x: range.offset.x + x,
+                        y: range.offset.y + y,
+                        z: range.offset.z + z,
+                    };
+                    let sample = region.sample(&offset, gvox_rs::ChannelId::COLOR)?;
+                    blit_ctx.output_write(self.written, &sample.data.to_le_bytes())?;
+                    self.written += std::mem::size_of::<u32>();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_default_receive_region() {
+    assert_eq!(
+        gvox_rs::BlitMode::DontCare,
+        gvox_rs::SerializeAdapterDetails::default().preferred_blit_mode(),
+        "Default serialize adapter details declared a blit mode."
+    );
+
+    let parsed = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx
+            .register_adapter::<gvox_rs::Parse, ParseDrivenAdapter>()
+            .expect("Failed to register parse-driven adapter.");
+        gvox_ctx
+            .register_adapter::<gvox_rs::Serialize, SerializeDrivenAdapter>()
+            .expect("Failed to register serialize-driven adapter.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, ParseDrivenAdapter>()
+            .expect("Failed to get parse-driven adapter.")
+            .create_adapter_context(parsed.clone())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, SerializeDrivenAdapter>()
+            .expect("Failed to get serialize-driven adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert!(
+        parsed.load(std::sync::atomic::Ordering::Relaxed) > 0,
+        "Blit did not use the parse-driven path."
+    );
+    assert_eq!(
+        [0xff0000ffu32; 8]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>(),
+        &o_buffer[..],
+        "Received regions were not serialized."
+    );
+}
+
+pub struct FooterAdapter {
+    color: u32,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for FooterAdapter {
+    type Configuration<'a> = ();
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for FooterAdapter {
+    fn name() -> &'static str {
+        "footer"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for FooterAdapter {
+    fn create(_: &()) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self { color: 0 })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        _: Option<&gvox_rs::RegionRange>,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let size = blit_ctx.input_size().ok_or_else(|| {
+            gvox_rs::GvoxError::new(
+                gvox_rs::ErrorType::InvalidParameter,
+                "The size of the input was not known.",
+            )
+        })?;
+        self.color = blit_ctx.read_u32_le(size - 4)?;
+        Ok(())
+    }
+}
+
+impl gvox_rs::ParseAdapterHandler<Self> for FooterAdapter {
+    type RegionData = ();
+
+    fn query_details() -> gvox_rs::ParseAdapterDetails {
+        gvox_rs::ParseAdapterDetails::new(gvox_rs::BlitMode::SerializeDriven)
+    }
+
+    fn query_parsable_range(&mut self, _: &gvox_rs::ParseBlitContext) -> gvox_rs::RegionRange {
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+        }
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        _: &gvox_rs::RegionRange,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
+        Ok(gvox_rs::RegionFlags::UNIFORM)
+    }
+
+    fn load_region(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::Region<()>, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Region::uniform(
+            *range,
+            channel_flags & gvox_rs::ChannelId::COLOR,
+            (),
+        ))
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        _: gvox_rs::Region<()>,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        _: &gvox_rs::Region<()>,
+        _: &gvox_rs::Offset3D,
+        _: gvox_rs::ChannelId,
+    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Sample {
+            data: self.color,
+            is_present: true,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_input_size() {
+    let color = 0xff00ff00u32;
+    let mut bytes = vec![0xab; 100];
+    bytes.extend_from_slice(&[0; 12]);
+    bytes.extend_from_slice(&color.to_le_bytes());
+
+    let file_name = std::env::temp_dir().join("gvox_rs_test_input_size.bin");
+    std::fs::write(&file_name, &bytes).expect("Failed to write temporary file.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, FooterAdapter>()
+        .expect("Failed to register footer parse adapter.");
+    gvox_ctx
+        .get_or_register_adapter::<gvox_rs::Serialize, SerializeDrivenAdapter>()
+        .expect("Failed to register serialize-driven adapter.");
+
+    let inputs = [
+        gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(&bytes[..])
+            .expect("Failed to create adapter context."),
+        gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::File>()
+            .expect("Failed to get file input adapter.")
+            .create_adapter_context(gvox_rs::adapters::FileInputAdapterConfig::new(
+                file_name.to_string_lossy().into_owned(),
+                0,
+            ))
+            .expect("Failed to create adapter context."),
+    ];
+
+    for mut i_ctx in inputs {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, FooterAdapter>()
+                .expect("Failed to get footer parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, SerializeDrivenAdapter>()
+                .expect("Failed to get serialize-driven adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                &mut i_ctx,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+
+        assert_eq!(
+            &color.to_le_bytes(),
+            &o_buffer[..],
+            "Footer was not read from the end of the input."
+        );
+    }
+
+    std::fs::remove_file(&file_name).expect("Failed to remove temporary file.");
+}
+
+type ReadProbeResults = std::sync::Arc<std::sync::Mutex<Vec<Result<Vec<u8>, gvox_rs::ErrorType>>>>;
+
+pub struct ReadProbeAdapter {
+    results: ReadProbeResults,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for ReadProbeAdapter {
+    type Configuration<'a> = ReadProbeResults;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for ReadProbeAdapter {
+    fn name() -> &'static str {
+        "read_probe"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for ReadProbeAdapter {
+    fn create(config: &ReadProbeResults) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            results: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        _: Option<&gvox_rs::RegionRange>,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let mut results = self.results.lock().expect("Failed to lock results.");
+        results.extend(
+            [
+                blit_ctx.read_vec(4, 4),
+                blit_ctx.read_vec(5, 4),
+                blit_ctx.read_vec(8, 0),
+                blit_ctx.read_exact_array::<1>(8).map(Vec::from),
+                blit_ctx.read_u32_le(4).map(|x| x.to_be_bytes().into()),
+                blit_ctx.read_i32_le(0).map(|x| x.to_be_bytes().into()),
+                blit_ctx
+                    .read_f32_le(usize::MAX)
+                    .map(|x| x.to_be_bytes().into()),
+            ]
+            .map(|x| x.map_err(|e| e.error_type())),
+        );
+        Ok(())
+    }
+}
+
+impl gvox_rs::ParseAdapterHandler<Self> for ReadProbeAdapter {
+    type RegionData = ();
+
+    fn query_details() -> gvox_rs::ParseAdapterDetails {
+        gvox_rs::ParseAdapterDetails::default()
+    }
+
+    fn query_parsable_range(&mut self, _: &gvox_rs::ParseBlitContext) -> gvox_rs::RegionRange {
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+        }
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        _: &gvox_rs::RegionRange,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
+        Ok(gvox_rs::RegionFlags::UNIFORM)
+    }
+
+    fn load_region(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::Region<()>, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Region::new(
+            *range,
+            gvox_rs::ChannelFlags::empty(),
+            gvox_rs::RegionFlags::UNIFORM,
+            (),
+        ))
+    }
+
+    fn unload_region(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        _: gvox_rs::Region<()>,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &gvox_rs::ParseBlitContext,
+        _: &gvox_rs::Region<()>,
+        _: &gvox_rs::Offset3D,
+        _: gvox_rs::ChannelId,
+    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Sample {
+            data: 0,
+            is_present: false,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_read_helpers() {
+    let bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+    let results = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, ReadProbeAdapter>()
+        .expect("Failed to register read probe adapter.");
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(&bytes[..])
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, ReadProbeAdapter>()
+        .expect("Failed to get read probe adapter.")
+        .create_adapter_context(results.clone())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    gvox_rs::blit_region(
+        &mut i_ctx,
+        None,
+        &mut p_ctx,
+        &mut s_ctx,
+        None,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Error while translating.");
+
+    assert_eq!(
+        vec![
+            Ok(vec![4, 5, 6, 7]),
+            Err(gvox_rs::ErrorType::InputAdapter),
+            Ok(vec![]),
+            Err(gvox_rs::ErrorType::InputAdapter),
+            Ok(vec![7, 6, 5, 4]),
+            Ok(vec![3, 2, 1, 0]),
+            Err(gvox_rs::ErrorType::InputAdapter),
+        ],
+        *results.lock().expect("Failed to lock results."),
+        "Reads at the end of the input did not succeed or fail as expected."
+    );
+}
+
+type SampleComparisons =
+    std::sync::Arc<std::sync::Mutex<Vec<(Vec<gvox_rs::Sample>, Vec<gvox_rs::Sample>)>>>;
+
+pub struct SampleComparisonAdapter {
+    comparisons: SampleComparisons,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Serialize> for SampleComparisonAdapter {
+    type Configuration<'a> = SampleComparisons;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for SampleComparisonAdapter {
+    fn name() -> &'static str {
+        "sample_comparison"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Serialize, Self> for SampleComparisonAdapter {
+    fn create(config: &SampleComparisons) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            comparisons: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::SerializeAdapterHandler<Self> for SampleComparisonAdapter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &gvox_rs::SerializeBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags)?;
+
+        let mut single = Vec::new();
+        for z in 0..range.extent.z as i32 {
+            for y in 0..range.extent.y as i32 {
+                for x in 0..range.extent.x as i32 {
+                    let offset = gvox_rs::Offset3D {
+                        x: range.offset.x + x,
+                        y: range.offset.y + y,
+                        z: range.offset.z + z,
+                    };
+                    single.push(region.sample(&offset, gvox_rs::ChannelId::COLOR)?);
+                }
+            }
+        }
+        let batch = region.sample_range(range, gvox_rs::ChannelId::COLOR)?;
+
+        self.comparisons
+            .lock()
+            .expect("Failed to lock comparisons.")
+            .push((single, batch));
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_sample_range() {
+    let mut footer = vec![0; 12];
+    footer.extend_from_slice(&0xff00ff00u32.to_le_bytes());
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, FooterAdapter>()
+        .expect("Failed to register footer parse adapter.");
+    gvox_ctx
+        .register_adapter::<gvox_rs::Serialize, SampleComparisonAdapter>()
+        .expect("Failed to register sample comparison adapter.");
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(&footer[..])
+        .expect("Failed to create adapter context.");
+
+    let parsers = [
+        gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context."),
+        gvox_ctx
+            .get_adapter::<gvox_rs::Parse, FooterAdapter>()
+            .expect("Failed to get footer parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context."),
+    ];
+
+    for mut p_ctx in parsers {
+        let comparisons = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, SampleComparisonAdapter>()
+            .expect("Failed to get sample comparison adapter.")
+            .create_adapter_context(comparisons.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_ctx
+            .blit()
+            .input(&mut i_ctx)
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR)
+            .mode(gvox_rs::BlitMode::SerializeDriven)
+            .execute()
+            .expect("Error while translating.");
+
+        let comparisons = comparisons.lock().expect("Failed to lock comparisons.");
+        assert!(!comparisons.is_empty(), "No regions were serialized.");
+        for (single, batch) in comparisons.iter() {
+            assert_eq!(
+                range.volume() as usize,
+                batch.len(),
+                "Batch sampling returned the wrong number of samples."
+            );
+            assert_eq!(
+                single, batch,
+                "Batch sampling did not match per-voxel sampling."
+            );
+        }
+    }
+}
+
+pub struct DenseExtraction {
+    dense: gvox_rs::DenseRegion,
+    samples: Vec<(gvox_rs::Offset3D, gvox_rs::Sample)>,
+    mismatched: Option<gvox_rs::ErrorType>,
+}
+
+type DenseExtractions = std::sync::Arc<std::sync::Mutex<Vec<DenseExtraction>>>;
+
+pub struct DenseExtractionAdapter {
+    extractions: DenseExtractions,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Serialize> for DenseExtractionAdapter {
+    type Configuration<'a> = DenseExtractions;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for DenseExtractionAdapter {
+    fn name() -> &'static str {
+        "dense_extraction"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Serialize, Self> for DenseExtractionAdapter {
+    fn create(config: &DenseExtractions) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            extractions: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::SerializeAdapterHandler<Self> for DenseExtractionAdapter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &gvox_rs::SerializeBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags)?;
+        let dense = region.to_dense(gvox_rs::ChannelId::COLOR, 0xdeadbeef)?;
+
+        let corner = region.range().offset;
+        let samples = [(0, 0, 0), (1, 2, 3), (7, 7, 7), (3, 0, 6)]
+            .into_iter()
+            .map(|(x, y, z)| gvox_rs::Offset3D {
+                x: corner.x + x,
+                y: corner.y + y,
+                z: corner.z + z,
+            })
+            .filter(|offset| region.range().contains(offset))
+            .map(|offset| Ok((offset, region.sample(&offset, gvox_rs::ChannelId::COLOR)?)))
+            .collect::<Result<_, gvox_rs::GvoxError>>()?;
+
+        let mismatched = region
+            .read_into(gvox_rs::ChannelId::COLOR, 0, &mut [0; 7])
+            .err()
+            .map(|e| e.error_type());
+
+        self.extractions
+            .lock()
+            .expect("Failed to lock extractions.")
+            .push(DenseExtraction {
+                dense,
+                samples,
+                mismatched,
+            });
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_dense_region() {
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+    let extractions = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx
+            .register_adapter::<gvox_rs::Serialize, DenseExtractionAdapter>()
+            .expect("Failed to register dense extraction adapter.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, DenseExtractionAdapter>()
+            .expect("Failed to get dense extraction adapter.")
+            .create_adapter_context(extractions.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_ctx
+            .blit()
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR)
+            .mode(gvox_rs::BlitMode::SerializeDriven)
+            .execute()
+            .expect("Error while translating.");
+    }
+
+    let extractions = extractions.lock().expect("Failed to lock extractions.");
+    assert!(!extractions.is_empty(), "No regions were extracted.");
+    for extraction in extractions.iter() {
+        let dense = &extraction.dense;
+        assert_eq!(
+            dense.range.volume() as usize,
+            dense.data.len(),
+            "Dense region did not cover its range."
+        );
+        for (offset, sample) in &extraction.samples {
+            let expected = if sample.is_present {
+                sample.data
+            } else {
+                0xdeadbeef
+            };
+            assert_eq!(
+                Some(expected),
+                dense.get(offset),
+                "Dense region did not match sampled voxel at {offset:?}."
+            );
+        }
+        assert_eq!(
+            Some(gvox_rs::ErrorType::InvalidParameter),
+            extraction.mismatched,
+            "Reading into a mismatched buffer did not fail."
+        );
+    }
+}
+
+type VoxelValues = Vec<(gvox_rs::Offset3D, Vec<Option<u32>>)>;
+type VoxelIterations = std::sync::Arc<std::sync::Mutex<Vec<(VoxelValues, VoxelValues)>>>;
+
+pub struct VoxelIterationAdapter {
+    iterations: VoxelIterations,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Serialize> for VoxelIterationAdapter {
+    type Configuration<'a> = VoxelIterations;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for VoxelIterationAdapter {
+    fn name() -> &'static str {
+        "voxel_iteration"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Serialize, Self> for VoxelIterationAdapter {
+    fn create(config: &VoxelIterations) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            iterations: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::SerializeAdapterHandler<Self> for VoxelIterationAdapter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &gvox_rs::SerializeBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags)?;
+        let iterated = region
+            .iter(channel_flags)
+            .map(|voxel| {
+                let (offset, samples) = voxel?;
+                let values = channel_flags.into_iter().map(|x| samples.get(x));
+                Ok((offset, values.collect()))
+            })
+            .collect::<Result<VoxelValues, gvox_rs::GvoxError>>()?;
+
+        let mut sampled = VoxelValues::new();
+        let gvox_rs::RegionRange { offset, extent } = region.range();
+        for z in 0..extent.z as i32 {
+            for y in 0..extent.y as i32 {
+                for x in 0..extent.x as i32 {
+                    let position = gvox_rs::Offset3D {
+                        x: offset.x + x,
+                        y: offset.y + y,
+                        z: offset.z + z,
+                    };
+                    let mut values = Vec::new();
+                    for channel_id in channel_flags {
+                        let sample = region.sample(&position, channel_id)?;
+                        values.push(sample.is_present.then_some(sample.data));
+                    }
+                    sampled.push((position, values));
+                }
+            }
+        }
+
+        self.iterations
+            .lock()
+            .expect("Failed to lock iterations.")
+            .push((iterated, sampled));
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_region_iter() {
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -2,
+            y: -2,
+            z: -2,
+        },
+        extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+    };
+    let iterations = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx
+            .register_adapter::<gvox_rs::Serialize, VoxelIterationAdapter>()
+            .expect("Failed to register voxel iteration adapter.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, VoxelIterationAdapter>()
+            .expect("Failed to get voxel iteration adapter.")
+            .create_adapter_context(iterations.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_ctx
+            .blit()
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID)
+            .mode(gvox_rs::BlitMode::SerializeDriven)
+            .execute()
+            .expect("Error while translating.");
+    }
+
+    let iterations = iterations.lock().expect("Failed to lock iterations.");
+    assert!(!iterations.is_empty(), "No regions were iterated.");
+    for (iterated, sampled) in iterations.iter() {
+        assert_eq!(
+            sampled, iterated,
+            "Voxel iterator did not match per-voxel sampling."
+        );
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_region_builder() {
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: -1, y: 2, z: 3 },
+        extent: gvox_rs::Extent3D { x: 4, y: 5, z: 6 },
+    };
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL;
+
+    let region = gvox_rs::Region::builder()
+        .range(range)
+        .channels(channels)
+        .uniform()
+        .data(7u32)
+        .build();
+    assert_eq!(range, region.range, "Builder did not set the range.");
+    assert_eq!(
+        channels, region.channels,
+        "Builder did not set the channels."
+    );
+    assert_eq!(
+        gvox_rs::RegionFlags::UNIFORM,
+        region.flags,
+        "Builder did not mark the region as uniform."
+    );
+    assert_eq!(7, *region.data(), "Builder did not set the data.");
+
+    let uniform = gvox_rs::Region::uniform(range, channels, 7u32);
+    assert_eq!(
+        (region.range, region.channels, region.flags, *region.data()),
+        (
+            uniform.range,
+            uniform.channels,
+            uniform.flags,
+            *uniform.data()
+        ),
+        "Uniform region did not match the built region."
+    );
+
+    let mut region = region.map_data(|x| vec![x; 2]);
+    region.data_mut().push(8);
+    assert_eq!(range, region.range, "Mapping data changed the range.");
+    assert_eq!(
+        channels, region.channels,
+        "Mapping data changed the channels."
+    );
+    assert_eq!(
+        gvox_rs::RegionFlags::UNIFORM,
+        region.flags,
+        "Mapping data changed the flags."
+    );
+    assert_eq!(
+        vec![7, 7, 8],
+        region.into_data(),
+        "Mapping data did not transform the data."
+    );
+
+    let empty = gvox_rs::Region::builder().build();
+    assert_eq!(
+        (
+            gvox_rs::RegionRange::default(),
+            gvox_rs::ChannelFlags::empty(),
+            gvox_rs::RegionFlags::empty()
+        ),
+        (empty.range, empty.channels, empty.flags),
+        "Builder did not default to an empty region."
+    );
+}
+
+pub struct PanickingInputAdapter;
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for PanickingInputAdapter {
+    type Configuration<'a> = ();
+    type Handler = Self;
+
+    fn input_size(_: &()) -> Option<usize> {
+        Some(16)
+    }
+}
+
+impl gvox_rs::NamedAdapter for PanickingInputAdapter {
+    fn name() -> &'static str {
+        "panicking_input"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Input, Self> for PanickingInputAdapter {
+    fn create(_: &()) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self)
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::InputAdapterHandler<Self> for PanickingInputAdapter {
+    fn read(
+        &mut self,
+        _: &gvox_rs::InputBlitContext,
+        position: usize,
+        data: &mut [u8],
+    ) -> Result<(), gvox_rs::GvoxError> {
+        // The input claims to hold 16 bytes, but only 4 are available, so the footer read goes out of bounds.
+        let bytes = [0; 4];
+        data.copy_from_slice(&bytes[position..position + data.len()]);
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_adapter_panic() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Input, PanickingInputAdapter>()
+        .expect("Failed to register panicking input adapter.");
+    gvox_ctx
+        .get_or_register_adapter::<gvox_rs::Parse, FooterAdapter>()
+        .expect("Failed to register footer parse adapter.");
+    gvox_ctx
+        .get_or_register_adapter::<gvox_rs::Serialize, SerializeDrivenAdapter>()
+        .expect("Failed to register serialize-driven adapter.");
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, PanickingInputAdapter>()
+        .expect("Failed to get panicking input adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, FooterAdapter>()
+        .expect("Failed to get footer parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, SerializeDrivenAdapter>()
+        .expect("Failed to get serialize-driven adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let error = gvox_ctx
+        .blit()
+        .input(&mut i_ctx)
+        .parse(&mut p_ctx)
+        .serialize(&mut s_ctx)
+        .channels(gvox_rs::ChannelId::COLOR)
+        .execute()
+        .err()
+        .expect("Blit with a panicking input adapter succeeded.");
+    assert_eq!(
+        gvox_rs::ErrorType::InputAdapter,
+        error.error_type(),
+        "Panic produced the wrong error type."
+    );
+    assert!(
+        error.to_string().contains("out of range"),
+        "Panic message was not preserved: {error}"
+    );
+}
+
+unsafe extern "C" fn native_noop_create(
+    _: *mut gvox_sys::GvoxAdapterContext,
+    _: *const std::os::raw::c_void,
+) {
+}
+
+unsafe extern "C" fn native_noop_destroy(_: *mut gvox_sys::GvoxAdapterContext) {}
+
+#[test]
+pub fn gvox_rs_test_mismatched_native_adapter() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let adapter_info = gvox_sys::GvoxInputAdapterInfo {
+        base_info: gvox_sys::GvoxAdapterBaseInfo {
+            name_str: cstr!("native_noop_input"),
+            create: Some(native_noop_create),
+            destroy: Some(native_noop_destroy),
+            blit_begin: None,
+            blit_end: None,
+        },
+        read: None,
+    };
+    let ptr = gvox_ctx
+        .with_raw(|ptr| unsafe { gvox_sys::gvox_register_input_adapter(ptr, &adapter_info) });
+
+    // The native adapter is wrapped as if it were the Rust adapter, so its contexts have no handler.
+    let adapter = unsafe {
+        gvox_rs::Adapter::<gvox_rs::Input, CustomAdapter>::from_raw(
+            &gvox_ctx,
+            ptr,
+            "native_noop_input",
+        )
+    }
+    .expect("Failed to wrap native input adapter.");
+
+    let error = adapter
+        .create_adapter_context(())
+        .err()
+        .expect("Created a Rust adapter context from a native adapter.");
+    assert_eq!(
+        gvox_rs::ErrorType::InputAdapter,
+        error.error_type(),
+        "Mismatched adapter produced the wrong error type."
+    );
+}
+
+fn describe_adapter_context<K: gvox_rs::AdapterKind>(
+    adapter_ctx: &gvox_rs::AdapterContext<'_, K>,
+) -> String {
+    format!(
+        "{} adapter '{}'",
+        adapter_ctx.kind(),
+        adapter_ctx.adapter_name()
+    )
+}
+
+#[test]
+pub fn gvox_rs_test_adapter_metadata() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let p_adapter = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+        .expect("Failed to get MagicaVoxel parse adapter.");
+    assert_eq!(
+        ("magicavoxel", gvox_rs::AdapterKindId::Parse),
+        (p_adapter.name(), p_adapter.kind()),
+        "Native adapter metadata did not match."
+    );
+
+    let i_adapter = gvox_ctx
+        .register_adapter_named::<gvox_rs::Input, CustomAdapter>("custom_metadata")
+        .expect("Failed to register custom input adapter.");
+    assert_eq!(
+        ("custom_metadata", gvox_rs::AdapterKindId::Input),
+        (i_adapter.name(), i_adapter.kind()),
+        "Rust adapter metadata did not match."
+    );
+
+    let p_ctx = p_adapter
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let i_ctx = i_adapter
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    assert_eq!(
+        "parse adapter 'magicavoxel'",
+        describe_adapter_context(&p_ctx),
+        "Native adapter context was described incorrectly."
+    );
+    assert_eq!(
+        "input adapter 'custom_metadata'",
+        describe_adapter_context(&i_ctx),
+        "Rust adapter context was described incorrectly."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_dyn_adapter_context() {
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+            downscale_factor: 1,
+            downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+            non_color_max_value: 254,
+            vertical: false,
+        };
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter_by_name::<gvox_rs::Input>("byte_buffer")
+            .expect("Failed to get input adapter.")
+            .create_adapter_context(gvox_rs::DynAdapterConfig::Bytes(MAGICAVOXEL_BYTES))
+            .expect("Failed to create adapter context.");
+        let mut o_ctx = gvox_ctx
+            .get_adapter_by_name::<gvox_rs::Output>("byte_buffer")
+            .expect("Failed to get output adapter.")
+            .create_adapter_context(gvox_rs::DynAdapterConfig::ByteBufferOutput(
+                gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer),
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter_by_name::<gvox_rs::Parse>("magicavoxel")
+            .expect("Failed to get parse adapter.")
+            .create_adapter_context(gvox_rs::DynAdapterConfig::Unit)
+            .expect("Failed to create adapter context.");
+
+        let s_adapter = gvox_ctx
+            .get_adapter_by_name::<gvox_rs::Serialize>("colored_text")
+            .expect("Failed to get serialize adapter.");
+        let error = s_adapter
+            .create_adapter_context(gvox_rs::DynAdapterConfig::Unit)
+            .expect_err("Adapter context was created with the wrong configuration.");
+        assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+        let mut s_ctx = s_adapter
+            .create_adapter_context(gvox_rs::DynAdapterConfig::ColoredText(s_config))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        2892,
+        o_buffer.len(),
+        "Buffer output length did not match expected."
+    );
+}
+
+#[test]
+fn gvox_rs_test_format_round_trips() {
+    fn blit_to_buffer(
+        gvox_ctx: &gvox_rs::Context,
+        i_bytes: Option<&[u8]>,
+        p_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Parse>,
+        serialize: &str,
+        s_config: gvox_rs::DynAdapterConfig<'_>,
+        range: &gvox_rs::RegionRange,
+    ) -> Box<[u8]> {
+        let mut o_buffer = Box::default();
+
+        {
+            let mut i_ctx = i_bytes.map(|bytes| {
+                gvox_ctx
+                    .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                    .expect("Failed to get byte buffer input adapter.")
+                    .create_adapter_context(bytes)
+                    .expect("Failed to create adapter context.")
+            });
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter_by_name::<gvox_rs::Serialize>(serialize)
+                .expect("Failed to get serialize adapter.")
+                .create_adapter_context(s_config)
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                i_ctx.as_mut(),
+                Some(&mut o_ctx),
+                p_ctx,
+                &mut s_ctx,
+                Some(range),
+                gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+            )
+            .unwrap_or_else(|e| panic!("Error while translating to {serialize}: {e}"));
+        }
+
+        o_buffer
+    }
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+        downscale_factor: 1,
+        downscale_mode: gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+        non_color_max_value: 5,
+        vertical: false,
+    };
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+    let expected = blit_to_buffer(
+        &gvox_ctx,
+        None,
+        &mut p_ctx,
+        "colored_text",
+        gvox_rs::DynAdapterConfig::ColoredText(s_config),
+        &range,
+    );
+
+    for format in [
+        "gvox_brickmap",
+        "gvox_global_palette",
+        "gvox_octree",
+        "gvox_run_length_encoding",
+        "rust_run_length_encoding",
+    ] {
+        let encoded = blit_to_buffer(
+            &gvox_ctx,
+            None,
+            &mut p_ctx,
+            format,
+            gvox_rs::DynAdapterConfig::Unit,
+            &range,
+        );
+
+        let mut format_p_ctx = gvox_ctx
+            .get_adapter_by_name::<gvox_rs::Parse>(format)
+            .expect("Failed to get parse adapter.")
+            .create_adapter_context(gvox_rs::DynAdapterConfig::Unit)
+            .expect("Failed to create adapter context.");
+        let decoded = blit_to_buffer(
+            &gvox_ctx,
+            Some(&encoded),
+            &mut format_p_ctx,
+            "colored_text",
+            gvox_rs::DynAdapterConfig::ColoredText(s_config),
+            &range,
+        );
+
+        assert_eq!(
+            expected, decoded,
+            "Round trip through {format} did not match."
+        );
+    }
+}
+
+#[test]
+fn gvox_rs_test_voxlap_config() {
+    assert_eq!(
+        std::mem::size_of::<gvox_sys::GvoxVoxlapParseAdapterConfig>(),
+        std::mem::size_of::<gvox_rs::adapters::VoxlapParseAdapterConfig>(),
+        "Voxlap configuration size did not match the native configuration."
+    );
+
+    let p_config = gvox_rs::adapters::VoxlapParseAdapterConfig {
+        size: gvox_rs::Extent3D { x: 2, y: 2, z: 64 },
+        make_solid: false,
+        is_ace_of_spades: true,
+    };
+    // Ace of Spades files have no header, so each column is a single span holding one colored voxel.
+    let column = [0, 10, 10, 0, 0x40, 0x80, 0xc0, 0x7f];
+    let i_bytes = column.repeat(4);
+
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(&i_bytes)
+            .expect("Failed to create adapter context.");
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::Voxlap>()
+            .expect("Failed to get voxlap parse adapter.")
+            .create_adapter_context(p_config)
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    let header = gvox_rs::format::read_header(&o_buffer).expect("Failed to read output header.");
+    assert_eq!(
+        p_config.size,
+        header.range().extent,
+        "Parsed extent did not match the configured size."
+    );
+    assert!(
+        o_buffer[gvox_rs::format::RAW_HEADER_SIZE..]
+            .iter()
+            .any(|&x| x != 0),
+        "No colored voxels were parsed."
+    );
+}
+
+#[test]
+fn gvox_rs_test_kvx_config() {
+    assert_eq!(
+        std::mem::offset_of!(gvox_sys::GvoxKvxParseAdapterConfig, mipmaplevels),
+        std::mem::offset_of!(gvox_rs::adapters::KvxParseAdapterConfig, mipmaplevels),
+        "Kvx configuration layout did not match the native configuration."
+    );
+    assert_eq!(
+        std::mem::align_of::<gvox_sys::GvoxKvxParseAdapterConfig>(),
+        std::mem::align_of::<gvox_rs::adapters::KvxParseAdapterConfig>()
+    );
+}
+
+#[test]
+fn gvox_rs_test_magicavoxel_serialize() {
+    fn blit_to_buffer(
+        gvox_ctx: &gvox_rs::Context,
+        i_bytes: Option<&[u8]>,
+        p_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Parse>,
+        s_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Serialize>,
+        range: Option<&gvox_rs::RegionRange>,
+    ) -> Result<Box<[u8]>, gvox_rs::GvoxError> {
+        let mut o_buffer = Box::default();
+
+        {
+            let mut i_ctx = i_bytes.map(|bytes| {
+                gvox_ctx
+                    .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                    .expect("Failed to get byte buffer input adapter.")
+                    .create_adapter_context(bytes)
+                    .expect("Failed to create adapter context.")
+            });
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                i_ctx.as_mut(),
+                Some(&mut o_ctx),
+                p_ctx,
+                s_ctx,
+                range,
+                gvox_rs::ChannelId::COLOR.into(),
+            )?;
+        }
+
+        Ok(o_buffer)
+    }
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+    let mut vox_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::MagicaVoxelOut>()
+        .expect("Failed to get magicavoxel serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let mut raw_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let expected = blit_to_buffer(&gvox_ctx, None, &mut p_ctx, &mut raw_ctx, Some(&range))
+        .expect("Error while translating.");
+    let vox = blit_to_buffer(&gvox_ctx, None, &mut p_ctx, &mut vox_ctx, Some(&range))
+        .expect("Error while translating.");
+    assert_eq!(
+        Some(gvox_rs::inspect::Format::MagicaVoxel),
+        gvox_rs::inspect::detect_format(&vox)
+    );
+
+    let mut vox_p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+        .expect("Failed to get magicavoxel parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let reparsed = blit_to_buffer(&gvox_ctx, Some(&vox), &mut vox_p_ctx, &mut raw_ctx, None)
+        .expect("Error while translating.");
+
+    let header = gvox_rs::format::read_header(&reparsed).expect("Failed to read output header.");
+    assert_eq!(
+        range.extent,
+        header.range().extent,
+        "Re-parsed extent did not match."
+    );
+    let colors = |bytes: &[u8]| {
+        bytes[gvox_rs::format::RAW_HEADER_SIZE..]
+            .chunks_exact(std::mem::size_of::<u32>())
+            .map(|x| u32::from_le_bytes(x.try_into().expect("Chunk was not four bytes long.")))
+            .map(|color| (color >> 24 != 0).then_some(color & 0x00ffffff))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        colors(&expected),
+        colors(&reparsed),
+        "Re-parsed colors did not match."
+    );
+
+    let large_range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 257, y: 1, z: 1 },
+    };
+    let error = blit_to_buffer(
+        &gvox_ctx,
+        None,
+        &mut p_ctx,
+        &mut vox_ctx,
+        Some(&large_range),
+    )
+    .expect_err("Oversized model was serialized.");
+    assert_eq!(
+        gvox_rs::ErrorType::SerializeAdapterUnrepresentableData,
+        error.error_type()
+    );
+}
+
+fn two_model_vox() -> Vec<u8> {
+    fn chunk(id: &[u8; 4], content: &[u8], children: &[u8]) -> Vec<u8> {
+        [
+            &id[..],
+            &(content.len() as u32).to_le_bytes(),
+            &(children.len() as u32).to_le_bytes(),
+            content,
+            children,
+        ]
+        .concat()
+    }
+    fn words(values: &[u32]) -> Vec<u8> {
+        values.iter().flat_map(|x| x.to_le_bytes()).collect()
+    }
+    fn translation(node: u32, child: u32, t: &str) -> Vec<u8> {
+        let mut content = words(&[node, 0, child, u32::MAX, 0, 1, 1, 2]);
+        content.extend_from_slice(b"_t");
+        content.extend_from_slice(&(t.len() as u32).to_le_bytes());
+        content.extend_from_slice(t.as_bytes());
+        chunk(b"nTRN", &content, &[])
+    }
+
+    let children = [
+        chunk(b"SIZE", &words(&[2, 2, 2]), &[]),
+        chunk(b"XYZI", &[&words(&[1])[..], &[1, 1, 1, 1]].concat(), &[]),
+        chunk(b"SIZE", &words(&[4, 3, 1]), &[]),
+        chunk(
+            b"XYZI",
+            &[&words(&[2])[..], &[0, 0, 0, 2], &[3, 2, 0, 3]].concat(),
+            &[],
+        ),
+        translation(0, 1, "0 0 0"),
+        chunk(b"nGRP", &words(&[1, 0, 2, 2, 4]), &[]),
+        translation(2, 3, "10 0 0"),
+        chunk(b"nSHP", &words(&[3, 0, 1, 0, 0]), &[]),
+        translation(4, 5, "0 0 5"),
+        chunk(b"nSHP", &words(&[5, 0, 1, 1, 0]), &[]),
+    ]
+    .concat();
+
+    [
+        &b"VOX "[..],
+        &words(&[150]),
+        &chunk(b"MAIN", &[], &children),
+    ]
+    .concat()
+}
+
+#[test]
+fn gvox_rs_test_magicavoxel_model() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let vox = two_model_vox();
+
+    let parse = |config: gvox_rs::adapters::MagicaVoxelParseAdapterConfig| {
+        let mut o_buffer = Box::default();
+
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(&vox)
+                .expect("Failed to create adapter context.");
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxelModel>()
+                .expect("Failed to get magicavoxel model parse adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )?;
+        }
+
+        Ok::<_, gvox_rs::GvoxError>(o_buffer)
+    };
+    let parsed_range = |config| {
+        let bytes = parse(config).expect("Error while translating.");
+        gvox_rs::format::read_header(&bytes)
+            .expect("Failed to read output header.")
+            .range()
+    };
+
+    let first = gvox_rs::adapters::MagicaVoxelParseAdapterConfig {
+        model_index: Some(0),
+        apply_scene_transforms: false,
+    };
+    let second = gvox_rs::adapters::MagicaVoxelParseAdapterConfig {
+        model_index: Some(1),
+        ..first
+    };
+    assert_eq!(
+        gvox_rs::Extent3D { x: 2, y: 2, z: 2 },
+        parsed_range(first).extent
+    );
+    assert_eq!(
+        gvox_rs::Extent3D { x: 4, y: 3, z: 1 },
+        parsed_range(second).extent
+    );
+
+    let bytes = parse(first).expect("Error while translating.");
+    let color_at = |index: usize| {
+        let start = gvox_rs::format::RAW_HEADER_SIZE + index * std::mem::size_of::<u32>();
+        u32::from_le_bytes(
+            bytes[start..start + 4]
+                .try_into()
+                .expect("Slice was not four bytes long."),
+        )
+    };
+    assert_eq!(0, color_at(0), "Empty voxel had a color.");
+    assert_eq!(
+        0xffffffff,
+        color_at(7),
+        "Voxel did not have the default palette color."
+    );
+
+    assert_eq!(
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 9, y: -1, z: -1 },
+            extent: gvox_rs::Extent3D { x: 2, y: 2, z: 2 },
+        },
+        parsed_range(gvox_rs::adapters::MagicaVoxelParseAdapterConfig {
+            apply_scene_transforms: true,
+            ..first
+        })
+    );
+
+    let error = parse(gvox_rs::adapters::MagicaVoxelParseAdapterConfig {
+        model_index: Some(2),
+        ..first
+    })
+    .expect_err("Model beyond the end of the file was parsed.");
+    assert_eq!(
+        gvox_rs::ErrorType::ParseAdapterInvalidInput,
+        error.error_type()
+    );
+    assert!(
+        error.to_string().contains("only contains 2 models"),
+        "Error did not report the number of models: {error}"
+    );
+}
+
+#[test]
+fn gvox_rs_test_magicavoxel_metadata() {
+    let metadata = gvox_rs::inspect::magicavoxel_metadata(MAGICAVOXEL_BYTES)
+        .expect("Failed to read magicavoxel metadata.");
+    assert_eq!(0, metadata.palette[0]);
+    assert_eq!(0xffffffff, metadata.palette[1]);
+    assert_eq!(0xffccffff, metadata.palette[2]);
+    assert_eq!(256, metadata.materials.len());
+    assert_eq!(1, metadata.materials[0].id);
+    assert_eq!(Some(0.1), metadata.materials[0].roughness);
+    assert_eq!(
+        gvox_rs::adapters::magicavoxel_default_palette(),
+        gvox_rs::inspect::magicavoxel_metadata(&two_model_vox())
+            .expect("Failed to read magicavoxel metadata.")
+            .palette
+    );
+    assert_eq!(None, gvox_rs::inspect::magicavoxel_metadata(PALETTE_BYTES));
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let query = |p_ctx: &gvox_rs::AdapterContext<'_, gvox_rs::Parse>| {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(MAGICAVOXEL_BYTES)
+            .expect("Failed to create adapter context.");
+        p_ctx
+            .query_metadata(&mut i_ctx)
+            .expect("Failed to query metadata.")
+    };
+
+    let p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+        .expect("Failed to get magicavoxel parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    assert_eq!(Some(&metadata), query(&p_ctx).as_ref());
+
+    let p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    assert_eq!(None, query(&p_ctx));
+}
+
+#[test]
+fn gvox_rs_test_colored_text_display_channel() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let render = |display_channel, channel_flags| {
+        let mut o_buffer = Box::default();
+
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                .expect("Failed to get function sampler parse adapter.")
+                .create_adapter_context(procedural_parse::terrain())
+                .expect("Failed to create adapter context.");
+            let text = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+                non_color_max_value: 5,
+                ..Default::default()
+            };
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::RustColoredText>()
+                .expect("Failed to get rust colored text serialize adapter.")
+                .create_adapter_context(
+                    gvox_rs::adapters::RustColoredTextConfig::new(text)
+                        .with_display_channel(display_channel),
+                )
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                None,
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                &gvox_rs::RegionRange {
+                    offset: gvox_rs::Offset3D {
+                        x: -4,
+                        y: -4,
+                        z: -4,
+                    },
+                    extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+                },
+                channel_flags,
+            )?;
+        }
+
+        Ok::<_, gvox_rs::GvoxError>(String::from_utf8_lossy(&o_buffer).into_owned())
+    };
+
+    let channel_flags = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+    let color = render(None, channel_flags).expect("Error while translating.");
+    let material = render(Some(gvox_rs::ChannelId::MATERIAL_ID), channel_flags)
+        .expect("Error while translating.");
+    assert_ne!(color, material, "Material IDs were rendered as colors.");
+    assert_eq!(color.lines().count(), material.lines().count());
+
+    let error = render(
+        Some(gvox_rs::ChannelId::NORMAL),
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect_err("Displaying a channel which was not blitted succeeded.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+}
+
+#[test]
+fn gvox_rs_test_colored_text_color_modes() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let render = |config| {
+        let mut o_buffer = Box::default();
+
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                .expect("Failed to get function sampler parse adapter.")
+                .create_adapter_context(procedural_parse::terrain())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::RustColoredText>()
+                .expect("Failed to get rust colored text serialize adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                None,
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                &gvox_rs::RegionRange {
+                    offset: gvox_rs::Offset3D {
+                        x: -4,
+                        y: -4,
+                        z: -4,
+                    },
+                    extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+                },
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+
+        String::from_utf8(o_buffer.into_vec()).expect("Colored text was not valid UTF-8.")
+    };
+
+    let plain = |text| {
+        gvox_rs::adapters::RustColoredTextConfig::new(text)
+            .with_color_mode(gvox_rs::adapters::ColoredTextSerializeAdapterColorMode::Plain)
+    };
+
+    // Each layer is eight voxels of two characters wide, and the eight layers are separated by spaces.
+    let text = render(plain(Default::default()));
+    assert!(!text.contains('\x1b'), "Plain text contained escape codes.");
+    assert_eq!(8, text.lines().count());
+    assert!(text.lines().all(|line| line.len() == 8 * 16 + 7));
+    assert!(!text.trim().is_empty(), "Plain text contained no voxels.");
+
+    // Downscaled layers of four cells are printed below one another, separated by empty lines.
+    let downscaled = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+        downscale_factor: 2,
+        vertical: true,
+        ..Default::default()
+    };
+    let text = render(plain(downscaled));
+    assert!(!text.contains('\x1b'), "Plain text contained escape codes.");
+    let lengths = text.lines().map(str::len).collect::<Vec<_>>();
+    assert_eq!(
+        [[8; 4].as_slice(), &[0]].concat().repeat(4)[..19],
+        lengths[..]
+    );
+
+    let text = render(
+        gvox_rs::adapters::RustColoredTextConfig::default()
+            .with_color_mode(gvox_rs::adapters::ColoredTextSerializeAdapterColorMode::Ansi256),
+    );
+    assert!(
+        text.contains("\x1b[48;5;"),
+        "No 256-color escape codes were written."
+    );
+    assert!(
+        !text.contains("\x1b[48;2;"),
+        "Truecolor escape codes were written."
+    );
+}
+
+#[test]
+fn gvox_rs_test_terminal_preview() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let render = |s_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Serialize>| {
+        let mut o_buffer = Box::default();
+
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                .expect("Failed to get function sampler parse adapter.")
+                .create_adapter_context(procedural_parse::terrain())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                None,
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                s_ctx,
+                &gvox_rs::RegionRange {
+                    offset: gvox_rs::Offset3D {
+                        x: -4,
+                        y: -4,
+                        z: -4,
+                    },
+                    extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+                },
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+
+        String::from_utf8(o_buffer.into_vec()).expect("Preview was not valid UTF-8.")
+    };
+    let preview = |config| {
+        render(
+            &mut gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::TerminalPreview>()
+                .expect("Failed to get terminal preview serialize adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context."),
+        )
+    };
+
+    for vertical in [false, true] {
+        let colored_text = render(
+            &mut gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::RustColoredText>()
+                .expect("Failed to get rust colored text serialize adapter.")
+                .create_adapter_context(gvox_rs::adapters::RustColoredTextConfig::new(
+                    gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+                        vertical,
+                        ..Default::default()
+                    },
+                ))
+                .expect("Failed to create adapter context."),
+        );
+        let half_block = preview(gvox_rs::adapters::TerminalPreviewConfig {
+            vertical,
+            ..Default::default()
+        });
+
+        // Each layer of eight rows becomes four, and vertical layers keep their empty separating lines.
+        let layers = if vertical { 8 } else { 1 };
+        let separators = layers - 1;
+        assert_eq!(
+            colored_text.lines().count() - separators,
+            2 * (half_block.lines().count() - separators)
+        );
+        assert!(half_block.contains('▀'), "No half blocks were drawn.");
+    }
+
+    let braille = preview(gvox_rs::adapters::TerminalPreviewConfig {
+        mode: gvox_rs::adapters::TerminalPreviewMode::Braille,
+        ..Default::default()
+    });
+    assert!(
+        !braille.contains('\x1b'),
+        "Braille preview contained escape codes."
+    );
+    assert_eq!(2, braille.lines().count());
+    assert!(braille
+        .lines()
+        .all(|line| line.chars().count() == 8 * 4 + 7));
+}
+
+pub struct HeaderLastAdapter;
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Serialize> for HeaderLastAdapter {
+    type Configuration<'a> = ();
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for HeaderLastAdapter {
+    fn name() -> &'static str {
+        "header_last"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Serialize, Self> for HeaderLastAdapter {
+    fn create(_: &()) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self)
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::SerializeAdapterHandler<Self> for HeaderLastAdapter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &gvox_rs::SerializeBlitContext,
+        _: &gvox_rs::RegionRange,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        blit_ctx.output_write(4, b"data\n")?;
+        blit_ctx.output_write(0, b"head")
+    }
+}
+
+#[test]
+fn gvox_rs_test_std_stream_outputs() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Serialize, HeaderLastAdapter>()
+        .expect("Failed to register header-last adapter.");
+
+    let blit = |o_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Output>| {
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, HeaderLastAdapter>()
+            .expect("Failed to get header-last adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            Some(o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            &gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D::default(),
+                extent: gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+            },
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+    };
+
+    let mut o_buffer = Box::default();
+    blit(
+        &mut gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context."),
+    )
+    .expect("Error while translating.");
+    assert_eq!(b"headdata\n", &o_buffer[..]);
+
+    let buffered = gvox_rs::adapters::StdOutOutputAdapterConfig {
+        buffered: true,
+        flush_on_blit_end: true,
+    };
+    let mut stderr = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::StdErr>()
+        .expect("Failed to get standard error output adapter.")
+        .create_adapter_context(buffered)
+        .expect("Failed to create adapter context.");
+    let (result, captured) = gvox_rs::testing::capture_std_streams(|| blit(&mut stderr));
+    result.expect("Error while translating to a buffered stream.");
+    assert_eq!(&o_buffer[..], &captured[..]);
+
+    // The native adapter cannot buffer, so buffered standard output is written by the Rust adapter instead.
+    let mut stdout = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::StdOut>()
+        .expect("Failed to get standard output adapter.")
+        .create_adapter_context(buffered)
+        .expect("Failed to create adapter context.");
+    let (result, captured) = gvox_rs::testing::capture_std_streams(|| blit(&mut stdout));
+    result.expect("Error while translating to a buffered stream.");
+    assert_eq!(&o_buffer[..], &captured[..]);
+
+    stderr
+        .reset::<gvox_rs::adapters::StdErr>(Default::default())
+        .expect("Failed to reset adapter context.");
+    let error = blit(&mut stderr).expect_err("Rewriting an unbuffered stream succeeded.");
+    assert_eq!(gvox_rs::ErrorType::OutputAdapter, error.error_type());
+}
+
+#[test]
+fn gvox_rs_test_file_config_from_path() {
+    let dir = std::env::temp_dir();
+    let input_path = dir.join("gvox rs from path ünïcødé 体素.gvox");
+    let output_path = dir.join("gvox rs from path ünïcødé 体素 out.gvox");
+
+    // The native file adapters cannot open paths which are not ASCII on Windows.
+    #[cfg(windows)]
+    let (input_path, output_path) = {
+        let error = gvox_rs::adapters::FileInputAdapterConfig::from_path(&input_path, 0)
+            .expect_err("Created a file input config with a non-ASCII path.");
+        assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+        let error = gvox_rs::adapters::FileOutputAdapterConfig::from_path(&output_path)
+            .expect_err("Created a file output config with a non-ASCII path.");
+        assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+        (
+            dir.join("gvox rs from path.gvox"),
+            dir.join("gvox rs from path out.gvox"),
+        )
+    };
+
+    std::fs::write(&input_path, PALETTE_BYTES).expect("Failed to write temporary file.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::File>()
+            .expect("Failed to get file input adapter.")
+            .create_adapter_context(
+                gvox_rs::adapters::FileInputAdapterConfig::from_path(&input_path, 0)
+                    .expect("Failed to create file input config."),
+            )
+            .expect("Failed to create adapter context.");
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::File>()
+            .expect("Failed to get file output adapter.")
+            .create_adapter_context(
+                gvox_rs::adapters::FileOutputAdapterConfig::from_path(&output_path)
+                    .expect("Failed to create file output config."),
+            )
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    let output = std::fs::read(&output_path).expect("Failed to read temporary file.");
+    std::fs::remove_file(&input_path).expect("Failed to remove temporary file.");
+    std::fs::remove_file(&output_path).expect("Failed to remove temporary file.");
+
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+    assert_eq!(expected, output);
+
+    let nul_path = dir.join("gvox_rs\0from_path.gvox");
+    let error = gvox_rs::adapters::FileInputAdapterConfig::from_path(&nul_path, 0)
+        .expect_err("Created a file input config with a null byte.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+    let error = gvox_rs::adapters::FileOutputAdapterConfig::from_path(&nul_path)
+        .expect_err("Created a file output config with a null byte.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+}
+
+#[test]
+fn gvox_rs_test_file_output_modes() {
+    let file_name = std::env::temp_dir().join("gvox_rs_test_file_output_modes.txt");
+    std::fs::write(&file_name, b"prefix").expect("Failed to write temporary file.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Serialize, HeaderLastAdapter>()
+        .expect("Failed to register header-last adapter.");
+
+    let blit = |mode: gvox_rs::adapters::FileOutputMode| {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::File>()
+            .expect("Failed to get file output adapter.")
+            .create_adapter_context(
+                gvox_rs::adapters::FileOutputAdapterConfig::from_path(&file_name)
+                    .expect("Failed to create file output config.")
+                    .with_mode(mode),
+            )
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, HeaderLastAdapter>()
+            .expect("Failed to get header-last adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D::default(),
+                extent: gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+            },
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    };
+
+    blit(gvox_rs::adapters::FileOutputMode::Append);
+    blit(gvox_rs::adapters::FileOutputMode::WriteAt { byte_offset: 20 });
+    let contents = std::fs::read(&file_name).expect("Failed to read temporary file.");
+    assert_eq!(
+        b"prefixheaddata\n\0\0\0\0\0headdata\n",
+        &contents[..],
+        "Blits in append and write-at modes were not placed correctly."
+    );
+
+    blit(gvox_rs::adapters::FileOutputMode::WriteAt { byte_offset: 2 });
+    let contents = std::fs::read(&file_name).expect("Failed to read temporary file.");
+    assert_eq!(
+        b"prheaddata\nata\n\0\0\0\0\0headdata\n",
+        &contents[..],
+        "Blit in write-at mode did not preserve the surrounding contents."
+    );
+
+    blit(gvox_rs::adapters::FileOutputMode::Truncate);
+    let contents = std::fs::read(&file_name).expect("Failed to read temporary file.");
+    std::fs::remove_file(&file_name).expect("Failed to remove temporary file.");
+    assert_eq!(b"headdata\n", &contents[..]);
+}
+
+#[test]
+fn gvox_rs_test_vec_output() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+
+    let mut output = vec![0xFF; 3];
+    let mut pointers = Vec::new();
+    for _ in 0..2 {
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(PALETTE_BYTES)
+                .expect("Failed to create adapter context.");
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::VecOutput>()
+                .expect("Failed to get vector output adapter.")
+                .create_adapter_context(gvox_rs::adapters::VecOutputAdapterConfig::from(
+                    &mut output,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                &mut i_ctx,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+
+        assert_eq!(expected, output, "Vector output did not match.");
+        pointers.push(output.as_ptr());
+    }
+
+    assert_eq!(
+        pointers[0], pointers[1],
+        "Vector output was reallocated for output of the same size."
+    );
+}
+
+pub struct PreparedInput {
+    i_ctx: gvox_rs::AdapterContext<'static, gvox_rs::Input>,
+}
+
+impl PreparedInput {
+    pub fn new(gvox_ctx: &gvox_rs::Context, bytes: std::sync::Arc<[u8]>) -> Self {
+        Self {
+            i_ctx: gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBufferOwned>()
+                .expect("Failed to get owned byte buffer input adapter.")
+                .create_adapter_context(bytes)
+                .expect("Failed to create adapter context."),
+        }
+    }
+
+    pub fn translate(&mut self, gvox_ctx: &gvox_rs::Context) -> Box<[u8]> {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                &mut self.i_ctx,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+        o_buffer
+    }
+}
+
+#[test]
+fn gvox_rs_test_byte_buffer_owned() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+
+    let mut prepared = {
+        let bytes = PALETTE_BYTES.to_vec();
+        PreparedInput::new(&gvox_ctx, bytes.into())
+    };
+    assert_eq!(
+        expected,
+        &prepared.translate(&gvox_ctx)[..],
+        "Blit from an owned buffer did not match."
+    );
+
+    let shared = std::sync::Arc::<[u8]>::from(PALETTE_BYTES);
+    let mut inputs = [
+        PreparedInput::new(&gvox_ctx, shared.clone()),
+        PreparedInput::new(&gvox_ctx, shared.clone()),
+    ];
+    assert_eq!(
+        3,
+        std::sync::Arc::strong_count(&shared),
+        "Shared buffer was copied into its adapter contexts."
+    );
+    drop(shared);
+
+    for input in &mut inputs {
+        assert_eq!(
+            expected,
+            &input.translate(&gvox_ctx)[..],
+            "Blit from a shared buffer did not match."
+        );
+    }
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn gvox_rs_test_gzip_input() {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(PALETTE_BYTES)
+        .expect("Failed to compress palette.");
+    let compressed = encoder.finish().expect("Failed to compress palette.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+
+    let mut o_buffer = Box::default();
+    {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::GzipInput>()
+            .expect("Failed to get gzip input adapter.")
+            .create_adapter_context(gvox_rs::adapters::GzipInputAdapterConfig::from(
+                &compressed[..],
+            ))
+            .expect("Failed to create adapter context.");
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        expected,
+        &o_buffer[..],
+        "Blit from a compressed input did not match the uncompressed blit."
+    );
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn gvox_rs_test_zstd_output() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let blit = |o_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Output>| {
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D {
+                    x: -4,
+                    y: -4,
+                    z: -4,
+                },
+                extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+            },
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .expect("Error while translating.");
+    };
+
+    let mut o_buffer = Box::default();
+    blit(
+        &mut gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context."),
+    );
+
+    let mut compressed = Vec::new();
+    blit(
+        &mut gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ZstdOutput>()
+            .expect("Failed to get zstd output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ZstdOutputAdapterConfig::from(
+                &mut compressed,
+            ))
+            .expect("Failed to create adapter context."),
+    );
+    let decompressed = zstd::decode_all(&compressed[..]).expect("Failed to decompress output.");
+    assert_eq!(
+        &o_buffer[..],
+        decompressed,
+        "Decompressed vector output did not match."
+    );
+
+    let file_name = std::env::temp_dir().join("gvox_rs_test_zstd_output.gvox.zst");
+    blit(
+        &mut gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ZstdOutput>()
+            .expect("Failed to get zstd output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ZstdOutputAdapterConfig::new(
+                gvox_rs::adapters::ZstdOutputSink::File(file_name.clone()),
+                19,
+            ))
+            .expect("Failed to create adapter context."),
+    );
+    let compressed = std::fs::read(&file_name).expect("Failed to read temporary file.");
+    std::fs::remove_file(&file_name).expect("Failed to remove temporary file.");
+    let decompressed = zstd::decode_all(&compressed[..]).expect("Failed to decompress output.");
+    assert_eq!(
+        &o_buffer[..],
+        decompressed,
+        "Decompressed file output did not match."
+    );
+}
+
+#[test]
+fn gvox_rs_test_io_reader_input() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+
+    let translate = |config: gvox_rs::adapters::IoReaderAdapterConfig<'_>| {
+        let mut o_buffer = Box::default();
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::IoReader>()
+                .expect("Failed to get reader input adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                &mut i_ctx,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+        o_buffer
+    };
+
+    let owned = translate(gvox_rs::adapters::IoReaderAdapterConfig::new(
+        std::io::Cursor::new(PALETTE_BYTES.to_vec()),
+    ));
+    assert_eq!(
+        expected,
+        &owned[..],
+        "Blit from an owned reader did not match."
+    );
+
+    let mut cursor = std::io::Cursor::new(PALETTE_BYTES);
+    let borrowed = translate(gvox_rs::adapters::IoReaderAdapterConfig::new(&mut cursor));
+    assert_eq!(
+        expected,
+        &borrowed[..],
+        "Blit from a borrowed reader did not match."
+    );
+    assert!(cursor.position() > 0, "Borrowed reader was not read from.");
+}
+
+#[test]
+fn gvox_rs_test_chained_input() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+
+    let translate = |config: gvox_rs::adapters::ChainedInputAdapterConfig<'_>| {
+        let mut o_buffer = Box::default();
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ChainedInput>()
+                .expect("Failed to get chained input adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                &mut i_ctx,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+        o_buffer
+    };
+
+    for chunk_size in [1, 7, 4096] {
+        let segments = PALETTE_BYTES.chunks(chunk_size).collect::<Vec<_>>();
+        assert_eq!(
+            expected,
+            &translate(segments.into())[..],
+            "Blit from {chunk_size} byte segments did not match."
+        );
+    }
+
+    let segments = PALETTE_BYTES
+        .chunks(7)
+        .map(<[u8]>::to_vec)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        expected,
+        &translate(segments.into())[..],
+        "Blit from owned segments did not match."
+    );
+}
+
+#[test]
+fn gvox_rs_test_windowed_input() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let expected = gvox_rs::convert(
+        MAGICAVOXEL_BYTES,
+        gvox_rs::ParseFormat::MagicaVoxel,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert model.");
+
+    let offset = 1000;
+    let mut container = vec![0xAB; offset];
+    container.extend_from_slice(MAGICAVOXEL_BYTES);
+    container.extend_from_slice(&[0xCD; 500]);
+
+    let translate = |config: gvox_rs::adapters::WindowedInputAdapterConfig<'_>| {
+        let mut o_buffer = Box::default();
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::WindowedInput>()
+                .expect("Failed to get windowed input adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+                .expect("Failed to get MagicaVoxel parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                &mut i_ctx,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )?;
+        }
+        Ok::<_, gvox_rs::GvoxError>(o_buffer)
+    };
+
+    let windowed = translate(gvox_rs::adapters::WindowedInputAdapterConfig::from_bytes(
+        &container,
+        offset,
+        MAGICAVOXEL_BYTES.len(),
+    ))
+    .expect("Error while translating from a buffer window.");
+    assert_eq!(
+        expected,
+        &windowed[..],
+        "Blit from a buffer window did not match."
+    );
+
+    let file_name = std::env::temp_dir().join("gvox_rs_test_windowed_input.bin");
+    std::fs::write(&file_name, &container).expect("Failed to write temporary file.");
+    let file_config = gvox_rs::adapters::FileInputAdapterConfig::from_path(&file_name, offset)
+        .expect("Failed to create file input config.");
+    let windowed = translate(
+        gvox_rs::adapters::WindowedInputAdapterConfig::from_file(
+            &file_config,
+            MAGICAVOXEL_BYTES.len(),
+        )
+        .expect("Failed to create windowed input config."),
+    );
+    std::fs::remove_file(&file_name).expect("Failed to remove temporary file.");
+    assert_eq!(
+        expected,
+        &windowed.expect("Error while translating from a file window.")[..],
+        "Blit from a file window did not match."
+    );
+
+    translate(gvox_rs::adapters::WindowedInputAdapterConfig::from_bytes(
+        &container,
+        offset,
+        MAGICAVOXEL_BYTES.len() / 2,
+    ))
+    .expect_err("Model was parsed from an undersized window.");
+}
+
+#[test]
+fn gvox_rs_test_voxel_grid() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+
+    let mut model = gvox_rs::VoxelGrid::default();
+    {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(MAGICAVOXEL_BYTES)
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+            .expect("Failed to get MagicaVoxel parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::voxel_grid::VoxelGridAdapter>()
+            .expect("Failed to get voxel grid serialize adapter.")
+            .create_adapter_context(gvox_rs::voxel_grid::VoxelGridTarget::from(&mut model))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(&mut i_ctx, None, &mut p_ctx, &mut s_ctx, None, channels)
+            .expect("Error while translating.");
+    }
+
+    let range = model.range();
+    assert!(range.volume() > 0, "Model was not loaded into the grid.");
+    assert_eq!(channels, model.channels());
+
+    let corner = range.offset;
+    model
+        .set(&corner, gvox_rs::ChannelId::COLOR, 0xFF123456)
+        .expect("Failed to set voxel.");
+    assert_eq!(
+        Some(0xFF123456),
+        model.get(&corner, gvox_rs::ChannelId::COLOR)
+    );
+
+    let outside = gvox_rs::Offset3D {
+        x: corner.x - 1,
+        ..corner
+    };
+    assert_eq!(None, model.get(&outside, gvox_rs::ChannelId::COLOR));
+    let error = model
+        .set(&outside, gvox_rs::ChannelId::COLOR, 0)
+        .expect_err("Set a voxel outside of the grid.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+    let error = model
+        .set(&corner, gvox_rs::ChannelId::NORMAL, 0)
+        .expect_err("Set a voxel in a channel that the grid does not hold.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::voxel_grid::VoxelGridAdapter>()
+            .expect("Failed to get voxel grid parse adapter.")
+            .create_adapter_context(&model)
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, None, channels)
+            .expect("Error while translating.");
+    }
+
+    let mut reloaded = gvox_rs::VoxelGrid::default();
+    {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(&o_buffer[..])
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::voxel_grid::VoxelGridAdapter>()
+            .expect("Failed to get voxel grid serialize adapter.")
+            .create_adapter_context(gvox_rs::voxel_grid::VoxelGridTarget::from(&mut reloaded))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(&mut i_ctx, None, &mut p_ctx, &mut s_ctx, None, channels)
+            .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        model, reloaded,
+        "Grid did not survive a round trip through gvox_raw."
+    );
+}
+
+#[test]
+fn gvox_rs_test_sparse_voxels() {
+    let positions = [
+        gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        gvox_rs::Offset3D { x: 3, y: 1, z: 0 },
+        gvox_rs::Offset3D { x: 1, y: 3, z: 0 },
+    ];
+    let config = positions
+        .iter()
+        .map(|&position| (position, gvox_rs::ChannelId::COLOR, 0xFF0000FF))
+        .collect::<gvox_rs::adapters::SparseVoxelsAdapterConfig>();
+    assert_eq!(
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 4, y: 4, z: 1 },
+        },
+        config.range()
+    );
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::SparseVoxels>()
+            .expect("Failed to get sparse voxel parse adapter.")
+            .create_adapter_context(config)
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::RustColoredText>()
+            .expect("Failed to get rust colored text serialize adapter.")
+            .create_adapter_context(
+                gvox_rs::adapters::RustColoredTextConfig::default().with_color_mode(
+                    gvox_rs::adapters::ColoredTextSerializeAdapterColorMode::Plain,
+                ),
+            )
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    // Each voxel is two characters wide, and empty voxels are left blank.
+    let text = String::from_utf8(o_buffer.into_vec()).expect("Colored text was not valid UTF-8.");
+    assert_eq!(4, text.lines().count());
+    assert!(text.lines().all(|line| line.len() == 4 * 2));
+    assert_eq!(
+        positions.len() * 2,
+        text.chars().filter(|x| !x.is_whitespace()).count(),
+        "Cells other than the sparse voxels were drawn."
+    );
+}
+
+#[test]
+fn gvox_rs_test_sparse_voxels_out() {
+    let text = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::RustColoredText(
+            gvox_rs::adapters::RustColoredTextConfig::default()
+                .with_color_mode(gvox_rs::adapters::ColoredTextSerializeAdapterColorMode::Plain),
+        ),
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert to colored text.");
+    // Each non-empty voxel is drawn as two non-space characters.
+    let expected = text.iter().filter(|x| !x.is_ascii_whitespace()).count() / 2;
+    assert_ne!(0, expected, "Colored text contained no voxels.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    for parse_driven in [true, false] {
+        let config = gvox_rs::adapters::SparseVoxelsOutAdapterConfig::new();
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(PALETTE_BYTES)
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::SparseVoxelsOut>()
+                .expect("Failed to get sparse voxel serialize adapter.")
+                .create_adapter_context(config.clone())
+                .expect("Failed to create adapter context.");
+
+            let blit = if parse_driven {
+                gvox_rs::blit_region_parse_driven
+            } else {
+                gvox_rs::blit_region_serialize_driven
+            };
+            blit(
+                Some(&mut i_ctx),
+                None,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+
+        let voxels = config.take();
+        assert_eq!(
+            expected,
+            voxels.len(),
+            "Collected voxel count did not match."
+        );
+        assert!(config.is_empty());
+        assert!(voxels
+            .values()
+            .all(|x| x.channels() == gvox_rs::ChannelId::COLOR.into()));
+    }
+}
+
+#[test]
+fn gvox_rs_test_binvox() {
+    let header =
+        gvox_rs::adapters::BinvoxHeader::read(BINVOX_BYTES).expect("Failed to read binvox header.");
+    assert_eq!(1, header.version);
+    assert_eq!(gvox_rs::Extent3D { x: 2, y: 4, z: 3 }, header.extent);
+    assert_eq!([-1.0; 3], header.translate);
+    assert_eq!(2.0, header.scale);
+    assert_eq!(BINVOX_BYTES.len() - 10, header.data_offset);
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let parse = |bytes: &[u8]| {
+        let config = gvox_rs::adapters::SparseVoxelsOutAdapterConfig::new();
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(bytes)
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::Binvox>()
+            .expect("Failed to get binvox parse adapter.")
+            .create_adapter_context(gvox_rs::adapters::BinvoxParseAdapterConfig {
+                fill_color: 0xff00ff00,
+            })
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::SparseVoxelsOut>()
+            .expect("Failed to get sparse voxel serialize adapter.")
+            .create_adapter_context(config.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            None,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .map(|()| config.take())
+    };
+
+    // Voxels are stored with y varying fastest, then z, then x.
+    let voxels = parse(BINVOX_BYTES).expect("Error while translating.");
+    assert_eq!(3, voxels.len(), "Occupied voxel count did not match.");
+    for position in [(0, 0, 0), (0, 1, 1), (1, 3, 2)] {
+        let (x, y, z) = position;
+        let samples = voxels
+            .get(&gvox_rs::Offset3D { x, y, z })
+            .unwrap_or_else(|| panic!("Voxel {position:?} was not occupied."));
+        assert_eq!(Some(0xff00ff00), samples.get(gvox_rs::ChannelId::COLOR));
+        assert_eq!(Some(1), samples.get(gvox_rs::ChannelId::MATERIAL_ID));
+    }
+
+    let truncated = parse(&BINVOX_BYTES[..BINVOX_BYTES.len() - 2])
+        .expect_err("Truncated binvox data was parsed.");
+    assert_eq!(
+        gvox_rs::ErrorType::ParseAdapterInvalidInput,
+        truncated.error_type()
+    );
+    assert!(
+        truncated.to_string().contains("ended at byte 60"),
+        "Error did not report where the data ended: {truncated}"
+    );
+
+    let mut corrupt = BINVOX_BYTES.to_vec();
+    corrupt[16] = b'x';
+    let corrupt = parse(&corrupt).expect_err("Corrupt binvox header was parsed.");
+    assert_eq!(
+        gvox_rs::ErrorType::ParseAdapterInvalidInput,
+        corrupt.error_type()
+    );
+    assert!(
+        corrupt.to_string().contains("at byte 10"),
+        "Error did not report the invalid line: {corrupt}"
+    );
+}
+
+#[cfg(feature = "minecraft")]
+fn schematic_nbt(extent: [i16; 3], blocks: &[u8]) -> Vec<u8> {
+    fn named(nbt: &mut Vec<u8>, tag: u8, name: &str) {
+        nbt.push(tag);
+        nbt.extend((name.len() as u16).to_be_bytes());
+        nbt.extend(name.as_bytes());
+    }
+
+    let mut nbt = Vec::new();
+    named(&mut nbt, 10, "Schematic");
+    for (name, value) in ["Width", "Height", "Length"].into_iter().zip(extent) {
+        named(&mut nbt, 2, name);
+        nbt.extend(value.to_be_bytes());
+    }
+    named(&mut nbt, 8, "Materials");
+    nbt.extend(5u16.to_be_bytes());
+    nbt.extend(b"Alpha");
+    for name in ["Blocks", "Data"] {
+        named(&mut nbt, 7, name);
+        nbt.extend((blocks.len() as i32).to_be_bytes());
+        nbt.extend(if name == "Blocks" {
+            blocks.to_vec()
+        } else {
+            vec![0; blocks.len()]
+        });
+    }
+    named(&mut nbt, 9, "Entities");
+    nbt.push(10);
+    nbt.extend(0i32.to_be_bytes());
+    nbt.push(0);
+    nbt
+}
+
+#[cfg(feature = "minecraft")]
+#[test]
+fn gvox_rs_test_schematic() {
+    use std::io::Write;
+
+    // Blocks are stored with x varying fastest, then z, then y.
+    let index = |x: usize, y: usize, z: usize| (y * 2 + z) * 3 + x;
+    let mut blocks = [0; 3 * 2 * 2];
+    blocks[index(0, 0, 0)] = 1;
+    blocks[index(2, 0, 1)] = 2;
+    blocks[index(1, 1, 0)] = 35;
+    let nbt = schematic_nbt([3, 2, 2], &blocks);
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&nbt)
+        .expect("Failed to compress schematic.");
+    let compressed = encoder.finish().expect("Failed to compress schematic.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let parse = |bytes: &[u8]| {
+        let config = gvox_rs::adapters::SparseVoxelsOutAdapterConfig::new();
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(bytes)
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::Schematic>()
+            .expect("Failed to get schematic parse adapter.")
+            .create_adapter_context(gvox_rs::adapters::SchematicParseAdapterConfig {
+                block_colors: [(1, 0xff808080), (2, 0xff00ff00)].into_iter().collect(),
+                default_color: 0xffff00ff,
+            })
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::SparseVoxelsOut>()
+            .expect("Failed to get sparse voxel serialize adapter.")
+            .create_adapter_context(config.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            None,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+        )
+        .map(|()| config.take())
+    };
+
+    for bytes in [&compressed, &nbt] {
+        let voxels = parse(bytes).expect("Error while translating.");
+        assert_eq!(3, voxels.len(), "Block count did not match.");
+        for ((x, y, z), id, color) in [
+            ((0, 0, 0), 1, 0xff808080),
+            ((2, 0, 1), 2, 0xff00ff00),
+            ((1, 1, 0), 35, 0xffff00ff),
+        ] {
+            let samples = voxels
+                .get(&gvox_rs::Offset3D { x, y, z })
+                .unwrap_or_else(|| panic!("Block {id} was not parsed at {x}, {y}, {z}."));
+            assert_eq!(Some(id), samples.get(gvox_rs::ChannelId::MATERIAL_ID));
+            assert_eq!(Some(color), samples.get(gvox_rs::ChannelId::COLOR));
+        }
+    }
+
+    let error = parse(&schematic_nbt([3, 2, 3], &blocks))
+        .expect_err("Schematic with too few blocks was parsed.");
+    assert_eq!(
+        gvox_rs::ErrorType::ParseAdapterInvalidInput,
+        error.error_type()
+    );
+    assert!(
+        error.to_string().contains("had 12 blocks"),
+        "Error did not report the number of blocks: {error}"
+    );
+}
+
+#[test]
+fn gvox_rs_test_voxel_dump() {
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: -2, y: -2, z: 0 },
+        extent: gvox_rs::Extent3D { x: 4, y: 4, z: 2 },
+    };
+    let present = |offset: gvox_rs::Offset3D| offset.x <= offset.y;
+    let sampler = gvox_rs::adapters::FnSamplerAdapterConfig::new(
+        move |offset, channel_id| present(offset).then_some(u32::from(channel_id) + 7),
+        gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL | gvox_rs::ChannelId::MATERIAL_ID,
+    );
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let dump = |format, range: &gvox_rs::RegionRange| {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                .expect("Failed to get function sampler parse adapter.")
+                .create_adapter_context(sampler.clone())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::VoxelDump>()
+                .expect("Failed to get voxel dump serialize adapter.")
+                .create_adapter_context(gvox_rs::adapters::VoxelDumpSerializeAdapterConfig {
+                    format,
+                })
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, range, channels)
+                .expect("Error while translating.");
+        }
+
+        String::from_utf8(o_buffer.into_vec()).expect("Voxel dump was not valid UTF-8.")
+    };
+
+    let present_count = (0..2)
+        .flat_map(|z| (-2..2).flat_map(move |y| (-2..2).map(move |x| (x, y, z))))
+        .filter(|&(x, y, z)| present(gvox_rs::Offset3D { x, y, z }))
+        .count();
+
+    let csv = dump(gvox_rs::adapters::VoxelDumpFormat::Csv, &range);
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!("x,y,z,channel,value", lines[0]);
+    assert_eq!(1 + present_count * 2, lines.len());
+    let color = u32::from(gvox_rs::ChannelId::COLOR);
+    assert_eq!(format!("-2,-2,0,{color},{}", color + 7), lines[1]);
+
+    // Voxels are ordered with x varying fastest, then y, then z.
+    let positions = lines[1..]
+        .iter()
+        .map(|line| {
+            let fields = line
+                .split(',')
+                .map(|x| x.parse::<i32>().expect("Invalid voxel dump field."))
+                .collect::<Vec<_>>();
+            (fields[2], fields[1], fields[0])
+        })
+        .collect::<Vec<_>>();
+    assert!(positions.windows(2).all(|x| x[0] <= x[1]));
+
+    let empty = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 1, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+    };
+    assert_eq!(
+        "x,y,z,channel,value\n",
+        dump(gvox_rs::adapters::VoxelDumpFormat::Csv, &empty)
+    );
+
+    #[cfg(feature = "serde_json")]
+    {
+        let json = dump(gvox_rs::adapters::VoxelDumpFormat::Json, &range);
+        assert_eq!(2 + present_count * 2, json.lines().count());
+        let objects: Vec<serde_json::Value> =
+            serde_json::from_str(&json).expect("Voxel dump was not valid JSON.");
+        assert_eq!(present_count * 2, objects.len());
+        assert_eq!(serde_json::json!(-2), objects[0]["x"]);
+
+        assert_eq!(
+            "[]\n",
+            dump(gvox_rs::adapters::VoxelDumpFormat::Json, &empty)
+        );
+    }
+}
+
+#[test]
+fn gvox_rs_test_dense_arrays() {
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+    let extractions = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Serialize, DenseExtractionAdapter>()
+        .expect("Failed to register dense extraction adapter.");
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+    {
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, DenseExtractionAdapter>()
+            .expect("Failed to get dense extraction adapter.")
+            .create_adapter_context(extractions.clone())
+            .expect("Failed to create adapter context.");
+        gvox_ctx
+            .blit()
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR)
+            .mode(gvox_rs::BlitMode::SerializeDriven)
+            .execute()
+            .expect("Error while translating.");
+    }
+
+    let output = std::sync::Arc::new(std::sync::Mutex::new(
+        gvox_rs::adapters::DenseOutput::default(),
+    ));
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::DenseArrays>()
+        .expect("Failed to get dense arrays serialize adapter.")
+        .create_adapter_context(output.clone())
+        .expect("Failed to create adapter context.");
+    let extractions = extractions.lock().expect("Failed to lock extractions.");
+    assert!(!extractions.is_empty(), "No regions were extracted.");
+
+    for mode in [
+        gvox_rs::BlitMode::SerializeDriven,
+        gvox_rs::BlitMode::ParseDriven,
+    ] {
+        gvox_ctx
+            .blit()
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID)
+            .mode(mode)
+            .execute()
+            .expect("Error while translating.");
+
+        let output = output.lock().expect("Failed to lock dense output.");
+        assert_eq!(range, output.range());
+        assert_eq!(
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+            output.channels()
+        );
+        let color = output
+            .plane(gvox_rs::ChannelId::COLOR)
+            .expect("Color plane was not written.");
+        assert_eq!(range.volume() as usize, color.data.len());
+        assert_eq!(range.volume() as usize, color.mask.len());
+
+        for (offset, sample) in extractions.iter().flat_map(|x| &x.samples) {
+            assert_eq!(
+                sample.is_present.then_some(sample.data),
+                output.get(offset, gvox_rs::ChannelId::COLOR),
+                "Dense arrays did not match sampled voxel at {offset:?} in {mode:?} mode."
+            );
+        }
+    }
+}
+
+#[test]
+fn gvox_rs_test_dense_arrays_input() {
+    let origin = gvox_rs::Offset3D { x: -1, y: 0, z: 2 };
+    let extent = gvox_rs::Extent3D { x: 4, y: 3, z: 2 };
+    let volume = (extent.x * extent.y * extent.z) as usize;
+    let colors = (0..volume as u32)
+        .map(|i| 0xff000000 | i * 0x010203)
+        .collect::<Vec<_>>();
+    let materials = (0..volume as u32).map(|i| i % 3).collect::<Vec<_>>();
+    let mask = (0..volume).map(|i| i % 5 != 0).collect::<Vec<_>>();
+    let config = gvox_rs::adapters::DenseArraysInputAdapterConfig::new(origin, extent)
+        .with_channel(gvox_rs::ChannelId::COLOR, &colors)
+        .with_channel(gvox_rs::ChannelId::MATERIAL_ID, &materials)
+        .with_mask(&mask);
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let error = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::DenseArraysInput>()
+        .expect("Failed to get dense arrays input parse adapter.")
+        .create_adapter_context(config.with_mask(&mask[1..]))
+        .expect_err("Mask of the wrong length was accepted.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+
+    let mut raw = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut raw,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::DenseArraysInput>()
+            .expect("Failed to get dense arrays input parse adapter.")
+            .create_adapter_context(config)
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, None, channels)
+            .expect("Error while translating.");
+    }
+
+    let output = std::sync::Arc::new(std::sync::Mutex::new(
+        gvox_rs::adapters::DenseOutput::default(),
+    ));
+    {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(&raw[..])
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::DenseArrays>()
+            .expect("Failed to get dense arrays serialize adapter.")
+            .create_adapter_context(output.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(&mut i_ctx, None, &mut p_ctx, &mut s_ctx, None, channels)
+            .expect("Error while translating.");
+    }
+
+    // Only voxels with a value are compared, since gvox_raw stores a value for every voxel.
+    let output = output.lock().expect("Failed to lock dense output.");
+    assert_eq!(config.range(), output.range());
+    for (i, _) in mask.iter().enumerate().filter(|&(_, &present)| present) {
+        let (x, y, z) = (
+            i as u32 % extent.x,
+            i as u32 / extent.x % extent.y,
+            i as u32 / (extent.x * extent.y),
+        );
+        let position = gvox_rs::Offset3D {
+            x: origin.x + x as i32,
+            y: origin.y + y as i32,
+            z: origin.z + z as i32,
+        };
+        assert_eq!(
+            Some(colors[i]),
+            output.get(&position, gvox_rs::ChannelId::COLOR)
+        );
+        assert_eq!(
+            Some(materials[i]),
+            output.get(&position, gvox_rs::ChannelId::MATERIAL_ID)
+        );
+    }
+}
+
+#[test]
+fn gvox_rs_test_brick_buffer() {
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -8,
+            y: -8,
+            z: -8,
+        },
+        extent: gvox_rs::Extent3D {
+            x: 16,
+            y: 16,
+            z: 16,
+        },
+    };
+    let terrain = procedural_parse::terrain();
+    let sample = move |offset: gvox_rs::Offset3D, channel_id| {
+        let corner = offset.x >= 4 && offset.y >= 4 && offset.z >= 4;
+        (!corner)
+            .then(|| (terrain.sampler)(offset, channel_id))
+            .flatten()
+    };
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let error = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::BrickBuffer>()
+        .expect("Failed to get brick buffer serialize adapter.")
+        .create_adapter_context(gvox_rs::adapters::BrickBufferSerializeAdapterConfig {
+            brick_size: 0,
+            ..Default::default()
+        })
+        .expect_err("Brick size of zero was accepted.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+
+    let config = gvox_rs::adapters::BrickBufferSerializeAdapterConfig {
+        brick_size: 4,
+        ..Default::default()
+    };
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(gvox_rs::adapters::FnSamplerAdapterConfig::new(
+            sample.clone(),
+            gvox_rs::ChannelId::COLOR.into(),
+        ))
+        .expect("Failed to create adapter context.");
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::BrickBuffer>()
+        .expect("Failed to get brick buffer serialize adapter.")
+        .create_adapter_context(config.clone())
+        .expect("Failed to create adapter context.");
+
+    for mode in [
+        gvox_rs::BlitMode::SerializeDriven,
+        gvox_rs::BlitMode::ParseDriven,
+    ] {
+        gvox_ctx
+            .blit()
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR)
+            .mode(mode)
+            .execute()
+            .expect("Error while translating.");
+
+        let output = config.take();
+        assert_eq!(range.offset, output.origin);
+        assert_eq!(4, output.brick_size);
+        assert_eq!(gvox_rs::Extent3D { x: 4, y: 4, z: 4 }, output.grid_extent);
+        assert_eq!(64, output.index.len());
+
+        // The corner without voxels is empty, the sky far from the terrain is constant, and the terrain is dense.
+        assert_eq!(Some(gvox_rs::adapters::Brick::Empty), output.brick(3, 3, 3));
+        assert_eq!(
+            gvox_rs::adapters::BrickBufferOutput::EMPTY,
+            output.index[63]
+        );
+        assert_eq!(
+            Some(gvox_rs::adapters::Brick::Constant(
+                sample(range.offset, gvox_rs::ChannelId::COLOR).expect("Sky had no color.")
+            )),
+            output.brick(0, 0, 0)
+        );
+        assert!(
+            matches!(
+                output.brick(1, 1, 1),
+                Some(gvox_rs::adapters::Brick::Dense(x)) if x.len() == 64
+            ),
+            "Terrain brick was not stored densely in {mode:?} mode."
+        );
+
+        for z in range.offset.z..range.offset.z + 16 {
+            for y in range.offset.y..range.offset.y + 16 {
+                for x in range.offset.x..range.offset.x + 16 {
+                    let offset = gvox_rs::Offset3D { x, y, z };
+                    assert_eq!(
+                        sample(offset, gvox_rs::ChannelId::COLOR),
+                        output.get(&offset),
+                        "Brick buffer did not match sampled voxel at {offset:?} in {mode:?} mode."
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gvox_rs_test_rust_run_length_encoding() {
+    fn encode(
+        gvox_ctx: &gvox_rs::Context,
+        config: gvox_rs::adapters::DenseArraysInputAdapterConfig<'_>,
+        channels: gvox_rs::ChannelFlags,
+    ) -> Box<[u8]> {
+        let mut bytes = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut bytes,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::DenseArraysInput>()
+                .expect("Failed to get dense arrays input parse adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::RustRunLengthEncoding>()
+                .expect("Failed to get Rust run-length encoding serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, None, channels)
+                .expect("Error while encoding.");
+        }
+        bytes
+    }
+
+    fn decode(
+        gvox_ctx: &gvox_rs::Context,
+        bytes: &[u8],
+        range: Option<&gvox_rs::RegionRange>,
+        channels: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::adapters::DenseOutput, gvox_rs::GvoxError> {
+        let output = std::sync::Arc::new(std::sync::Mutex::new(
+            gvox_rs::adapters::DenseOutput::default(),
+        ));
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(bytes)
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::RustRunLengthEncoding>()
+                .expect("Failed to get Rust run-length encoding parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::DenseArrays>()
+                .expect("Failed to get dense arrays serialize adapter.")
+                .create_adapter_context(output.clone())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(&mut i_ctx, None, &mut p_ctx, &mut s_ctx, range, channels)?;
+        }
+        let output = output.lock().expect("Failed to lock dense output.");
+        Ok(output.clone())
+    }
+
+    let mut state = 0x2545f4914f6cdd1d_u64;
+    let mut random = move |n: u32| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % n as u64) as u32
+    };
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+    let gvox_ctx = gvox_rs::Context::new();
+
+    // Sparse regions draw from a small palette so that they contain runs, while uniform regions are entirely
+    // filled with a single value, or entirely empty.
+    for case in 0..24 {
+        let origin = gvox_rs::Offset3D {
+            x: random(32) as i32 - 16,
+            y: random(32) as i32 - 16,
+            z: random(32) as i32 - 16,
+        };
+        let extent = gvox_rs::Extent3D {
+            x: 1 + random(9),
+            y: 1 + random(9),
+            z: 1 + random(9),
+        };
+        let volume = (extent.x * extent.y * extent.z) as usize;
+        let uniform = case % 3 != 0;
+        let value = random(4);
+        let colors = (0..volume)
+            .map(|_| 0xff000000 | if uniform { value } else { random(3) } * 0x404040)
+            .collect::<Vec<_>>();
+        let materials = (0..volume)
+            .map(|_| if uniform { value } else { random(2) })
+            .collect::<Vec<_>>();
+        let mask = (0..volume)
+            .map(|_| {
+                if uniform {
+                    case % 3 == 1
+                } else {
+                    random(3) == 0
+                }
+            })
+            .collect::<Vec<_>>();
+        let config = gvox_rs::adapters::DenseArraysInputAdapterConfig::new(origin, extent)
+            .with_channel(gvox_rs::ChannelId::COLOR, &colors)
+            .with_channel(gvox_rs::ChannelId::MATERIAL_ID, &materials)
+            .with_mask(&mask);
+
+        let bytes = encode(&gvox_ctx, config, channels);
+        let header = gvox_rs::format::RleHeader::read(&bytes).expect("Failed to read header.");
+        assert_eq!(config.range(), header.range);
+        assert_eq!(2, header.channel_n);
+
+        let output = decode(&gvox_ctx, &bytes, None, channels).expect("Error while decoding.");
+        assert_eq!(config.range(), output.range());
+        for i in 0..volume {
+            let position = gvox_rs::Offset3D {
+                x: origin.x + (i as u32 % extent.x) as i32,
+                y: origin.y + (i as u32 / extent.x % extent.y) as i32,
+                z: origin.z + (i as u32 / (extent.x * extent.y)) as i32,
+            };
+            assert_eq!(
+                mask[i].then_some(colors[i]),
+                output.get(&position, gvox_rs::ChannelId::COLOR),
+                "Color did not round trip at {position:?} in case {case}."
+            );
+            assert_eq!(
+                mask[i].then_some(materials[i]),
+                output.get(&position, gvox_rs::ChannelId::MATERIAL_ID),
+                "Material did not round trip at {position:?} in case {case}."
+            );
+        }
+
+        let mut future = bytes.to_vec();
+        future[4..8].copy_from_slice(&(gvox_rs::format::RLE_VERSION + 1).to_le_bytes());
+        let error =
+            decode(&gvox_ctx, &future, None, channels).expect_err("Unknown version was accepted.");
+        assert_eq!(
+            gvox_rs::ErrorType::ParseAdapterInvalidInput,
+            error.error_type()
+        );
+    }
+
+    let words = |bytes: &[u8]| {
+        bytes
+            .chunks_exact(4)
+            .map(|x| u32::from_le_bytes(x.try_into().expect("Chunk was not a word.")))
+            .collect::<Vec<_>>()
+    };
+    let mut runs = Vec::new();
+    gvox_rs::format::write_runs(
+        [(u32::MAX as u64 + 5, 7), (3, 7), (0, 1), (2, 9)],
+        &mut runs,
+    );
+    assert_eq!(vec![u32::MAX, 7, 8, 7, 2, 9], words(&runs));
+
+    // Runs which cover more than u32::MAX voxels are split, so a small part of a huge volume is decoded.
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D {
+            x: 1 << 16,
+            y: 1 << 16,
+            z: 2,
+        },
+    };
+    let mut bytes = Vec::new();
+    gvox_rs::format::RleHeader::new(range, 1).write(&mut bytes);
+    bytes.extend_from_slice(&u32::from(gvox_rs::ChannelId::COLOR).to_le_bytes());
+    gvox_rs::format::write_runs([(range.volume(), 1)], &mut bytes);
+    let split = u32::MAX as u64;
+    gvox_rs::format::write_runs(
+        [(split, 1), (split, 2), (range.volume() - 2 * split, 3)],
+        &mut bytes,
+    );
+    assert_eq!(
+        12,
+        words(&bytes[gvox_rs::format::RLE_HEADER_SIZE + 4..]).len(),
+        "Each list of runs was not split into three."
+    );
+
+    let tail = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: (1 << 16) - 4,
+            y: (1 << 16) - 1,
+            z: 0,
+        },
+        extent: gvox_rs::Extent3D { x: 4, y: 1, z: 2 },
+    };
+    let output = decode(
+        &gvox_ctx,
+        &bytes,
+        Some(&tail),
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Error while decoding.");
+    for z in 0..2 {
+        for x in tail.offset.x..tail.offset.x + 4 {
+            let position = gvox_rs::Offset3D {
+                x,
+                y: tail.offset.y,
+                z,
+            };
+            let index = x as u64 + (1 << 16) * (tail.offset.y as u64 + (1 << 16) * z as u64);
+            assert_eq!(
+                Some(1 + (index / split) as u32),
+                output.get(&position, gvox_rs::ChannelId::COLOR),
+                "Huge volume did not decode at {position:?}."
+            );
+        }
+    }
+}
+
+#[test]
+fn gvox_rs_test_transform_parse() {
+    type TransformedProcedural = gvox_rs::adapters::TransformParse<gvox_rs::adapters::FnSampler>;
+
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+    let halve_red = |color: u32| (color & !0xff) | (color & 0xff) / 2;
+    let terrain = procedural_parse::terrain();
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter_named::<gvox_rs::Parse, TransformedProcedural>("procedural_transformed")
+        .expect("Failed to register transformed procedural parse adapter.");
+    let mut p_ctx = gvox_ctx
+        .get_adapter_named::<gvox_rs::Parse, TransformedProcedural>("procedural_transformed")
+        .expect("Failed to get transformed procedural parse adapter.")
+        .create_adapter_context(gvox_rs::adapters::TransformParseAdapterConfig {
+            transforms: gvox_rs::adapters::ChannelTransforms::new()
+                .with(gvox_rs::ChannelId::COLOR, halve_red),
+            config: procedural_parse::terrain(),
+        })
+        .expect("Failed to create adapter context.");
+
+    let output = std::sync::Arc::new(std::sync::Mutex::new(
+        gvox_rs::adapters::DenseOutput::default(),
+    ));
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::DenseArrays>()
+        .expect("Failed to get dense arrays serialize adapter.")
+        .create_adapter_context(output.clone())
+        .expect("Failed to create adapter context.");
+
+    for mode in [
+        gvox_rs::BlitMode::SerializeDriven,
+        gvox_rs::BlitMode::ParseDriven,
+    ] {
+        gvox_ctx
+            .blit()
+            .parse(&mut p_ctx)
+            .serialize(&mut s_ctx)
+            .range(range)
+            .channels(gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID)
+            .mode(mode)
+            .execute()
+            .expect("Error while translating.");
+
+        // Colors are transformed, while material IDs have no transform and pass through untouched.
+        let output = output.lock().expect("Failed to lock dense output.");
+        for z in range.offset.z..range.offset.z + 8 {
+            for y in range.offset.y..range.offset.y + 8 {
+                for x in range.offset.x..range.offset.x + 8 {
+                    let offset = gvox_rs::Offset3D { x, y, z };
+                    assert_eq!(
+                        (terrain.sampler)(offset, gvox_rs::ChannelId::COLOR).map(halve_red),
+                        output.get(&offset, gvox_rs::ChannelId::COLOR),
+                        "Color was not transformed at {offset:?} in {mode:?} mode."
+                    );
+                    assert_eq!(
+                        (terrain.sampler)(offset, gvox_rs::ChannelId::MATERIAL_ID),
+                        output.get(&offset, gvox_rs::ChannelId::MATERIAL_ID),
+                        "Material ID was changed at {offset:?} in {mode:?} mode."
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn gvox_rs_test_transformed_parse() {
+    type RotatedProcedural = gvox_rs::adapters::TransformedParse<gvox_rs::adapters::FnSampler>;
+
+    let inner_range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+    let terrain = gvox_rs::adapters::FnSamplerAdapterConfig {
+        range: Some(inner_range),
+        ..procedural_parse::terrain()
+    };
+    let transform = gvox_rs::adapters::VoxelTransform::identity().rotate_z(1);
+
+    let position = gvox_rs::Offset3D { x: 1, y: -2, z: 3 };
+    assert_eq!(
+        gvox_rs::Offset3D { x: 2, y: 1, z: 3 },
+        transform.apply(&position)
+    );
+    assert_eq!(
+        position,
+        transform.inverse().apply(&transform.apply(&position))
+    );
+    assert_eq!(
+        gvox_rs::adapters::VoxelTransform::identity(),
+        transform.rotate_z(3)
+    );
+    let mirrored = gvox_rs::adapters::VoxelTransform::new(
+        [0, 1, 2],
+        [-1, 1, 1],
+        gvox_rs::Offset3D { x: 3, y: 0, z: 0 },
+    )
+    .expect("Failed to create mirror transform.");
+    assert_eq!(
+        gvox_rs::adapters::VoxelTransform::identity()
+            .mirror_x()
+            .translate(gvox_rs::Offset3D { x: 3, y: 0, z: 0 }),
+        mirrored
+    );
+    assert_eq!(
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: -4, z: -4 },
+            extent: inner_range.extent,
+        },
+        mirrored.apply_range(&inner_range)
+    );
+    let error =
+        gvox_rs::adapters::VoxelTransform::new([0, 0, 2], [1, 1, 1], gvox_rs::Offset3D::default())
+            .expect_err("Repeated axis was accepted.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter_named::<gvox_rs::Parse, RotatedProcedural>("procedural_rotated")
+        .expect("Failed to register rotated procedural parse adapter.");
+    let mut p_ctx = gvox_ctx
+        .get_adapter_named::<gvox_rs::Parse, RotatedProcedural>("procedural_rotated")
+        .expect("Failed to get rotated procedural parse adapter.")
+        .create_adapter_context(gvox_rs::adapters::TransformedParseAdapterConfig {
+            transform,
+            config: terrain.clone(),
+        })
+        .expect("Failed to create adapter context.");
+
+    let output = std::sync::Arc::new(std::sync::Mutex::new(
+        gvox_rs::adapters::DenseOutput::default(),
+    ));
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::DenseArrays>()
+        .expect("Failed to get dense arrays serialize adapter.")
+        .create_adapter_context(output.clone())
+        .expect("Failed to create adapter context.");
+    gvox_rs::blit_region(
+        None,
+        None,
+        &mut p_ctx,
+        &mut s_ctx,
+        None,
+        gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL,
+    )
+    .expect("Error while translating.");
+
+    // Rotating moves the last column of x onto the first column of y, which shifts the range by one voxel.
+    let output = output.lock().expect("Failed to lock dense output.");
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -3,
+            y: -4,
+            z: -4,
+        },
+        extent: inner_range.extent,
+    };
+    assert_eq!(range, output.range());
+
+    let rotate_normal = |normal: u32| {
+        let (x, y, z) = (normal & 0xff, (normal >> 8) & 0xff, (normal >> 16) & 0xff);
+        (normal & 0xff000000) | (0xff - y) | (x << 8) | (z << 16)
+    };
+    for z in range.offset.z..range.offset.z + 8 {
+        for y in range.offset.y..range.offset.y + 8 {
+            for x in range.offset.x..range.offset.x + 8 {
+                let offset = gvox_rs::Offset3D { x, y, z };
+                let inner = gvox_rs::Offset3D { x: y, y: -x, z };
+                assert_eq!(
+                    (terrain.sampler)(inner, gvox_rs::ChannelId::COLOR),
+                    output.get(&offset, gvox_rs::ChannelId::COLOR),
+                    "Color at {offset:?} did not match {inner:?} of the inner adapter."
+                );
+                assert_eq!(
+                    (terrain.sampler)(inner, gvox_rs::ChannelId::NORMAL).map(rotate_normal),
+                    output.get(&offset, gvox_rs::ChannelId::NORMAL),
+                    "Normal at {offset:?} was not rotated from {inner:?} of the inner adapter."
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn gvox_rs_test_magicavoxel_model_corrupt_size() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let parse = |vox: &[u8]| {
+        let mut o_buffer = Box::default();
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(vox)
+                .expect("Failed to create adapter context.");
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxelModel>()
+                .expect("Failed to get magicavoxel model parse adapter.")
+                .create_adapter_context(Default::default())
+                .expect("Failed to create adapter context.");
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+        }
+    };
+
+    // The content of the first SIZE chunk follows the file header, the MAIN chunk header, and its own header.
+    let size_offset = 32;
+    let vox = two_model_vox();
+    assert_eq!(b"SIZE", &vox[size_offset - 12..size_offset - 8]);
+
+    let with_size = |x: u32, y: u32, z: u32| {
+        let mut vox = vox.clone();
+        for (i, value) in [x, y, z].into_iter().enumerate() {
+            vox[size_offset + 4 * i..size_offset + 4 * i + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        vox
+    };
+    for (x, y, z) in [
+        (u32::MAX, u32::MAX, u32::MAX),
+        (257, 1, 1),
+        (1, 1, 1 << 20),
+        (65536, 65536, 2),
+    ] {
+        let error = parse(&with_size(x, y, z)).expect_err("Oversized model was parsed.");
+        assert_eq!(
+            gvox_rs::ErrorType::ParseAdapterInvalidInput,
+            error.error_type(),
+            "Oversized model of {x}x{y}x{z} produced the wrong error type."
+        );
+    }
+    parse(&with_size(256, 256, 1)).expect("Error while translating.");
+
+    // Every other corruption of the SIZE chunk must be rejected or parsed, without panicking.
+    let mut state = 0x2545f491u32;
+    for _ in 0..64 {
+        let mut corrupt = vox.clone();
+        for byte in &mut corrupt[size_offset..size_offset + 12] {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            *byte = (state % 5) as u8 * 64;
+        }
+        let _ = parse(&corrupt);
+    }
+}
+
+pub struct SlabParseAdapter;
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for SlabParseAdapter {
+    type Configuration<'a> = ();
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for SlabParseAdapter {
+    fn name() -> &'static str {
+        "parse_driven_slabs"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for SlabParseAdapter {
+    fn create(config: &()) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self)
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::ParseAdapterHandler<Self> for SlabParseAdapter {
+    type RegionData = ();
+
+    fn query_details() -> gvox_rs::ParseAdapterDetails {
+        gvox_rs::ParseAdapterDetails::new(gvox_rs::BlitMode::ParseDriven)
+    }
+
+    fn query_parsable_range(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+    ) -> gvox_rs::RegionRange {
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 4, y: 2, z: 2 },
+        }
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
+        Ok(gvox_rs::RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::Region<()>, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Region::builder()
+            .range(*range)
+            .channels(channel_flags & gvox_rs::ChannelId::COLOR)
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: gvox_rs::Region<()>,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: &gvox_rs::Region<()>,
+        offset: &gvox_rs::Offset3D,
+        channel_id: gvox_rs::ChannelId,
+    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Sample {
+            data: offset.x as u32 + 1,
+            is_present: true,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        for x in 0..range.extent.x as i32 {
+            let slab = gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D {
+                    x: range.offset.x + x,
+                    ..range.offset
+                },
+                extent: gvox_rs::Extent3D {
+                    x: 1,
+                    ..range.extent
+                },
+            };
+            let region = self.load_region(blit_ctx, &slab, channel_flags)?;
+            blit_ctx.emit_region(&region)?;
+        }
+        Ok(())
+    }
+}
+
+fn blit_slabs_into_grid(
+    gvox_ctx: &gvox_rs::Context,
+    grid: &mut gvox_rs::VoxelGrid,
+    range: Option<&gvox_rs::RegionRange>,
+) {
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, SlabParseAdapter>()
+        .expect("Failed to get slab parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::voxel_grid::VoxelGridAdapter>()
+        .expect("Failed to get voxel grid serialize adapter.")
+        .create_adapter_context(gvox_rs::voxel_grid::VoxelGridTarget::from(grid))
+        .expect("Failed to create adapter context.");
+
+    gvox_rs::blit_region(
+        None,
+        None,
+        &mut p_ctx,
+        &mut s_ctx,
+        range,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Error while translating.");
+}
+
+#[test]
+fn gvox_rs_test_voxel_grid_regions() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, SlabParseAdapter>()
+        .expect("Failed to register slab parse adapter.");
+
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 4, y: 2, z: 2 },
+    };
+    let check = |grid: &gvox_rs::VoxelGrid| {
+        assert_eq!(range, grid.range(), "Grid did not cover every region.");
+        for z in 0..2 {
+            for y in 0..2 {
+                for x in 0..4 {
+                    let position = gvox_rs::Offset3D { x, y, z };
+                    assert_eq!(
+                        Some(x as u32 + 1),
+                        grid.get(&position, gvox_rs::ChannelId::COLOR),
+                        "Voxel {position:?} did not match its region."
+                    );
+                }
+            }
+        }
+    };
+
+    let mut grid = gvox_rs::VoxelGrid::default();
+    blit_slabs_into_grid(&gvox_ctx, &mut grid, None);
+    check(&grid);
+
+    let plane = grid
+        .plane(gvox_rs::ChannelId::COLOR)
+        .expect("Grid did not hold the color channel.")
+        .as_ptr();
+    blit_slabs_into_grid(&gvox_ctx, &mut grid, Some(&range));
+    check(&grid);
+    assert_eq!(
+        Some(plane),
+        grid.plane(gvox_rs::ChannelId::COLOR).map(<[u32]>::as_ptr),
+        "Grid was reallocated although its range was unchanged."
+    );
+}
+
+#[test]
+fn gvox_rs_test_dense_arrays_regions() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, SlabParseAdapter>()
+        .expect("Failed to register slab parse adapter.");
+
+    let output = std::sync::Arc::new(std::sync::Mutex::new(
+        gvox_rs::adapters::DenseOutput::default(),
+    ));
+    {
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, SlabParseAdapter>()
+            .expect("Failed to get slab parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::DenseArrays>()
+            .expect("Failed to get dense arrays serialize adapter.")
+            .create_adapter_context(output.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            None,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    let output = output.lock().expect("Failed to lock dense output.");
+    let mut grid = gvox_rs::VoxelGrid::default();
+    blit_slabs_into_grid(&gvox_ctx, &mut grid, None);
+    assert_eq!(grid.range(), output.range());
+    assert!(output.grid().has_presence());
+    let color = output
+        .plane(gvox_rs::ChannelId::COLOR)
+        .expect("Dense output did not hold the color channel.");
+    assert!(color.mask.iter().all(|&x| x), "Voxel was missing.");
+    assert_eq!(
+        grid.plane(gvox_rs::ChannelId::COLOR),
+        Some(&color.data[..]),
+        "Dense output did not match the voxel grid."
+    );
+}
+
+#[test]
+fn gvox_rs_test_brick_buffer_regions() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, SlabParseAdapter>()
+        .expect("Failed to register slab parse adapter.");
+
+    let config = gvox_rs::adapters::BrickBufferSerializeAdapterConfig {
+        brick_size: 2,
+        ..Default::default()
+    };
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, SlabParseAdapter>()
+        .expect("Failed to get slab parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::BrickBuffer>()
+        .expect("Failed to get brick buffer serialize adapter.")
+        .create_adapter_context(config.clone())
+        .expect("Failed to create adapter context.");
+
+    let error = gvox_ctx
+        .blit()
+        .parse(&mut p_ctx)
+        .serialize(&mut s_ctx)
+        .channels(gvox_rs::ChannelId::COLOR)
+        .mode(gvox_rs::BlitMode::ParseDriven)
+        .execute()
+        .expect_err("Parse-driven blit without a range was accepted.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 4, y: 2, z: 2 },
+    };
+    gvox_ctx
+        .blit()
+        .parse(&mut p_ctx)
+        .serialize(&mut s_ctx)
+        .range(range)
+        .channels(gvox_rs::ChannelId::COLOR)
+        .mode(gvox_rs::BlitMode::ParseDriven)
+        .execute()
+        .expect("Error while translating.");
+
+    let output = config.take();
+    assert_eq!(gvox_rs::Extent3D { x: 2, y: 1, z: 1 }, output.grid_extent);
+    for z in 0..2 {
+        for y in 0..2 {
+            for x in 0..4 {
+                let position = gvox_rs::Offset3D { x, y, z };
+                assert_eq!(
+                    Some(x as u32 + 1),
+                    output.get(&position),
+                    "Brick buffer dropped the region holding {position:?}."
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn gvox_rs_test_substitute_raw_access() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let adapter = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::StdOut>()
+        .expect("Failed to get standard output adapter.");
+
+    let mut o_ctx = adapter
+        .create_adapter_context(gvox_rs::adapters::StdOutOutputAdapterConfig::default())
+        .expect("Failed to create adapter context.");
+    o_ctx
+        .with_raw(|_| ())
+        .expect("Unbuffered output did not allow raw access.");
+
+    let buffered = gvox_rs::adapters::StdOutOutputAdapterConfig {
+        buffered: true,
+        ..Default::default()
+    };
+    let mut o_ctx = adapter
+        .create_adapter_context(buffered)
+        .expect("Failed to create adapter context.");
+    let error = o_ctx
+        .with_raw(|_| ())
+        .expect_err("Buffered output allowed raw access.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+    let error = o_ctx
+        .into_raw()
+        .expect_err("Buffered output was released as a raw adapter context.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+}
+
+#[test]
+fn gvox_rs_test_input_read() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(MAGICAVOXEL_BYTES)
+        .expect("Failed to create adapter context.");
+    let mut magic = [0; 4];
+    i_ctx
+        .read(0, &mut magic)
+        .expect("Failed to read byte buffer input.");
+    assert_eq!(*b"VOX ", magic);
+    assert!(i_ctx.read(MAGICAVOXEL_BYTES.len() - 2, &mut magic).is_err());
+
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBufferOwned>()
+        .expect("Failed to get owned byte buffer input adapter.")
+        .create_adapter_context(MAGICAVOXEL_BYTES.into())
+        .expect("Failed to create adapter context.");
+    let mut version = [0; 4];
+    i_ctx
+        .read(4, &mut version)
+        .expect("Failed to read owned byte buffer input.");
+    assert_eq!(MAGICAVOXEL_BYTES[4..8], version);
+
+    let p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+        .expect("Failed to get magicavoxel parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    assert_eq!(
+        gvox_rs::inspect::magicavoxel_metadata(MAGICAVOXEL_BYTES),
+        p_ctx
+            .query_metadata(&mut i_ctx)
+            .expect("Failed to query metadata.")
+    );
+}
+
+fn kvx_cubes(sizes: &[u32]) -> Vec<u8> {
+    let mut kvx = Vec::new();
+    for &n in sizes {
+        let slab = 3 + n;
+        let tables = (n + 1) * 4 + n * (n + 1) * 2;
+        let mut level = Vec::new();
+        for value in [n, n, n, n * 128, n * 128, n * 128] {
+            level.extend_from_slice(&u32::to_le_bytes(value));
+        }
+        for x in 0..=n {
+            level.extend_from_slice(&u32::to_le_bytes(tables + x * n * slab));
+        }
+        for _ in 0..n {
+            for y in 0..=n {
+                level.extend_from_slice(&u16::to_le_bytes((y * slab) as u16));
+            }
+        }
+        for _ in 0..n * n {
+            level.extend_from_slice(&[0, n as u8, 0x3f]);
+            level.extend(std::iter::repeat(1).take(n as usize));
+        }
+        kvx.extend_from_slice(&u32::to_le_bytes(level.len() as u32));
+        kvx.extend(level);
+    }
+    kvx.extend(std::iter::repeat(0x3f).take(768));
+    kvx
+}
+
+#[test]
+fn gvox_rs_test_kvx_level_to_parse() {
+    let kvx = kvx_cubes(&[2, 1]);
+    let gvox_ctx = gvox_rs::Context::new();
+    let parse = |level_to_parse| {
+        let mut grid = gvox_rs::VoxelGrid::default();
+        {
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(&kvx)
+                .expect("Failed to create adapter context.");
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::Kvx>()
+                .expect("Failed to get kvx parse adapter.")
+                .create_adapter_context(gvox_rs::adapters::KvxParseAdapterConfig {
+                    mipmaplevels: 2,
+                    level_to_parse,
+                })?;
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::voxel_grid::VoxelGridAdapter>()
+                .expect("Failed to get voxel grid serialize adapter.")
+                .create_adapter_context(gvox_rs::voxel_grid::VoxelGridTarget::from(&mut grid))
+                .expect("Failed to create adapter context.");
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                None,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )?;
+        }
+        Ok::<_, gvox_rs::GvoxError>(grid.range().extent)
+    };
+
+    assert_eq!(
+        gvox_rs::Extent3D { x: 2, y: 2, z: 2 },
+        parse(0).expect("Failed to parse level 0.")
+    );
+    assert_eq!(
+        gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+        parse(1).expect("Failed to parse level 1.")
+    );
+    assert_eq!(
+        gvox_rs::ErrorType::ParseAdapterInvalidInput,
+        parse(2)
+            .expect_err("Level beyond the last one was parsed.")
+            .error_type()
+    );
+
+    let config = gvox_rs::adapters::KvxParseAdapterConfig {
+        mipmaplevels: 2,
+        level_to_parse: 1,
+    };
+    let level = config
+        .read_level(|position, data| {
+            data.copy_from_slice(&kvx[position..position + data.len()]);
+            Ok(())
+        })
+        .expect("Failed to read level 1.");
+    assert_eq!(&kvx[kvx.len() - level.len()..], &level[..]);
+}
+
+pub struct BlitCountingAdapter {
+    blits: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for BlitCountingAdapter {
+    type Configuration<'a> = std::sync::Arc<std::sync::atomic::AtomicUsize>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for BlitCountingAdapter {
+    fn name() -> &'static str {
+        "blit_counting_uniform"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for BlitCountingAdapter {
+    fn create(
+        config: &std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            blits: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: Option<&gvox_rs::RegionRange>,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        self.blits
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl gvox_rs::ParseAdapterHandler<Self> for BlitCountingAdapter {
+    type RegionData = ();
+
+    fn query_details() -> gvox_rs::ParseAdapterDetails {
+        gvox_rs::ParseAdapterDetails::new(gvox_rs::BlitMode::DontCare)
+    }
+
+    fn query_parsable_range(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+    ) -> gvox_rs::RegionRange {
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+        }
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
+        Ok(gvox_rs::RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::Region<()>, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Region::builder()
+            .range(*range)
+            .channels(channel_flags & gvox_rs::ChannelId::COLOR)
+            .build())
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: gvox_rs::Region<()>,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: &gvox_rs::Region<()>,
+        offset: &gvox_rs::Offset3D,
+        channel_id: gvox_rs::ChannelId,
+    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Sample {
+            data: 0xff00ff00,
+            is_present: true,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_blit_regions_parse_once() {
+    let ranges = [0, 3, 6].map(|x| gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 2, y: 8, z: 8 },
+    });
+    let blits = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx
+            .register_adapter::<gvox_rs::Parse, BlitCountingAdapter>()
+            .expect("Failed to register blit counting adapter.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, BlitCountingAdapter>()
+            .expect("Failed to get blit counting adapter.")
+            .create_adapter_context(blits.clone())
+            .expect("Failed to create adapter context.");
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_regions(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &ranges,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        1,
+        blits.load(std::sync::atomic::Ordering::Relaxed),
+        "The parse adapter did not begin exactly one blit across all of the ranges."
+    );
+    assert!(!o_buffer.is_empty(), "No output was written.");
+}
+
+pub struct NestingAdapter;
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for NestingAdapter {
+    type Configuration<'a> = std::sync::Arc<std::sync::atomic::AtomicUsize>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for NestingAdapter {
+    fn name() -> &'static str {
+        "nesting_input_adapter"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Input, Self> for NestingAdapter {
+    fn create(
+        _: &std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        Err(gvox_rs::GvoxError::new(
+            gvox_rs::ErrorType::InvalidParameter,
+            "Nesting adapter was created without a context.",
+        ))
+    }
+
+    fn create_with_context(
+        ctx: &gvox_rs::Context,
+        config: &std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        let _p_ctx = ctx
+            .get_or_register_adapter::<gvox_rs::Parse, ParseDrivenAdapter>()?
+            .create_adapter_context(config.clone())?;
+        let _i_ctx = ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()?
+            .create_adapter_context(&[0; 4])?;
+        Ok(Self)
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+}
+
+impl gvox_rs::InputAdapterHandler<NestingAdapter> for NestingAdapter {
+    fn read(
+        &mut self,
+        _: &gvox_rs::InputBlitContext,
+        _: usize,
+        data: &mut [u8],
+    ) -> Result<(), gvox_rs::GvoxError> {
+        data.fill(0);
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_create_with_context_nested() {
+    let gvox_ctx = gvox_rs::Context::new();
+    let adapter = gvox_ctx
+        .register_adapter::<gvox_rs::Input, NestingAdapter>()
+        .expect("Failed to register nesting input adapter.");
+
+    let mut i_ctx = adapter
+        .create_adapter_context(std::sync::Arc::default())
+        .expect("Failed to create adapter context.");
+    i_ctx
+        .reset::<NestingAdapter>(std::sync::Arc::default())
+        .expect("Failed to reset adapter context.");
+
+    gvox_ctx
+        .get_adapter::<gvox_rs::Parse, ParseDrivenAdapter>()
+        .expect("Adapter handler did not register the parse-driven adapter.");
+}
+
+pub struct CancellingAdapter {
+    cancelled: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    open_blits: Vec<Vec<u8>>,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for CancellingAdapter {
+    type Configuration<'a> = std::sync::Arc<std::sync::atomic::AtomicBool>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for CancellingAdapter {
+    fn name() -> &'static str {
+        "cancelling_uniform"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for CancellingAdapter {
+    fn create(
+        config: &std::sync::Arc<std::sync::atomic::AtomicBool>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            cancelled: config.clone(),
+            open_blits: Vec::new(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn blit_begin(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: Option<&gvox_rs::RegionRange>,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        self.open_blits.push(vec![0; 1024]);
+        Ok(())
+    }
+
+    fn blit_end(&mut self, blit_ctx: &gvox_rs::ParseBlitContext) -> Result<(), gvox_rs::GvoxError> {
+        self.open_blits.pop();
+        Ok(())
+    }
+}
+
+impl gvox_rs::ParseAdapterHandler<Self> for CancellingAdapter {
+    type RegionData = Vec<u8>;
+
+    fn query_details() -> gvox_rs::ParseAdapterDetails {
+        gvox_rs::ParseAdapterDetails::new(gvox_rs::BlitMode::DontCare)
+    }
+
+    fn query_parsable_range(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+    ) -> gvox_rs::RegionRange {
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+            extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+        }
+    }
+
+    fn query_region_flags(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
+        Ok(gvox_rs::RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<gvox_rs::Region<Vec<u8>>, gvox_rs::GvoxError> {
+        Ok(gvox_rs::Region::new(
+            *range,
+            channel_flags & gvox_rs::ChannelId::COLOR,
+            gvox_rs::RegionFlags::empty(),
+            vec![0; 1024],
+        ))
+    }
+
+    fn unload_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: gvox_rs::Region<Vec<u8>>,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        region: &gvox_rs::Region<Vec<u8>>,
+        offset: &gvox_rs::Offset3D,
+        channel_id: gvox_rs::ChannelId,
+    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
+        self.cancelled
+            .store(true, std::sync::atomic::Ordering::Release);
+        Ok(gvox_rs::Sample {
+            data: 0xff0000ff,
+            is_present: true,
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &gvox_rs::ParseBlitContext,
+        range: &gvox_rs::RegionRange,
+        channel_flags: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_cancel_blit_leaks() {
+    let cancelled = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let gvox_ctx = gvox_rs::Context::new();
+    let mut p_ctx = gvox_ctx
+        .register_adapter::<gvox_rs::Parse, CancellingAdapter>()
+        .expect("Failed to register cancelling adapter.")
+        .create_adapter_context(cancelled.clone())
+        .expect("Failed to create adapter context.");
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    gvox_rs::testing::LeakCheck::default().assert(|| {
+        cancelled.store(false, std::sync::atomic::Ordering::Release);
+        let mut o_buffer = Box::default();
+        let res = {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region_cancellable(
+                None,
+                &mut o_ctx,
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+                &cancelled,
+            )
+        };
+
+        assert_eq!(
+            Some(gvox_rs::ErrorType::Cancelled),
+            res.err().map(|e| e.error_type()),
+            "Blit did not fail with a cancellation error."
+        );
+    });
+}
+
+#[test]
+pub fn gvox_rs_test_blit_progress_native() {
+    let extent = gvox_rs::inspect::inspect(MAGICAVOXEL_BYTES)
+        .declared_extent
+        .expect("Failed to inspect MagicaVoxel file.");
+    let range = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent,
+    };
+    let mut o_buffer = Box::default();
+    let mut reports = Vec::new();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(MAGICAVOXEL_BYTES)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+            .expect("Failed to get MagicaVoxel parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region_with_progress(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &range,
+            gvox_rs::ChannelId::COLOR.into(),
+            |done, total| reports.push((done, total)),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert!(!reports.is_empty(), "Progress callback was never invoked.");
+    for window in reports.windows(2) {
+        assert!(
+            window[0].0 <= window[1].0,
+            "Reported progress decreased from {} to {}.",
+            window[0].0,
+            window[1].0
+        );
+    }
+    for (done, total) in reports {
+        assert_eq!(
+            range.volume(),
+            total,
+            "Reported total did not match the range volume."
+        );
+        assert!(done <= total, "Reported progress exceeded the total.");
+    }
+    assert!(!o_buffer.is_empty(), "No output was written.");
+}
+
+#[test]
+pub fn gvox_rs_test_blit_stats_byte_buffer() {
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    for mode in [
+        gvox_rs::BlitMode::ParseDriven,
+        gvox_rs::BlitMode::SerializeDriven,
+    ] {
+        let mut o_buffer = Box::default();
+        let stats = {
+            let gvox_ctx = gvox_rs::Context::new();
+
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                .expect("Failed to get function sampler parse adapter.")
+                .create_adapter_context(procedural_parse::terrain())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_ctx
+                .blit()
+                .output(&mut o_ctx)
+                .parse(&mut p_ctx)
+                .serialize(&mut s_ctx)
+                .range(region)
+                .channels(gvox_rs::ChannelId::COLOR)
+                .mode(mode)
+                .execute_with_stats()
+                .expect("Error while translating.")
+        };
+
+        assert!(!o_buffer.is_empty(), "No output was written.");
+        assert_eq!(
+            o_buffer.len() as u64,
+            stats.bytes_written,
+            "Bytes written did not match the output length in {mode:?} mode."
+        );
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_blit_regions_far_apart() {
+    let ranges = [0, 1_000_000].map(|x| gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+    });
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+        .expect("Failed to get function sampler parse adapter.")
+        .create_adapter_context(procedural_parse::terrain())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut expected = Vec::new();
+    for range in &ranges {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, range, channels)
+                .expect("Error while translating.");
+        }
+        expected.extend_from_slice(&o_buffer);
+    }
+
+    let mut o_buffer = Box::default();
+    {
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_regions(None, &mut o_ctx, &mut p_ctx, &mut s_ctx, &ranges, channels)
+            .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        expected,
+        &o_buffer[..],
+        "Distant ranges did not match the separately blitted ranges."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_regions_unwritable_output() {
+    let ranges = [gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+        extent: gvox_rs::Extent3D { x: 2, y: 2, z: 2 },
+    }];
+    let blits = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter::<gvox_rs::Parse, BlitCountingAdapter>()
+        .expect("Failed to register blit counting adapter.");
+
+    let mut o_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::StdOut>()
+        .expect("Failed to get standard output adapter.")
+        .create_adapter_context(gvox_rs::adapters::StdOutOutputAdapterConfig::default())
+        .expect("Failed to create adapter context.");
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, BlitCountingAdapter>()
+        .expect("Failed to get blit counting adapter.")
+        .create_adapter_context(blits.clone())
+        .expect("Failed to create adapter context.");
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let error = gvox_rs::blit_regions(
+        None,
+        &mut o_ctx,
+        &mut p_ctx,
+        &mut s_ctx,
+        &ranges,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect_err("Blitting to a native standard output succeeded.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+    assert_eq!(
+        0,
+        blits.load(std::sync::atomic::Ordering::Relaxed),
+        "The input was parsed before the output was found to be unwritable."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_blit_progress_native_whole() {
+    let mut o_buffer = Box::default();
+    let mut reports = Vec::new();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(MAGICAVOXEL_BYTES)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>()
+            .expect("Failed to get MagicaVoxel parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region_with_progress(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+            |done, total| reports.push((done, total)),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert!(!reports.is_empty(), "Progress callback was never invoked.");
+    for (done, total) in reports {
+        assert_ne!(0, total, "Total was not taken from the parsable range.");
+        assert!(done <= total, "Reported progress exceeded the total.");
+    }
+    assert!(!o_buffer.is_empty(), "No output was written.");
+}
+
+#[test]
+pub fn gvox_rs_test_file_input_config_clone() {
+    let file_name = std::env::temp_dir().join("gvox_rs_test_file_input_config_clone.gvox");
+    std::fs::write(&file_name, PALETTE_BYTES).expect("Failed to write temporary file.");
+
+    let config = {
+        let original = gvox_rs::adapters::FileInputAdapterConfig::new(
+            file_name.to_string_lossy().into_owned(),
+            0,
+        );
+        original.clone()
+    };
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let mut o_buffer = Box::default();
+    {
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::File>()
+            .expect("Failed to get file input adapter.")
+            .create_adapter_context(config)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            &mut i_ctx,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+    std::fs::remove_file(&file_name).expect("Failed to remove temporary file.");
+
+    let expected = gvox_rs::convert(
+        PALETTE_BYTES,
+        gvox_rs::ParseFormat::GvoxPalette,
+        gvox_rs::SerializeFormat::GvoxRaw,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+    .expect("Failed to convert palette.");
+    assert_eq!(
+        expected,
+        &o_buffer[..],
+        "Blit from a clone of a dropped file configuration did not match."
+    );
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn gvox_rs_test_gzip_input_limit() {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&[0; 1 << 20])
+        .expect("Failed to compress zeros.");
+    let compressed = encoder.finish().expect("Failed to compress zeros.");
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let adapter = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::GzipInput>()
+        .expect("Failed to get gzip input adapter.");
+
+    let mut i_ctx = adapter
+        .create_adapter_context(
+            gvox_rs::adapters::GzipInputAdapterConfig::from(&compressed[..])
+                .with_max_decompressed_bytes(4096),
+        )
+        .expect("Failed to create adapter context.");
+    let mut data = [1; 16];
+    i_ctx
+        .read(4080, &mut data)
+        .expect("Failed to read within the limit.");
+    assert_eq!([0; 16], data, "Decompressed data did not match.");
+    let error = i_ctx
+        .read(4090, &mut data)
+        .expect_err("Read past the decompression limit succeeded.");
+    assert_eq!(gvox_rs::ErrorType::InputAdapter, error.error_type());
+
+    let mut i_ctx = adapter
+        .create_adapter_context(
+            gvox_rs::adapters::GzipInputAdapterConfig::from(&compressed[..])
+                .with_max_decompressed_bytes(1 << 20),
+        )
+        .expect("Failed to create adapter context.");
+    i_ctx
+        .read((1 << 20) - 16, &mut data)
+        .expect("Input which fits the limit exactly was rejected.");
+}
+
+pub struct OutputLayoutAdapter {
+    written: Option<(usize, usize)>,
+    report: std::sync::Arc<std::sync::Mutex<Option<(usize, usize)>>>,
+}
+
+impl gvox_rs::AdapterDescriptor<gvox_rs::Serialize> for OutputLayoutAdapter {
+    type Configuration<'a> = std::sync::Arc<std::sync::Mutex<Option<(usize, usize)>>>;
+    type Handler = Self;
+}
+
+impl gvox_rs::NamedAdapter for OutputLayoutAdapter {
+    fn name() -> &'static str {
+        "output_layout"
+    }
+}
+
+impl gvox_rs::BaseAdapterHandler<gvox_rs::Serialize, Self> for OutputLayoutAdapter {
+    fn create(
+        config: &std::sync::Arc<std::sync::Mutex<Option<(usize, usize)>>>,
+    ) -> Result<Self, gvox_rs::GvoxError> {
+        Ok(Self {
+            written: None,
+            report: config.clone(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
+        *self.report.lock().expect("Failed to lock report.") = self.written;
+        Ok(())
+    }
+}
+
+impl gvox_rs::SerializeAdapterHandler<Self> for OutputLayoutAdapter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &gvox_rs::SerializeBlitContext,
+        _: &gvox_rs::RegionRange,
+        _: gvox_rs::ChannelFlags,
+    ) -> Result<(), gvox_rs::GvoxError> {
+        if self.written.is_some() {
+            return Ok(());
+        }
+
+        blit_ctx.output_write(0, &[0; 8])?;
+        let first = blit_ctx.output_append(b"abcd")?;
+        let second = blit_ctx.output_append(b"efgh")?;
+        blit_ctx.output_write(0, &(first as u32).to_le_bytes())?;
+        blit_ctx.output_write(4, &(second as u32).to_le_bytes())?;
+        blit_ctx.output_write(18, b"yz")?;
+        blit_ctx.output_write(16, b"wx")?;
+        blit_ctx.output_append(b"!")?;
+
+        self.written = Some((blit_ctx.bytes_written(), blit_ctx.max_position()));
+        Ok(())
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_output_position() {
+    let report = std::sync::Arc::new(std::sync::Mutex::new(None));
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+        gvox_ctx
+            .register_adapter::<gvox_rs::Serialize, OutputLayoutAdapter>()
+            .expect("Failed to register output layout adapter.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                &mut o_buffer,
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(gvox_rs::adapters::FnSamplerAdapterConfig::new(
+                |_, _| Some(0),
+                gvox_rs::ChannelId::COLOR.into(),
+            ))
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, OutputLayoutAdapter>()
+            .expect("Failed to get output layout adapter.")
+            .create_adapter_context(report.clone())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            &mut o_ctx,
+            &mut p_ctx,
+            &mut s_ctx,
+            &gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+                extent: gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+            },
+            gvox_rs::ChannelId::COLOR.into(),
+        )
+        .expect("Error while translating.");
+    }
+
+    assert_eq!(
+        Some((29, 21)),
+        *report.lock().expect("Failed to lock report."),
+        "Output position was not tracked correctly."
+    );
+    assert_eq!(
+        b"\x08\0\0\0\x0c\0\0\0abcdefghwxyz!",
+        &o_buffer[..],
+        "Output layout did not match expected."
+    );
+}
+
+#[test]
+pub fn gvox_rs_test_scoped_raw_context() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let threads = (0..4)
+        .map(|_| {
+            let gvox_ctx = gvox_ctx.clone();
+            std::thread::spawn(move || {
+                let mut o_buffer = Box::default();
+
+                {
+                    let o_config =
+                        gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+                    let s_config = gvox_rs::adapters::ColoredTextSerializeAdapterConfig {
+                        downscale_factor: 1,
+                        downscale_mode:
+                            gvox_rs::adapters::ColoredTextSerializeAdapterDownscaleMode::Nearest,
+                        non_color_max_value: 5,
+                        vertical: false,
+                    };
+
+                    let mut o_ctx = gvox_ctx
+                        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                        .expect("Failed to get byte buffer output adapter.")
+                        .create_adapter_context(o_config)
+                        .expect("Failed to create adapter context.");
+
+                    let mut p_ctx = gvox_ctx
+                        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                        .expect("Failed to get function sampler parse adapter.")
+                        .create_adapter_context(procedural_parse::terrain())
+                        .expect("Failed to create adapter context.");
+
+                    let mut s_ctx = gvox_ctx
+                        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>()
+                        .expect("Failed to get colored text serialize adapter.")
+                        .create_adapter_context(s_config)
+                        .expect("Failed to create adapter context.");
+
+                    let region = gvox_rs::RegionRange {
+                        offset: gvox_rs::Offset3D {
+                            x: -4,
+                            y: -4,
+                            z: -4,
+                        },
+                        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+                    };
+
+                    gvox_rs::blit_region(
+                        None,
+                        Some(&mut o_ctx),
+                        &mut p_ctx,
+                        &mut s_ctx,
+                        Some(&region),
+                        gvox_rs::ChannelId::COLOR
+                            | gvox_rs::ChannelId::NORMAL
+                            | gvox_rs::ChannelId::MATERIAL_ID,
+                    )
+                    .expect("Error while translating.");
+                }
+
+                o_buffer.len()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Blits drain the native error stack before releasing the context lock, so
+    // scoped access must never observe a blit that is still in progress.
+    for _ in 0..64 {
+        gvox_ctx.with_raw(|ptr| unsafe {
+            assert_eq!(
+                gvox_sys::GvoxResult_GVOX_RESULT_SUCCESS,
+                gvox_sys::gvox_get_result(ptr)
+            );
+        });
+    }
+
+    for thread in threads {
+        assert_eq!(
+            33342,
+            thread.join().expect("Blit thread panicked."),
+            "Buffer output length did not match expected."
+        );
+    }
+}
+
+#[test]
+pub fn gvox_rs_test_named_rust_adapter() {
+    let gvox_ctx = gvox_rs::Context::new();
+    gvox_ctx
+        .register_adapter_named::<gvox_rs::Input, CustomAdapter>("custom_input_a")
+        .expect("Failed to register first custom input adapter.");
+    gvox_ctx
+        .register_adapter_named::<gvox_rs::Input, gvox_rs::adapters::ByteBufferOwned>(
+            "custom_input_b",
+        )
+        .expect("Failed to register second custom input adapter.");
+
+    assert!(gvox_ctx
+        .get_adapter::<gvox_rs::Input, CustomAdapter>()
+        .is_err());
+    assert!(gvox_ctx
+        .register_adapter_named::<gvox_rs::Input, CustomAdapter>("custom_input_b")
+        .is_err());
+    assert!(gvox_ctx
+        .get_adapter_named::<gvox_rs::Input, CustomAdapter>("custom_input_b")
+        .is_err());
+    assert!(gvox_ctx
+        .get_adapter_named::<gvox_rs::Input, gvox_rs::adapters::ByteBufferOwned>("custom_input_a")
+        .is_err());
+
+    let blit = |i_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Input>,
+                p_ctx: &mut gvox_rs::AdapterContext<'_, gvox_rs::Parse>| {
+        let mut o_buffer = Box::default();
+        {
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(i_ctx),
+                Some(&mut o_ctx),
+                p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR
+                    | gvox_rs::ChannelId::NORMAL
+                    | gvox_rs::ChannelId::MATERIAL_ID,
+            )
+            .expect("Error while translating.");
+        }
+        o_buffer
+    };
+
+    let mut palette_p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get gvox palette parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let mut raw_p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut a_ctx = gvox_ctx
+        .get_adapter_named::<gvox_rs::Input, CustomAdapter>("custom_input_a")
+        .expect("Failed to get first custom input adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+    let mut palette_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.");
+    let a = blit(&mut a_ctx, &mut palette_p_ctx);
+    assert_eq!(
+        blit(&mut palette_ctx, &mut palette_p_ctx),
+        a,
+        "The first name did not resolve to its own adapter."
+    );
+
+    let mut b_ctx = gvox_ctx
+        .get_adapter_named::<gvox_rs::Input, gvox_rs::adapters::ByteBufferOwned>("custom_input_b")
+        .expect("Failed to get second custom input adapter.")
+        .create_adapter_context(RAW_UNIFORM_BYTES.into())
+        .expect("Failed to create adapter context.");
+    let mut raw_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(RAW_UNIFORM_BYTES)
+        .expect("Failed to create adapter context.");
+    let b = blit(&mut b_ctx, &mut raw_p_ctx);
+    assert_eq!(
+        blit(&mut raw_ctx, &mut raw_p_ctx),
+        b,
+        "The second name did not resolve to its own adapter."
+    );
+
+    assert_ne!(a, b, "Both names produced the same output.");
+}
+
+#[test]
+fn gvox_rs_test_format_palette_header() {
+    assert_eq!(
+        gvox_rs::format::PALETTE_HEADER_SIZE,
+        std::mem::size_of::<gvox_rs::format::PaletteHeader>()
+    );
+    assert_eq!(
+        gvox_rs::format::PALETTE_CHANNEL_HEADER_SIZE,
+        std::mem::size_of::<gvox_rs::format::PaletteChannelHeader>()
+    );
+
+    let header = match gvox_rs::format::read_header(PALETTE_BYTES)
+        .expect("Failed to read gvox palette header.")
+    {
+        gvox_rs::format::ContainerHeader::Palette(header) => header,
+        other => panic!("Expected a gvox palette header, got {other:?}."),
+    };
+
+    assert_eq!(gvox_rs::format::PALETTE_MAGIC, header.magic);
+    assert_eq!(
+        gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: -4,
+                y: -4,
+                z: -4,
+            },
+            extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+        },
+        header.range
+    );
+    assert_eq!(
+        gvox_rs::ChannelFlags::from(
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL | gvox_rs::ChannelId::MATERIAL_ID
+        ),
+        header.channels()
+    );
+    assert_eq!(3, header.channel_n);
+    assert_eq!(
+        PALETTE_BYTES.len(),
+        gvox_rs::format::PALETTE_HEADER_SIZE
+            + header.channel_headers_size()
+            + header.blob_size as usize
+    );
+    assert_eq!(
+        gvox_rs::Extent3D { x: 1, y: 1, z: 1 },
+        header.region_counts()
+    );
+
+    let channel = gvox_rs::format::PaletteChannelHeader::read(PALETTE_BYTES, &header, 0, 1)
+        .expect("Failed to read gvox palette channel header.");
+    assert_eq!(
+        gvox_rs::format::PaletteChannelHeader {
+            variant_n: 145,
+            blob_offset: 216,
+        },
+        channel
+    );
+    assert!(gvox_rs::format::PaletteChannelHeader::read(PALETTE_BYTES, &header, 1, 0).is_ok());
+    assert!(gvox_rs::format::PaletteChannelHeader::read(&PALETTE_BYTES[..60], &header, 0, 2).is_err());
+
+    let mut written = Vec::new();
+    gvox_rs::format::write_header(&gvox_rs::format::ContainerHeader::Palette(header), &mut written);
+    assert_eq!(&PALETTE_BYTES[..gvox_rs::format::PALETTE_HEADER_SIZE], &written[..]);
+
+    assert!(gvox_rs::format::read_header(&PALETTE_BYTES[..16]).is_err());
+    assert!(gvox_rs::format::read_header(MAGICAVOXEL_BYTES).is_err());
+}
+
+#[test]
+fn gvox_rs_test_format_raw_header() {
+    assert_eq!(
+        gvox_rs::format::RAW_HEADER_SIZE,
+        std::mem::size_of::<gvox_rs::format::RawHeader>()
+    );
+
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+    let channels = gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID;
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+            .expect("Failed to get function sampler parse adapter.")
+            .create_adapter_context(procedural_parse::terrain())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            None,
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            Some(&region),
+            channels,
+        )
+        .expect("Error while translating.");
+    }
+
+    let header = gvox_rs::format::read_header(&o_buffer).expect("Failed to read gvox raw header.");
+    assert_eq!(
+        gvox_rs::format::ContainerHeader::Raw(gvox_rs::format::RawHeader::new(region, channels)),
+        header
+    );
+
+    let header = match header {
+        gvox_rs::format::ContainerHeader::Raw(header) => header,
+        other => panic!("Expected a gvox raw header, got {other:?}."),
+    };
+    assert_eq!(
+        o_buffer.len(),
+        gvox_rs::format::RAW_HEADER_SIZE + header.data_size()
+    );
+
+    let mut written = Vec::new();
+    gvox_rs::format::write_header(&gvox_rs::format::ContainerHeader::Raw(header), &mut written);
+    assert_eq!(&o_buffer[..gvox_rs::format::RAW_HEADER_SIZE], &written[..]);
+}
+
+#[test]
+fn gvox_rs_test_adapter_names() {
+    let gvox_ctx = gvox_rs::Context::new();
+    assert!(gvox_ctx
+        .adapter_names(gvox_rs::AdapterKindId::Parse)
+        .contains(&"magicavoxel".to_string()));
+    assert!(gvox_ctx
+        .adapter_names(gvox_rs::AdapterKindId::Serialize)
+        .contains(&"colored_text".to_string()));
+    assert!(!gvox_ctx
+        .adapter_names(gvox_rs::AdapterKindId::Parse)
+        .contains(&"colored_text".to_string()));
+    assert!(gvox_ctx.has_adapter::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>());
+
+    assert!(!gvox_ctx.has_adapter::<gvox_rs::Input, CustomAdapter>());
+    gvox_ctx
+        .register_adapter::<gvox_rs::Input, CustomAdapter>()
+        .expect("Failed to register custom input adapter.");
+    assert!(gvox_ctx.has_adapter::<gvox_rs::Input, CustomAdapter>());
+    assert!(gvox_ctx
+        .adapter_names(gvox_rs::AdapterKindId::Input)
+        .contains(&<CustomAdapter as gvox_rs::NamedAdapter>::name().to_string()));
+}
+
+#[test]
+fn gvox_rs_test_missing_native_adapter() {
+    struct MissingAdapter;
+
+    impl gvox_rs::AdapterDescriptor<gvox_rs::Input> for MissingAdapter {
+        type Configuration<'a> = ();
+        type Handler = gvox_rs::ExternalHandler;
+    }
+
+    impl gvox_rs::NamedAdapter for MissingAdapter {
+        fn name() -> &'static str {
+            "missing_native_adapter"
+        }
+    }
+
+    let mut inner = gvox_rs::ContextInner::empty();
+    unsafe {
+        inner
+            .add_default_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to probe byte buffer input adapter.");
+        inner
+            .add_default_adapter::<gvox_rs::Input, MissingAdapter>()
+            .expect("Failed to probe missing input adapter.");
+    }
+    let gvox_ctx = gvox_rs::Context(std::sync::Arc::new(std::sync::Mutex::new(inner)));
+
+    assert_eq!(
+        vec!["byte_buffer".to_string()],
+        gvox_ctx.adapters::<gvox_rs::Input>()
+    );
+    assert!(gvox_ctx.adapters::<gvox_rs::Output>().is_empty());
+
+    assert!(gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .is_ok());
+
+    let error = gvox_ctx
+        .get_adapter::<gvox_rs::Input, MissingAdapter>()
+        .err()
+        .expect("Missing adapter should not be available.");
+    assert_eq!(gvox_rs::ErrorType::InvalidParameter, error.error_type());
+    assert!(error
+        .to_string()
+        .contains("not available in this build of the native library"));
+
+    let error = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+        .err()
+        .expect("Unregistered adapter should not be available.");
+    assert!(!error
+        .to_string()
+        .contains("not available in this build of the native library"));
+
+    let default_ctx = gvox_rs::Context::new();
+    assert!(default_ctx
+        .adapters::<gvox_rs::Parse>()
+        .contains(&"gvox_palette".to_string()));
+}
+
+fn decode_palette_to_raw(bytes: &[u8], channels: gvox_rs::ChannelFlags) -> Box<[u8]> {
+    let mut o_buffer = Box::default();
+
+    {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(bytes)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        let mut p_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+            .expect("Failed to get gvox palette parse adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        let mut s_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+            .expect("Failed to get gvox raw serialize adapter.")
+            .create_adapter_context(())
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::blit_region(
+            Some(&mut i_ctx),
+            Some(&mut o_ctx),
+            &mut p_ctx,
+            &mut s_ctx,
+            None,
+            channels,
+        )
+        .expect("Error while translating.");
+    }
+
+    o_buffer
+}
+
+#[test]
+fn gvox_rs_test_palette_meta_remap_colors() {
+    const FROM: u32 = 0x01197f33;
+    const TO: u32 = 0x01ff00ff;
+
+    let mapping = std::collections::HashMap::from([(FROM, TO)]);
+    let mut o_buffer = Box::default();
+
+    let report = {
+        let gvox_ctx = gvox_rs::Context::new();
+
+        let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+        let mut i_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer input adapter.")
+            .create_adapter_context(PALETTE_BYTES)
+            .expect("Failed to create adapter context.");
+
+        let mut o_ctx = gvox_ctx
+            .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+            .expect("Failed to get byte buffer output adapter.")
+            .create_adapter_context(o_config)
+            .expect("Failed to create adapter context.");
+
+        gvox_rs::palette_meta::remap_colors(&mut i_ctx, &mut o_ctx, &mapping)
+            .expect("Failed to remap palette colors.")
+    };
+
+    assert_eq!(
+        gvox_rs::palette_meta::RemapReport {
+            regions: 1,
+            palette_entries: 1,
+            reencoded_regions: 0,
+        },
+        report
+    );
+    assert_eq!(PALETTE_BYTES.len(), o_buffer.len());
+
+    let header = match gvox_rs::format::read_header(&o_buffer)
+        .expect("Failed to read gvox palette header.")
+    {
+        gvox_rs::format::ContainerHeader::Palette(header) => header,
+        other => panic!("Expected a gvox palette header, got {other:?}."),
+    };
+    let color = gvox_rs::format::PaletteChannelHeader::read(&o_buffer, &header, 0, 0)
+        .expect("Failed to read gvox palette channel header.");
+    let indices_start = gvox_rs::format::PALETTE_HEADER_SIZE
+        + header.channel_headers_size()
+        + color.blob_offset as usize
+        + color.variant_n as usize * std::mem::size_of::<u32>();
+    assert_eq!(&PALETTE_BYTES[indices_start..], &o_buffer[indices_start..]);
+
+    let original = decode_palette_to_raw(PALETTE_BYTES, gvox_rs::ChannelId::COLOR.into());
+    let remapped = decode_palette_to_raw(&o_buffer, gvox_rs::ChannelId::COLOR.into());
+    assert_eq!(original.len(), remapped.len());
+
+    let colors = |bytes: &[u8]| {
+        bytes[gvox_rs::format::RAW_HEADER_SIZE..]
+            .chunks_exact(4)
+            .map(|x| u32::from_le_bytes(x.try_into().unwrap()))
+            .collect::<Vec<_>>()
+    };
+    let mut changed = 0;
+    for (before, after) in colors(&original).into_iter().zip(colors(&remapped)) {
+        if before == FROM {
+            assert_eq!(TO, after);
+            changed += 1;
+        } else {
+            assert_eq!(before, after);
+        }
+    }
+    assert!(changed > 0, "Remapped color did not appear in the model.");
+
+    let other_channels = gvox_rs::ChannelId::NORMAL | gvox_rs::ChannelId::MATERIAL_ID;
+    assert_eq!(
+        decode_palette_to_raw(PALETTE_BYTES, other_channels),
+        decode_palette_to_raw(&o_buffer, other_channels)
+    );
+}
+
+#[test]
+fn gvox_rs_test_inspect() {
+    assert_eq!(
+        gvox_rs::inspect::Inspection {
+            format: Some(gvox_rs::inspect::Format::GvoxPalette),
+            declared_extent: Some(gvox_rs::Extent3D { x: 8, y: 8, z: 8 }),
+            declared_channels: Some(
+                gvox_rs::ChannelId::COLOR
+                    | gvox_rs::ChannelId::NORMAL
+                    | gvox_rs::ChannelId::MATERIAL_ID
+            ),
+        },
+        gvox_rs::inspect::inspect(&PALETTE_BYTES[..gvox_rs::inspect::HEADER_PREFIX_SIZE])
+    );
+
+    let magicavoxel = gvox_rs::inspect::Inspection {
+        format: Some(gvox_rs::inspect::Format::MagicaVoxel),
+        declared_extent: Some(gvox_rs::Extent3D { x: 4, y: 4, z: 4 }),
+        declared_channels: None,
+    };
+    assert_eq!(magicavoxel, gvox_rs::inspect::inspect(MAGICAVOXEL_BYTES));
+    assert_eq!(
+        magicavoxel,
+        gvox_rs::inspect::inspect_reader(std::io::Cursor::new(MAGICAVOXEL_BYTES))
+            .expect("Failed to read MagicaVoxel header.")
+    );
+    assert_eq!(
+        Some(gvox_rs::inspect::Format::MagicaVoxel),
+        gvox_rs::inspect::detect_format(&MAGICAVOXEL_BYTES[..8])
+    );
+    assert_eq!(
+        None,
+        gvox_rs::inspect::inspect(&MAGICAVOXEL_BYTES[..8]).declared_extent
+    );
+
+    let mut kvx = Vec::new();
+    for value in [
+        24 + 3 * 4 + 2 * 3 * 2 + 16,
+        2,
+        2,
+        3,
+        256,
+        256,
+        384,
+        3 * 4 + 2 * 3 * 2,
+    ] {
+        kvx.extend_from_slice(&u32::to_le_bytes(value));
+    }
+    assert_eq!(
+        gvox_rs::inspect::Inspection {
+            format: Some(gvox_rs::inspect::Format::Kvx),
+            declared_extent: Some(gvox_rs::Extent3D { x: 2, y: 2, z: 3 }),
+            declared_channels: None,
+        },
+        gvox_rs::inspect::inspect(&kvx)
+    );
+    assert_eq!(
+        Some("kvx"),
+        gvox_rs::inspect::detect_format(&kvx).map(|x| x.parse_adapter_name())
+    );
+
+    let mut state = 0x2545f491u32;
+    let random = (0..gvox_rs::inspect::HEADER_PREFIX_SIZE)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        gvox_rs::inspect::Inspection::default(),
+        gvox_rs::inspect::inspect(&random)
+    );
+    assert_eq!(None, gvox_rs::inspect::detect_format(&[]));
+}
+
+#[test]
+fn gvox_rs_test_poisoned_context() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    let panicking_ctx = gvox_ctx.clone();
+    let result = std::thread::spawn(move || {
+        panicking_ctx.with_raw(|_| panic!("Panicked while holding the context lock."))
+    })
+    .join();
+    assert!(result.is_err());
+
+    let blit_ctx = gvox_ctx.clone();
+    let o_buffer = std::thread::spawn(move || {
+        let mut o_buffer = Box::default();
+
+        {
+            let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+            let mut i_ctx = blit_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(PALETTE_BYTES)
+                .expect("Failed to create adapter context.");
+
+            let mut o_ctx = blit_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(o_config)
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = blit_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = blit_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR.into(),
+            )
+            .expect("Error while translating.");
+        }
+
+        o_buffer
+    })
+    .join()
+    .expect("Blit after a poisoning panic should not panic.");
+
+    assert_eq!(
+        decode_palette_to_raw(PALETTE_BYTES, gvox_rs::ChannelId::COLOR.into()),
+        o_buffer
+    );
+}
+
+#[test]
+fn gvox_rs_test_leak_check_context() {
+    gvox_rs::testing::LeakCheck::default().assert(|| drop(gvox_rs::Context::new()));
+}
+
+#[test]
+fn gvox_rs_test_leak_check_adapter_contexts() {
+    fn churn<K: gvox_rs::AdapterKind, A: gvox_rs::AdapterDescriptor<K> + gvox_rs::NamedAdapter>(
+        gvox_ctx: &gvox_rs::Context,
+        config: impl Fn() -> A::Configuration<'static>,
+    ) {
+        if let Ok(adapter) = gvox_ctx.get_adapter::<K, A>() {
+            gvox_rs::testing::LeakCheck::default().assert(|| {
+                drop(
+                    adapter
+                        .create_adapter_context(config())
+                        .expect("Failed to create adapter context."),
+                )
+            });
+        }
+    }
+
+    let file_name = std::env::temp_dir().join("gvox_rs_test_leak_check.gvox");
+    std::fs::write(&file_name, PALETTE_BYTES).expect("Failed to write temporary file.");
+    let file_name = file_name.to_string_lossy().into_owned();
+
+    let gvox_ctx = gvox_rs::Context::new();
+
+    churn::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>(&gvox_ctx, || PALETTE_BYTES);
+    churn::<gvox_rs::Input, gvox_rs::adapters::File>(&gvox_ctx, || {
+        gvox_rs::adapters::FileInputAdapterConfig::new(file_name.clone(), 0)
+    });
+    churn::<gvox_rs::Output, gvox_rs::adapters::File>(&gvox_ctx, || {
+        gvox_rs::adapters::FileOutputAdapterConfig::new(file_name.clone())
+    });
+    churn::<gvox_rs::Output, gvox_rs::adapters::StdOut>(&gvox_ctx, Default::default);
+    churn::<gvox_rs::Output, gvox_rs::adapters::StdErr>(&gvox_ctx, Default::default);
+    churn::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::GvoxRaw>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::GvoxBrickmap>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::GvoxGlobalPalette>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::GvoxOctree>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::GvoxRunLengthEncoding>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::MagicaVoxel>(&gvox_ctx, || ());
+    churn::<gvox_rs::Parse, gvox_rs::adapters::Voxlap>(&gvox_ctx, Default::default);
+    churn::<gvox_rs::Parse, gvox_rs::adapters::Kvx>(&gvox_ctx, Default::default);
+    churn::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>(&gvox_ctx, procedural_parse::terrain);
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::ColoredText>(&gvox_ctx, Default::default);
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::GvoxPalette>(&gvox_ctx, || ());
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>(&gvox_ctx, || ());
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::GvoxBrickmap>(&gvox_ctx, || ());
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::GvoxGlobalPalette>(&gvox_ctx, || ());
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::GvoxOctree>(&gvox_ctx, || ());
+    churn::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRunLengthEncoding>(&gvox_ctx, || ());
+
+    let mut o_buffer = Box::default();
+    let adapter = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer output adapter.");
+    gvox_rs::testing::LeakCheck::default().assert(|| {
+        drop(
+            adapter
+                .create_adapter_context(gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(
+                    &mut o_buffer,
+                ))
+                .expect("Failed to create adapter context."),
+        )
+    });
+
+    let _ = std::fs::remove_file(file_name);
+}
+
+#[test]
+fn gvox_rs_test_leak_check_blit() {
+    let gvox_ctx = gvox_rs::Context::new();
+
+    gvox_rs::testing::LeakCheck {
+        iterations: 20,
+        ..Default::default()
+    }
+    .assert(|| {
+        let mut o_buffer = Box::default();
+
+        {
+            let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(PALETTE_BYTES)
+                .expect("Failed to create adapter context.");
+
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(o_config)
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+                .expect("Failed to get gvox palette parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                None,
+                gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL,
+            )
+            .expect("Error while translating.");
+        }
+
+        assert!(!o_buffer.is_empty());
+    });
+}
+
+fn blit_palette_to_channel(
+    gvox_ctx: &gvox_rs::Context,
+    config: gvox_rs::adapters::ChannelOutputAdapterConfig,
+) -> Result<(), gvox_rs::GvoxError> {
+    let mut i_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+        .expect("Failed to get byte buffer input adapter.")
+        .create_adapter_context(PALETTE_BYTES)
+        .expect("Failed to create adapter context.");
+
+    let mut o_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ChannelOutput>()
+        .expect("Failed to get channel output adapter.")
+        .create_adapter_context(config)
+        .expect("Failed to create adapter context.");
+
+    let mut p_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxPalette>()
+        .expect("Failed to get gvox palette parse adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    let mut s_ctx = gvox_ctx
+        .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+        .expect("Failed to get gvox raw serialize adapter.")
+        .create_adapter_context(())
+        .expect("Failed to create adapter context.");
+
+    gvox_rs::blit_region(
+        Some(&mut i_ctx),
+        Some(&mut o_ctx),
+        &mut p_ctx,
+        &mut s_ctx,
+        None,
+        gvox_rs::ChannelId::COLOR.into(),
+    )
+}
+
+#[test]
+fn gvox_rs_test_channel_output_block() {
+    let (config, receiver) = gvox_rs::adapters::ChannelOutputAdapterConfig::new(
+        64,
+        gvox_rs::adapters::ChannelOutputBackPressure::Block,
+    );
+
+    let consumer = std::thread::spawn(move || {
+        let mut assembler = gvox_rs::adapters::ChannelOutputAssembler::default();
+        let mut segments = 0;
+        while let Some(segment) = receiver.recv() {
+            std::thread::sleep(std::time::Duration::from_micros(50));
+            assembler.insert(&segment);
+            segments += 1;
+        }
+        (segments, assembler.into_bytes())
+    });
+
+    let gvox_ctx = gvox_rs::Context::new();
+    blit_palette_to_channel(&gvox_ctx, config).expect("Error while translating.");
+
+    let (segments, bytes) = consumer.join().expect("Consumer thread panicked.");
+    assert!(segments > 1);
+    assert_eq!(
+        &decode_palette_to_raw(PALETTE_BYTES, gvox_rs::ChannelId::COLOR.into())[..],
+        &bytes[..]
+    );
+}
+
+#[test]
+fn gvox_rs_test_channel_output_fail() {
+    let (config, receiver) = gvox_rs::adapters::ChannelOutputAdapterConfig::new(
+        1,
+        gvox_rs::adapters::ChannelOutputBackPressure::Fail,
+    );
+
+    let gvox_ctx = gvox_rs::Context::new();
+    let error = blit_palette_to_channel(&gvox_ctx, config)
+        .err()
+        .expect("Blit should fail when the consumer falls behind.");
+    assert_eq!(gvox_rs::ErrorType::OutputAdapter, error.error_type());
+
+    assert!(receiver.recv().is_some());
+}
+
+#[test]
+fn gvox_rs_test_blit_stats() {
+    let region = gvox_rs::RegionRange {
+        offset: gvox_rs::Offset3D {
+            x: -4,
+            y: -4,
+            z: -4,
+        },
+        extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+    };
+
+    for mode in [
+        gvox_rs::BlitMode::ParseDriven,
+        gvox_rs::BlitMode::SerializeDriven,
+    ] {
+        let (config, receiver) = gvox_rs::adapters::ChannelOutputAdapterConfig::new(
+            usize::MAX / 2,
+            gvox_rs::adapters::ChannelOutputBackPressure::Fail,
+        );
+
+        let stats = {
+            let gvox_ctx = gvox_rs::Context::new();
+
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ChannelOutput>()
+                .expect("Failed to get channel output adapter.")
+                .create_adapter_context(config)
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::FnSampler>()
+                .expect("Failed to get function sampler parse adapter.")
+                .create_adapter_context(procedural_parse::terrain())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_ctx
+                .blit()
+                .output(&mut o_ctx)
+                .parse(&mut p_ctx)
+                .serialize(&mut s_ctx)
+                .range(region)
+                .channels(gvox_rs::ChannelId::COLOR)
+                .mode(mode)
+                .execute_with_stats()
+                .expect("Error while translating.")
+        };
+
+        let o_buffer = receiver.assemble();
+        assert!(!o_buffer.is_empty(), "No output was written.");
+        assert_eq!(
+            o_buffer.len() as u64,
+            stats.bytes_written,
+            "Bytes written did not match the output length in {mode:?} mode."
+        );
+        assert!(
+            stats.regions > 0,
+            "No regions were counted in {mode:?} mode."
+        );
+        assert!(
+            stats.voxels >= region.volume(),
+            "Counted voxels did not cover the range in {mode:?} mode."
+        );
+        assert_eq!(0, stats.bytes_read, "Procedural parse adapter read input.");
+    }
+}
+
+#[test]
+fn gvox_rs_test_channel_output_assembler() {
+    let mut assembler = gvox_rs::adapters::ChannelOutputAssembler::default();
+    assembler.insert(&gvox_rs::adapters::ChannelOutputSegment {
+        position: 4,
+        data: vec![5, 6, 7],
+    });
+    assembler.insert(&gvox_rs::adapters::ChannelOutputSegment {
+        position: 0,
+        data: vec![1, 2, 3, 4, 9],
+    });
+    assembler.insert(&gvox_rs::adapters::ChannelOutputSegment {
+        position: 6,
+        data: vec![8],
+    });
+    assert_eq!(&[1, 2, 3, 4, 9, 6, 8][..], assembler.bytes());
+}
+
+fn raw_fixtures() -> [(
+    &'static str,
+    &'static [u8],
+    gvox_rs::testing::FixturePreset,
+    gvox_rs::RegionRange,
+    gvox_rs::ChannelFlags,
+); 4] {
+    [
+        (
+            "src/raw_negative_offset.gvox",
+            RAW_NEGATIVE_OFFSET_BYTES,
+            gvox_rs::testing::FixturePreset::Gradient,
+            gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D {
+                    x: -12,
+                    y: -7,
+                    z: -3,
+                },
+                extent: gvox_rs::Extent3D { x: 5, y: 4, z: 3 },
+            },
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::MATERIAL_ID,
+        ),
+        (
+            "src/raw_uniform.gvox",
+            RAW_UNIFORM_BYTES,
+            gvox_rs::testing::FixturePreset::Uniform,
+            gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
+                extent: gvox_rs::Extent3D { x: 8, y: 8, z: 8 },
+            },
+            gvox_rs::ChannelId::COLOR.into(),
+        ),
+        (
+            "src/raw_extreme_voxel.gvox",
+            RAW_EXTREME_VOXEL_BYTES,
+            gvox_rs::testing::FixturePreset::SingleVoxel,
+            gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D {
+                    x: i32::MAX - 3,
+                    y: i32::MIN,
+                    z: -2,
+                },
+                extent: gvox_rs::Extent3D { x: 4, y: 4, z: 4 },
+            },
+            gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL,
+        ),
+        (
+            "src/raw_all_channels.gvox",
+            RAW_ALL_CHANNELS_BYTES,
+            gvox_rs::testing::FixturePreset::Gradient,
+            gvox_rs::RegionRange {
+                offset: gvox_rs::Offset3D { x: -1, y: -1, z: -1 },
+                extent: gvox_rs::Extent3D { x: 2, y: 2, z: 2 },
+            },
+            gvox_rs::ChannelId::COLOR
+                | gvox_rs::ChannelId::NORMAL
+                | gvox_rs::ChannelId::MATERIAL_ID
+                | gvox_rs::ChannelId::ROUGHNESS
+                | gvox_rs::ChannelId::METALNESS
+                | gvox_rs::ChannelId::TRANSPARENCY
+                | gvox_rs::ChannelId::IOR
+                | gvox_rs::ChannelId::EMISSIVE_COLOR
+                | gvox_rs::ChannelId::HARDNESS,
+        ),
+    ]
+}
+
+/// Set the `GVOX_RS_BLESS` environment variable to rewrite the checked-in fixtures.
+#[test]
+fn gvox_rs_test_raw_fixtures_are_current() {
+    let bless = std::env::var_os("GVOX_RS_BLESS").is_some();
+    for (path, bytes, preset, range, channels) in raw_fixtures() {
+        let generated = gvox_rs::testing::make_gvox_raw(preset, range, channels);
+        if bless {
+            std::fs::write(
+                std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(path),
+                &generated,
+            )
+            .expect("Failed to write fixture.");
+        } else {
+            assert_eq!(bytes, &generated[..], "Fixture {path} is out of date.");
+        }
+
+        assert_eq!(
+            gvox_rs::format::ContainerHeader::Raw(gvox_rs::format::RawHeader::new(
+                range, channels
+            )),
+            gvox_rs::format::read_header(&generated).expect("Failed to read gvox raw header.")
+        );
+    }
+}
+
+#[test]
+fn gvox_rs_test_raw_fixture_range_clipping() {
+    for (path, bytes, preset, range, channels) in raw_fixtures() {
+        if preset != gvox_rs::testing::FixturePreset::Gradient {
+            continue;
+        }
+
+        let clipped = gvox_rs::RegionRange {
+            offset: gvox_rs::Offset3D {
+                x: range.offset.x + 1,
+                y: range.offset.y,
+                z: range.offset.z + 1,
+            },
+            extent: gvox_rs::Extent3D {
+                x: range.extent.x - 1,
+                y: 1,
+                z: range.extent.z - 1,
+            },
+        };
+
+        let mut o_buffer = Box::default();
+
+        {
+            let gvox_ctx = gvox_rs::Context::new();
+
+            let o_config = gvox_rs::adapters::ByteBufferOutputAdapterConfig::from(&mut o_buffer);
+
+            let mut i_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Input, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer input adapter.")
+                .create_adapter_context(bytes)
+                .expect("Failed to create adapter context.");
+
+            let mut o_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Output, gvox_rs::adapters::ByteBuffer>()
+                .expect("Failed to get byte buffer output adapter.")
+                .create_adapter_context(o_config)
+                .expect("Failed to create adapter context.");
+
+            let mut p_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Parse, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw parse adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            let mut s_ctx = gvox_ctx
+                .get_adapter::<gvox_rs::Serialize, gvox_rs::adapters::GvoxRaw>()
+                .expect("Failed to get gvox raw serialize adapter.")
+                .create_adapter_context(())
+                .expect("Failed to create adapter context.");
+
+            gvox_rs::blit_region(
+                Some(&mut i_ctx),
+                Some(&mut o_ctx),
+                &mut p_ctx,
+                &mut s_ctx,
+                Some(&clipped),
+                channels,
+            )
+            .expect("Error while translating.");
+        }
+
+        assert_eq!(
+            &gvox_rs::testing::make_gvox_raw(preset, clipped, channels)[..],
+            &o_buffer[..],
+            "Clipped blit of {path} did not match."
+        );
+    }
 }
@@ -1,5 +1,3 @@
-use std::ptr::null_mut;
-
 use crate::{self as gvox_rs};
 
 fn stable_rand(x: f32) -> f32 {
@@ -29,193 +27,74 @@ fn sample_terrain_i(xi: i32, yi: i32, zi: i32) -> f32 {
     sample_terrain(x, y, z)
 }
 
-pub struct Procedural;
-
-impl gvox_rs::AdapterDescriptor<gvox_rs::Parse> for Procedural {
-    type Configuration<'a> = ();
-    type Handler = Self;
-}
-
-impl gvox_rs::NamedAdapter for Procedural {
-    fn name() -> &'static str {
-        "procedural"
-    }
-}
-
-impl gvox_rs::BaseAdapterHandler<gvox_rs::Parse, Self> for Procedural {
-    fn create(config: &()) -> Result<Self, gvox_rs::GvoxError> {
-        Ok(Self)
-    }
-
-    fn destroy(self) -> Result<(), gvox_rs::GvoxError> {
-        Ok(())
-    }
+/// Creates a configuration which generates a ball of terrain, centered at the origin.
+pub fn terrain() -> gvox_rs::adapters::FnSamplerAdapterConfig<'static> {
+    gvox_rs::adapters::FnSamplerAdapterConfig::new(
+        sample_terrain_voxel,
+        gvox_rs::ChannelId::COLOR | gvox_rs::ChannelId::NORMAL | gvox_rs::ChannelId::MATERIAL_ID,
+    )
 }
 
-impl gvox_rs::ParseAdapterHandler<Self> for Procedural {
-    type RegionData = ();
-
-    fn query_details() -> gvox_rs::ParseAdapterDetails {
-        gvox_rs::ParseAdapterDetails {
-            preferred_blit_mode: gvox_rs::BlitMode::DontCare,
-        }
-    }
-
-    fn query_parsable_range(
-        &mut self,
-        blit_ctx: &gvox_rs::ParseBlitContext,
-    ) -> gvox_rs::RegionRange {
-        gvox_rs::RegionRange {
-            offset: gvox_rs::Offset3D { x: 0, y: 0, z: 0 },
-            extent: gvox_rs::Extent3D { x: 0, y: 0, z: 0 },
-        }
-    }
-
-    fn query_region_flags(
-        &mut self,
-        blit_ctx: &gvox_rs::ParseBlitContext,
-        range: &gvox_rs::RegionRange,
-        channel_flags: gvox_rs::ChannelFlags,
-    ) -> Result<gvox_rs::RegionFlags, gvox_rs::GvoxError> {
-        Ok(gvox_rs::RegionFlags::empty())
-    }
-
-    fn load_region(
-        &mut self,
-        blit_ctx: &gvox_rs::ParseBlitContext,
-        range: &gvox_rs::RegionRange,
-        channel_flags: gvox_rs::ChannelFlags,
-    ) -> Result<gvox_rs::Region<Self::RegionData>, gvox_rs::GvoxError> {
-        let available_channels = gvox_rs::ChannelId::COLOR
-            | gvox_rs::ChannelId::NORMAL
-            | gvox_rs::ChannelId::MATERIAL_ID;
-        if (channel_flags & !available_channels) != gvox_rs::ChannelFlags::empty() {
-            // gvox_sys::gvox_adapter_push_error(
-            //     ctx,
-            //     gvox_rs::RESULT_ERROR_PARSE_ADAPTER_REQUESTED_CHANNEL_NOT_PRESENT,
-            //     cstr!("procedural 'parser' does not generate anything other than color & normal"),
-            // );
-        }
-        Ok(gvox_rs::Region::new(
-            *range,
-            channel_flags & available_channels,
-            gvox_rs::RegionFlags::empty(),
-            (),
-        ))
-    }
-
-    fn unload_region(
-        &mut self,
-        blit_ctx: &gvox_rs::ParseBlitContext,
-        region: gvox_rs::Region<Self::RegionData>,
-    ) -> Result<(), gvox_rs::GvoxError> {
-        Ok(())
-    }
-
-    fn sample_region(
-        &mut self,
-        blit_ctx: &gvox_rs::ParseBlitContext,
-        region: &gvox_rs::Region<Self::RegionData>,
-        offset: &gvox_rs::Offset3D,
-        channel_id: gvox_rs::ChannelId,
-    ) -> Result<gvox_rs::Sample, gvox_rs::GvoxError> {
-        let val = sample_terrain_i((*offset).x, (*offset).y, (*offset).z);
-        let mut color = create_color(0.6, 0.7, 0.9, 0);
-        let mut normal = create_normal(0.0, 0.0, 0.0);
-        let mut id = 0;
-        if val > 0.0 {
+fn sample_terrain_voxel(offset: gvox_rs::Offset3D, channel_id: gvox_rs::ChannelId) -> Option<u32> {
+    let val = sample_terrain_i(offset.x, offset.y, offset.z);
+    let mut color = create_color(0.6, 0.7, 0.9, 0);
+    let mut normal = create_normal(0.0, 0.0, 0.0);
+    let mut id = 0;
+    if val > 0.0 {
+        {
+            let nx_val = sample_terrain_i(offset.x - 1, offset.y, offset.z);
+            let ny_val = sample_terrain_i(offset.x, offset.y - 1, offset.z);
+            let nz_val = sample_terrain_i(offset.x, offset.y, offset.z - 1);
+            let px_val = sample_terrain_i(offset.x + 1, offset.y, offset.z);
+            let py_val = sample_terrain_i(offset.x, offset.y + 1, offset.z);
+            let pz_val = sample_terrain_i(offset.x, offset.y, offset.z + 1);
+            if nx_val < 0.0
+                || ny_val < 0.0
+                || nz_val < 0.0
+                || px_val < 0.0
+                || py_val < 0.0
+                || pz_val < 0.0
             {
-                let nx_val = sample_terrain_i((*offset).x - 1, (*offset).y, (*offset).z);
-                let ny_val = sample_terrain_i((*offset).x, (*offset).y - 1, (*offset).z);
-                let nz_val = sample_terrain_i((*offset).x, (*offset).y, (*offset).z - 1);
-                let px_val = sample_terrain_i((*offset).x + 1, (*offset).y, (*offset).z);
-                let py_val = sample_terrain_i((*offset).x, (*offset).y + 1, (*offset).z);
-                let pz_val = sample_terrain_i((*offset).x, (*offset).y, (*offset).z + 1);
-                if nx_val < 0.0
-                    || ny_val < 0.0
-                    || nz_val < 0.0
-                    || px_val < 0.0
-                    || py_val < 0.0
-                    || pz_val < 0.0
-                {
-                    let nx = px_val - val;
-                    let ny = py_val - val;
-                    let nz = pz_val - val;
-                    let inv_mag = 1.0 / (nx * nx + ny * ny + nz * nz).sqrt();
-                    normal = create_normal(nx * inv_mag, ny * inv_mag, nz * inv_mag);
-                }
-            }
-            let mut si = 0;
-            for _ in 0..16 {
-                let sval = sample_terrain_i((*offset).x, (*offset).y, (*offset).z + si);
-                if sval < 0.0 {
-                    break;
-                }
-                si += 1;
-            }
-            if si < 2 {
-                color = create_color(0.2, 0.5, 0.1, 1);
-                id = 1;
-            } else if si < 4 {
-                color = create_color(0.4, 0.3, 0.2, 1);
-                id = 2;
-            } else {
-                let r = stable_rand_3i((*offset).x, (*offset).y, (*offset).z);
-                if r < 0.5 {
-                    color = create_color(0.36, 0.34, 0.34, 1);
-                } else {
-                    color = create_color(0.25, 0.24, 0.23, 1);
-                }
-                id = 3;
+                let nx = px_val - val;
+                let ny = py_val - val;
+                let nz = pz_val - val;
+                let inv_mag = 1.0 / (nx * nx + ny * ny + nz * nz).sqrt();
+                normal = create_normal(nx * inv_mag, ny * inv_mag, nz * inv_mag);
             }
         }
-
-        Ok(if channel_id == gvox_rs::ChannelId::COLOR {
-            gvox_rs::Sample {
-                data: color,
-                is_present: true,
-            }
-        } else if channel_id == gvox_rs::ChannelId::NORMAL {
-            gvox_rs::Sample {
-                data: normal,
-                is_present: true,
-            }
-        } else if channel_id == gvox_rs::ChannelId::MATERIAL_ID {
-            gvox_rs::Sample {
-                data: id,
-                is_present: true,
+        let mut si = 0;
+        for _ in 0..16 {
+            let sval = sample_terrain_i(offset.x, offset.y, offset.z + si);
+            if sval < 0.0 {
+                break;
             }
+            si += 1;
+        }
+        if si < 2 {
+            color = create_color(0.2, 0.5, 0.1, 1);
+            id = 1;
+        } else if si < 4 {
+            color = create_color(0.4, 0.3, 0.2, 1);
+            id = 2;
         } else {
-            gvox_rs::Sample {
-                data: 0,
-                is_present: true,
+            let r = stable_rand_3i(offset.x, offset.y, offset.z);
+            if r < 0.5 {
+                color = create_color(0.36, 0.34, 0.34, 1);
+            } else {
+                color = create_color(0.25, 0.24, 0.23, 1);
             }
-        })
-    }
-
-    fn parse_region(
-        &mut self,
-        blit_ctx: &gvox_rs::ParseBlitContext,
-        range: &gvox_rs::RegionRange,
-        channel_flags: gvox_rs::ChannelFlags,
-    ) -> Result<(), gvox_rs::GvoxError> {
-        let available_channels = gvox_rs::ChannelId::COLOR
-            | gvox_rs::ChannelId::NORMAL
-            | gvox_rs::ChannelId::MATERIAL_ID;
-        if (channel_flags & !available_channels) != gvox_rs::ChannelFlags::empty() {
-            // gvox_sys::gvox_adapter_push_error(
-            //     ctx,
-            //     gvox_rs::RESULT_ERROR_PARSE_ADAPTER_REQUESTED_CHANNEL_NOT_PRESENT,
-            //     cstr!("procedural 'parser' does not generate anything other than color & normal"),
-            // );
+            id = 3;
         }
+    }
 
-        blit_ctx.emit_region(&gvox_rs::Region::new(
-            *range,
-            channel_flags & available_channels,
-            gvox_rs::RegionFlags::empty(),
-            (),
-        ))
+    if channel_id == gvox_rs::ChannelId::COLOR {
+        Some(color)
+    } else if channel_id == gvox_rs::ChannelId::NORMAL {
+        Some(normal)
+    } else if channel_id == gvox_rs::ChannelId::MATERIAL_ID {
+        Some(id)
+    } else {
+        None
     }
 }
 
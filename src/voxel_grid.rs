@@ -0,0 +1,597 @@
+use crate::*;
+
+/// A dense, in-memory box of voxels, which stores a plane of values for each of its channels.
+/// Grids may be the source of blits through the parse half of [`VoxelGridAdapter`], and the
+/// destination of blits through its serialize half.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoxelGrid {
+    /// The range of voxels that the grid holds.
+    range: RegionRange,
+    /// Whether the grid records which voxels have a value. Otherwise, every voxel within the range has one.
+    presence: bool,
+    /// The plane of each channel, indexed by channel ID. Channels which the grid does not hold have no plane.
+    planes: [Option<DensePlane>; CHANNEL_ID_COUNT],
+}
+
+/// The values of a single channel for every voxel within the range of a [`VoxelGrid`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DensePlane {
+    /// The value of each voxel, indexed like [`DenseRegion::data`]. Voxels which have no value are zero.
+    pub data: Vec<u32>,
+    /// Whether each voxel has a value, indexed like `data`. This is empty if the grid does not record
+    /// which voxels have a value.
+    pub mask: Vec<bool>,
+}
+
+impl VoxelGrid {
+    /// Creates a grid over the provided range, which holds the given channels. Every value is initially zero.
+    pub fn new(range: RegionRange, channels: ChannelFlags) -> Self {
+        Self::allocate(range, channels, false)
+    }
+
+    /// Creates a grid over the provided range, which holds the given channels and records which
+    /// voxels have a value. Initially, no voxel has one.
+    pub fn with_presence(range: RegionRange, channels: ChannelFlags) -> Self {
+        Self::allocate(range, channels, true)
+    }
+
+    /// The range of voxels that the grid holds.
+    pub fn range(&self) -> RegionRange {
+        self.range
+    }
+
+    /// Whether the grid records which voxels have a value.
+    pub fn has_presence(&self) -> bool {
+        self.presence
+    }
+
+    /// The set of channels that the grid holds.
+    pub fn channels(&self) -> ChannelFlags {
+        self.planes
+            .iter()
+            .enumerate()
+            .filter(|(_, plane)| plane.is_some())
+            .filter_map(|(index, _)| ChannelId::try_from(index as u32).ok())
+            .fold(ChannelFlags::empty(), |acc, x| acc | x)
+    }
+
+    /// The value of the voxel at the provided position, or `None` if it lies outside of the range,
+    /// the grid does not hold the channel, or the grid records that the voxel has no value.
+    pub fn get(&self, position: &Offset3D, channel_id: ChannelId) -> Option<u32> {
+        let index = self.index(position)?;
+        let plane = self.dense_plane(channel_id)?;
+        (!self.presence || plane.mask[index]).then_some(plane.data[index])
+    }
+
+    /// Sets the value of the voxel at the provided position, which then has a value. Returns an error
+    /// if the position lies outside of the range, or the grid does not hold the channel.
+    pub fn set(
+        &mut self,
+        position: &Offset3D,
+        channel_id: ChannelId,
+        value: u32,
+    ) -> Result<(), GvoxError> {
+        let index = self.index(position).ok_or_else(|| {
+            GvoxError::new(
+                ErrorType::InvalidParameter,
+                format!(
+                    "Position {position:?} lies outside of the grid range {:?}.",
+                    self.range
+                ),
+            )
+        })?;
+        let presence = self.presence;
+        let plane = self.planes[u32::from(channel_id) as usize]
+            .as_mut()
+            .ok_or_else(|| {
+                GvoxError::new(
+                    ErrorType::InvalidParameter,
+                    format!("The grid does not hold channel {channel_id:?}."),
+                )
+            })?;
+        plane.data[index] = value;
+        if presence {
+            plane.mask[index] = true;
+        }
+        Ok(())
+    }
+
+    /// The values of every voxel in the channel, indexed like [`DenseRegion::data`], or `None` if the grid
+    /// does not hold the channel.
+    pub fn plane(&self, channel_id: ChannelId) -> Option<&[u32]> {
+        Some(&self.dense_plane(channel_id)?.data)
+    }
+
+    /// Mutably accesses the values of every voxel in the channel, indexed like [`DenseRegion::data`], or
+    /// `None` if the grid does not hold the channel.
+    pub fn plane_mut(&mut self, channel_id: ChannelId) -> Option<&mut [u32]> {
+        Some(&mut self.planes[u32::from(channel_id) as usize].as_mut()?.data)
+    }
+
+    /// The values of every voxel in the channel together with whether each has a value, or `None` if the
+    /// grid does not hold the channel.
+    pub fn dense_plane(&self, channel_id: ChannelId) -> Option<&DensePlane> {
+        self.planes[u32::from(channel_id) as usize].as_ref()
+    }
+
+    /// Moves the plane of the provided channel out of the grid, which then no longer holds the channel.
+    pub fn take_dense_plane(&mut self, channel_id: ChannelId) -> Option<DensePlane> {
+        self.planes[u32::from(channel_id) as usize].take()
+    }
+
+    /// Copies the values of the channel into a new dense region, or returns `None` if the grid does not hold it.
+    pub fn to_dense(&self, channel_id: ChannelId) -> Option<DenseRegion> {
+        Some(DenseRegion {
+            range: self.range,
+            data: self.plane(channel_id)?.to_vec(),
+        })
+    }
+
+    /// Copies the voxels of the region into every channel that both hold, for the voxels that lie within
+    /// both ranges. Voxels which have no value in the region are given zero.
+    pub fn copy_region(&mut self, region: &RegionRef<'_>) -> Result<(), GvoxError> {
+        let Some(overlap) = overlapping_range(&self.range, &region.range()) else {
+            return Ok(());
+        };
+
+        let row = overlap.extent.x as usize;
+        for channel_id in region.channels() & self.channels() {
+            let samples = region.sample_range(&overlap, channel_id)?;
+            let range = self.range;
+            let presence = self.presence;
+            let Some(plane) = self.planes[u32::from(channel_id) as usize].as_mut() else {
+                continue;
+            };
+
+            for (i, samples) in samples.chunks_exact(row).enumerate() {
+                let start = Offset3D {
+                    x: overlap.offset.x,
+                    y: overlap.offset.y + (i % overlap.extent.y as usize) as i32,
+                    z: overlap.offset.z + (i / overlap.extent.y as usize) as i32,
+                };
+                let to = dense_index(&range, &start);
+                for (x, sample) in samples.iter().enumerate() {
+                    plane.data[to + x] = if sample.is_present { sample.data } else { 0 };
+                    if presence {
+                        plane.mask[to + x] = sample.is_present;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the voxels of the other grid into every channel that both hold, for the voxels that lie
+    /// within both ranges. Voxels which have no value in the other grid are given zero.
+    pub fn copy_grid(&mut self, other: &VoxelGrid) {
+        let Some(overlap) = overlapping_range(&self.range, &other.range) else {
+            return;
+        };
+
+        let row = overlap.extent.x as usize;
+        for (target, source) in self.planes.iter_mut().zip(&other.planes) {
+            let (Some(target), Some(source)) = (target, source) else {
+                continue;
+            };
+            for z in 0..overlap.extent.z as i32 {
+                for y in 0..overlap.extent.y as i32 {
+                    let start = Offset3D {
+                        x: overlap.offset.x,
+                        y: overlap.offset.y + y,
+                        z: overlap.offset.z + z,
+                    };
+                    let from = dense_index(&other.range, &start);
+                    let to = dense_index(&self.range, &start);
+                    for x in 0..row {
+                        let present = !other.presence || source.mask[from + x];
+                        target.data[to + x] = if present { source.data[from + x] } else { 0 };
+                        if self.presence {
+                            target.mask[to + x] = present;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gives every voxel a value of zero, or no value if the grid records which voxels have one.
+    pub fn clear(&mut self) {
+        for plane in self.planes.iter_mut().flatten() {
+            plane.data.fill(0);
+            plane.mask.fill(false);
+        }
+    }
+
+    /// Creates a grid over the provided range, which holds the given channels.
+    fn allocate(range: RegionRange, channels: ChannelFlags, presence: bool) -> Self {
+        let volume = range.volume() as usize;
+        Self {
+            range,
+            presence,
+            planes: std::array::from_fn(|index| {
+                ChannelId::try_from(index as u32)
+                    .ok()
+                    .filter(|&x| channels.contains(x))
+                    .map(|_| DensePlane {
+                        data: vec![0; volume],
+                        mask: if presence {
+                            vec![false; volume]
+                        } else {
+                            Vec::new()
+                        },
+                    })
+            }),
+        }
+    }
+
+    /// The index of the voxel at the provided position within each plane, or `None` if it lies outside of the range.
+    fn index(&self, position: &Offset3D) -> Option<usize> {
+        self.range
+            .contains(position)
+            .then(|| dense_index(&self.range, position))
+    }
+}
+
+impl Default for VoxelGrid {
+    fn default() -> Self {
+        Self::new(RegionRange::default(), ChannelFlags::empty())
+    }
+}
+
+/// Collects the regions of a serialize blit into a [`VoxelGrid`], allocating the grid only once. When the
+/// range of the blit is known as it begins, regions are copied straight into the grid. Otherwise, they are
+/// set aside until the blit ends, and then copied into a grid which is allocated to fit all of them.
+#[derive(Clone, Debug, Default)]
+pub struct VoxelGridBuilder {
+    /// The grid into which regions are copied, once it has been allocated.
+    grid: VoxelGrid,
+    /// The channels which are collected.
+    channels: ChannelFlags,
+    /// The regions which have been set aside, if the range of the blit was not known.
+    pending: Option<Vec<VoxelGrid>>,
+}
+
+impl VoxelGridBuilder {
+    /// Begins collecting the provided channels into a grid. Whether the grid records which voxels have
+    /// a value is taken from the provided grid, whose planes are reused if it already has the range
+    /// of the blit and the same channels.
+    pub fn begin(grid: VoxelGrid, range: Option<&RegionRange>, channels: ChannelFlags) -> Self {
+        let presence = grid.presence;
+        let (grid, pending) = match range {
+            Some(range) if grid.range == *range && grid.channels() == channels => {
+                let mut grid = grid;
+                grid.clear();
+                (grid, None)
+            }
+            Some(range) => (VoxelGrid::allocate(*range, channels, presence), None),
+            None => (
+                VoxelGrid::allocate(RegionRange::default(), channels, presence),
+                Some(Vec::new()),
+            ),
+        };
+
+        Self {
+            grid,
+            channels,
+            pending,
+        }
+    }
+
+    /// The channels which are collected.
+    pub fn channels(&self) -> ChannelFlags {
+        self.channels
+    }
+
+    /// Copies the voxels of the region into the grid, or sets them aside until the blit ends.
+    pub fn insert(&mut self, region: &RegionRef<'_>) -> Result<(), GvoxError> {
+        match &mut self.pending {
+            Some(pending) => {
+                let mut grid =
+                    VoxelGrid::allocate(region.range(), self.channels, self.grid.presence);
+                grid.copy_region(region)?;
+                pending.push(grid);
+                Ok(())
+            }
+            None => self.grid.copy_region(region),
+        }
+    }
+
+    /// Finishes collecting, and returns the grid which holds every region.
+    pub fn finish(self) -> VoxelGrid {
+        let Some(pending) = self.pending else {
+            return self.grid;
+        };
+
+        let bounds = pending
+            .iter()
+            .map(VoxelGrid::range)
+            .reduce(|a, b| bounding_range(&a, &b))
+            .unwrap_or_default();
+        let mut grid = VoxelGrid::allocate(bounds, self.channels, self.grid.presence);
+        for region in &pending {
+            grid.copy_grid(region);
+        }
+        grid
+    }
+}
+
+/// Reads voxels from a [`VoxelGrid`] during parsing, or writes them into one during serialization.
+pub struct VoxelGridAdapter;
+
+impl AdapterDescriptor<Parse> for VoxelGridAdapter {
+    type Configuration<'a> = &'a VoxelGrid;
+    type Handler = VoxelGridReader;
+}
+
+impl AdapterDescriptor<Serialize> for VoxelGridAdapter {
+    type Configuration<'a> = VoxelGridTarget<'a>;
+    type Handler = VoxelGridWriter;
+}
+
+impl NamedAdapter for VoxelGridAdapter {
+    fn name() -> &'static str {
+        "voxel_grid"
+    }
+}
+
+/// Describes the grid into which a [`VoxelGridAdapter`] serializes.
+pub struct VoxelGridTarget<'a> {
+    /// The grid, until it is moved into the adapter context that is created with this configuration.
+    grid: MoveOnce<&'a mut VoxelGrid>,
+}
+
+impl<'a> From<&'a mut VoxelGrid> for VoxelGridTarget<'a> {
+    fn from(value: &'a mut VoxelGrid) -> Self {
+        Self {
+            grid: MoveOnce::new(value),
+        }
+    }
+}
+
+/// Handles the adapter context operations of grids which are parsed.
+pub struct VoxelGridReader {
+    /// The grid from which voxels are read. Its borrow actually lasts only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    grid: &'static VoxelGrid,
+}
+
+impl BaseAdapterHandler<Parse, VoxelGridAdapter> for VoxelGridReader {
+    fn create(config: &&VoxelGrid) -> Result<Self, GvoxError> {
+        Ok(Self {
+            grid: unsafe { transmute::<&VoxelGrid, &'static VoxelGrid>(*config) },
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+}
+
+impl ParseAdapterHandler<VoxelGridAdapter> for VoxelGridReader {
+    type RegionData = ();
+
+    fn query_details() -> ParseAdapterDetails {
+        ParseAdapterDetails::new(BlitMode::DontCare)
+    }
+
+    fn query_parsable_range(&mut self, _: &ParseBlitContext) -> RegionRange {
+        self.grid.range
+    }
+
+    fn query_region_flags(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &RegionRange,
+        _: ChannelFlags,
+    ) -> Result<RegionFlags, GvoxError> {
+        Ok(RegionFlags::empty())
+    }
+
+    fn load_region(
+        &mut self,
+        _: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<Region<()>, GvoxError> {
+        Ok(Region::new(
+            *range,
+            channel_flags & self.grid.channels(),
+            RegionFlags::empty(),
+            (),
+        ))
+    }
+
+    fn unload_region(&mut self, _: &ParseBlitContext, _: Region<()>) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    fn sample_region(
+        &mut self,
+        _: &ParseBlitContext,
+        _: &Region<()>,
+        offset: &Offset3D,
+        channel_id: ChannelId,
+    ) -> Result<Sample, GvoxError> {
+        Ok(match self.grid.get(offset, channel_id) {
+            Some(data) => Sample {
+                data,
+                is_present: true,
+            },
+            None => Sample {
+                data: 0,
+                is_present: false,
+            },
+        })
+    }
+
+    fn parse_region(
+        &mut self,
+        blit_ctx: &ParseBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = self.load_region(blit_ctx, range, channel_flags)?;
+        blit_ctx.emit_region(&region)
+    }
+}
+
+/// Handles the adapter context operations of grids which are serialized into.
+pub struct VoxelGridWriter {
+    /// The grid into which voxels are written. Its borrow actually lasts only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    grid: &'static mut VoxelGrid,
+    /// Collects the voxels of the current blit, which are moved into the grid when it ends.
+    builder: VoxelGridBuilder,
+}
+
+impl BaseAdapterHandler<Serialize, VoxelGridAdapter> for VoxelGridWriter {
+    fn create(config: &VoxelGridTarget<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            grid: unsafe {
+                config
+                    .grid
+                    .take_extended("grid of a voxel grid configuration")?
+            },
+            builder: VoxelGridBuilder::default(),
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    /// When the range of the blit is known, the grid is allocated for it as the blit begins, and its existing
+    /// planes are reused if it already has that range and those channels. Otherwise, the grid is allocated
+    /// once the blit ends, to fit every region that was received.
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        range: Option<&RegionRange>,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.builder = VoxelGridBuilder::begin(take(self.grid), range, channel_flags);
+        Ok(())
+    }
+
+    fn blit_end(&mut self, _: &SerializeBlitContext) -> Result<(), GvoxError> {
+        *self.grid = take(&mut self.builder).finish();
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<VoxelGridAdapter> for VoxelGridWriter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        let region = blit_ctx.load_region_range(range, channel_flags & self.builder.channels())?;
+        self.builder.insert(&region)
+    }
+
+    fn receive_region(
+        &mut self,
+        _: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        self.builder.insert(region)
+    }
+}
+
+/// Writes voxels into several [`VoxelGrid`]s during serialization, each of which receives the voxels within its own
+/// range. Only the voxels within the ranges of the grids are loaded, so ranges which lie far apart may be collected
+/// by a single blit over the range which contains all of them, without loading the voxels between them.
+pub struct VoxelGridListAdapter;
+
+impl AdapterDescriptor<Serialize> for VoxelGridListAdapter {
+    type Configuration<'a> = VoxelGridListTarget<'a>;
+    type Handler = VoxelGridListWriter;
+}
+
+impl NamedAdapter for VoxelGridListAdapter {
+    fn name() -> &'static str {
+        "voxel_grid_list"
+    }
+}
+
+/// Describes the grids into which a [`VoxelGridListAdapter`] serializes.
+pub struct VoxelGridListTarget<'a> {
+    /// The grids, until they are moved into the adapter context that is created with this configuration.
+    grids: MoveOnce<&'a mut [VoxelGrid]>,
+}
+
+impl<'a> From<&'a mut [VoxelGrid]> for VoxelGridListTarget<'a> {
+    fn from(value: &'a mut [VoxelGrid]) -> Self {
+        Self {
+            grids: MoveOnce::new(value),
+        }
+    }
+}
+
+/// Handles the adapter context operations of grid lists which are serialized into.
+pub struct VoxelGridListWriter {
+    /// The grids into which voxels are written. Their borrow actually lasts only as long as the adapter
+    /// context's configuration lifetime, which the adapter context never outlives.
+    grids: &'static mut [VoxelGrid],
+}
+
+impl BaseAdapterHandler<Serialize, VoxelGridListAdapter> for VoxelGridListWriter {
+    fn create(config: &VoxelGridListTarget<'_>) -> Result<Self, GvoxError> {
+        Ok(Self {
+            grids: unsafe {
+                config
+                    .grids
+                    .take_extended("grids of a voxel grid list configuration")?
+            },
+        })
+    }
+
+    fn destroy(self) -> Result<(), GvoxError> {
+        Ok(())
+    }
+
+    /// The grids keep their ranges and channels, and are cleared as the blit begins.
+    fn blit_begin(
+        &mut self,
+        _: &SerializeBlitContext,
+        _: Option<&RegionRange>,
+        _: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        self.grids.iter_mut().for_each(VoxelGrid::clear);
+        Ok(())
+    }
+}
+
+impl SerializeAdapterHandler<VoxelGridListAdapter> for VoxelGridListWriter {
+    type RegionData = ();
+
+    fn serialize_region(
+        &mut self,
+        blit_ctx: &SerializeBlitContext,
+        range: &RegionRange,
+        channel_flags: ChannelFlags,
+    ) -> Result<(), GvoxError> {
+        for grid in self.grids.iter_mut() {
+            if let Some(overlap) = overlapping_range(&grid.range, range) {
+                let region =
+                    blit_ctx.load_region_range(&overlap, channel_flags & grid.channels())?;
+                grid.copy_region(&region)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn receive_region(
+        &mut self,
+        _: &SerializeBlitContext,
+        region: &RegionRef<'_>,
+    ) -> Result<(), GvoxError> {
+        for grid in self.grids.iter_mut() {
+            grid.copy_region(region)?;
+        }
+        Ok(())
+    }
+}